@@ -0,0 +1,152 @@
+use crate::app::state::ActivityEntry;
+use crate::aws::types::ServiceType;
+use crate::utils::error::Result;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+
+/// How many entries are kept, oldest dropped first
+pub const MAX_ACTIVITY_ENTRIES: usize = 500;
+
+/// Every command run from the app, newest first, saved so it survives restarts
+pub struct ActivityLog {
+    entries: VecDeque<ActivityEntry>,
+    max_items: usize,
+    config_path: PathBuf,
+}
+
+impl ActivityLog {
+    pub fn config_path() -> Result<PathBuf> {
+        Ok(dirs::config_dir()
+            .ok_or("Cannot find config directory")?
+            .join("nimbus-ctl")
+            .join("activity_log.json"))
+    }
+
+    pub fn new(max_items: usize) -> Result<Self> {
+        Self::load(max_items, Self::config_path()?)
+    }
+
+    /// The log saved at `config_path`, empty if nothing was saved there yet
+    pub fn load(max_items: usize, config_path: PathBuf) -> Result<Self> {
+        let mut loaded = Self::empty(max_items, config_path);
+        if loaded.config_path.exists() {
+            let content = std::fs::read_to_string(&loaded.config_path)?;
+            let entries: Vec<ActivityEntry> = serde_json::from_str(&content)?;
+            loaded.entries = entries.into_iter().take(max_items).collect();
+        }
+        Ok(loaded)
+    }
+
+    /// An empty log that saves to `config_path`, replacing whatever is there
+    pub fn empty(max_items: usize, config_path: PathBuf) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            max_items,
+            config_path,
+        }
+    }
+
+    pub fn record(&mut self, entry: ActivityEntry) -> Result<()> {
+        self.entries.push_front(entry);
+        self.entries.truncate(self.max_items);
+        self.save()
+    }
+
+    pub fn clear(&mut self) -> Result<()> {
+        self.entries.clear();
+        self.save()
+    }
+
+    /// Newest first
+    pub fn iter(&self) -> impl Iterator<Item = &ActivityEntry> {
+        self.entries.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Services with at least one entry, in display order
+    pub fn services(&self) -> Vec<ServiceType> {
+        ServiceType::all()
+            .into_iter()
+            .filter(|service| self.entries.iter().any(|e| e.service_type == *service))
+            .collect()
+    }
+
+    /// Regions with at least one entry, sorted
+    pub fn regions(&self) -> Vec<String> {
+        let mut regions: Vec<String> = self.entries.iter().map(|e| e.region.clone()).collect();
+        regions.sort();
+        regions.dedup();
+        regions
+    }
+
+    /// Action types with at least one entry, sorted
+    pub fn actions(&self) -> Vec<String> {
+        let mut actions: Vec<String> = self.entries.iter().map(|e| e.action.clone()).collect();
+        actions.sort();
+        actions.dedup();
+        actions
+    }
+
+    /// The entries `filter` keeps, in its sort order
+    pub fn filtered(&self, filter: &ActivityFilter) -> Vec<&ActivityEntry> {
+        let mut entries: Vec<&ActivityEntry> =
+            self.entries.iter().filter(|e| filter.matches(e)).collect();
+        // Entries are recorded in order, so reversing is enough
+        if filter.oldest_first {
+            entries.reverse();
+        }
+        entries
+    }
+
+    fn save(&self) -> Result<()> {
+        if let Some(parent) = self.config_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let content = serde_json::to_string_pretty(&self.entries)?;
+        std::fs::write(&self.config_path, content)?;
+        Ok(())
+    }
+}
+
+/// What the Activity Log page shows; `None` shows every value
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActivityFilter {
+    pub service: Option<ServiceType>,
+    pub region: Option<String>,
+    pub action: Option<String>,
+    pub oldest_first: bool,
+}
+
+impl ActivityFilter {
+    pub fn matches(&self, entry: &ActivityEntry) -> bool {
+        self.service
+            .is_none_or(|service| entry.service_type == service)
+            && self.region.as_ref().is_none_or(|r| entry.region == *r)
+            && self.action.as_ref().is_none_or(|a| entry.action == *a)
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.service.is_some() || self.region.is_some() || self.action.is_some()
+    }
+}
+
+/// The value after `current` in `options`, going from "all" through each
+/// option and back to "all"
+pub fn cycle_option<T: Clone + PartialEq>(current: &Option<T>, options: &[T]) -> Option<T> {
+    match current {
+        None => options.first().cloned(),
+        Some(value) => options
+            .iter()
+            .position(|option| option == value)
+            .and_then(|i| options.get(i + 1))
+            .cloned(),
+    }
+}
@@ -0,0 +1,69 @@
+use std::path::Path;
+
+/// Layout of an exported keybinding cheat sheet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheatSheetFormat {
+    Markdown,
+    PlainText,
+}
+
+impl CheatSheetFormat {
+    /// Markdown for `.md`/`.markdown` files, plain text for anything else
+    pub fn for_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown") => {
+                CheatSheetFormat::Markdown
+            }
+            _ => CheatSheetFormat::PlainText,
+        }
+    }
+}
+
+/// A titled group of (keys, action) rows
+#[derive(Debug, Clone)]
+pub struct CheatSheetSection {
+    pub title: String,
+    pub bindings: Vec<(String, String)>,
+}
+
+pub fn render_cheat_sheet(sections: &[CheatSheetSection], format: CheatSheetFormat) -> String {
+    let mut out = String::new();
+    match format {
+        CheatSheetFormat::Markdown => {
+            out.push_str("# Nimbus CTL Keybindings\n");
+            for section in sections.iter().filter(|s| !s.bindings.is_empty()) {
+                out.push_str(&format!(
+                    "\n## {}\n\n| Keys | Action |\n| --- | --- |\n",
+                    section.title
+                ));
+                for (keys, action) in &section.bindings {
+                    out.push_str(&format!(
+                        "| `{}` | {} |\n",
+                        keys.replace('|', "\\|"),
+                        action.replace('|', "\\|")
+                    ));
+                }
+            }
+        }
+        CheatSheetFormat::PlainText => {
+            out.push_str("NIMBUS CTL KEYBINDINGS\n");
+            for section in sections.iter().filter(|s| !s.bindings.is_empty()) {
+                out.push_str(&format!(
+                    "\n{}\n{}\n",
+                    section.title,
+                    "-".repeat(section.title.len())
+                ));
+                let width = section
+                    .bindings
+                    .iter()
+                    .map(|(keys, _)| keys.chars().count())
+                    .max()
+                    .unwrap_or(0);
+                for (keys, action) in &section.bindings {
+                    out.push_str(&format!("  {:<width$}  {}\n", keys, action, width = width));
+                }
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,46 @@
+use crate::aws::types::{EksCluster, Resource};
+use std::time::{Duration, Instant};
+
+/// How long creation is tracked before giving up on the cluster ever
+/// reaching a terminal status
+pub const CLUSTER_CREATION_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// An EKS cluster between CreateCluster and a terminal status. It is polled
+/// in the background wherever the user is, and listed as "creating…" until
+/// a listing of its region includes it.
+#[derive(Debug, Clone)]
+pub struct ClusterCreation {
+    pub region: String,
+    /// The cluster as last described, standing in for it in the resource list
+    pub resource: Resource,
+    pub started_at: Instant,
+    pub polled_at: Option<Instant>,
+}
+
+impl ClusterCreation {
+    pub fn new(region: String, cluster: &EksCluster) -> Self {
+        Self {
+            resource: cluster.to_resource(&region),
+            region,
+            started_at: Instant::now(),
+            polled_at: None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.resource.name
+    }
+
+    pub fn status(&self) -> &str {
+        &self.resource.state
+    }
+
+    pub fn timed_out(&self) -> bool {
+        self.started_at.elapsed() >= CLUSTER_CREATION_TIMEOUT
+    }
+}
+
+/// Whether EKS is done with a cluster's creation, one way or the other
+pub fn is_terminal_status(status: &str) -> bool {
+    matches!(status, "ACTIVE" | "FAILED")
+}
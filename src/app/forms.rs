@@ -0,0 +1,955 @@
+use crate::aws::credentials::{parse_role_arn, AssumeRoleRequest};
+use crate::aws::types::{
+    Ec2Instance, EksNodeGroup, InstanceLaunch, Resource, SecurityGroup, ServiceType,
+};
+use crate::command::Command;
+use crate::ui::pages::dashboard::favorites::FavoriteResource;
+use crate::utils::helpers::pop_grapheme;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Room reserved up front so typical secrets fit without the buffer having to grow
+const MASKED_INPUT_CAPACITY: usize = 256;
+
+/// Text entry for a secret (a password, secret value or MFA code) that is
+/// drawn masked unless revealed. `Debug` never prints the text, the field
+/// cannot be cloned, and its buffer is wiped when it grows, when it is
+/// cleared and when it is dropped, i.e. when its form closes. Text handed
+/// out by `value` or `display` is the caller's to look after.
+pub struct MaskedInput {
+    value: String,
+    revealed: bool,
+}
+
+impl MaskedInput {
+    pub fn new() -> Self {
+        Self {
+            value: String::with_capacity(MASKED_INPUT_CAPACITY),
+            revealed: false,
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    pub fn is_revealed(&self) -> bool {
+        self.revealed
+    }
+
+    pub fn toggle_reveal(&mut self) {
+        self.revealed = !self.revealed;
+    }
+
+    pub fn push(&mut self, c: char) {
+        if self.value.len() + c.len_utf8() > self.value.capacity() {
+            // Grow by hand so the old buffer is wiped rather than freed as it is
+            let mut grown = String::with_capacity(self.value.capacity() * 2);
+            grown.push_str(&self.value);
+            self.clear();
+            self.value = grown;
+        }
+        self.value.push(c);
+    }
+
+    pub fn pop(&mut self) {
+        pop_grapheme(&mut self.value);
+    }
+
+    /// What to draw: the text when revealed, otherwise one bullet per character
+    /// as the user sees it (a combining accent does not add a bullet)
+    pub fn display(&self) -> String {
+        if self.revealed {
+            self.value.clone()
+        } else {
+            "•".repeat(self.value.graphemes(true).count())
+        }
+    }
+
+    /// Overwrite the text in place before forgetting it
+    pub fn clear(&mut self) {
+        let len = self.value.len();
+        self.value.clear();
+        // Within capacity, so this writes over the old bytes rather than reallocating
+        self.value.extend(std::iter::repeat_n('\0', len));
+        std::hint::black_box(&self.value);
+        self.value.clear();
+    }
+}
+
+impl Default for MaskedInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for MaskedInput {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl fmt::Debug for MaskedInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MaskedInput")
+            .field("len", &self.value.len())
+            .field("revealed", &self.revealed)
+            .finish()
+    }
+}
+
+/// The focused field of a form: plain text, or a secret kept masked
+enum FocusedField<'a> {
+    Text(&'a mut String),
+    Secret(&'a mut MaskedInput),
+}
+
+impl FocusedField<'_> {
+    fn push(self, c: char) {
+        match self {
+            FocusedField::Text(text) => text.push(c),
+            FocusedField::Secret(input) => input.push(c),
+        }
+    }
+
+    fn pop(self) {
+        match self {
+            FocusedField::Text(text) => {
+                pop_grapheme(text);
+            }
+            FocusedField::Secret(input) => input.pop(),
+        }
+    }
+}
+
+/// How the value of a new secret is entered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretValueMode {
+    Plain,
+    KeyValue,
+}
+
+/// Input state for the Create Secret overlay
+#[derive(Debug)]
+pub struct SecretForm {
+    pub name: String,
+    pub mode: SecretValueMode,
+    pub value: MaskedInput,
+    pub pairs: Vec<(String, MaskedInput)>,
+    /// 0 is the name; plain mode has the value at 1, key/value mode has
+    /// each pair's key and value at 1 + 2i and 2 + 2i
+    pub focus: usize,
+    pub error: Option<String>,
+}
+
+impl SecretForm {
+    pub fn new() -> Self {
+        Self {
+            name: String::new(),
+            mode: SecretValueMode::Plain,
+            value: MaskedInput::new(),
+            pairs: vec![(String::new(), MaskedInput::new())],
+            focus: 0,
+            error: None,
+        }
+    }
+
+    fn field_count(&self) -> usize {
+        match self.mode {
+            SecretValueMode::Plain => 2,
+            SecretValueMode::KeyValue => 1 + self.pairs.len() * 2,
+        }
+    }
+
+    pub fn focus_next(&mut self) {
+        self.focus = (self.focus + 1) % self.field_count();
+    }
+
+    pub fn focus_previous(&mut self) {
+        let count = self.field_count();
+        self.focus = (self.focus + count - 1) % count;
+    }
+
+    pub fn toggle_mode(&mut self) {
+        self.mode = match self.mode {
+            SecretValueMode::Plain => SecretValueMode::KeyValue,
+            SecretValueMode::KeyValue => SecretValueMode::Plain,
+        };
+        self.focus = self.focus.min(self.field_count() - 1);
+    }
+
+    /// Append an empty pair and focus its key
+    pub fn add_pair(&mut self) {
+        if self.mode == SecretValueMode::KeyValue {
+            self.pairs.push((String::new(), MaskedInput::new()));
+            self.focus = self.field_count() - 2;
+        }
+    }
+
+    fn focused_field(&mut self) -> FocusedField<'_> {
+        match (self.mode, self.focus) {
+            (_, 0) => FocusedField::Text(&mut self.name),
+            (SecretValueMode::Plain, _) => FocusedField::Secret(&mut self.value),
+            (SecretValueMode::KeyValue, focus) => {
+                let (key, value) = &mut self.pairs[(focus - 1) / 2];
+                if focus % 2 == 1 {
+                    FocusedField::Text(key)
+                } else {
+                    FocusedField::Secret(value)
+                }
+            }
+        }
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        self.error = None;
+        self.focused_field().push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.error = None;
+        self.focused_field().pop();
+    }
+
+    /// Show or hide the focused value; names and keys are never masked
+    pub fn toggle_reveal(&mut self) {
+        if let FocusedField::Secret(input) = self.focused_field() {
+            input.toggle_reveal();
+        }
+    }
+
+    /// Validate the form, returning the name and the assembled `SecretString`
+    pub fn build(&self) -> Result<(String, String), String> {
+        validate_secret_name(&self.name)?;
+
+        let secret_string = match self.mode {
+            SecretValueMode::Plain => {
+                if self.value.is_empty() {
+                    return Err("Secret value cannot be empty".to_string());
+                }
+                self.value.value().to_string()
+            }
+            SecretValueMode::KeyValue => {
+                let mut object = Map::new();
+                for (key, value) in self.pairs.iter().filter(|(key, _)| !key.is_empty()) {
+                    if object
+                        .insert(key.clone(), Value::String(value.value().to_string()))
+                        .is_some()
+                    {
+                        return Err(format!("Duplicate key '{}'", key));
+                    }
+                }
+                if object.is_empty() {
+                    return Err("Add at least one key/value pair".to_string());
+                }
+                Value::Object(object).to_string()
+            }
+        };
+
+        Ok((self.name.clone(), secret_string))
+    }
+}
+
+impl Default for SecretForm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Input state for the Create Bucket prompt
+#[derive(Debug, Clone, Default)]
+pub struct BucketForm {
+    pub name: String,
+    pub error: Option<String>,
+}
+
+impl BucketForm {
+    pub fn insert_char(&mut self, c: char) {
+        self.error = None;
+        self.name.push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.error = None;
+        pop_grapheme(&mut self.name);
+    }
+}
+
+/// Input state for the Create Cluster prompt
+#[derive(Debug, Clone, Default)]
+pub struct ClusterForm {
+    pub name: String,
+    pub error: Option<String>,
+}
+
+impl ClusterForm {
+    pub fn insert_char(&mut self, c: char) {
+        self.error = None;
+        self.name.push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.error = None;
+        pop_grapheme(&mut self.name);
+    }
+}
+
+/// Input state for the Go to View prompt
+#[derive(Debug, Clone, Default)]
+pub struct ViewStateForm {
+    pub input: String,
+    pub error: Option<String>,
+}
+
+impl ViewStateForm {
+    pub fn insert_char(&mut self, c: char) {
+        self.error = None;
+        self.input.push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.error = None;
+        pop_grapheme(&mut self.input);
+    }
+}
+
+/// Input state for the Assume Role prompt
+#[derive(Debug, Default)]
+pub struct AssumeRoleForm {
+    pub role_arn: String,
+    pub external_id: String,
+    pub mfa_serial: String,
+    pub mfa_code: MaskedInput,
+    /// 0 role ARN, 1 external id, 2 MFA serial, 3 MFA code
+    pub focus: usize,
+    pub error: Option<String>,
+}
+
+impl AssumeRoleForm {
+    const FIELD_COUNT: usize = 4;
+
+    pub fn focus_next(&mut self) {
+        self.focus = (self.focus + 1) % Self::FIELD_COUNT;
+    }
+
+    pub fn focus_previous(&mut self) {
+        self.focus = (self.focus + Self::FIELD_COUNT - 1) % Self::FIELD_COUNT;
+    }
+
+    fn focused_field(&mut self) -> FocusedField<'_> {
+        match self.focus {
+            0 => FocusedField::Text(&mut self.role_arn),
+            1 => FocusedField::Text(&mut self.external_id),
+            2 => FocusedField::Text(&mut self.mfa_serial),
+            _ => FocusedField::Secret(&mut self.mfa_code),
+        }
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        self.error = None;
+        self.focused_field().push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.error = None;
+        self.focused_field().pop();
+    }
+
+    /// Show or hide the MFA code while it is focused
+    pub fn toggle_reveal(&mut self) {
+        if let FocusedField::Secret(input) = self.focused_field() {
+            input.toggle_reveal();
+        }
+    }
+
+    /// Validate the form; external id and MFA are optional
+    pub fn build(&self) -> Result<AssumeRoleRequest, String> {
+        let role_arn = self.role_arn.trim();
+        parse_role_arn(role_arn)?;
+
+        let serial = self.mfa_serial.trim();
+        let code = self.mfa_code.value().trim();
+        let mfa = match (serial.is_empty(), code.is_empty()) {
+            (true, true) => None,
+            (true, false) => return Err("Enter the MFA device serial for the code".to_string()),
+            (false, true) => return Err("Enter the current MFA code".to_string()),
+            (false, false) => {
+                if code.len() != 6 || !code.chars().all(|c| c.is_ascii_digit()) {
+                    return Err("MFA code must be 6 digits".to_string());
+                }
+                Some((serial.to_string(), code.to_string()))
+            }
+        };
+
+        let external_id = self.external_id.trim();
+        Ok(AssumeRoleRequest {
+            role_arn: role_arn.to_string(),
+            external_id: (!external_id.is_empty()).then(|| external_id.to_string()),
+            mfa,
+        })
+    }
+}
+
+/// The instance a Clone Instance form was pre-filled from, and the values it
+/// had, so edited fields stop being shown as inherited
+#[derive(Debug, Clone)]
+pub struct CloneSource {
+    pub instance_id: String,
+    original: [String; InstanceForm::FIELD_COUNT],
+}
+
+/// Input state for the Create Instance prompt, blank or pre-filled by Clone
+/// Instance. Security groups and tags are comma separated, tags as `key=value`.
+#[derive(Debug, Clone)]
+pub struct InstanceForm {
+    pub instance_type: String,
+    pub image_id: String,
+    pub subnet_id: String,
+    pub security_groups: String,
+    pub tags: String,
+    /// Where the instance is launched: the source's region when cloning
+    pub region: String,
+    pub source: Option<CloneSource>,
+    /// 0 type, 1 AMI, 2 subnet, 3 security groups, 4 tags
+    pub focus: usize,
+    pub error: Option<String>,
+}
+
+impl InstanceForm {
+    pub const FIELD_COUNT: usize = 5;
+    pub const LABELS: [&'static str; Self::FIELD_COUNT] = [
+        "Instance type",
+        "AMI",
+        "Subnet (optional)",
+        "Security groups",
+        "Tags",
+    ];
+
+    pub fn new(region: &str) -> Self {
+        Self {
+            instance_type: String::new(),
+            image_id: String::new(),
+            subnet_id: String::new(),
+            security_groups: String::new(),
+            tags: String::new(),
+            region: region.to_string(),
+            source: None,
+            focus: 0,
+            error: None,
+        }
+    }
+
+    /// A form launching in `region` with everything `instance` was launched with
+    pub fn clone_of(instance: &Ec2Instance, region: &str) -> Self {
+        let mut tags: Vec<String> = instance
+            .tags
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        tags.sort();
+
+        let mut form = Self {
+            instance_type: instance.instance_type.clone(),
+            image_id: instance.image_id.clone().unwrap_or_default(),
+            subnet_id: instance.subnet_id.clone().unwrap_or_default(),
+            security_groups: instance.security_groups.join(", "),
+            tags: tags.join(", "),
+            ..Self::new(region)
+        };
+        form.source = Some(CloneSource {
+            instance_id: instance.instance_id.clone(),
+            original: form.values().map(str::to_string),
+        });
+        form
+    }
+
+    pub fn values(&self) -> [&str; Self::FIELD_COUNT] {
+        [
+            &self.instance_type,
+            &self.image_id,
+            &self.subnet_id,
+            &self.security_groups,
+            &self.tags,
+        ]
+    }
+
+    /// Whether field `index` still holds the value copied from the source
+    pub fn is_inherited(&self, index: usize) -> bool {
+        self.source.as_ref().is_some_and(|source| {
+            !source.original[index].is_empty() && source.original[index] == self.values()[index]
+        })
+    }
+
+    pub fn focus_next(&mut self) {
+        self.focus = (self.focus + 1) % Self::FIELD_COUNT;
+    }
+
+    pub fn focus_previous(&mut self) {
+        self.focus = (self.focus + Self::FIELD_COUNT - 1) % Self::FIELD_COUNT;
+    }
+
+    fn focused_field(&mut self) -> &mut String {
+        match self.focus {
+            0 => &mut self.instance_type,
+            1 => &mut self.image_id,
+            2 => &mut self.subnet_id,
+            3 => &mut self.security_groups,
+            _ => &mut self.tags,
+        }
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        self.error = None;
+        self.focused_field().push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.error = None;
+        pop_grapheme(self.focused_field());
+    }
+
+    /// Validate the form; the subnet and tags are optional
+    pub fn build(&self) -> Result<InstanceLaunch, String> {
+        let instance_type = self.instance_type.trim();
+        if instance_type.is_empty() {
+            return Err("Enter an instance type, e.g. t3.micro".to_string());
+        }
+        let image_id = self.image_id.trim();
+        if !image_id.starts_with("ami-") {
+            return Err("Enter an AMI id starting with ami-".to_string());
+        }
+        let subnet_id = self.subnet_id.trim();
+        if !subnet_id.is_empty() && !subnet_id.starts_with("subnet-") {
+            return Err("Subnet ids start with subnet-".to_string());
+        }
+
+        let security_group_ids: Vec<String> = split_list(&self.security_groups)
+            .map(str::to_string)
+            .collect();
+        let tags = parse_tags(&self.tags)?;
+
+        Ok(InstanceLaunch {
+            instance_type: instance_type.to_string(),
+            image_id: image_id.to_string(),
+            subnet_id: (!subnet_id.is_empty()).then(|| subnet_id.to_string()),
+            security_group_ids,
+            tags,
+        })
+    }
+}
+
+/// The non-empty items of a comma separated list
+fn split_list(text: &str) -> impl Iterator<Item = &str> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
+
+/// `key=value, key=value` as (key, value) pairs, in the order written
+fn parse_tags(text: &str) -> Result<Vec<(String, String)>, String> {
+    split_list(text)
+        .map(|tag| match tag.split_once('=') {
+            Some((key, value)) if !key.trim().is_empty() => {
+                Ok((key.trim().to_string(), value.trim().to_string()))
+            }
+            _ => Err(format!("Tag '{}' is not key=value", tag)),
+        })
+        .collect()
+}
+
+/// Input state for the Edit Tags prompt: the resource's whole tag set as
+/// `key=value, key=value`, reviewed as a diff before anything is sent
+#[derive(Debug, Clone)]
+pub struct TagEditForm {
+    pub service_type: ServiceType,
+    pub resource_id: String,
+    pub name: String,
+    pub tags: String,
+    pub error: Option<String>,
+}
+
+impl TagEditForm {
+    pub fn new(resource: &Resource) -> Self {
+        let mut tags: Vec<String> = resource
+            .tags
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        tags.sort();
+        Self {
+            service_type: resource.service_type,
+            resource_id: resource.id.clone(),
+            name: resource.name.clone(),
+            tags: tags.join(", "),
+            error: None,
+        }
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        self.error = None;
+        self.tags.push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.error = None;
+        pop_grapheme(&mut self.tags);
+    }
+
+    /// The edited tag set; a key written twice keeps its last value
+    pub fn edited_tags(&self) -> Result<HashMap<String, String>, String> {
+        Ok(parse_tags(&self.tags)?.into_iter().collect())
+    }
+}
+
+/// Input state for the Export Keybinding Cheat Sheet prompt
+#[derive(Debug, Clone)]
+pub struct CheatSheetForm {
+    pub path: String,
+    pub error: Option<String>,
+}
+
+impl CheatSheetForm {
+    pub fn new() -> Self {
+        Self {
+            path: "~/nimbus-ctl-keybindings.md".to_string(),
+            error: None,
+        }
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        self.error = None;
+        self.path.push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.error = None;
+        pop_grapheme(&mut self.path);
+    }
+
+    /// The entered path with a leading `~/` expanded to the home directory
+    pub fn resolved_path(&self) -> Result<PathBuf, String> {
+        resolve_path(&self.path)
+    }
+}
+
+impl Default for CheatSheetForm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An entered file path with a leading `~/` expanded to the home directory
+fn resolve_path(path: &str) -> Result<PathBuf, String> {
+    let path = path.trim();
+    if path.is_empty() {
+        return Err("Enter a file path".to_string());
+    }
+    match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir()
+            .map(|home| home.join(rest))
+            .ok_or_else(|| "Cannot find home directory".to_string()),
+        None => Ok(PathBuf::from(path)),
+    }
+}
+
+/// Input state for the Export Resource List prompt
+#[derive(Debug, Clone)]
+pub struct ResourceExportForm {
+    pub service_type: ServiceType,
+    pub path: String,
+    /// Export every cached resource instead of only the filtered rows
+    pub include_all: bool,
+    pub error: Option<String>,
+}
+
+impl ResourceExportForm {
+    pub fn new(service_type: ServiceType, region: &str) -> Self {
+        let service = service_type.display_name().to_lowercase().replace(' ', "-");
+        Self {
+            service_type,
+            path: format!("~/nimbus-ctl-{}-{}.csv", service, region),
+            include_all: false,
+            error: None,
+        }
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        self.error = None;
+        self.path.push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.error = None;
+        pop_grapheme(&mut self.path);
+    }
+
+    pub fn toggle_include_all(&mut self) {
+        self.include_all = !self.include_all;
+    }
+
+    /// The entered path with a leading `~/` expanded to the home directory
+    pub fn resolved_path(&self) -> Result<PathBuf, String> {
+        resolve_path(&self.path)
+    }
+}
+
+/// Input state for the Update Node Group Size prompt
+#[derive(Debug, Clone)]
+pub struct NodeGroupSizeForm {
+    pub cluster: String,
+    pub node_group: String,
+    pub current: i32,
+    pub min_size: i32,
+    pub max_size: i32,
+    pub input: String,
+    pub error: Option<String>,
+}
+
+impl NodeGroupSizeForm {
+    pub fn new(cluster: &str, node_group: &EksNodeGroup) -> Self {
+        Self {
+            cluster: cluster.to_string(),
+            node_group: node_group.name.clone(),
+            current: node_group.desired_size,
+            min_size: node_group.min_size,
+            max_size: node_group.max_size,
+            input: node_group.desired_size.to_string(),
+            error: None,
+        }
+    }
+
+    /// Only digits can be typed, so the input never holds a sign or separator
+    pub fn insert_char(&mut self, c: char) {
+        if c.is_ascii_digit() {
+            self.error = None;
+            self.input.push(c);
+        }
+    }
+
+    pub fn backspace(&mut self) {
+        self.error = None;
+        pop_grapheme(&mut self.input);
+    }
+
+    /// The entered desired size, if it lies within the node group's min/max
+    pub fn desired_size(&self) -> Result<i32, String> {
+        let desired: i32 = self
+            .input
+            .parse()
+            .map_err(|_| "Enter the number of nodes".to_string())?;
+        if desired < self.min_size || desired > self.max_size {
+            return Err(format!(
+                "Desired size must be between {} and {}",
+                self.min_size, self.max_size
+            ));
+        }
+        if desired == self.current {
+            return Err(format!("Node group already has {} nodes", desired));
+        }
+        Ok(desired)
+    }
+}
+
+/// The Favorites overlay; rows are the favorite resources followed by the
+/// favorite commands
+#[derive(Debug, Clone, Default)]
+pub struct FavoritesOverlay {
+    pub selected: usize,
+}
+
+/// Prompt for the note kept with a favorite resource
+#[derive(Debug, Clone)]
+pub struct FavoriteNoteForm {
+    pub resource_id: String,
+    pub name: String,
+    pub note: String,
+}
+
+impl FavoriteNoteForm {
+    pub fn new(favorite: &FavoriteResource) -> Self {
+        Self {
+            resource_id: favorite.id.clone(),
+            name: favorite.name.clone(),
+            note: favorite.note.clone().unwrap_or_default(),
+        }
+    }
+
+    /// The trimmed note, or `None` to clear it
+    pub fn note(&self) -> Option<String> {
+        let note = self.note.trim();
+        (!note.is_empty()).then(|| note.to_string())
+    }
+}
+
+/// Prompt for the name to save the current profile and region under
+#[derive(Debug, Clone)]
+pub struct PresetForm {
+    pub profile: String,
+    pub region: String,
+    pub name: String,
+    pub error: Option<String>,
+}
+
+impl PresetForm {
+    pub fn new(profile: &str, region: &str) -> Self {
+        Self {
+            profile: profile.to_string(),
+            region: region.to_string(),
+            name: String::new(),
+            error: None,
+        }
+    }
+
+    pub fn insert_char(&mut self, c: char) {
+        self.error = None;
+        self.name.push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.error = None;
+        pop_grapheme(&mut self.name);
+    }
+}
+
+/// The detail page's action menu: the service commands that apply to the
+/// shown resource, as the palette would offer them
+#[derive(Debug, Clone)]
+pub struct ActionMenu {
+    pub resource_name: String,
+    pub commands: Vec<Command>,
+    pub selected: usize,
+}
+
+impl ActionMenu {
+    pub fn new(resource_name: String, commands: Vec<Command>) -> Self {
+        Self {
+            resource_name,
+            commands,
+            selected: 0,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.commands.is_empty() {
+            self.selected = (self.selected + 1) % self.commands.len();
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if !self.commands.is_empty() {
+            self.selected = (self.selected + self.commands.len() - 1) % self.commands.len();
+        }
+    }
+
+    pub fn selected_command(&self) -> Option<&Command> {
+        self.commands.get(self.selected)
+    }
+}
+
+/// The Notifications overlay over the notifications still active, newest first
+#[derive(Debug, Clone, Default)]
+pub struct NotificationOverlay {
+    pub selected: usize,
+}
+
+/// Picker for narrowing a resource list to one availability zone
+#[derive(Debug, Clone)]
+pub struct ZonePicker {
+    pub service_type: ServiceType,
+    pub zones: Vec<String>,
+    /// 0 is "All zones", which clears the filter; zone i is at i + 1
+    pub selected: usize,
+}
+
+impl ZonePicker {
+    pub fn new(service_type: ServiceType, zones: Vec<String>, current: Option<&str>) -> Self {
+        let selected = current
+            .and_then(|zone| zones.iter().position(|z| z == zone))
+            .map_or(0, |i| i + 1);
+        Self {
+            service_type,
+            zones,
+            selected,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % (self.zones.len() + 1);
+    }
+
+    pub fn select_previous(&mut self) {
+        let count = self.zones.len() + 1;
+        self.selected = (self.selected + count - 1) % count;
+    }
+
+    /// The highlighted zone, or `None` for "All zones"
+    pub fn selected_zone(&self) -> Option<&str> {
+        self.selected
+            .checked_sub(1)
+            .and_then(|i| self.zones.get(i))
+            .map(String::as_str)
+    }
+}
+
+/// Payloads above this size are only copied after a second confirming key press
+pub const LARGE_COPY_BYTES: usize = 1024 * 1024;
+
+/// Read-only view of an EC2 instance's security group rules
+#[derive(Debug, Clone)]
+pub struct SecurityGroupInspector {
+    pub instance_id: String,
+    pub groups: Vec<SecurityGroup>,
+    pub scroll: u16,
+}
+
+/// Read-only view of a resource's describe output as pretty-printed JSON
+#[derive(Debug, Clone)]
+pub struct RawInspector {
+    pub title: String,
+    pub json: String,
+    pub scroll: u16,
+    /// Set after the size warning so the next copy goes through
+    pub large_copy_confirmed: bool,
+}
+
+impl RawInspector {
+    pub fn new(title: String, json: String) -> Self {
+        Self {
+            title,
+            json,
+            scroll: 0,
+            large_copy_confirmed: false,
+        }
+    }
+
+    pub fn is_large(&self) -> bool {
+        self.json.len() > LARGE_COPY_BYTES
+    }
+}
+
+/// Secrets Manager names are 1-512 characters of letters, digits and `/_+=.@-`
+pub fn validate_secret_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Secret name cannot be empty".to_string());
+    }
+    if name.chars().count() > 512 {
+        return Err("Secret name must be at most 512 characters".to_string());
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || "/_+=.@-".contains(*c)))
+    {
+        return Err(format!(
+            "Secret name cannot contain '{}'; use letters, digits and /_+=.@-",
+            c
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,71 @@
+/// Where data fetched from AWS stands, so "not loaded yet", "loading",
+/// "loaded but empty" and "failed" are never confused with one another
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum LoadState<T> {
+    #[default]
+    NotLoaded,
+    Loading,
+    Loaded(T),
+    Failed(String),
+}
+
+impl<T> LoadState<T> {
+    pub fn loaded(&self) -> Option<&T> {
+        match self {
+            LoadState::Loaded(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn loaded_mut(&mut self) -> Option<&mut T> {
+        match self {
+            LoadState::Loaded(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn is_loading(&self) -> bool {
+        matches!(self, LoadState::Loading)
+    }
+
+    /// Mark a load as started. Data already loaded stays in place while it
+    /// refreshes rather than flashing back to a spinner.
+    pub fn start(&mut self) {
+        if !matches!(self, LoadState::Loaded(_)) {
+            *self = LoadState::Loading;
+        }
+    }
+
+    /// Record a failed load. Data already loaded is kept; the failure is
+    /// reported separately.
+    pub fn fail(&mut self, error: String) {
+        if !matches!(self, LoadState::Loaded(_)) {
+            *self = LoadState::Failed(error);
+        }
+    }
+
+    /// Forget a load that ended without a result, e.g. because it was cancelled
+    pub fn abandon(&mut self) {
+        if self.is_loading() {
+            *self = LoadState::NotLoaded;
+        }
+    }
+
+    pub fn as_ref(&self) -> LoadState<&T> {
+        match self {
+            LoadState::NotLoaded => LoadState::NotLoaded,
+            LoadState::Loading => LoadState::Loading,
+            LoadState::Loaded(value) => LoadState::Loaded(value),
+            LoadState::Failed(error) => LoadState::Failed(error.clone()),
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> LoadState<U> {
+        match self {
+            LoadState::NotLoaded => LoadState::NotLoaded,
+            LoadState::Loading => LoadState::Loading,
+            LoadState::Loaded(value) => LoadState::Loaded(f(value)),
+            LoadState::Failed(error) => LoadState::Failed(error),
+        }
+    }
+}
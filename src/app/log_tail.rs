@@ -0,0 +1,69 @@
+use crate::aws::types::{LogEvent, ResourceId, ServiceType};
+use chrono::{DateTime, Utc};
+use std::collections::VecDeque;
+use std::time::Instant;
+
+/// Lines kept in a tail's scrollback before the oldest are dropped
+pub const LOG_TAIL_SCROLLBACK: usize = 5_000;
+
+/// How far back the first read of a tail reaches
+const LOG_TAIL_BACKFILL: chrono::Duration = chrono::Duration::minutes(10);
+
+/// The log tail view: CloudWatch Logs events of one resource, polled while open
+#[derive(Debug, Clone)]
+pub struct LogTail {
+    pub service_type: ServiceType,
+    pub resource_id: ResourceId,
+    pub region: String,
+    pub log_groups: Vec<String>,
+    pub events: VecDeque<LogEvent>,
+    /// Where the next poll starts reading
+    pub next_start: DateTime<Utc>,
+    pub polled_at: Option<Instant>,
+    /// Why the last poll failed; cleared by the next successful one
+    pub error: Option<String>,
+    pub scroll: u16,
+    /// Keep the newest line in view as events arrive
+    pub follow: bool,
+}
+
+impl LogTail {
+    pub fn new(
+        service_type: ServiceType,
+        resource_id: ResourceId,
+        region: String,
+        log_groups: Vec<String>,
+    ) -> Self {
+        Self {
+            service_type,
+            resource_id,
+            region,
+            log_groups,
+            events: VecDeque::new(),
+            next_start: Utc::now() - LOG_TAIL_BACKFILL,
+            polled_at: None,
+            error: None,
+            scroll: 0,
+            follow: true,
+        }
+    }
+
+    /// Add events from a poll, skipping any already shown and dropping the
+    /// oldest past `LOG_TAIL_SCROLLBACK`
+    pub fn append(&mut self, events: Vec<LogEvent>) {
+        for event in events {
+            // Polls start at the newest timestamp seen, so its events come back again
+            if event.timestamp < self.next_start
+                || (event.timestamp == self.next_start && self.events.contains(&event))
+            {
+                continue;
+            }
+            self.next_start = event.timestamp;
+            self.events.push_back(event);
+        }
+        while self.events.len() > LOG_TAIL_SCROLLBACK {
+            self.events.pop_front();
+        }
+        self.error = None;
+    }
+}
@@ -1,8 +1,25 @@
+pub mod activity_log;
+pub mod cheat_sheet;
+pub mod cluster_creation;
 pub mod config;
 pub mod events;
+pub mod forms;
+pub mod load_state;
+pub mod log_tail;
+pub mod operations;
+pub mod resource_diff;
+pub mod resource_export;
+pub mod service_health;
 pub mod settings;
+pub mod setup_wizard;
 pub mod startup;
 pub mod state;
+pub mod view_state;
+
+#[cfg(test)]
+mod operations_test;
+#[cfg(test)]
+mod state_test;
 
 use crate::utils::error::Result;
 use crossterm::event::KeyEvent;
@@ -13,8 +30,8 @@ pub struct App {
 }
 
 impl App {
-    pub async fn new() -> Result<Self> {
-        let state = AppState::new().await?;
+    pub async fn new(demo: bool) -> Result<Self> {
+        let state = AppState::new(demo).await?;
         Ok(Self { state })
     }
 
@@ -22,7 +39,19 @@ impl App {
         self.state.handle_input(key).await
     }
 
+    pub fn handle_resize(&mut self, width: u16, height: u16) {
+        self.state.handle_resize(width, height);
+    }
+
+    pub fn handle_focus_change(&mut self, focused: bool) {
+        self.state.handle_focus_change(focused);
+    }
+
     pub async fn update(&mut self) -> Result<()> {
         self.state.update().await
     }
+
+    pub async fn shutdown(&mut self) {
+        self.state.shutdown().await;
+    }
 }
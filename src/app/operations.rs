@@ -0,0 +1,558 @@
+use crate::app::state::{AppPage, NotificationAction};
+use crate::aws::client::{MultiRegionAwsClients, RegionClients};
+use crate::aws::credentials::{CredentialsValidator, ProfileCheck, ProfileValidation};
+use crate::aws::permissions::PermissionReport;
+use crate::aws::services::iam::IamEntity;
+use crate::aws::services::list_resources;
+use crate::aws::types::{
+    AccountSummary, AwsProfile, AwsRegion, CostSummary, Ec2Instance, EksCluster, EksNodeGroup,
+    IamUserDetails, LogEvent, RelatedResource, Resource, ResourceEvent, ResourceId, SecurityGroup,
+    ServiceType,
+};
+use crate::utils::error::Result;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, watch, Semaphore};
+use tokio::task::JoinHandle;
+
+pub type OperationId = u64;
+
+/// Data produced by a background operation that the app state should absorb
+#[derive(Debug)]
+pub enum OperationOutput {
+    /// The operation completed with nothing to store
+    Done,
+    /// A fresh resource listing for the operation's (region, service)
+    Resources(Vec<Resource>),
+    /// One service listed across several regions, some of which may have failed
+    RegionResources {
+        service_type: ServiceType,
+        listings: Vec<RegionListing>,
+    },
+    /// A single resource re-described for the operation's (region, service),
+    /// or `None` when the service cannot describe it yet
+    Described { resource: Option<Box<Resource>> },
+    /// The service reported the resource as not found: it was deleted elsewhere
+    Missing { resource_id: ResourceId },
+    /// Text to show the user in place of the success message
+    Message(String),
+    /// Temporary profile built from an ad-hoc STS AssumeRole call
+    AssumedRole(Box<AwsProfile>),
+    /// Identity of the account behind `profile`
+    Account {
+        profile: String,
+        summary: AccountSummary,
+    },
+    /// Month-to-date spend, or why billing data could not be read
+    Cost(std::result::Result<CostSummary, String>),
+    /// Availability zones to offer in the zone picker
+    Zones(Vec<String>),
+    /// Regions enabled for the account behind `profile`
+    Regions {
+        profile: String,
+        regions: Vec<AwsRegion>,
+    },
+    /// Both sides of a profile comparison, each of which may have failed
+    ProfileComparison(Box<(ProfileCheck, ProfileCheck)>),
+    /// Every configured profile checked against STS
+    ProfileValidation(Box<ProfileValidation>),
+    /// Clients built in the background for the profile they were requested for
+    Clients(Box<MultiRegionAwsClients>),
+    /// What the active identity is expected to be allowed to do
+    Permissions(Box<PermissionReport>),
+    /// Per-resource outcomes of an action run over several resources
+    Batch(BatchResults),
+    /// An EKS cluster whose creation EKS has accepted
+    ClusterCreating(Box<EksCluster>),
+    /// A resource the operation created, announced with an offer to view it
+    Created {
+        service_type: ServiceType,
+        region: String,
+        resource_id: ResourceId,
+        message: String,
+    },
+    /// An instance described to pre-fill a Clone Instance form, or `None` when
+    /// it no longer exists
+    InstanceConfig {
+        region: String,
+        instance_id: String,
+        instance: Option<Box<Ec2Instance>>,
+    },
+    /// A cluster being created as described now, or `None` while EKS does not
+    /// report it yet
+    ClusterStatus {
+        region: String,
+        cluster: ResourceId,
+        resource: Option<Box<Resource>>,
+    },
+    /// One page of the IAM listing; `first` pages replace the cached listing,
+    /// later ones are added to it
+    IamPage {
+        entity: IamEntity,
+        first: bool,
+        resources: Vec<Resource>,
+        marker: Option<String>,
+    },
+    /// Groups and attached policies of an IAM user
+    IamUserDetails {
+        user: ResourceId,
+        details: IamUserDetails,
+    },
+    /// Managed node groups of an EKS cluster
+    NodeGroups {
+        cluster: ResourceId,
+        node_groups: Vec<EksNodeGroup>,
+    },
+    /// Recent events of one resource for the detail page's Events tab
+    Events {
+        resource_id: ResourceId,
+        events: Vec<ResourceEvent>,
+    },
+    /// Security groups of an EC2 instance with their rules
+    SecurityGroups {
+        instance_id: ResourceId,
+        groups: Vec<SecurityGroup>,
+    },
+    /// CloudWatch Logs groups to tail for a resource
+    LogGroups {
+        service_type: ServiceType,
+        resource_id: ResourceId,
+        log_groups: Vec<String>,
+    },
+    /// New events for the open log tail, or why they could not be read
+    LogEvents {
+        resource_id: ResourceId,
+        events: std::result::Result<Vec<LogEvent>, String>,
+    },
+    /// Resources referenced by the detail page's resource
+    Related {
+        service_type: ServiceType,
+        resource_id: ResourceId,
+        related: Vec<RelatedResource>,
+    },
+}
+
+/// One region's part of a multi-region listing
+#[derive(Debug)]
+pub struct RegionListing {
+    pub region: String,
+    /// The resources, or a short reason the region could not be listed
+    pub outcome: std::result::Result<Vec<Resource>, String>,
+}
+
+/// List a service in every region of `clients` at once. Each regional call
+/// holds a request permit, and a region that fails leaves the others intact.
+/// `progress` advances as regions report back.
+pub async fn list_in_regions(
+    limiter: RequestLimiter,
+    clients: Vec<RegionClients>,
+    service_type: ServiceType,
+    progress: OperationProgress,
+) -> Vec<RegionListing> {
+    let total = clients.len();
+    let mut tasks = tokio::task::JoinSet::new();
+    for clients in clients {
+        let limiter = limiter.clone();
+        tasks.spawn(async move {
+            let outcome = limiter
+                .run(list_resources(&clients, service_type))
+                .await
+                .map_err(|e| e.region_failure_reason());
+            RegionListing {
+                region: clients.region,
+                outcome,
+            }
+        });
+    }
+
+    let mut listings = Vec::new();
+    let mut finished = 0;
+    while let Some(joined) = tasks.join_next().await {
+        finished += 1;
+        progress.set_steps(finished, total);
+        match joined {
+            Ok(listing) => listings.push(listing),
+            // Only a panic gets here; the region is simply missing from the overview
+            Err(e) => tracing::warn!("Regional listing task failed: {}", e),
+        }
+    }
+    listings.sort_by(|a, b| a.region.cmp(&b.region));
+    listings
+}
+
+/// Validate each (profile, region) concurrently, every STS call waiting for a
+/// request permit; a failed profile is part of the report rather than an error
+pub async fn validate_profiles(
+    limiter: RequestLimiter,
+    profiles: Vec<(String, Option<String>)>,
+    progress: OperationProgress,
+) -> ProfileValidation {
+    let total = profiles.len();
+    let mut tasks = tokio::task::JoinSet::new();
+    for (profile, region) in profiles {
+        let limiter = limiter.clone();
+        tasks.spawn(async move {
+            limiter
+                .run(CredentialsValidator::check_profile(&profile, region))
+                .await
+        });
+    }
+
+    let mut checks = Vec::new();
+    let mut finished = 0;
+    while let Some(joined) = tasks.join_next().await {
+        finished += 1;
+        progress.set_steps(finished, total);
+        match joined {
+            Ok(check) => checks.push(check),
+            Err(e) => tracing::warn!("Profile validation task failed: {}", e),
+        }
+    }
+    checks.sort_by(|a, b| a.profile.cmp(&b.profile));
+    ProfileValidation {
+        checks,
+        checked_at: chrono::Utc::now(),
+    }
+}
+
+/// Outcome of one resource in a batch action
+#[derive(Debug, Clone)]
+pub struct BatchItemResult {
+    pub resource_id: String,
+    pub outcome: std::result::Result<(), String>,
+}
+
+/// Every item of a finished batch action, kept until the user dismisses it
+#[derive(Debug, Clone)]
+pub struct BatchResults {
+    pub action: String,
+    pub items: Vec<BatchItemResult>,
+}
+
+impl BatchResults {
+    pub fn failures(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.items.iter().filter_map(|item| match &item.outcome {
+            Ok(()) => None,
+            Err(message) => Some((item.resource_id.as_str(), message.as_str())),
+        })
+    }
+}
+
+/// Where a progress bar is drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressDisplay {
+    /// In the status bar, for work that runs while the user carries on
+    StatusBar,
+    /// In a small overlay, for work the user is waiting on
+    Overlay,
+}
+
+/// Fraction of an operation done, shared between the task that advances it
+/// and the UI that draws it
+#[derive(Debug, Clone)]
+pub struct OperationProgress {
+    /// Bits of an `f64` in `0.0..=1.0`
+    fraction: Arc<AtomicU64>,
+    pub display: ProgressDisplay,
+}
+
+impl OperationProgress {
+    pub fn new(display: ProgressDisplay) -> Self {
+        Self {
+            fraction: Arc::new(AtomicU64::new(0f64.to_bits())),
+            display,
+        }
+    }
+
+    pub fn set(&self, fraction: f64) {
+        self.fraction
+            .store(fraction.clamp(0.0, 1.0).to_bits(), Ordering::Relaxed);
+    }
+
+    /// Record `done` of `total` steps, e.g. items of a batch action
+    pub fn set_steps(&self, done: usize, total: usize) {
+        if total > 0 {
+            self.set(done as f64 / total as f64);
+        }
+    }
+
+    pub fn fraction(&self) -> f64 {
+        f64::from_bits(self.fraction.load(Ordering::Relaxed))
+    }
+}
+
+/// Bookkeeping for an operation that is still running
+#[derive(Debug, Clone)]
+pub struct InFlightOperation {
+    pub label: String,
+    pub success_message: String,
+    /// Service whose loading state the operation drives, if any
+    pub service_type: Option<ServiceType>,
+    pub region: String,
+    pub origin_page: AppPage,
+    pub started_at: Instant,
+    /// Set for operations that report how far along they are; the rest show a spinner
+    pub progress: Option<OperationProgress>,
+    /// What a failure notification offers, for operations started by a service command
+    pub retry: Option<NotificationAction>,
+    /// Started by a timer rather than the user: Esc leaves it running and it
+    /// does not count as busy
+    pub background: bool,
+}
+
+/// A finished operation, paired with the bookkeeping captured when it was spawned
+#[derive(Debug)]
+pub struct CompletedOperation {
+    pub operation: InFlightOperation,
+    pub outcome: Result<OperationOutput>,
+}
+
+/// Caps how many AWS requests run at once. Clones share the same permits, so
+/// a fan-out can run each of its calls through the limiter.
+#[derive(Debug, Clone)]
+pub struct RequestLimiter {
+    semaphore: Arc<Semaphore>,
+    limit: Arc<AtomicUsize>,
+    /// Permits still held by requests when the limit shrank, retired as they finish
+    excess: Arc<AtomicUsize>,
+}
+
+impl RequestLimiter {
+    pub fn new(limit: usize) -> Self {
+        let limit = limit.max(1);
+        Self {
+            semaphore: Arc::new(Semaphore::new(limit)),
+            limit: Arc::new(AtomicUsize::new(limit)),
+            excess: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit.load(Ordering::SeqCst)
+    }
+
+    /// Requests currently holding a permit
+    pub fn in_use(&self) -> usize {
+        (self.limit() + self.excess.load(Ordering::SeqCst))
+            .saturating_sub(self.semaphore.available_permits())
+    }
+
+    /// Change the limit for requests that have not started yet; running ones finish
+    pub fn resize(&self, limit: usize) {
+        let limit = limit.max(1);
+        let previous = self.limit.swap(limit, Ordering::SeqCst);
+        if limit > previous {
+            // Permits still owed to an earlier shrink cover part of the growth
+            let grow = limit - previous;
+            let owed = self
+                .excess
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+                    Some(n - n.min(grow))
+                })
+                .unwrap_or_default();
+            self.semaphore.add_permits(grow - owed.min(grow));
+        } else {
+            let shrink = previous - limit;
+            let forgotten = self.semaphore.forget_permits(shrink);
+            self.excess.fetch_add(shrink - forgotten, Ordering::SeqCst);
+        }
+    }
+
+    /// Wait for a free permit, then run the request while holding it
+    pub async fn run<F: Future>(&self, future: F) -> F::Output {
+        let permit = self
+            .semaphore
+            .acquire()
+            .await
+            .expect("request semaphore is never closed");
+        let output = future.await;
+        // Permits handed back after the limit shrank are retired instead of released
+        if self
+            .excess
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok()
+        {
+            permit.forget();
+        }
+        output
+    }
+}
+
+/// Runs AWS operations as background tasks so the UI loop never awaits a round-trip
+pub struct OperationQueue {
+    sender: mpsc::UnboundedSender<(OperationId, Result<OperationOutput>)>,
+    receiver: mpsc::UnboundedReceiver<(OperationId, Result<OperationOutput>)>,
+    in_flight: HashMap<OperationId, InFlightOperation>,
+    handles: HashMap<OperationId, JoinHandle<()>>,
+    next_id: OperationId,
+    limiter: RequestLimiter,
+    /// Set once on quit; every task stops at its next await point when it flips
+    shutdown: watch::Sender<bool>,
+}
+
+impl OperationQueue {
+    /// A queue that runs at most `max_concurrent_requests` operations at once
+    pub fn new(max_concurrent_requests: usize) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        Self {
+            sender,
+            receiver,
+            in_flight: HashMap::new(),
+            handles: HashMap::new(),
+            next_id: 1,
+            limiter: RequestLimiter::new(max_concurrent_requests),
+            shutdown: watch::channel(false).0,
+        }
+    }
+
+    pub fn limiter(&self) -> &RequestLimiter {
+        &self.limiter
+    }
+
+    /// Spawn an operation; it waits for a request permit before it starts, and
+    /// its outcome is picked up by `drain_completed`
+    pub fn spawn<F>(&mut self, operation: InFlightOperation, future: F) -> OperationId
+    where
+        F: Future<Output = Result<OperationOutput>> + Send + 'static,
+    {
+        let limiter = self.limiter.clone();
+        self.spawn_task(operation, async move { limiter.run(future).await })
+    }
+
+    /// Spawn an operation that makes several requests and takes a permit from
+    /// `limiter()` for each of them, instead of one for the whole operation
+    pub fn spawn_fan_out<F>(&mut self, operation: InFlightOperation, future: F) -> OperationId
+    where
+        F: Future<Output = Result<OperationOutput>> + Send + 'static,
+    {
+        self.spawn_task(operation, future)
+    }
+
+    fn spawn_task<F>(&mut self, operation: InFlightOperation, future: F) -> OperationId
+    where
+        F: Future<Output = Result<OperationOutput>> + Send + 'static,
+    {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let sender = self.sender.clone();
+        let mut shutdown = self.shutdown.subscribe();
+        let handle = tokio::spawn(async move {
+            tokio::select! {
+                outcome = future => {
+                    // The receiver only goes away on shutdown, when nobody cares about the result
+                    let _ = sender.send((id, outcome));
+                }
+                // Dropping the future here cancels its requests and kills any child process
+                _ = shutdown.wait_for(|stopping| *stopping) => {}
+            }
+        });
+
+        self.in_flight.insert(id, operation);
+        self.handles.insert(id, handle);
+        id
+    }
+
+    /// Collect every operation that finished since the last call
+    pub fn drain_completed(&mut self) -> Vec<CompletedOperation> {
+        let mut completed = Vec::new();
+
+        while let Ok((id, outcome)) = self.receiver.try_recv() {
+            self.handles.remove(&id);
+            // Cancelled operations were already removed and their late results are dropped
+            if let Some(operation) = self.in_flight.remove(&id) {
+                completed.push(CompletedOperation { operation, outcome });
+            }
+        }
+
+        completed
+    }
+
+    /// Abort a single operation, leaving any others running
+    pub fn cancel(&mut self, id: OperationId) -> Option<InFlightOperation> {
+        if let Some(handle) = self.handles.remove(&id) {
+            handle.abort();
+        }
+        self.in_flight.remove(&id)
+    }
+
+    /// Abort every running operation, returning how many were cancelled
+    pub fn cancel_all(&mut self) -> usize {
+        for (_, handle) in self.handles.drain() {
+            handle.abort();
+        }
+        let cancelled = self.in_flight.len();
+        self.in_flight.clear();
+        cancelled
+    }
+
+    /// Abort the most recently started operation
+    pub fn cancel_latest(&mut self) -> Option<InFlightOperation> {
+        let id = self
+            .in_flight
+            .iter()
+            .filter(|(_, op)| !op.background)
+            .max_by_key(|(_, op)| op.started_at)
+            .map(|(id, _)| *id)?;
+        self.cancel(id)
+    }
+
+    /// Whether any operation for the given (region, service) is still running
+    pub fn is_loading(&self, region: &str, service_type: ServiceType) -> bool {
+        self.in_flight
+            .values()
+            .any(|op| op.region == region && op.service_type == Some(service_type))
+    }
+
+    /// The oldest running operation that reports progress in `display`
+    pub fn progress(&self, display: ProgressDisplay) -> Option<(&str, f64)> {
+        self.in_flight()
+            .into_iter()
+            .find_map(|op| match &op.progress {
+                Some(progress) if progress.display == display => {
+                    Some((op.label.as_str(), progress.fraction()))
+                }
+                _ => None,
+            })
+    }
+
+    /// Operations still running, oldest first
+    pub fn in_flight(&self) -> Vec<&InFlightOperation> {
+        let mut operations: Vec<&InFlightOperation> = self.in_flight.values().collect();
+        operations.sort_by_key(|op| op.started_at);
+        operations
+    }
+
+    /// Operations the user started that are still running, oldest first
+    pub fn user_operations(&self) -> Vec<&InFlightOperation> {
+        let mut operations = self.in_flight();
+        operations.retain(|op| !op.background);
+        operations
+    }
+
+    /// Whether an operation the user started is still running
+    pub fn is_busy(&self) -> bool {
+        self.in_flight.values().any(|op| !op.background)
+    }
+
+    /// Signal every task to stop and wait up to `grace` for them to finish,
+    /// aborting any still running after that. Returns how many were running.
+    pub async fn shutdown(&mut self, grace: Duration) -> usize {
+        self.shutdown.send_replace(true);
+        let running = self.handles.len();
+        let deadline = tokio::time::Instant::now() + grace;
+        for (_, mut handle) in self.handles.drain() {
+            if tokio::time::timeout_at(deadline, &mut handle)
+                .await
+                .is_err()
+            {
+                handle.abort();
+            }
+        }
+        self.in_flight.clear();
+        running
+    }
+}
@@ -0,0 +1,40 @@
+use crate::app::operations::RequestLimiter;
+use std::time::Duration;
+use tokio::sync::watch;
+
+#[tokio::test]
+async fn growing_after_a_shrink_does_not_exceed_the_limit() {
+    let limiter = RequestLimiter::new(2);
+    let (release, released) = watch::channel(false);
+    let mut held = vec![];
+    for _ in 0..2 {
+        let limiter = limiter.clone();
+        let mut released = released.clone();
+        held.push(tokio::spawn(async move {
+            limiter
+                .run(async move {
+                    let _ = released.wait_for(|released| *released).await;
+                })
+                .await
+        }));
+    }
+    while limiter.in_use() < 2 {
+        tokio::task::yield_now().await;
+    }
+
+    // Both permits are held, so the shrink is owed and the growth repays it
+    limiter.resize(1);
+    limiter.resize(2);
+
+    assert_eq!(limiter.in_use(), 2);
+    let third = tokio::time::timeout(Duration::from_millis(20), limiter.run(async {}));
+    assert!(third.await.is_err(), "a third request started at limit 2");
+
+    release.send_replace(true);
+    for handle in held {
+        handle.await.unwrap();
+    }
+    assert_eq!(limiter.in_use(), 0);
+    let first = tokio::time::timeout(Duration::from_millis(20), limiter.run(async {}));
+    assert!(first.await.is_ok());
+}
@@ -0,0 +1,117 @@
+use crate::aws::types::Resource;
+use serde_json::Value;
+use std::time::SystemTime;
+
+/// A resource's describe output as first seen, kept until the baseline is reset
+#[derive(Debug, Clone)]
+pub struct ResourceBaseline {
+    pub describe: Value,
+    pub captured_at: SystemTime,
+}
+
+impl ResourceBaseline {
+    pub fn new(resource: &Resource, captured_at: SystemTime) -> Self {
+        Self {
+            describe: describe(resource),
+            captured_at,
+        }
+    }
+}
+
+/// A resource's describe output as a JSON tree
+pub fn describe(resource: &Resource) -> Value {
+    // Resources hold only strings, maps and timestamps, which always serialize
+    serde_json::to_value(resource).unwrap_or_default()
+}
+
+/// One field's difference between a baseline and the current describe output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldChange {
+    Added {
+        path: String,
+        value: String,
+    },
+    Changed {
+        path: String,
+        old: String,
+        new: String,
+    },
+    Removed {
+        path: String,
+        value: String,
+    },
+}
+
+impl FieldChange {
+    pub fn path(&self) -> &str {
+        match self {
+            FieldChange::Added { path, .. }
+            | FieldChange::Changed { path, .. }
+            | FieldChange::Removed { path, .. } => path,
+        }
+    }
+}
+
+/// Every field that differs between two describe outputs, sorted by path.
+/// Objects are compared key by key (e.g. `tags.Owner`); other values are
+/// compared whole.
+pub fn diff_describe(before: &Value, after: &Value) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    diff_at("", before, after, &mut changes);
+    changes.sort_by(|a, b| a.path().cmp(b.path()));
+    changes
+}
+
+fn diff_at(path: &str, before: &Value, after: &Value, changes: &mut Vec<FieldChange>) {
+    match (before, after) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                let child = join(path, key);
+                match new.get(key) {
+                    Some(new_value) => diff_at(&child, old_value, new_value, changes),
+                    None => changes.push(FieldChange::Removed {
+                        path: child,
+                        value: display(old_value),
+                    }),
+                }
+            }
+            for (key, new_value) in new.iter().filter(|(key, _)| !old.contains_key(*key)) {
+                changes.push(FieldChange::Added {
+                    path: join(path, key),
+                    value: display(new_value),
+                });
+            }
+        }
+        _ if before != after => changes.push(FieldChange::Changed {
+            path: path.to_string(),
+            old: display(before),
+            new: display(after),
+        }),
+        _ => {}
+    }
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// Strings without their JSON quotes, everything else as compact JSON
+fn display(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// The Resource Changes overlay: a resource's drift from its baseline
+#[derive(Debug, Clone)]
+pub struct ResourceChanges {
+    pub title: String,
+    pub since: SystemTime,
+    pub changes: Vec<FieldChange>,
+    pub scroll: u16,
+}
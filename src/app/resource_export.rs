@@ -0,0 +1,132 @@
+use crate::aws::types::{Resource, ServiceType};
+use chrono::{DateTime, Utc};
+use serde_json::json;
+use std::path::Path;
+
+/// File format of an exported resource list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    /// JSON for `.json` files, CSV for anything else
+    pub fn for_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => ExportFormat::Json,
+            _ => ExportFormat::Csv,
+        }
+    }
+}
+
+/// Where an export came from, written alongside the rows so the file says
+/// exactly what it contains
+#[derive(Debug, Clone)]
+pub struct ExportMetadata {
+    pub service_type: ServiceType,
+    pub profile: String,
+    pub region: String,
+    /// Filters the rows were narrowed by, e.g. "state: running"; empty when
+    /// every cached resource was exported
+    pub filters: Vec<String>,
+    pub exported_at: DateTime<Utc>,
+}
+
+impl ExportMetadata {
+    fn filters_label(&self) -> String {
+        if self.filters.is_empty() {
+            "none".to_string()
+        } else {
+            self.filters.join(", ")
+        }
+    }
+}
+
+const CSV_COLUMNS: [&str; 8] = [
+    "id",
+    "name",
+    "state",
+    "region",
+    "availability_zone",
+    "arn",
+    "created_at",
+    "tags",
+];
+
+pub fn render_resource_export(
+    resources: &[&Resource],
+    metadata: &ExportMetadata,
+    format: ExportFormat,
+) -> String {
+    match format {
+        ExportFormat::Csv => render_csv(resources, metadata),
+        ExportFormat::Json => {
+            let document = json!({
+                "metadata": {
+                    "service": metadata.service_type.display_name(),
+                    "profile": metadata.profile,
+                    "region": metadata.region,
+                    "filters": metadata.filters,
+                    "exported_at": metadata.exported_at.to_rfc3339(),
+                    "count": resources.len(),
+                },
+                "resources": resources,
+            });
+            // Resources hold only strings, maps and timestamps, which always serialize
+            let mut out = serde_json::to_string_pretty(&document).unwrap_or_default();
+            out.push('\n');
+            out
+        }
+    }
+}
+
+/// `#` comment lines with the metadata, then a header row and one row per resource
+fn render_csv(resources: &[&Resource], metadata: &ExportMetadata) -> String {
+    let mut out = format!(
+        "# service: {}\n# profile: {}\n# region: {}\n# filters: {}\n# exported_at: {}\n# count: {}\n",
+        metadata.service_type.display_name(),
+        metadata.profile,
+        metadata.region,
+        metadata.filters_label(),
+        metadata.exported_at.to_rfc3339(),
+        resources.len()
+    );
+    out.push_str(&CSV_COLUMNS.join(","));
+    out.push('\n');
+
+    for resource in resources {
+        let mut tags: Vec<String> = resource
+            .tags
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        tags.sort();
+        let row = [
+            resource.id.clone(),
+            resource.name.clone(),
+            resource.state.clone(),
+            resource.region.clone(),
+            resource.availability_zone.clone().unwrap_or_default(),
+            resource.arn.clone(),
+            resource
+                .created_at
+                .map(|at| at.to_rfc3339())
+                .unwrap_or_default(),
+            tags.join(";"),
+        ];
+        let fields: Vec<String> = row.iter().map(|field| csv_field(field)).collect();
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Quote a field when it holds a separator, quote or line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
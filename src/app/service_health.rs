@@ -0,0 +1,41 @@
+use std::time::{Duration, Instant};
+
+/// Network or permission failures in a row after which a service's commands
+/// are held back
+pub const SERVICE_FAILURE_THRESHOLD: u32 = 3;
+
+/// How long a failing service's commands stay disabled; the next call after
+/// that either re-enables them or starts another cooldown
+pub const SERVICE_COOLDOWN: Duration = Duration::from_secs(120);
+
+/// Recent failures of one service, reset by its next successful call
+#[derive(Debug, Clone, Default)]
+pub struct ServiceHealth {
+    failures: u32,
+    last_failure: Option<Instant>,
+}
+
+impl ServiceHealth {
+    pub fn record_failure(&mut self) {
+        self.failures += 1;
+        self.last_failure = Some(Instant::now());
+    }
+
+    pub fn record_success(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Time left before commands are offered again, while the service is disabled
+    pub fn cooldown_left(&self) -> Option<Duration> {
+        if self.failures < SERVICE_FAILURE_THRESHOLD {
+            return None;
+        }
+        SERVICE_COOLDOWN
+            .checked_sub(self.last_failure?.elapsed())
+            .filter(|left| !left.is_zero())
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        self.cooldown_left().is_some()
+    }
+}
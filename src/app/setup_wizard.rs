@@ -0,0 +1,160 @@
+use crate::aws::types::{AwsProfile, AwsRegion};
+use crate::config::user_config::{DisplayConfig, UserConfig};
+
+/// The wizard's pages, in the order they are shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetupStep {
+    Profile,
+    Region,
+    Theme,
+    Confirmations,
+}
+
+impl SetupStep {
+    pub const ALL: [SetupStep; 4] = [
+        SetupStep::Profile,
+        SetupStep::Region,
+        SetupStep::Theme,
+        SetupStep::Confirmations,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            SetupStep::Profile => "Default profile",
+            SetupStep::Region => "Default region",
+            SetupStep::Theme => "Theme",
+            SetupStep::Confirmations => "Destructive actions",
+        }
+    }
+
+    /// 1-based position, for "step 2 of 4"
+    pub fn number(&self) -> usize {
+        Self::ALL.iter().position(|step| step == self).unwrap_or(0) + 1
+    }
+}
+
+/// The first-run wizard, shown when no config file exists yet. Nothing is
+/// written until it finishes; skipping it writes the defaults instead.
+#[derive(Debug, Clone)]
+pub struct SetupWizard {
+    pub step: SetupStep,
+    pub profiles: Vec<String>,
+    pub regions: Vec<AwsRegion>,
+    pub profile: usize,
+    pub region: usize,
+    pub theme: usize,
+    pub confirm_destructive_actions: bool,
+    pub error: Option<String>,
+}
+
+impl SetupWizard {
+    /// Starts on the profile and region already in use (e.g. from
+    /// `AWS_PROFILE`), and on the config's other defaults
+    pub fn new(
+        profiles: &[AwsProfile],
+        regions: &[AwsRegion],
+        current_profile: &str,
+        current_region: &str,
+        config: &UserConfig,
+    ) -> Self {
+        let profiles: Vec<String> = profiles.iter().map(|p| p.name.clone()).collect();
+        Self {
+            step: SetupStep::Profile,
+            profile: profiles
+                .iter()
+                .position(|name| name == current_profile)
+                .unwrap_or(0),
+            region: regions
+                .iter()
+                .position(|r| r.name == current_region)
+                .unwrap_or(0),
+            theme: DisplayConfig::THEMES
+                .iter()
+                .position(|theme| *theme == config.display.theme)
+                .unwrap_or(0),
+            confirm_destructive_actions: config.behavior.confirm_destructive_actions,
+            profiles,
+            regions: regions.to_vec(),
+            error: None,
+        }
+    }
+
+    pub fn selected_profile(&self) -> Option<&str> {
+        self.profiles.get(self.profile).map(String::as_str)
+    }
+
+    pub fn selected_region(&self) -> Option<&AwsRegion> {
+        self.regions.get(self.region)
+    }
+
+    pub fn selected_theme(&self) -> &'static str {
+        DisplayConfig::THEMES[self.theme]
+    }
+
+    pub fn is_last_step(&self) -> bool {
+        self.step == SetupStep::Confirmations
+    }
+
+    pub fn next_step(&mut self) {
+        self.error = None;
+        let next = self.step.number().min(SetupStep::ALL.len() - 1);
+        self.step = SetupStep::ALL[next];
+    }
+
+    pub fn previous_step(&mut self) {
+        self.error = None;
+        let previous = self.step.number().saturating_sub(2);
+        self.step = SetupStep::ALL[previous];
+    }
+
+    pub fn select_next(&mut self) {
+        match self.step {
+            SetupStep::Profile => self.profile = wrap_next(self.profile, self.profiles.len()),
+            SetupStep::Region => self.region = wrap_next(self.region, self.regions.len()),
+            SetupStep::Theme => self.theme = wrap_next(self.theme, DisplayConfig::THEMES.len()),
+            SetupStep::Confirmations => self.toggle_confirmations(),
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        match self.step {
+            SetupStep::Profile => self.profile = wrap_previous(self.profile, self.profiles.len()),
+            SetupStep::Region => self.region = wrap_previous(self.region, self.regions.len()),
+            SetupStep::Theme => self.theme = wrap_previous(self.theme, DisplayConfig::THEMES.len()),
+            SetupStep::Confirmations => self.toggle_confirmations(),
+        }
+    }
+
+    pub fn toggle_confirmations(&mut self) {
+        self.confirm_destructive_actions = !self.confirm_destructive_actions;
+    }
+
+    /// Write the choices into `config`. Without any profile the configured
+    /// default is left alone, so one created later under that name is used.
+    pub fn apply(&self, config: &mut UserConfig) {
+        if let Some(profile) = self.selected_profile() {
+            config.aws.default_profile = profile.to_string();
+        }
+        if let Some(region) = self.selected_region() {
+            config.aws.default_region = region.name.clone();
+        }
+        config.display.theme = self.selected_theme().to_string();
+        config.behavior.confirm_destructive_actions = self.confirm_destructive_actions;
+    }
+}
+
+fn wrap_next(index: usize, len: usize) -> usize {
+    if len == 0 {
+        0
+    } else {
+        (index + 1) % len
+    }
+}
+
+fn wrap_previous(index: usize, len: usize) -> usize {
+    if len == 0 {
+        0
+    } else {
+        (index + len - 1) % len
+    }
+}
@@ -1,14 +1,61 @@
-use crate::aws::client::MultiRegionAwsClients;
+use crate::app::activity_log::{cycle_option, ActivityFilter, ActivityLog, MAX_ACTIVITY_ENTRIES};
+use crate::app::cheat_sheet::{render_cheat_sheet, CheatSheetFormat, CheatSheetSection};
+use crate::app::cluster_creation::{is_terminal_status, ClusterCreation};
+use crate::app::forms::{
+    ActionMenu, AssumeRoleForm, BucketForm, CheatSheetForm, ClusterForm, FavoriteNoteForm,
+    FavoritesOverlay, InstanceForm, NodeGroupSizeForm, NotificationOverlay, PresetForm,
+    RawInspector, ResourceExportForm, SecretForm, SecurityGroupInspector, TagEditForm,
+    ViewStateForm, ZonePicker,
+};
+use crate::app::load_state::LoadState;
+use crate::app::log_tail::LogTail;
+use crate::app::operations::{
+    list_in_regions, validate_profiles, BatchItemResult, BatchResults, CompletedOperation,
+    InFlightOperation, OperationOutput, OperationProgress, OperationQueue, ProgressDisplay,
+};
+use crate::app::resource_diff::{describe, diff_describe, ResourceBaseline, ResourceChanges};
+use crate::app::resource_export::{render_resource_export, ExportFormat, ExportMetadata};
+use crate::app::service_health::ServiceHealth;
+use crate::app::setup_wizard::{SetupStep, SetupWizard};
+use crate::app::view_state::ViewState;
+use crate::aws::client::{MultiRegionAwsClients, RegionClients};
+use crate::aws::console::{console_url, docs_url};
+use crate::aws::credentials::{CredentialsValidator, ProfileCheck, ProfileValidation};
+use crate::aws::demo;
+use crate::aws::permissions::{diagnose_permissions, PermissionReport};
 use crate::aws::profiles::ProfileManager;
-use crate::aws::types::{AwsProfile, AwsRegion, Resource, ResourceId, ServiceType};
-use crate::command::{CommandContext, CommandPalette, CommandRegistry};
-use crate::config::user_config::UserConfig;
-use crate::ui::pages::dashboard::favorites::FavoritesManager;
+use crate::aws::regions::RegionManager;
+use crate::aws::services::cloudwatch_logs::CloudWatchLogsService;
+use crate::aws::services::cost_explorer::{CostExplorerService, COST_EXPLORER_REGION};
+use crate::aws::services::ec2::Ec2Service;
+use crate::aws::services::eks::{validate_cluster_name, EksService};
+use crate::aws::services::iam::{self, IamEntity, IamService};
+use crate::aws::services::rds::RdsService;
+use crate::aws::services::s3::{validate_bucket_name, S3Service};
+use crate::aws::services::secrets::SecretsService;
+use crate::aws::services::{describe_resource, list_resources};
+use crate::aws::tagging::{TagDiff, TaggingService};
+use crate::aws::types::{
+    AccountSummary, AwsProfile, AwsRegion, CostSummary, CredentialSource, EksNodeGroup,
+    IamUserDetails, RelatedResource, Resource, ResourceEvent, ResourceId, ServiceType,
+};
+use crate::command::registry::{
+    create_custom_commands_for_context, create_preset_commands_for_context,
+};
+use crate::command::{CommandCategory, CommandContext, CommandPalette, CommandRegistry};
+use crate::config::defaults::get_default_keybindings;
+use crate::config::user_config::{ContextPreset, EnterAction, StateFilter, UserConfig};
+use crate::ui::pages::dashboard::favorites::{FavoriteCommand, FavoriteResource, FavoritesManager};
+use crate::ui::pages::dashboard::recently_viewed::{RecentlyViewed, ViewedResource};
 use crate::ui::pages::dashboard::widgets::DashboardLayout;
-use crate::utils::error::Result;
+use crate::ui::pages::resource_detail::{overview_fields, DetailField};
+use crate::utils::browser::{open_url_or_copy, UrlHandoff};
+use crate::utils::error::{AppError, Result};
+use crate::utils::helpers::{format_duration, pop_grapheme};
+use crate::utils::shell::{render_command_template, run_shell_command};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use std::collections::HashMap;
-use std::time::SystemTime;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant, SystemTime};
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum AppPage {
@@ -16,6 +63,34 @@ pub enum AppPage {
     ResourceList(ServiceType),
     ResourceDetail(ServiceType, ResourceId),
     Settings,
+    ActivityLog,
+}
+
+/// Sections of the resource detail page, switched with the number keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetailTab {
+    Overview,
+    Events,
+    RawJson,
+}
+
+impl DetailTab {
+    /// Tabs shown for a service, in order; services without an events source skip Events
+    pub fn for_service(service_type: ServiceType) -> Vec<DetailTab> {
+        if ResourceEvent::supported(service_type) {
+            vec![DetailTab::Overview, DetailTab::Events, DetailTab::RawJson]
+        } else {
+            vec![DetailTab::Overview, DetailTab::RawJson]
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            DetailTab::Overview => "Overview",
+            DetailTab::Events => "Events",
+            DetailTab::RawJson => "Raw JSON",
+        }
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -33,6 +108,41 @@ pub struct Notification {
     pub message: String,
     pub level: NotificationLevel,
     pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// Offered on the toast (`A`) and in the overlay (Enter)
+    pub action: Option<NotificationAction>,
+}
+
+/// Something a notification offers to do next
+#[derive(Debug, Clone)]
+pub enum NotificationAction {
+    /// Open a resource's detail page, switching to its region first
+    ViewResource {
+        service_type: ServiceType,
+        resource_id: ResourceId,
+        region: String,
+    },
+    /// Run a failed service command again on the resource it ran on
+    Retry {
+        service_type: ServiceType,
+        command: crate::command::ServiceCommand,
+        resource_id: Option<ResourceId>,
+        region: String,
+    },
+    /// Run a registered command by id
+    RunCommand {
+        label: &'static str,
+        command_id: &'static str,
+    },
+}
+
+impl NotificationAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            NotificationAction::ViewResource { .. } => "View it",
+            NotificationAction::Retry { .. } => "Retry",
+            NotificationAction::RunCommand { label, .. } => label,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -43,6 +153,119 @@ pub enum NotificationLevel {
     Success,
 }
 
+/// How long a notification other than an error stays up; errors stay until dismissed
+const NOTIFICATION_LIFETIME: chrono::Duration = chrono::Duration::seconds(5);
+
+/// Label of the background operation that builds the AWS clients
+const CONNECT_LABEL: &str = "Connect to AWS";
+
+/// Label of the background DescribeRegions call
+const REGIONS_LABEL: &str = "Load regions";
+
+/// Label of the background STS calls validating every profile
+const VALIDATE_PROFILES_LABEL: &str = "Validate profiles";
+
+/// Label of the background STS call that checks whether AWS is reachable again
+const CONNECTIVITY_PROBE_LABEL: &str = "Check connectivity";
+
+/// Assumed lifetime of temporary credentials until real STS expirations are read
+const TEMPORARY_CREDENTIALS_LIFETIME: chrono::Duration = chrono::Duration::hours(1);
+
+/// How often and how many times to check whether a node group update has started
+const NODE_GROUP_UPDATE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3);
+const NODE_GROUP_UPDATE_POLLS: usize = 20;
+
+/// How long after a failed connection to try connecting again
+const RECONNECT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How often to check whether AWS is reachable again while offline
+const CONNECTIVITY_PROBE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// How long quitting waits for background tasks to stop before aborting them
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(1);
+
+/// Label of the background read that fetches new events for the log tail
+const LOG_TAIL_LABEL: &str = "Tail logs";
+
+/// How often the open log tail reads new events
+const LOG_TAIL_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Label of the background read that checks on a cluster being created
+const CLUSTER_STATUS_LABEL: &str = "Check cluster status";
+
+/// How often each cluster being created is described
+const CLUSTER_CREATION_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Label of the operation listing a service in one region
+fn list_label(service_type: ServiceType) -> String {
+    format!("Refresh {}", service_type.display_name())
+}
+
+/// How long before temporary credentials expire to re-authenticate
+const CREDENTIALS_REFRESH_LEAD: chrono::Duration = chrono::Duration::minutes(1);
+
+/// An action held back until the user confirms it
+#[derive(Debug, Clone)]
+pub enum ConfirmAction {
+    ClearCache,
+    ClearActivityLog,
+    /// Apply a reviewed tag edit to the resource with this ARN
+    ApplyTags {
+        service_type: ServiceType,
+        resource_arn: String,
+        diff: TagDiff,
+    },
+    /// Run a destructive command on every listed resource
+    Batch {
+        command: crate::command::ServiceCommand,
+        resources: Vec<Resource>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingConfirmation {
+    pub title: String,
+    pub message: String,
+    pub action: ConfirmAction,
+    /// What has been typed so far for confirmations that must be typed out
+    pub typed: String,
+}
+
+impl PendingConfirmation {
+    pub fn new(title: String, message: String, action: ConfirmAction) -> Self {
+        Self {
+            title,
+            message,
+            action,
+            typed: String::new(),
+        }
+    }
+
+    /// Batches are confirmed by typing their size or "yes", never by one key
+    pub fn requires_typing(&self) -> bool {
+        matches!(self.action, ConfirmAction::Batch { .. })
+    }
+
+    /// Whether the typed text confirms the batch
+    pub fn typed_matches(&self) -> bool {
+        let ConfirmAction::Batch { resources, .. } = &self.action else {
+            return false;
+        };
+        let typed = self.typed.trim();
+        typed.eq_ignore_ascii_case("yes") || typed == resources.len().to_string()
+    }
+}
+
+/// A key that can continue the pending key sequence
+#[derive(Debug, Clone)]
+pub struct SequenceHint {
+    pub key: char,
+    /// The command it runs, or how many commands lie behind a longer sequence
+    pub label: String,
+    /// False when the command exists but is not offered in the current context
+    pub available: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct NavigationItem {
     pub name: String,
@@ -52,10 +275,30 @@ pub struct NavigationItem {
     pub keywords: Vec<String>,
 }
 
+impl NavigationItem {
+    /// Shown next to the suggestion so services and resources are told apart
+    pub fn kind_label(&self) -> &'static str {
+        match self.action {
+            NavigationAction::NavigateToService(_) => "service",
+            NavigationAction::NavigateToResource(..) => "resource",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum NavigationAction {
     NavigateToService(ServiceType),
-    NavigateToResource(ServiceType, ResourceId),
+    /// Open a resource's detail page, switching to its region first
+    NavigateToResource(ServiceType, ResourceId, String),
+}
+
+/// What the app keeps between runs besides the user config, loaded before
+/// the state is assembled
+pub struct SavedData {
+    pub favorites_manager: FavoritesManager,
+    pub recently_viewed: RecentlyViewed,
+    pub recent_activity: ActivityLog,
+    pub dashboard_layout: DashboardLayout,
 }
 
 pub struct AppState {
@@ -76,12 +319,32 @@ pub struct AppState {
     // Dashboard
     pub dashboard_layout: DashboardLayout,
     pub favorites_manager: FavoritesManager,
-    pub recent_activity: Vec<ActivityEntry>,
+    pub recently_viewed: RecentlyViewed,
+    pub recent_activity: ActivityLog,
+    /// Filters and sort order of the Activity Log page
+    pub activity_filter: ActivityFilter,
+    /// First entry shown on the Activity Log page
+    pub activity_scroll: usize,
+    pub account_summary: Option<AccountSummary>,
+    /// Month-to-date spend, or why billing data is unavailable
+    pub cost_summary: Option<std::result::Result<CostSummary, String>>,
+    cost_refreshed_at: Option<Instant>,
+    region_overview_refreshed_at: Option<Instant>,
+    /// When the current list or detail page last started a refresh
+    view_refreshed_at: Option<Instant>,
 
     // Resource Data (per region)
-    pub resources: HashMap<(String, ServiceType), Vec<Resource>>,
-    pub loading_states: HashMap<(String, ServiceType), bool>,
+    /// Listings from first load through refreshes, including why one failed
+    pub resources: HashMap<(String, ServiceType), LoadState<Vec<Resource>>>,
     pub last_refresh: HashMap<(String, ServiceType), SystemTime>,
+    /// Availability zone each resource list is narrowed to
+    pub zone_filters: HashMap<ServiceType, String>,
+    /// Listings the active credentials are not allowed to read
+    pub permission_denied: HashSet<(String, ServiceType)>,
+    /// Network and permission failures per service, which disable its
+    /// commands for a while once they pile up
+    service_health: HashMap<ServiceType, ServiceHealth>,
+    pub operations: OperationQueue,
 
     // UI State
     pub selected_resource: Option<ResourceId>,
@@ -92,6 +355,78 @@ pub struct AppState {
     pub selected_widget: Option<usize>,
     pub selected_service: Option<ServiceType>,
     pub selected_resource_index: usize,
+    /// Id of the highlighted list row, so a refresh can find it again
+    selected_resource_id: Option<ResourceId>,
+    /// Resources marked for a batch action, in the order they were marked
+    pub marked_resources: Vec<ResourceId>,
+    /// Service the marks belong to; marks never carry over to another list
+    pub marked_service: Option<ServiceType>,
+    /// Results of the last batch action, shown until dismissed
+    pub batch_results: Option<BatchResults>,
+    pub cheat_sheet_form: Option<CheatSheetForm>,
+    pub resource_export_form: Option<ResourceExportForm>,
+    pub assume_role_form: Option<AssumeRoleForm>,
+    pub instance_form: Option<InstanceForm>,
+    /// Profile to switch to on the next update, set when an assumed role lands
+    pending_profile_switch: Option<String>,
+    /// Set while a service command is being dispatched, so a failure of an
+    /// operation it starts can offer to retry it
+    retry_action: Option<NotificationAction>,
+    /// Set while a timer starts its polls, so Esc and the busy indicators
+    /// leave the operations out
+    starting_background: bool,
+    /// Describe output of each (region, service, resource) when first seen
+    resource_baselines: HashMap<(String, ServiceType, ResourceId), ResourceBaseline>,
+    /// Resources whose describe call reported them gone, per (region, service, id)
+    missing_resources: HashSet<(String, ServiceType, ResourceId)>,
+    pub resource_changes: Option<ResourceChanges>,
+    pub favorites_overlay: Option<FavoritesOverlay>,
+    pub favorite_note_form: Option<FavoriteNoteForm>,
+    pub tag_edit_form: Option<TagEditForm>,
+    pub preset_form: Option<PresetForm>,
+    pub action_menu: Option<ActionMenu>,
+    /// The first-run wizard, until it is finished or skipped
+    pub setup_wizard: Option<SetupWizard>,
+    pub security_group_inspector: Option<SecurityGroupInspector>,
+    pub log_tail: Option<LogTail>,
+    /// EKS clusters still being created, polled until they settle
+    pub cluster_creations: Vec<ClusterCreation>,
+    pub batch_results_scroll: u16,
+    /// Set once the user has been told MFA credentials are about to expire
+    credentials_expiry_warned: bool,
+    /// When a connectivity error last took the app offline; cleared by any success
+    offline_since: Option<Instant>,
+    last_connectivity_probe: Option<Instant>,
+    /// When the clients for the active profile were last requested
+    last_connect_attempt: Option<Instant>,
+    /// First visible line of the resource detail info panel
+    pub detail_scroll: u16,
+    pub detail_tab: DetailTab,
+    /// Selected row among the Overview fields matching `detail_filter`
+    pub detail_field: usize,
+    pub detail_filter: String,
+    /// Typed keys go to `detail_filter` instead of the page
+    pub detail_filter_editing: bool,
+    /// Recent events per (region, resource), shown on the detail page's Events tab
+    pub resource_events: HashMap<(String, ResourceId), Vec<ResourceEvent>>,
+    /// Resources the detail page's resource references; `None` until loaded
+    pub related_resources: Option<Vec<RelatedResource>>,
+    /// Related resource highlighted with Tab, opened with Enter
+    pub related_index: Option<usize>,
+    /// Managed node groups per (region, cluster), shown on the cluster's detail page
+    pub node_groups: HashMap<(String, ResourceId), Vec<EksNodeGroup>>,
+    /// Groups and attached policies of IAM users, per (region, user)
+    pub iam_user_details: HashMap<(String, ResourceId), IamUserDetails>,
+    /// Node group highlighted on a cluster's detail page
+    pub selected_node_group: usize,
+    pub node_group_size_form: Option<NodeGroupSizeForm>,
+    pub raw_inspector: Option<RawInspector>,
+    pub terminal_size: (u16, u16),
+    /// False while the terminal reports that it lost focus
+    pub focused: bool,
+    /// Whether the terminal reports modifiers on Enter, so Shift+Enter can
+    /// be told apart from Enter; most terminals only do once asked to
+    pub shift_enter_supported: bool,
 
     // Quick Navigation
     pub quick_nav_visible: bool,
@@ -99,8 +434,32 @@ pub struct AppState {
     pub quick_nav_suggestions: Vec<NavigationItem>,
     pub quick_nav_selected_index: usize,
 
+    // Key Sequences
+    pub pending_keys: String,
+    pub pending_keys_since: Option<Instant>,
+
     // Command Palette
     pub command_palette: CommandPalette,
+    /// The palette command shown in the explain overlay instead of being run
+    pub explained_command: Option<crate::command::Command>,
+
+    // Confirmation Dialog
+    pub pending_confirmation: Option<PendingConfirmation>,
+
+    // Forms
+    pub secret_form: Option<SecretForm>,
+    pub bucket_form: Option<BucketForm>,
+    pub cluster_form: Option<ClusterForm>,
+    pub view_state_form: Option<ViewStateForm>,
+    pub zone_picker: Option<ZonePicker>,
+
+    // Diagnostics
+    pub profile_comparison: Option<(ProfileCheck, ProfileCheck)>,
+    pub profile_validation: Option<ProfileValidation>,
+    pub profile_validation_scroll: u16,
+    /// Permission diagnostics, cached per profile
+    pub permission_reports: HashMap<String, PermissionReport>,
+    pub permission_report_visible: bool,
 
     // User Configuration
     pub user_config: UserConfig,
@@ -108,42 +467,263 @@ pub struct AppState {
     // Error State
     pub error_message: Option<String>,
     pub notifications: Vec<Notification>,
+    pub notification_overlay: Option<NotificationOverlay>,
+
+    /// Started with `--demo`: every client serves fixed mock data
+    pub demo_mode: bool,
 }
 
 impl AppState {
-    pub async fn new() -> Result<Self> {
-        let user_config = UserConfig::load().unwrap_or_default();
+    /// `demo` swaps every AWS client for deterministic mock data under a
+    /// single synthetic profile
+    pub async fn new(demo: bool) -> Result<Self> {
+        let mut notifications = vec![];
+
+        // Checked before loading, since a missing file loads as the defaults
+        let first_run = !demo && UserConfig::is_first_run();
+
+        // A malformed config file is worth telling the user about; anything else
+        // (e.g. an unwritable config dir) just falls back to defaults quietly
+        let user_config = match UserConfig::load() {
+            Ok(config) => config,
+            Err(e) if e.is_parse_error() => {
+                notifications.push(Notification {
+                    message: format!("{}, using defaults", e),
+                    level: NotificationLevel::Warning,
+                    timestamp: chrono::Utc::now(),
+                    action: None,
+                });
+                UserConfig::default()
+            }
+            Err(e) => {
+                tracing::warn!("Failed to load user config: {}", e);
+                UserConfig::default()
+            }
+        };
+        let unknown_categories = user_config.command_palette.unknown_categories();
+        if !unknown_categories.is_empty() {
+            notifications.push(Notification {
+                message: format!(
+                    "Ignoring unknown command palette categories: {} (expected {})",
+                    unknown_categories.join(", "),
+                    CommandCategory::NAMES.join(", ")
+                ),
+                level: NotificationLevel::Warning,
+                timestamp: chrono::Utc::now(),
+                action: None,
+            });
+        }
+
         let profile_manager = ProfileManager::new()?;
+        for e in profile_manager.load_errors() {
+            notifications.push(Notification {
+                message: format!("{}; its profiles were skipped", e),
+                level: NotificationLevel::Warning,
+                timestamp: chrono::Utc::now(),
+                action: None,
+            });
+        }
         let available_profiles: Vec<AwsProfile> = profile_manager
             .get_profiles()
             .into_iter()
             .cloned()
             .collect();
 
-        let current_profile = user_config.aws.default_profile.clone();
-        let current_region = user_config.aws.default_region.clone();
+        let available_regions = Self::default_regions();
 
-        let available_regions = vec![
-            AwsRegion {
-                name: "us-east-1".to_string(),
-                display_name: "US East (N. Virginia)".to_string(),
-            },
-            AwsRegion {
-                name: "us-west-2".to_string(),
-                display_name: "US West (Oregon)".to_string(),
-            },
-            AwsRegion {
-                name: "eu-west-1".to_string(),
-                display_name: "Europe (Ireland)".to_string(),
-            },
-            AwsRegion {
-                name: "ap-southeast-1".to_string(),
-                display_name: "Asia Pacific (Singapore)".to_string(),
-            },
-        ];
+        // Config defaults that no longer resolve fall back to the first valid choice
+        let (default_profile, profile_warning) = Self::validated_default(
+            "profile",
+            &user_config.aws.default_profile,
+            available_profiles.iter().map(|p| p.name.as_str()),
+        );
+        let (default_region, region_warning) = Self::validated_default(
+            "region",
+            &user_config.aws.default_region,
+            available_regions.iter().map(|r| r.name.as_str()),
+        );
+        // Demo mode ignores the configured profiles, so a stale default is irrelevant
+        let profile_warning = profile_warning.filter(|_| !demo);
+        for warning in [profile_warning, region_warning].into_iter().flatten() {
+            notifications.push(Notification {
+                message: warning,
+                level: NotificationLevel::Warning,
+                timestamp: chrono::Utc::now(),
+                action: None,
+            });
+        }
+
+        // Honor AWS_PROFILE / AWS_REGION like the AWS CLI does, falling back to config defaults
+        let mut sources = vec![];
+
+        let current_profile = match Self::env_override(&["AWS_PROFILE"]) {
+            _ if demo => demo::DEMO_PROFILE.to_string(),
+            Some((var, name)) if available_profiles.iter().any(|p| p.name == name) => {
+                sources.push(format!("profile '{}' from {}", name, var));
+                name
+            }
+            Some((var, name)) => {
+                sources.push(format!(
+                    "profile '{}' from config ({} '{}' is not a known profile)",
+                    default_profile, var, name
+                ));
+                default_profile
+            }
+            None => default_profile,
+        };
+
+        let current_region = match Self::env_override(&["AWS_REGION", "AWS_DEFAULT_REGION"]) {
+            Some((var, name)) if available_regions.iter().any(|r| r.name == name) => {
+                sources.push(format!("region '{}' from {}", name, var));
+                name
+            }
+            Some((var, name)) => {
+                sources.push(format!(
+                    "region '{}' from config ({} '{}' is not an available region)",
+                    default_region, var, name
+                ));
+                default_region
+            }
+            None => default_region,
+        };
+
+        if !sources.is_empty() {
+            notifications.push(Notification {
+                message: format!("Using {}", sources.join(", ")),
+                level: NotificationLevel::Info,
+                timestamp: chrono::Utc::now(),
+                action: None,
+            });
+        }
 
         let favorites_manager = FavoritesManager::new()?;
-        let dashboard_layout = DashboardLayout::new();
+        // Like the layout below, history that cannot be read starts over
+        let max_recent_items = user_config.dashboard.max_recent_items;
+        let recently_viewed = RecentlyViewed::new(max_recent_items).unwrap_or_else(|e| {
+            notifications.push(Notification {
+                message: format!(
+                    "Recently viewed resources could not be read ({}); starting empty",
+                    e
+                ),
+                level: NotificationLevel::Warning,
+                timestamp: chrono::Utc::now(),
+                action: None,
+            });
+            RecentlyViewed::empty(
+                max_recent_items,
+                RecentlyViewed::config_path().unwrap_or_default(),
+            )
+        });
+        let recent_activity = ActivityLog::new(MAX_ACTIVITY_ENTRIES).unwrap_or_else(|e| {
+            notifications.push(Notification {
+                message: format!("Activity log could not be read ({}); starting empty", e),
+                level: NotificationLevel::Warning,
+                timestamp: chrono::Utc::now(),
+                action: None,
+            });
+            ActivityLog::empty(
+                MAX_ACTIVITY_ENTRIES,
+                ActivityLog::config_path().unwrap_or_default(),
+            )
+        });
+        // A layout that cannot be read at all is replaced, not fatal
+        let dashboard_layout = match DashboardLayout::load() {
+            Ok((layout, warnings)) => {
+                for warning in warnings {
+                    notifications.push(Notification {
+                        message: warning,
+                        level: NotificationLevel::Warning,
+                        timestamp: chrono::Utc::now(),
+                        action: None,
+                    });
+                }
+                layout
+            }
+            Err(e) => {
+                notifications.push(Notification {
+                    message: format!(
+                        "Saved dashboard layout could not be read ({}); using the default",
+                        e
+                    ),
+                    level: NotificationLevel::Warning,
+                    timestamp: chrono::Utc::now(),
+                    action: None,
+                });
+                DashboardLayout::new()
+            }
+        };
+
+        // Clients connect in the background so slow credential resolution never delays the UI
+        let saved = SavedData {
+            favorites_manager,
+            recently_viewed,
+            recent_activity,
+            dashboard_layout,
+        };
+        let mut state = Self::from_parts(
+            user_config,
+            profile_manager,
+            saved,
+            None,
+            current_profile,
+            current_region,
+        );
+        state.notifications = notifications;
+        if demo {
+            state.demo_mode = true;
+            state.available_profiles = vec![demo::profile()];
+            state.update_command_context();
+        }
+        // Without any known profile there is nothing to build clients from
+        if state
+            .available_profiles
+            .iter()
+            .any(|p| p.name == state.current_profile)
+        {
+            state.connect_aws_clients();
+            state.warn_if_no_credentials();
+        }
+
+        if state.dashboard_layout.account_summary_widget().is_some() {
+            state.refresh_account_summary();
+        }
+
+        if first_run {
+            state.setup_wizard = Some(SetupWizard::new(
+                &state.available_profiles,
+                &state.available_regions,
+                &state.current_profile,
+                &state.current_region,
+                &state.user_config,
+            ));
+        }
+
+        Ok(state)
+    }
+
+    /// Assemble the state from already loaded configuration, without reading
+    /// files or the environment
+    pub fn from_parts(
+        user_config: UserConfig,
+        profile_manager: ProfileManager,
+        saved: SavedData,
+        aws_clients: Option<MultiRegionAwsClients>,
+        current_profile: String,
+        current_region: String,
+    ) -> Self {
+        let SavedData {
+            favorites_manager,
+            recently_viewed,
+            recent_activity,
+            mut dashboard_layout,
+        } = saved;
+        let available_profiles: Vec<AwsProfile> = profile_manager
+            .get_profiles()
+            .into_iter()
+            .cloned()
+            .collect();
+        let available_regions = Self::default_regions();
+        dashboard_layout.enable_widgets(&user_config.dashboard.enabled_widgets);
 
         // Initialize command context
         let command_context = CommandContext::new(
@@ -157,19 +737,14 @@ impl AppState {
         );
 
         // Initialize command palette
-        let command_palette = CommandPalette::new(command_context);
-
-        // Try to initialize AWS clients
-        let aws_clients = match MultiRegionAwsClients::new(&current_profile, &current_region).await
-        {
-            Ok(clients) => Some(clients),
-            Err(e) => {
-                tracing::warn!("Failed to initialize AWS clients: {}", e);
-                None
-            }
-        };
+        let mut command_palette = CommandPalette::new(command_context);
+        command_palette.set_pinned(user_config.command_palette.pinned_commands.clone());
+        command_palette.set_category_layout(
+            user_config.command_palette.category_order.clone(),
+            user_config.command_palette.hidden_categories.clone(),
+        );
 
-        Ok(Self {
+        Self {
             current_page: user_config.dashboard.default_page.clone(),
             page_history: vec![],
             current_profile,
@@ -180,10 +755,21 @@ impl AppState {
             profile_manager,
             dashboard_layout,
             favorites_manager,
-            recent_activity: vec![],
+            recently_viewed,
+            recent_activity,
+            activity_filter: ActivityFilter::default(),
+            activity_scroll: 0,
+            account_summary: None,
+            cost_summary: None,
+            cost_refreshed_at: None,
+            region_overview_refreshed_at: None,
+            view_refreshed_at: None,
             resources: HashMap::new(),
-            loading_states: HashMap::new(),
             last_refresh: HashMap::new(),
+            permission_denied: HashSet::new(),
+            service_health: HashMap::new(),
+            zone_filters: HashMap::new(),
+            operations: OperationQueue::new(user_config.aws.max_concurrent_requests),
             selected_resource: None,
             help_visible: false,
             settings_visible: false,
@@ -192,18 +778,270 @@ impl AppState {
             selected_widget: None,
             selected_service: None,
             selected_resource_index: 0,
+            selected_resource_id: None,
+            detail_scroll: 0,
+            detail_tab: DetailTab::Overview,
+            detail_field: 0,
+            detail_filter: String::new(),
+            detail_filter_editing: false,
+            resource_events: HashMap::new(),
+            related_resources: None,
+            related_index: None,
+            node_groups: HashMap::new(),
+            iam_user_details: HashMap::new(),
+            selected_node_group: 0,
+            node_group_size_form: None,
+            raw_inspector: None,
+            terminal_size: crossterm::terminal::size().unwrap_or((80, 24)),
+            focused: true,
+            shift_enter_supported: false,
             quick_nav_visible: false,
             quick_nav_input: String::new(),
             quick_nav_suggestions: vec![],
             quick_nav_selected_index: 0,
+            pending_keys: String::new(),
+            pending_keys_since: None,
             command_palette,
+            explained_command: None,
+            pending_confirmation: None,
+            secret_form: None,
+            bucket_form: None,
+            cluster_form: None,
+            view_state_form: None,
+            zone_picker: None,
+            profile_comparison: None,
+            profile_validation: None,
+            profile_validation_scroll: 0,
+            marked_resources: vec![],
+            marked_service: None,
+            batch_results: None,
+            cheat_sheet_form: None,
+            resource_export_form: None,
+            assume_role_form: None,
+            instance_form: None,
+            pending_profile_switch: None,
+            retry_action: None,
+            starting_background: false,
+            resource_baselines: HashMap::new(),
+            missing_resources: HashSet::new(),
+            resource_changes: None,
+            favorites_overlay: None,
+            favorite_note_form: None,
+            tag_edit_form: None,
+            preset_form: None,
+            action_menu: None,
+            setup_wizard: None,
+            security_group_inspector: None,
+            log_tail: None,
+            cluster_creations: vec![],
+            batch_results_scroll: 0,
+            credentials_expiry_warned: false,
+            offline_since: None,
+            last_connectivity_probe: None,
+            last_connect_attempt: None,
+            permission_reports: HashMap::new(),
+            permission_report_visible: false,
             user_config,
             error_message: None,
             notifications: vec![],
+            notification_overlay: None,
+            demo_mode: false,
+        }
+    }
+
+    /// The embedded region list, used until DescribeRegions answers for the
+    /// account or when it cannot be called
+    fn default_regions() -> Vec<AwsRegion> {
+        RegionManager::get_all_regions()
+    }
+
+    /// The configured default if it is one of `available`, otherwise the first
+    /// available name (or "default" when there is none) and a warning saying so
+    pub fn validated_default<'a>(
+        kind: &str,
+        configured: &str,
+        available: impl IntoIterator<Item = &'a str>,
+    ) -> (String, Option<String>) {
+        let mut available = available.into_iter().peekable();
+        let fallback = available.peek().copied();
+        if available.any(|name| name == configured) {
+            return (configured.to_string(), None);
+        }
+        match fallback {
+            Some(fallback) => (
+                fallback.to_string(),
+                Some(format!(
+                    "Default {} '{}' not found; using '{}' instead",
+                    kind, configured, fallback
+                )),
+            ),
+            None => (
+                "default".to_string(),
+                Some(format!(
+                    "Default {} '{}' not found and none are configured",
+                    kind, configured
+                )),
+            ),
+        }
+    }
+
+    /// Return the first non-empty environment variable among `vars` with its name
+    fn env_override(vars: &[&'static str]) -> Option<(&'static str, String)> {
+        vars.iter().find_map(|var| {
+            std::env::var(var)
+                .ok()
+                .filter(|value| !value.is_empty())
+                .map(|value| (*var, value))
         })
     }
 
     pub async fn handle_input(&mut self, key: KeyEvent) -> Result<()> {
+        // A pending confirmation captures all input until answered
+        if self.pending_confirmation.is_some() {
+            return self.handle_confirmation_input(key).await;
+        }
+
+        if self.setup_wizard.is_some() {
+            return self.handle_setup_wizard_input(key).await;
+        }
+
+        if self.secret_form.is_some() {
+            self.handle_secret_form_input(key);
+            return Ok(());
+        }
+
+        if self.raw_inspector.is_some() {
+            self.handle_raw_inspector_input(key);
+            return Ok(());
+        }
+
+        if self.node_group_size_form.is_some() {
+            self.handle_node_group_size_input(key);
+            return Ok(());
+        }
+
+        if self.bucket_form.is_some() {
+            self.handle_bucket_form_input(key);
+            return Ok(());
+        }
+
+        if self.cluster_form.is_some() {
+            self.handle_cluster_form_input(key);
+            return Ok(());
+        }
+
+        if self.view_state_form.is_some() {
+            return self.handle_view_state_input(key).await;
+        }
+
+        if self.cheat_sheet_form.is_some() {
+            self.handle_cheat_sheet_input(key);
+            return Ok(());
+        }
+
+        if self.resource_export_form.is_some() {
+            self.handle_resource_export_input(key);
+            return Ok(());
+        }
+
+        if self.favorite_note_form.is_some() {
+            self.handle_favorite_note_input(key);
+            return Ok(());
+        }
+
+        if self.tag_edit_form.is_some() {
+            self.handle_tag_edit_input(key);
+            return Ok(());
+        }
+
+        if self.preset_form.is_some() {
+            self.handle_preset_input(key);
+            return Ok(());
+        }
+
+        if self.assume_role_form.is_some() {
+            self.handle_assume_role_input(key);
+            return Ok(());
+        }
+
+        if self.instance_form.is_some() {
+            self.handle_instance_form_input(key);
+            return Ok(());
+        }
+
+        if self.zone_picker.is_some() {
+            self.handle_zone_picker_input(key);
+            return Ok(());
+        }
+
+        // The comparison overlay is read-only; any dismiss key closes it
+        if self.profile_comparison.is_some() {
+            if matches!(key.code, KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q')) {
+                self.profile_comparison = None;
+            }
+            return Ok(());
+        }
+
+        if self.batch_results.is_some() {
+            self.handle_batch_results_input(key);
+            return Ok(());
+        }
+
+        if self.profile_validation.is_some() {
+            self.handle_profile_validation_input(key);
+            return Ok(());
+        }
+
+        if self.resource_changes.is_some() {
+            self.handle_resource_changes_input(key);
+            return Ok(());
+        }
+
+        if self.favorites_overlay.is_some() {
+            return self.handle_favorites_overlay_input(key).await;
+        }
+
+        if self.security_group_inspector.is_some() {
+            self.handle_security_group_inspector_input(key);
+            return Ok(());
+        }
+
+        if self.log_tail.is_some() {
+            self.handle_log_tail_input(key);
+            return Ok(());
+        }
+
+        if self.notification_overlay.is_some() {
+            return self.handle_notification_overlay_input(key).await;
+        }
+
+        if self.permission_report_visible {
+            match key.code {
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                    self.permission_report_visible = false;
+                }
+                KeyCode::Char('r') => {
+                    self.permission_report_visible = false;
+                    self.run_permission_diagnostics();
+                }
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        if self.detail_filter_editing {
+            self.handle_detail_filter_input(key);
+            return Ok(());
+        }
+
+        if self.explained_command.is_some() {
+            return self.handle_command_explanation_input(key).await;
+        }
+
+        if self.action_menu.is_some() {
+            return self.handle_action_menu_input(key).await;
+        }
+
         // Handle command palette input first
         if self.command_palette.is_visible() {
             return self.handle_command_palette_input(key).await;
@@ -214,6 +1052,10 @@ impl AppState {
             return self.handle_quick_nav_input(key).await;
         }
 
+        if self.handle_key_sequence(key).await? {
+            return Ok(());
+        }
+
         match key.code {
             KeyCode::Char('q') => {
                 // Handled in main.rs
@@ -231,11 +1073,26 @@ impl AppState {
                 self.help_visible = !self.help_visible;
                 Ok(())
             }
+            KeyCode::Char('N') => {
+                self.notification_overlay = Some(NotificationOverlay::default());
+                Ok(())
+            }
+            KeyCode::Char('A') => match self.notifications.len().checked_sub(1) {
+                Some(latest) => self.run_notification_action(latest).await,
+                None => Ok(()),
+            },
             KeyCode::Esc => {
                 self.handle_escape();
                 Ok(())
             }
-            KeyCode::Enter => self.handle_enter().await,
+            KeyCode::Enter => {
+                self.handle_enter(key.modifiers.contains(KeyModifiers::SHIFT))
+                    .await
+            }
+            KeyCode::Tab => {
+                self.handle_tab();
+                Ok(())
+            }
             KeyCode::Up => {
                 self.handle_up();
                 Ok(())
@@ -244,10 +1101,75 @@ impl AppState {
                 self.handle_down();
                 Ok(())
             }
+            KeyCode::PageUp => {
+                self.handle_page_scroll(false);
+                Ok(())
+            }
+            KeyCode::PageDown => {
+                self.handle_page_scroll(true);
+                Ok(())
+            }
             KeyCode::Left => {
                 self.handle_left();
                 Ok(())
             }
+            KeyCode::Char(' ') => {
+                self.toggle_mark();
+                Ok(())
+            }
+            KeyCode::Char('J') => {
+                self.open_raw_inspector();
+                Ok(())
+            }
+            KeyCode::Char('a') if matches!(self.current_page, AppPage::ResourceDetail(_, _)) => {
+                self.open_action_menu();
+                Ok(())
+            }
+            KeyCode::Char('r') => {
+                self.refresh_current_view();
+                Ok(())
+            }
+            KeyCode::Char('R') if matches!(self.current_page, AppPage::ResourceList(_)) => {
+                self.reload_current_service();
+                Ok(())
+            }
+            KeyCode::Char('/') if self.detail_fields_focused() => {
+                self.detail_filter_editing = true;
+                Ok(())
+            }
+            KeyCode::Char('c') if self.detail_fields_focused() => {
+                self.copy_detail_field();
+                Ok(())
+            }
+            KeyCode::Char(c @ '1'..='9')
+                if matches!(self.current_page, AppPage::ResourceDetail(_, _)) =>
+            {
+                self.select_detail_tab(c as usize - '1' as usize);
+                Ok(())
+            }
+            KeyCode::Char(c @ '1'..='9')
+                if matches!(self.current_page, AppPage::ResourceList(_)) =>
+            {
+                self.select_state_filter(c as usize - '1' as usize);
+                Ok(())
+            }
+            KeyCode::Char(c @ '1'..='3') if self.current_page == AppPage::ActivityLog => {
+                self.cycle_activity_filter(c);
+                Ok(())
+            }
+            KeyCode::Char('o') if self.current_page == AppPage::ActivityLog => {
+                self.activity_filter.oldest_first = !self.activity_filter.oldest_first;
+                self.activity_scroll = 0;
+                Ok(())
+            }
+            KeyCode::Char('x') if self.current_page == AppPage::ActivityLog => {
+                self.request_clear_activity_log();
+                Ok(())
+            }
+            KeyCode::Char(c @ ('+' | '-')) if self.current_page == AppPage::Settings => {
+                self.adjust_max_concurrent_requests(c == '+');
+                Ok(())
+            }
             KeyCode::Right => {
                 self.handle_right();
                 Ok(())
@@ -256,17 +1178,2745 @@ impl AppState {
         }
     }
 
-    pub async fn update(&mut self) -> Result<()> {
-        // Update dashboard widgets if needed
-        // This would typically refresh data periodically
-        Ok(())
-    }
+    /// Single-key bindings the input handlers act on right now, as (key, description)
+    pub fn active_key_hints(&self) -> Vec<(&'static str, &'static str)> {
+        let mut hints = vec![];
 
-    fn navigate_to_dashboard(&mut self) {
-        self.page_history.push(self.current_page.clone());
-        self.current_page = AppPage::Dashboard;
-        self.selected_widget = None;
-    }
+        match &self.current_page {
+            AppPage::Dashboard if self.favorites_focused() || self.recently_viewed_focused() => {
+                hints.push(("↑↓", "Move"));
+                hints.push(("Enter", "Open"));
+                hints.push(("Tab", "Next widget"));
+            }
+            AppPage::Dashboard if self.recent_activity_focused() => {
+                hints.push(("Enter", "Activity log"));
+                hints.push(("Tab", "Next widget"));
+            }
+            AppPage::Dashboard => hints.push(("Tab", "Widgets")),
+            AppPage::ResourceList(service_type) => {
+                hints.push(("↑↓", "Select"));
+                hints.push(("←→", "Service"));
+                hints.push(("Space", "Mark"));
+                hints.push(("r", "Refresh"));
+                hints.push(("R", "Reload"));
+                if !StateFilter::options(*service_type).is_empty() {
+                    hints.push(("1-3", "State"));
+                }
+                let action = self.user_config.resource_list.enter_action(*service_type);
+                let label = |action| match action {
+                    EnterAction::Details => "Details",
+                    EnterAction::Contents => "Contents",
+                };
+                if crate::command::ServiceCommand::contents_command(*service_type).is_some() {
+                    hints.push(("Enter", label(action)));
+                    if self.shift_enter_supported {
+                        hints.push(("Shift+Enter", label(action.alternate())));
+                    }
+                } else {
+                    hints.push(("Enter", "Details"));
+                }
+            }
+            AppPage::ResourceDetail(service_type, resource_id)
+                if self.is_resource_missing(*service_type, resource_id) =>
+            {
+                hints.push(("Enter", "Open list"));
+                hints.push(("Esc", "Back"));
+            }
+            AppPage::ResourceDetail(_, _) if self.detail_filter_editing => {
+                hints.push(("Enter", "Keep search"));
+                hints.push(("Esc", "Clear search"));
+            }
+            AppPage::ResourceDetail(service_type, resource_id) => {
+                if self.detail_tab == DetailTab::Overview {
+                    hints.push(("↑↓", "Field"));
+                    hints.push(("c", "Copy value"));
+                    hints.push(("/", "Search"));
+                } else {
+                    hints.push(("↑↓ PgUp PgDn", "Scroll"));
+                }
+                hints.push(("←→", "Service"));
+                let tab_keys = match DetailTab::for_service(*service_type).len() {
+                    2 => "1-2",
+                    _ => "1-3",
+                };
+                hints.push((tab_keys, "Tabs"));
+                hints.push(("a", "Actions"));
+                hints.push(("r", "Refresh"));
+                hints.push(("J", "Raw JSON"));
+                // Node groups and related resources are only on the Overview tab
+                if self.detail_tab == DetailTab::Overview {
+                    if *service_type == ServiceType::EKS
+                        && self
+                            .node_groups
+                            .get(&(self.current_region.clone(), resource_id.clone()))
+                            .is_some_and(|groups| groups.len() > 1)
+                    {
+                        hints.push(("Tab", "Node group"));
+                    }
+                    if self
+                        .related_resources
+                        .as_ref()
+                        .is_some_and(|related| !related.is_empty())
+                    {
+                        hints.push(("Tab", "Related"));
+                        if self.related_index.is_some() {
+                            hints.push(("Enter", "Open"));
+                        }
+                    }
+                }
+            }
+            AppPage::Settings => hints.push(("+-", "Max requests")),
+            AppPage::ActivityLog => {
+                hints.push(("↑↓ PgUp PgDn", "Scroll"));
+                hints.push(("1", "Service"));
+                hints.push(("2", "Region"));
+                hints.push(("3", "Action"));
+                hints.push(("o", "Sort"));
+                hints.push(("x", "Clear log"));
+            }
+        }
+
+        // Mirrors the precedence in handle_escape
+        if self.current_page == AppPage::Dashboard
+            && self.dashboard_layout.get_selected_widget().is_some()
+        {
+            hints.push(("Esc", "Unfocus"));
+        } else if self.operations.is_busy() {
+            hints.push(("Esc", "Cancel"));
+        } else if !self.page_history.is_empty() {
+            hints.push(("Esc", "Back"));
+        }
+
+        if !self.user_config.keybindings.sequences.is_empty() {
+            hints.push(("g", "Go to"));
+        }
+        if !self.notifications.is_empty() {
+            hints.push(("N", "Notifications"));
+        }
+        if let Some(action) = self.notifications.last().and_then(|n| n.action.as_ref()) {
+            hints.push(("A", action.label()));
+        }
+        hints.push(("Ctrl+O", "Commands"));
+        hints.push(("Ctrl+P", "Quick nav"));
+        hints.push(("?", "Help"));
+        hints
+    }
+
+    /// Show what a tag edit would add, change and remove, and hold it until confirmed
+    pub fn request_tag_changes(&mut self, resource: &Resource, edited: &HashMap<String, String>) {
+        let diff = TagDiff::between(&resource.tags, edited);
+        if diff.is_empty() {
+            self.add_notification(
+                "No tag changes to apply".to_string(),
+                NotificationLevel::Info,
+            );
+            return;
+        }
+
+        self.pending_confirmation = Some(PendingConfirmation::new(
+            "Apply Tag Changes".to_string(),
+            format!("Apply {} to {}?", diff.summary(), resource.name),
+            ConfirmAction::ApplyTags {
+                service_type: resource.service_type,
+                resource_arn: resource.arn.clone(),
+                diff,
+            },
+        ));
+    }
+
+    /// Hold a destructive command on the marked resources behind a review of
+    /// every resource it affects
+    fn request_batch_confirmation(
+        &mut self,
+        service_type: ServiceType,
+        command: &crate::command::ServiceCommand,
+    ) {
+        let marked = self.marked_for(service_type).to_vec();
+        let cached = self.cached_resources(service_type);
+        // Marked rows are always listed, so a missing one is only possible mid-refresh
+        let resources: Vec<Resource> = marked
+            .iter()
+            .map(|id| {
+                cached
+                    .and_then(|resources| resources.iter().find(|r| r.id == *id))
+                    .cloned()
+                    .unwrap_or_else(|| Resource {
+                        id: id.clone(),
+                        name: id.clone(),
+                        service_type,
+                        region: self.current_region.clone(),
+                        arn: String::new(),
+                        state: "unknown".to_string(),
+                        availability_zone: None,
+                        tags: HashMap::new(),
+                        created_at: None,
+                        last_modified: None,
+                        instance_type: None,
+                    })
+            })
+            .collect();
+
+        self.pending_confirmation = Some(PendingConfirmation::new(
+            format!("{} ({})", command.display_name(), resources.len()),
+            format!(
+                "{} {} {} resources in {}. This cannot be undone.",
+                command.display_name(),
+                resources.len(),
+                service_type.display_name(),
+                self.current_region
+            ),
+            ConfirmAction::Batch {
+                command: command.clone(),
+                resources,
+            },
+        ));
+    }
+
+    async fn handle_confirmation_input(&mut self, key: KeyEvent) -> Result<()> {
+        if let Some(confirmation) = self
+            .pending_confirmation
+            .as_mut()
+            .filter(|confirmation| confirmation.requires_typing())
+        {
+            match key.code {
+                KeyCode::Esc => {
+                    self.pending_confirmation = None;
+                    self.add_notification("Cancelled".to_string(), NotificationLevel::Info);
+                }
+                KeyCode::Enter if confirmation.typed_matches() => {
+                    if let Some(confirmation) = self.pending_confirmation.take() {
+                        self.run_confirmed_action(confirmation.action).await?;
+                    }
+                }
+                KeyCode::Backspace => {
+                    pop_grapheme(&mut confirmation.typed);
+                }
+                KeyCode::Char(c) => confirmation.typed.push(c),
+                _ => {}
+            }
+            return Ok(());
+        }
+
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
+                if let Some(confirmation) = self.pending_confirmation.take() {
+                    self.run_confirmed_action(confirmation.action).await?;
+                }
+            }
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
+                self.pending_confirmation = None;
+                self.add_notification("Cancelled".to_string(), NotificationLevel::Info);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn run_confirmed_action(&mut self, action: ConfirmAction) -> Result<()> {
+        match action {
+            ConfirmAction::ClearCache => self.clear_cache(),
+            ConfirmAction::ClearActivityLog => self.clear_activity_log(),
+            ConfirmAction::ApplyTags {
+                service_type,
+                resource_arn,
+                diff,
+            } => {
+                let summary = diff.summary();
+                self.spawn_operation(
+                    service_type,
+                    "Apply tags",
+                    &format!("Tags updated ({})", summary),
+                    async move {
+                        TaggingService::new()
+                            .apply_tag_diff(&resource_arn, &diff)
+                            .await?;
+                        Ok(OperationOutput::Done)
+                    },
+                );
+            }
+            ConfirmAction::Batch { command, resources } => {
+                self.clear_marks();
+                let ids = resources.into_iter().map(|resource| resource.id).collect();
+                self.spawn_ec2_batch(&command, ids);
+            }
+        }
+        self.update_command_context();
+        Ok(())
+    }
+
+    fn handle_secret_form_input(&mut self, key: KeyEvent) {
+        let Some(form) = &mut self.secret_form else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => self.secret_form = None,
+            KeyCode::Enter => self.submit_secret_form(),
+            KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                form.toggle_mode();
+            }
+            KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                form.add_pair();
+            }
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                form.toggle_reveal();
+            }
+            KeyCode::Tab | KeyCode::Down => form.focus_next(),
+            KeyCode::BackTab | KeyCode::Up => form.focus_previous(),
+            KeyCode::Backspace => form.backspace(),
+            KeyCode::Char(c) => form.insert_char(c),
+            _ => {}
+        }
+    }
+
+    fn handle_bucket_form_input(&mut self, key: KeyEvent) {
+        let Some(form) = &mut self.bucket_form else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => self.bucket_form = None,
+            KeyCode::Enter => self.submit_bucket_form(),
+            KeyCode::Backspace => form.backspace(),
+            KeyCode::Char(c) => form.insert_char(c),
+            _ => {}
+        }
+    }
+
+    fn handle_cluster_form_input(&mut self, key: KeyEvent) {
+        let Some(form) = &mut self.cluster_form else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => self.cluster_form = None,
+            KeyCode::Enter => self.submit_cluster_form(),
+            KeyCode::Backspace => form.backspace(),
+            KeyCode::Char(c) => form.insert_char(c),
+            _ => {}
+        }
+    }
+
+    async fn handle_view_state_input(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(form) = &mut self.view_state_form else {
+            return Ok(());
+        };
+
+        match key.code {
+            KeyCode::Esc => self.view_state_form = None,
+            KeyCode::Enter => return self.submit_view_state_form().await,
+            KeyCode::Backspace => form.backspace(),
+            KeyCode::Char(c) => form.insert_char(c),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Where the user is now, as a link that `submit_view_state_form` can open
+    fn current_view_state(&self) -> ViewState {
+        ViewState {
+            profile: self.current_profile.clone(),
+            region: self.current_region.clone(),
+            page: self.current_page.clone(),
+        }
+    }
+
+    /// Whether the detail page's Overview fields take the arrow, copy and search keys
+    fn detail_fields_focused(&self) -> bool {
+        match &self.current_page {
+            AppPage::ResourceDetail(service_type, resource_id) => {
+                self.detail_tab == DetailTab::Overview
+                    && !self.is_resource_missing(*service_type, resource_id)
+            }
+            _ => false,
+        }
+    }
+
+    /// The detail page's Overview fields that match the search, in display order
+    pub fn visible_detail_fields(&self) -> Vec<DetailField> {
+        let AppPage::ResourceDetail(service_type, resource_id) = &self.current_page else {
+            return vec![];
+        };
+        overview_fields(self, *service_type, resource_id)
+            .into_iter()
+            .filter(|field| field.matches(&self.detail_filter))
+            .collect()
+    }
+
+    fn move_detail_field(&mut self, down: bool, step: usize) {
+        let last = self.visible_detail_fields().len().saturating_sub(1);
+        self.detail_field = if down {
+            self.detail_field.saturating_add(step).min(last)
+        } else {
+            self.detail_field.saturating_sub(step)
+        };
+    }
+
+    fn handle_detail_filter_input(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Enter => self.detail_filter_editing = false,
+            KeyCode::Esc => self.clear_detail_filter(),
+            KeyCode::Backspace => {
+                pop_grapheme(&mut self.detail_filter);
+                self.detail_field = 0;
+            }
+            KeyCode::Char(c) => {
+                self.detail_filter.push(c);
+                self.detail_field = 0;
+            }
+            _ => {}
+        }
+    }
+
+    fn clear_detail_filter(&mut self) {
+        self.detail_filter.clear();
+        self.detail_filter_editing = false;
+        self.detail_field = 0;
+    }
+
+    /// Copy just the selected field's value, e.g. a private IP or VPC id
+    fn copy_detail_field(&mut self) {
+        let Some(field) = self
+            .visible_detail_fields()
+            .into_iter()
+            .nth(self.detail_field)
+        else {
+            self.add_notification("No field to copy".to_string(), NotificationLevel::Info);
+            return;
+        };
+        match crate::utils::clipboard::copy_to_clipboard(&field.value) {
+            Ok(()) => self.add_notification(
+                format!("Copied {} {}", field.label, field.value),
+                NotificationLevel::Success,
+            ),
+            Err(e) => self.add_notification(e.to_string(), NotificationLevel::Error),
+        }
+    }
+
+    /// Copy the marked ids in the current list, or the highlighted row's id
+    /// when nothing is marked, joined by the configured separator
+    fn copy_selected_ids(&mut self) {
+        let AppPage::ResourceList(service_type) = self.current_page else {
+            return;
+        };
+        let ids = match self.marked_for(service_type) {
+            [] => self
+                .resource_id_at(service_type, self.selected_resource_index)
+                .into_iter()
+                .collect(),
+            marked => marked.to_vec(),
+        };
+        if ids.is_empty() {
+            self.add_notification("No resource to copy".to_string(), NotificationLevel::Info);
+            return;
+        }
+
+        let text = self.user_config.resource_list.id_separator.join(&ids);
+        match crate::utils::clipboard::copy_to_clipboard(&text) {
+            Ok(()) => {
+                let message = match ids.as_slice() {
+                    [id] => format!("Copied {}", id),
+                    _ => format!("Copied {} resource ids", ids.len()),
+                };
+                self.add_notification(message, NotificationLevel::Success);
+            }
+            Err(e) => self.add_notification(e.to_string(), NotificationLevel::Error),
+        }
+    }
+
+    fn copy_view_state(&mut self) {
+        let link = self.current_view_state().encode();
+        match crate::utils::clipboard::copy_to_clipboard(&link) {
+            Ok(()) => self.add_notification(
+                format!("Copied view link {}", link),
+                NotificationLevel::Success,
+            ),
+            Err(e) => self.add_notification(e.to_string(), NotificationLevel::Error),
+        }
+    }
+
+    /// Parse the entered link and go there, switching profile and region first
+    async fn submit_view_state_form(&mut self) -> Result<()> {
+        let Some(form) = &mut self.view_state_form else {
+            return Ok(());
+        };
+
+        // Problems stay in the prompt so the link can be corrected in place
+        let state = match ViewState::parse(&form.input) {
+            Ok(state) => state,
+            Err(reason) => {
+                form.error = Some(reason);
+                return Ok(());
+            }
+        };
+        if !self
+            .available_profiles
+            .iter()
+            .any(|p| p.name == state.profile)
+        {
+            form.error = Some(format!("Profile '{}' is not configured", state.profile));
+            return Ok(());
+        }
+        if !self
+            .available_regions
+            .iter()
+            .any(|r| r.name == state.region)
+        {
+            form.error = Some(format!("Region '{}' is not available", state.region));
+            return Ok(());
+        }
+
+        self.view_state_form = None;
+        if state.profile != self.current_profile {
+            self.switch_profile(&state.profile).await?;
+        }
+        if state.region != self.current_region {
+            self.switch_region(&state.region).await?;
+        }
+        match state.page {
+            AppPage::ResourceDetail(service_type, resource_id) => {
+                self.enter_resource_detail(service_type, resource_id);
+            }
+            page => {
+                self.navigate_to_page(page);
+                self.select_resource_at(0);
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate the bucket name and create the bucket in the current region
+    fn submit_bucket_form(&mut self) {
+        let Some(form) = &mut self.bucket_form else {
+            return;
+        };
+
+        // Name errors stay in the prompt so they can be fixed without retyping
+        if let Err(reason) = validate_bucket_name(&form.name) {
+            form.error = Some(format!("Bucket name {}", reason));
+            return;
+        }
+
+        let name = form.name.clone();
+        self.bucket_form = None;
+        self.add_notification(
+            format!("Creating S3 bucket {}...", name),
+            NotificationLevel::Info,
+        );
+        let clients = self.region_clients();
+        self.spawn_operation(
+            ServiceType::S3,
+            "Create Bucket",
+            "S3 bucket created",
+            async move {
+                S3Service::new(&clients).create_bucket(&name).await?;
+                Ok(OperationOutput::Message(format!(
+                    "Created bucket {} in {}",
+                    name, clients.region
+                )))
+            },
+        );
+    }
+
+    /// Validate the cluster name and start creating the cluster in the current
+    /// region; its progress is tracked by `poll_cluster_creation`
+    fn submit_cluster_form(&mut self) {
+        let Some(form) = &self.cluster_form else {
+            return;
+        };
+
+        let name = form.name.clone();
+        let error = match validate_cluster_name(&name) {
+            Err(reason) => Some(format!("Cluster name {}", reason)),
+            Ok(())
+                if self
+                    .visible_resources(ServiceType::EKS)
+                    .iter()
+                    .any(|resource| resource.id == name) =>
+            {
+                Some(AppError::ResourceExists(format!("Cluster {}", name)).to_string())
+            }
+            Ok(()) => None,
+        };
+        if let Some(error) = error {
+            if let Some(form) = &mut self.cluster_form {
+                form.error = Some(error);
+            }
+            return;
+        }
+
+        self.cluster_form = None;
+        self.add_notification(
+            format!("Creating EKS cluster {}...", name),
+            NotificationLevel::Info,
+        );
+        let clients = self.region_clients();
+        self.spawn_operation(ServiceType::EKS, "Create Cluster", "", async move {
+            let cluster = EksService::new(&clients).create_cluster(&name).await?;
+            Ok(OperationOutput::ClusterCreating(Box::new(cluster)))
+        });
+    }
+
+    /// Show the detail page's resource as JSON, from the cached describe output
+    /// when there is one
+    fn open_raw_inspector(&mut self) {
+        let AppPage::ResourceDetail(service_type, resource_id) = &self.current_page else {
+            return;
+        };
+
+        match self.resource_json(*service_type, resource_id) {
+            Ok(json) => {
+                self.raw_inspector = Some(RawInspector::new(resource_id.clone(), json));
+            }
+            Err(e) => self.add_notification(
+                format!("Cannot show {} as JSON: {}", resource_id, e),
+                NotificationLevel::Error,
+            ),
+        }
+    }
+
+    /// A resource as pretty-printed JSON, from the cached describe output when
+    /// there is one
+    pub fn resource_json(
+        &self,
+        service_type: ServiceType,
+        resource_id: &str,
+    ) -> serde_json::Result<String> {
+        match self.cached_resource(service_type, resource_id) {
+            Some(resource) => serde_json::to_string_pretty(resource),
+            None => serde_json::to_string_pretty(&serde_json::json!({
+                "id": resource_id,
+                "service_type": service_type,
+                "region": self.current_region,
+            })),
+        }
+    }
+
+    /// Switch the detail page to its `index`th tab, loading events on first view
+    fn select_detail_tab(&mut self, index: usize) {
+        let AppPage::ResourceDetail(service_type, resource_id) = &self.current_page else {
+            return;
+        };
+        let Some(tab) = DetailTab::for_service(*service_type).get(index).copied() else {
+            return;
+        };
+        if tab == self.detail_tab {
+            return;
+        }
+
+        let (service_type, resource_id) = (*service_type, resource_id.clone());
+        self.detail_tab = tab;
+        self.detail_scroll = 0;
+        let loaded = self
+            .resource_events
+            .contains_key(&(self.current_region.clone(), resource_id.clone()));
+        if tab == DetailTab::Events && !loaded {
+            self.load_resource_events(service_type, resource_id);
+        }
+    }
+
+    /// Fetch a resource's recent events from the service's events API
+    fn load_resource_events(&mut self, service_type: ServiceType, resource_id: ResourceId) {
+        let clients = self.region_clients();
+        self.spawn_operation(service_type, "Load events", "", async move {
+            let events = match service_type {
+                ServiceType::EC2 => {
+                    Ec2Service::new(&clients)
+                        .instance_events(&resource_id)
+                        .await?
+                }
+                ServiceType::RDS => {
+                    RdsService::new(&clients)
+                        .describe_events(&resource_id)
+                        .await?
+                }
+                ServiceType::EKS => {
+                    EksService::new(&clients)
+                        .cluster_updates(&resource_id)
+                        .await?
+                }
+                _ => vec![],
+            };
+            Ok(OperationOutput::Events {
+                resource_id,
+                events,
+            })
+        });
+    }
+
+    fn handle_raw_inspector_input(&mut self, key: KeyEvent) {
+        let Some(inspector) = &mut self.raw_inspector else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('J') => self.raw_inspector = None,
+            KeyCode::Up => inspector.scroll = inspector.scroll.saturating_sub(1),
+            // Clamped to the content height when the inspector is drawn
+            KeyCode::Down => inspector.scroll = inspector.scroll.saturating_add(1),
+            KeyCode::PageUp => inspector.scroll = inspector.scroll.saturating_sub(10),
+            KeyCode::PageDown => inspector.scroll = inspector.scroll.saturating_add(10),
+            KeyCode::Home => inspector.scroll = 0,
+            KeyCode::Char('c') => self.copy_raw_json(),
+            _ => {}
+        }
+    }
+
+    /// Copy the whole JSON document, not just the scrolled-to part
+    fn copy_raw_json(&mut self) {
+        let Some(inspector) = &mut self.raw_inspector else {
+            return;
+        };
+
+        let megabytes = inspector.json.len() as f64 / (1024.0 * 1024.0);
+        if inspector.is_large() && !inspector.large_copy_confirmed {
+            inspector.large_copy_confirmed = true;
+            self.add_notification(
+                format!("JSON is {:.1} MB; press c again to copy it", megabytes),
+                NotificationLevel::Warning,
+            );
+            return;
+        }
+
+        inspector.large_copy_confirmed = false;
+        match crate::utils::clipboard::copy_to_clipboard(&inspector.json) {
+            Ok(()) => {
+                let lines = inspector.json.lines().count();
+                self.add_notification(
+                    format!("Copied {} lines of JSON", lines),
+                    NotificationLevel::Success,
+                );
+            }
+            Err(e) => self.add_notification(e.to_string(), NotificationLevel::Error),
+        }
+    }
+
+    fn handle_node_group_size_input(&mut self, key: KeyEvent) {
+        let Some(form) = &mut self.node_group_size_form else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => self.node_group_size_form = None,
+            KeyCode::Enter => self.submit_node_group_size(),
+            KeyCode::Backspace => form.backspace(),
+            KeyCode::Char(c) => form.insert_char(c),
+            _ => {}
+        }
+    }
+
+    /// Check the size against the group's bounds, request it, and wait for EKS
+    /// to start the update
+    fn submit_node_group_size(&mut self) {
+        let Some(form) = &mut self.node_group_size_form else {
+            return;
+        };
+
+        let desired = match form.desired_size() {
+            Ok(desired) => desired,
+            Err(reason) => {
+                form.error = Some(reason);
+                return;
+            }
+        };
+
+        let cluster = form.cluster.clone();
+        let node_group = form.node_group.clone();
+        self.node_group_size_form = None;
+        self.add_notification(
+            format!("Scaling node group {} to {}...", node_group, desired),
+            NotificationLevel::Info,
+        );
+        let clients = self.region_clients();
+        let limiter = self.operations.limiter().clone();
+        let operation = self.in_flight_operation(
+            Some(ServiceType::EKS),
+            "Update Node Group Size",
+            &format!("Node group {} is scaling to {} nodes", node_group, desired),
+        );
+        // Each request takes its own permit, so the waits between polls hold none
+        self.operations.spawn_fan_out(operation, async move {
+            let eks = EksService::new(&clients);
+            let update_id = limiter
+                .run(eks.update_nodegroup_size(&cluster, &node_group, desired))
+                .await?;
+
+            // The update is accepted before it starts; stop polling once EKS picks it up
+            for _ in 0..NODE_GROUP_UPDATE_POLLS {
+                let status = limiter
+                    .run(eks.describe_update(&cluster, &node_group, &update_id))
+                    .await?;
+                match status.as_str() {
+                    "Pending" => tokio::time::sleep(NODE_GROUP_UPDATE_POLL_INTERVAL).await,
+                    "Failed" | "Cancelled" => {
+                        return Err(AppError::AwsSdk(format!(
+                            "Update {} of node group {} {}",
+                            update_id,
+                            node_group,
+                            status.to_lowercase()
+                        )));
+                    }
+                    _ => break,
+                }
+            }
+
+            let node_groups = limiter.run(eks.node_groups(&cluster)).await?;
+            Ok(OperationOutput::NodeGroups {
+                cluster,
+                node_groups,
+            })
+        });
+    }
+
+    fn handle_zone_picker_input(&mut self, key: KeyEvent) {
+        let Some(picker) = &mut self.zone_picker else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => self.zone_picker = None,
+            KeyCode::Up => picker.select_previous(),
+            KeyCode::Down => picker.select_next(),
+            KeyCode::Enter => {
+                let service_type = picker.service_type;
+                let zone = picker.selected_zone().map(str::to_string);
+                self.zone_picker = None;
+                self.set_zone_filter(service_type, zone);
+            }
+            _ => {}
+        }
+    }
+
+    /// Offer the zones of the cached listing, asking EC2 for them when nothing is loaded yet
+    fn open_zone_picker(&mut self, service_type: ServiceType) {
+        let mut zones: Vec<String> = self
+            .cached_resources(service_type)
+            .map(|resources| {
+                resources
+                    .iter()
+                    .filter_map(|r| r.availability_zone.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+        zones.sort();
+        zones.dedup();
+
+        if !zones.is_empty() {
+            let current = self.zone_filters.get(&service_type).map(String::as_str);
+            self.zone_picker = Some(ZonePicker::new(service_type, zones, current));
+            return;
+        }
+
+        let clients = self.region_clients();
+        self.spawn_operation(
+            service_type,
+            "Describe availability zones",
+            "",
+            async move {
+                let zones = Ec2Service::new(&clients)
+                    .describe_availability_zones()
+                    .await?;
+                Ok(OperationOutput::Zones(zones))
+            },
+        );
+    }
+
+    /// Narrow a resource list to one zone, or restore the full list with `None`
+    fn set_zone_filter(&mut self, service_type: ServiceType, zone: Option<String>) {
+        self.select_resource_at(0);
+        let message = match zone {
+            Some(zone) => {
+                let message = format!(
+                    "Showing {} resources in {}",
+                    service_type.display_name(),
+                    zone
+                );
+                self.zone_filters.insert(service_type, zone);
+                message
+            }
+            None => {
+                if self.zone_filters.remove(&service_type).is_none() {
+                    return;
+                }
+                format!(
+                    "Showing {} resources in every availability zone",
+                    service_type.display_name()
+                )
+            }
+        };
+        self.add_notification(message, NotificationLevel::Info);
+    }
+
+    /// Show only resources in the list's `index`th state, remembering the
+    /// choice for the service
+    fn select_state_filter(&mut self, index: usize) {
+        let AppPage::ResourceList(service_type) = self.current_page else {
+            return;
+        };
+        let Some(filter) = StateFilter::options(service_type).get(index).copied() else {
+            return;
+        };
+        let filters = &mut self.user_config.resource_list.state_filters;
+        if filters
+            .get(&service_type)
+            .copied()
+            .unwrap_or(StateFilter::All)
+            == filter
+        {
+            return;
+        }
+        if filter == StateFilter::All {
+            filters.remove(&service_type);
+        } else {
+            filters.insert(service_type, filter);
+        }
+        if let Err(e) = self.user_config.save() {
+            tracing::warn!("Failed to save state filter: {}", e);
+        }
+
+        self.select_resource_at(0);
+        self.add_notification(
+            format!(
+                "Showing {} {} resources",
+                filter.label(),
+                service_type.display_name()
+            ),
+            NotificationLevel::Info,
+        );
+    }
+
+    /// Validate the secret form and create the secret in the background
+    fn submit_secret_form(&mut self) {
+        let Some(form) = &mut self.secret_form else {
+            return;
+        };
+
+        let (name, secret_string) = match form.build() {
+            Ok(built) => built,
+            Err(message) => {
+                form.error = Some(message);
+                return;
+            }
+        };
+
+        // Catch duplicates we already know about before the round-trip
+        let known = self
+            .resources
+            .get(&(self.current_region.clone(), ServiceType::Secrets))
+            .and_then(LoadState::loaded)
+            .is_some_and(|secrets| secrets.iter().any(|secret| secret.name == name));
+        if known {
+            form.error = Some(format!("A secret named {} already exists", name));
+            return;
+        }
+
+        self.secret_form = None;
+        self.add_notification(
+            format!("Creating secret {}...", name),
+            NotificationLevel::Info,
+        );
+        let clients = self.region_clients();
+        self.spawn_operation(
+            ServiceType::Secrets,
+            "Create Secret",
+            "Secret created",
+            async move {
+                SecretsService::new(&clients)
+                    .create_secret(&name, &secret_string)
+                    .await?;
+                Ok(OperationOutput::Message(format!("Created secret {}", name)))
+            },
+        );
+    }
+
+    /// Feed a key into the pending sequence buffer. Returns true when the key was
+    /// consumed, either as part of a sequence or by breaking one.
+    async fn handle_key_sequence(&mut self, key: KeyEvent) -> Result<bool> {
+        if self.key_sequence_expired() {
+            self.clear_pending_keys();
+        }
+
+        let c = match key.code {
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                c
+            }
+            _ => {
+                self.clear_pending_keys();
+                return Ok(false);
+            }
+        };
+
+        let candidate = format!("{}{}", self.pending_keys, c);
+        let sequences = &self.user_config.keybindings.sequences;
+
+        if let Some(command_id) = sequences.get(&candidate).cloned() {
+            self.clear_pending_keys();
+            self.execute_command_by_id(&command_id).await?;
+            return Ok(true);
+        }
+
+        if sequences.keys().any(|keys| keys.starts_with(&candidate)) {
+            self.pending_keys = candidate;
+            self.pending_keys_since = Some(Instant::now());
+            return Ok(true);
+        }
+
+        // An unmatched key discards the sequence it interrupted along with itself
+        let had_pending = !self.pending_keys.is_empty();
+        self.clear_pending_keys();
+        Ok(had_pending)
+    }
+
+    fn handle_assume_role_input(&mut self, key: KeyEvent) {
+        let Some(form) = &mut self.assume_role_form else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => self.assume_role_form = None,
+            KeyCode::Enter => self.submit_assume_role_form(),
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                form.toggle_reveal();
+            }
+            KeyCode::Tab | KeyCode::Down => form.focus_next(),
+            KeyCode::BackTab | KeyCode::Up => form.focus_previous(),
+            KeyCode::Backspace => form.backspace(),
+            KeyCode::Char(c) => form.insert_char(c),
+            _ => {}
+        }
+    }
+
+    /// Validate the form and assume the role from the current profile in the background
+    fn submit_assume_role_form(&mut self) {
+        let Some(form) = &mut self.assume_role_form else {
+            return;
+        };
+        let request = match form.build() {
+            Ok(request) => request,
+            Err(message) => {
+                form.error = Some(message);
+                return;
+            }
+        };
+        self.assume_role_form = None;
+
+        let source_profile = self.current_profile.clone();
+        let region = self
+            .available_profiles
+            .iter()
+            .find(|p| p.name == source_profile)
+            .and_then(|p| p.region.clone());
+        self.start_operation(None, "Assume role", "", async move {
+            let credentials = CredentialsValidator::for_profile(&source_profile)
+                .assume_role(&request)
+                .await?;
+            let profile = credentials.into_profile(&request, source_profile, region)?;
+            Ok(OperationOutput::AssumedRole(Box::new(profile)))
+        });
+    }
+
+    fn handle_instance_form_input(&mut self, key: KeyEvent) {
+        let Some(form) = &mut self.instance_form else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => self.instance_form = None,
+            KeyCode::Enter => self.submit_instance_form(),
+            KeyCode::Tab | KeyCode::Down => form.focus_next(),
+            KeyCode::BackTab | KeyCode::Up => form.focus_previous(),
+            KeyCode::Backspace => form.backspace(),
+            KeyCode::Char(c) => form.insert_char(c),
+            _ => {}
+        }
+    }
+
+    /// Validate the form and launch the instance in the form's region, which
+    /// for a clone is the source instance's region
+    fn submit_instance_form(&mut self) {
+        let Some(form) = &mut self.instance_form else {
+            return;
+        };
+        let launch = match form.build() {
+            Ok(launch) => launch,
+            Err(message) => {
+                form.error = Some(message);
+                return;
+            }
+        };
+        let region = form.region.clone();
+        self.instance_form = None;
+
+        let clients = self.clients_for_region(&region);
+        self.spawn_operation(ServiceType::EC2, "Launch instance", "", async move {
+            let instance_id = Ec2Service::new(&clients).run_instance(&launch).await?;
+            Ok(OperationOutput::Created {
+                service_type: ServiceType::EC2,
+                message: format!(
+                    "Launched {} instance {} in {}",
+                    launch.instance_type, instance_id, region
+                ),
+                region,
+                resource_id: instance_id,
+            })
+        });
+    }
+
+    /// Drop assumed role profiles whose credentials have run out, moving off
+    /// the current one back to the profile it was assumed from
+    async fn expire_assumed_roles(&mut self) -> Result<()> {
+        let now = chrono::Utc::now();
+        let expired: Vec<AwsProfile> = self
+            .available_profiles
+            .iter()
+            .filter(|p| p.ephemeral_expiry().is_some_and(|expiry| expiry <= now))
+            .cloned()
+            .collect();
+        if expired.is_empty() {
+            return Ok(());
+        }
+        self.available_profiles
+            .retain(|p| p.ephemeral_expiry().is_none_or(|expiry| expiry > now));
+
+        for profile in expired {
+            self.add_notification(
+                format!("Temporary profile {} expired", profile.name),
+                NotificationLevel::Warning,
+            );
+            if profile.name != self.current_profile {
+                continue;
+            }
+            let fallback = match &profile.credential_source {
+                CredentialSource::AssumedRole { source_profile, .. }
+                    if self
+                        .available_profiles
+                        .iter()
+                        .any(|p| &p.name == source_profile) =>
+                {
+                    source_profile.clone()
+                }
+                _ => self.user_config.aws.default_profile.clone(),
+            };
+            self.switch_profile(&fallback).await?;
+        }
+        self.update_command_context();
+        Ok(())
+    }
+
+    fn handle_cheat_sheet_input(&mut self, key: KeyEvent) {
+        let Some(form) = &mut self.cheat_sheet_form else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => self.cheat_sheet_form = None,
+            KeyCode::Enter => self.export_cheat_sheet(),
+            KeyCode::Backspace => form.backspace(),
+            KeyCode::Char(c) => form.insert_char(c),
+            _ => {}
+        }
+    }
+
+    /// Write the cheat sheet to the form's path; failures stay in the form
+    fn export_cheat_sheet(&mut self) {
+        let Some(form) = &self.cheat_sheet_form else {
+            return;
+        };
+        let written = form.resolved_path().and_then(|path| {
+            let contents = render_cheat_sheet(
+                &self.keybinding_sections(),
+                CheatSheetFormat::for_path(&path),
+            );
+            std::fs::write(&path, contents)
+                .map(|()| path.clone())
+                .map_err(|e| format!("Cannot write {}: {}", path.display(), e))
+        });
+
+        match written {
+            Ok(path) => {
+                self.cheat_sheet_form = None;
+                self.add_notification(
+                    format!("Wrote keybinding cheat sheet to {}", path.display()),
+                    NotificationLevel::Success,
+                );
+            }
+            Err(message) => {
+                if let Some(form) = &mut self.cheat_sheet_form {
+                    form.error = Some(message);
+                }
+            }
+        }
+    }
+
+    fn handle_resource_export_input(&mut self, key: KeyEvent) {
+        let Some(form) = &mut self.resource_export_form else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => self.resource_export_form = None,
+            KeyCode::Enter => self.export_resources(),
+            KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                form.toggle_include_all();
+            }
+            KeyCode::Backspace => form.backspace(),
+            KeyCode::Char(c) => form.insert_char(c),
+            _ => {}
+        }
+    }
+
+    /// Write the list's resources to the form's path, as currently filtered
+    /// unless the form asks for all of them; failures stay in the form
+    fn export_resources(&mut self) {
+        let Some(form) = &self.resource_export_form else {
+            return;
+        };
+        let service_type = form.service_type;
+        let include_all = form.include_all;
+
+        let written = form.resolved_path().and_then(|path| {
+            let (resources, filters): (Vec<&Resource>, _) = if include_all {
+                let resources = self
+                    .cached_resources(service_type)
+                    .map(|resources| resources.iter().collect())
+                    .unwrap_or_default();
+                (resources, vec![])
+            } else {
+                (
+                    self.visible_resources(service_type),
+                    self.active_list_filters(service_type),
+                )
+            };
+            let metadata = ExportMetadata {
+                service_type,
+                profile: self.current_profile.clone(),
+                region: self.current_region.clone(),
+                filters,
+                exported_at: chrono::Utc::now(),
+            };
+            let contents =
+                render_resource_export(&resources, &metadata, ExportFormat::for_path(&path));
+            std::fs::write(&path, contents)
+                .map(|()| (path.clone(), resources.len()))
+                .map_err(|e| format!("Cannot write {}: {}", path.display(), e))
+        });
+
+        match written {
+            Ok((path, count)) => {
+                self.resource_export_form = None;
+                self.add_notification(
+                    format!(
+                        "Exported {} {} resources to {}",
+                        count,
+                        service_type.display_name(),
+                        path.display()
+                    ),
+                    NotificationLevel::Success,
+                );
+            }
+            Err(message) => {
+                if let Some(form) = &mut self.resource_export_form {
+                    form.error = Some(message);
+                }
+            }
+        }
+    }
+
+    /// The list filters narrowing `service_type`'s rows, e.g. "state: running"
+    pub fn active_list_filters(&self, service_type: ServiceType) -> Vec<String> {
+        let mut filters = vec![];
+        if let Some(zone) = self.zone_filters.get(&service_type) {
+            filters.push(format!("zone: {}", zone));
+        }
+        let state_filter = self.user_config.resource_list.state_filter(service_type);
+        if state_filter != StateFilter::All {
+            filters.push(format!("state: {}", state_filter.label()));
+        }
+        filters
+    }
+
+    /// The effective keybindings: global defaults, the current page's keys and
+    /// the configured key sequences with the commands they run
+    fn keybinding_sections(&self) -> Vec<CheatSheetSection> {
+        let pairs = |hints: Vec<(&str, &str)>| {
+            hints
+                .into_iter()
+                .map(|(keys, action)| (keys.to_string(), action.to_string()))
+                .collect()
+        };
+
+        let page = match &self.current_page {
+            AppPage::Dashboard => "Dashboard".to_string(),
+            AppPage::Settings => "Settings".to_string(),
+            AppPage::ActivityLog => "Activity Log".to_string(),
+            AppPage::ResourceList(service_type) => format!("{} list", service_type.display_name()),
+            AppPage::ResourceDetail(service_type, _) => {
+                format!("{} detail", service_type.display_name())
+            }
+        };
+
+        let mut sequences: Vec<(String, String)> = self
+            .user_config
+            .keybindings
+            .sequences
+            .iter()
+            .map(|(keys, command_id)| {
+                let action = self
+                    .command_palette
+                    .commands
+                    .iter()
+                    .find(|cmd| cmd.id == *command_id)
+                    .map_or_else(
+                        || format!("{} (unknown command)", command_id),
+                        |cmd| cmd.name.clone(),
+                    );
+                (
+                    keys.chars().map(String::from).collect::<Vec<_>>().join(" "),
+                    action,
+                )
+            })
+            .collect();
+        sequences.sort();
+
+        vec![
+            CheatSheetSection {
+                title: "Global".to_string(),
+                bindings: pairs(get_default_keybindings()),
+            },
+            CheatSheetSection {
+                title: format!("Current page ({})", page),
+                bindings: pairs(self.active_key_hints()),
+            },
+            CheatSheetSection {
+                title: "Key sequences".to_string(),
+                bindings: sequences,
+            },
+        ]
+    }
+
+    /// Keys that can follow the pending sequence, read from the same map
+    /// `handle_key_sequence` dispatches on so the two never disagree
+    pub fn key_sequence_hints(&self) -> Vec<SequenceHint> {
+        let mut next_keys: std::collections::BTreeMap<char, Vec<(bool, &str)>> =
+            std::collections::BTreeMap::new();
+        for (keys, command_id) in &self.user_config.keybindings.sequences {
+            let Some(rest) = keys.strip_prefix(self.pending_keys.as_str()) else {
+                continue;
+            };
+            let mut chars = rest.chars();
+            if let Some(key) = chars.next() {
+                let completes = chars.as_str().is_empty();
+                next_keys
+                    .entry(key)
+                    .or_default()
+                    .push((completes, command_id.as_str()));
+            }
+        }
+
+        next_keys
+            .into_iter()
+            .map(|(key, targets)| match targets.as_slice() {
+                [(true, command_id)] => {
+                    let command = self
+                        .command_palette
+                        .commands
+                        .iter()
+                        .find(|cmd| cmd.id == *command_id);
+                    SequenceHint {
+                        key,
+                        label: command.map_or_else(|| command_id.to_string(), |c| c.name.clone()),
+                        available: command.is_some(),
+                    }
+                }
+                _ => SequenceHint {
+                    key,
+                    label: format!("+{} commands", targets.len()),
+                    available: true,
+                },
+            })
+            .collect()
+    }
+
+    fn key_sequence_expired(&self) -> bool {
+        let timeout = Duration::from_millis(self.user_config.keybindings.sequence_timeout_ms);
+        self.pending_keys_since
+            .is_some_and(|since| since.elapsed() > timeout)
+    }
+
+    fn clear_pending_keys(&mut self) {
+        self.pending_keys.clear();
+        self.pending_keys_since = None;
+    }
+
+    /// Pause timed refreshes while the terminal is unfocused, and reload the
+    /// current view once when focus comes back. Overdue dashboard widgets
+    /// catch up on the next `update`.
+    pub fn handle_focus_change(&mut self, focused: bool) {
+        if !self.user_config.behavior.pause_when_unfocused || focused == self.focused {
+            return;
+        }
+        self.focused = focused;
+
+        if focused && self.user_config.behavior.auto_refresh_resources {
+            self.refresh_current_view();
+        }
+    }
+
+    /// Refresh what the current page shows: a list in full, a detail page's
+    /// resource on its own
+    fn refresh_current_view(&mut self) {
+        self.view_refreshed_at = Some(Instant::now());
+        match self.current_page {
+            AppPage::ResourceList(service_type) => self.refresh_service(service_type),
+            AppPage::ResourceDetail(_, _) => self.refresh_detail_resource(),
+            _ => {}
+        }
+    }
+
+    /// Whether the list or detail page has gone its configured refresh
+    /// interval without new data or a refresh attempt
+    fn view_refresh_due(&self) -> bool {
+        let Some(interval) = self.user_config.refresh_interval(&self.current_page) else {
+            return false;
+        };
+        let (AppPage::ResourceList(service_type) | AppPage::ResourceDetail(service_type, _)) =
+            &self.current_page
+        else {
+            return false;
+        };
+        if self
+            .operations
+            .is_loading(&self.current_region, *service_type)
+        {
+            return false;
+        }
+
+        let since_loaded = self
+            .last_refresh
+            .get(&(self.current_region.clone(), *service_type))
+            .and_then(|at| at.elapsed().ok());
+        let since_attempt = self.view_refreshed_at.map(|at| at.elapsed());
+        since_loaded
+            .into_iter()
+            .chain(since_attempt)
+            .min()
+            .is_some_and(|age| age >= interval)
+    }
+
+    /// Timed refresh of the current page, without a notification each time
+    fn auto_refresh_current_view(&mut self) {
+        self.view_refreshed_at = Some(Instant::now());
+        match &self.current_page {
+            AppPage::ResourceList(service_type) => self.list_service(*service_type, ""),
+            AppPage::ResourceDetail(service_type, resource_id) => {
+                self.load_resource_description(*service_type, resource_id.clone(), "")
+            }
+            _ => {}
+        }
+    }
+
+    /// Record the new terminal size and keep overlay selections within their lists
+    pub fn handle_resize(&mut self, width: u16, height: u16) {
+        self.terminal_size = (width, height);
+
+        // Overlays are re-centered from f.area() on the next draw; the lists
+        // render with a stateful offset so the clamped selection stays in view
+        self.command_palette.clamp_selection();
+
+        if self.quick_nav_selected_index >= self.quick_nav_suggestions.len() {
+            self.quick_nav_selected_index = self.quick_nav_suggestions.len().saturating_sub(1);
+        }
+    }
+
+    pub async fn update(&mut self) -> Result<()> {
+        for completed in self.operations.drain_completed() {
+            self.apply_completed_operation(completed);
+        }
+
+        if let Some(profile) = self.pending_profile_switch.take() {
+            self.switch_profile(&profile).await?;
+        }
+        self.expire_assumed_roles().await?;
+
+        // Nothing disappears from under the cursor while the list is open
+        if self.notification_overlay.is_none() {
+            self.expire_notifications();
+        }
+
+        if self.key_sequence_expired() {
+            self.clear_pending_keys();
+        }
+
+        if self.connectivity_probe_due() {
+            self.in_background(Self::probe_connectivity);
+        }
+
+        if self.reconnect_due() && !self.is_offline() {
+            self.connect_aws_clients();
+        }
+
+        if self.log_tail_poll_due() && !self.is_offline() {
+            self.in_background(Self::poll_log_tail);
+        }
+
+        // Clusters being created are tracked even while the window is unfocused
+        if self.cluster_creation_poll_due() && !self.is_offline() {
+            self.in_background(Self::poll_cluster_creation);
+        }
+
+        // Timed refreshes wait while the user is in another window or offline
+        if self.focused && !self.is_offline() {
+            if self.cost_summary_due() {
+                self.in_background(Self::refresh_cost_summary);
+            }
+
+            if self.region_overview_due() {
+                self.in_background(Self::refresh_region_overview);
+            }
+
+            if self.view_refresh_due() {
+                self.in_background(Self::auto_refresh_current_view);
+            }
+        }
+
+        self.check_credentials_expiry();
+
+        Ok(())
+    }
+
+    /// Start operations on a timer's behalf: Esc does not cancel them and
+    /// they do not count as busy
+    fn in_background(&mut self, start: impl FnOnce(&mut Self)) {
+        self.starting_background = true;
+        start(self);
+        self.starting_background = false;
+    }
+
+    /// Fold a finished background operation back into the state
+    fn apply_completed_operation(&mut self, completed: CompletedOperation) {
+        let CompletedOperation { operation, outcome } = completed;
+        self.clear_operation_loading_state(&operation);
+        self.record_service_health(&operation, &outcome);
+
+        // A failed listing is kept against its (region, service) so pages can say why
+        if let (Err(e), Some(service_type)) = (&outcome, operation.service_type) {
+            if operation.label == list_label(service_type) {
+                self.resources
+                    .entry((operation.region.clone(), service_type))
+                    .or_default()
+                    .fail(e.to_string());
+            }
+        }
+
+        // Any call that got through means the network is back
+        if outcome.is_ok() && self.is_offline() {
+            self.come_back_online();
+        }
+
+        match outcome {
+            Ok(OperationOutput::Done) => {
+                if !operation.success_message.is_empty() {
+                    self.add_notification(operation.success_message, NotificationLevel::Success);
+                }
+            }
+            Ok(OperationOutput::Resources(resources)) => {
+                // Cached data is keyed by the region the operation ran against,
+                // so results that land after the user navigated away stay valid
+                if let Some(service_type) = operation.service_type {
+                    // Only touch the selection if the user is still looking at this list
+                    let viewing = self.current_page == operation.origin_page
+                        && self.current_region == operation.region;
+                    self.store_resources(
+                        operation.region.clone(),
+                        service_type,
+                        resources,
+                        viewing,
+                    );
+                }
+                if !operation.success_message.is_empty() {
+                    self.add_notification(operation.success_message, NotificationLevel::Success);
+                }
+            }
+            Ok(OperationOutput::RegionResources {
+                service_type,
+                listings,
+            }) => {
+                let total = listings.len();
+                let mut failures = vec![];
+                for listing in listings {
+                    match listing.outcome {
+                        Ok(resources) => {
+                            let viewing = self.current_page == AppPage::ResourceList(service_type)
+                                && self.current_region == listing.region;
+                            self.store_resources(listing.region, service_type, resources, viewing);
+                        }
+                        Err(reason) => {
+                            failures.push(format!("{} ({})", listing.region, reason));
+                            self.resources
+                                .entry((listing.region, service_type))
+                                .or_default()
+                                .fail(reason);
+                        }
+                    }
+                }
+                if !failures.is_empty() {
+                    self.add_notification(
+                        format!(
+                            "{} unavailable in {} of {} regions: {}",
+                            service_type.display_name(),
+                            failures.len(),
+                            total,
+                            failures.join(", ")
+                        ),
+                        NotificationLevel::Warning,
+                    );
+                }
+            }
+            // A describe that returned nothing is not proof the resource is
+            // gone, so the cache and saved lists are left as they are
+            Ok(OperationOutput::Described { resource }) => {
+                if let (Some(service_type), Some(resource)) = (operation.service_type, resource) {
+                    self.replace_resource(operation.region, service_type, *resource);
+                    if !operation.success_message.is_empty() {
+                        self.add_notification(
+                            operation.success_message,
+                            NotificationLevel::Success,
+                        );
+                    }
+                }
+            }
+            Ok(OperationOutput::Missing { resource_id }) => {
+                if let Some(service_type) = operation.service_type {
+                    self.forget_missing_resource(operation.region, service_type, resource_id);
+                }
+            }
+            Ok(OperationOutput::Message(message)) => {
+                self.add_notification(message, NotificationLevel::Success);
+            }
+            Ok(OperationOutput::Account { profile, summary }) => {
+                // A late answer for the profile switched away from is stale
+                if profile == self.current_profile {
+                    self.account_summary = Some(summary);
+                }
+            }
+            Ok(OperationOutput::SecurityGroups {
+                instance_id,
+                groups,
+            }) => {
+                self.security_group_inspector = Some(SecurityGroupInspector {
+                    instance_id,
+                    groups,
+                    scroll: 0,
+                });
+            }
+            Ok(OperationOutput::LogGroups {
+                service_type,
+                resource_id,
+                log_groups,
+            }) => {
+                self.log_tail = Some(LogTail::new(
+                    service_type,
+                    resource_id,
+                    operation.region,
+                    log_groups,
+                ));
+            }
+            Ok(OperationOutput::ClusterCreating(cluster)) => {
+                self.add_notification(
+                    format!(
+                        "EKS cluster {} is being created; this usually takes 10-15 minutes",
+                        cluster.name
+                    ),
+                    NotificationLevel::Success,
+                );
+                self.cluster_creations
+                    .push(ClusterCreation::new(operation.region, &cluster));
+            }
+            Ok(OperationOutput::ClusterStatus {
+                region,
+                cluster,
+                resource,
+            }) => self.apply_cluster_status(region, cluster, resource.map(|r| *r)),
+            Ok(OperationOutput::Created {
+                service_type,
+                region,
+                resource_id,
+                message,
+            }) => {
+                self.add_notification_with_action(
+                    message,
+                    NotificationLevel::Success,
+                    NotificationAction::ViewResource {
+                        service_type,
+                        resource_id,
+                        region,
+                    },
+                );
+            }
+            Ok(OperationOutput::InstanceConfig {
+                region,
+                instance_id,
+                instance,
+            }) => match instance {
+                Some(instance) => {
+                    self.instance_form = Some(InstanceForm::clone_of(&instance, &region));
+                }
+                None => {
+                    self.add_notification(
+                        format!("EC2 instance {} was not found in {}", instance_id, region),
+                        NotificationLevel::Warning,
+                    );
+                }
+            },
+            Ok(OperationOutput::LogEvents {
+                resource_id,
+                events,
+            }) => {
+                // Drop reads for a tail that was closed or replaced meanwhile
+                if let Some(tail) = self
+                    .log_tail
+                    .as_mut()
+                    .filter(|tail| tail.resource_id == resource_id)
+                {
+                    match events {
+                        Ok(events) => tail.append(events),
+                        Err(reason) => tail.error = Some(reason),
+                    }
+                }
+            }
+            Ok(OperationOutput::Related {
+                service_type,
+                resource_id,
+                related,
+            }) => {
+                // Drop results for a detail page the user has already left
+                if self.current_page == AppPage::ResourceDetail(service_type, resource_id) {
+                    self.related_resources = Some(related);
+                }
+            }
+            Ok(OperationOutput::Events {
+                resource_id,
+                events,
+            }) => {
+                self.resource_events
+                    .insert((operation.region.clone(), resource_id), events);
+            }
+            Ok(OperationOutput::IamPage {
+                entity,
+                first,
+                resources,
+                marker,
+            }) => {
+                self.apply_iam_page(operation, entity, first, resources, marker);
+            }
+            Ok(OperationOutput::IamUserDetails { user, details }) => {
+                self.iam_user_details
+                    .insert((operation.region.clone(), user), details);
+            }
+            Ok(OperationOutput::NodeGroups {
+                cluster,
+                node_groups,
+            }) => {
+                if self.selected_node_group >= node_groups.len() {
+                    self.selected_node_group = 0;
+                }
+                self.node_groups
+                    .insert((operation.region.clone(), cluster), node_groups);
+                if !operation.success_message.is_empty() {
+                    self.add_notification(operation.success_message, NotificationLevel::Success);
+                }
+            }
+            Ok(OperationOutput::Batch(results)) => {
+                self.batch_results = Some(results);
+                self.batch_results_scroll = 0;
+            }
+            Ok(OperationOutput::Cost(outcome)) => {
+                self.cost_summary = Some(outcome);
+            }
+            Ok(OperationOutput::ProfileComparison(comparison)) => {
+                self.profile_comparison = Some(*comparison);
+            }
+            Ok(OperationOutput::ProfileValidation(validation)) => {
+                self.profile_validation = Some(*validation);
+            }
+            Ok(OperationOutput::Permissions(report)) => {
+                let mut report = *report;
+                let observed: Vec<ServiceType> = self
+                    .permission_denied
+                    .iter()
+                    .map(|(_, service_type)| *service_type)
+                    .collect();
+                report.merge_observed_denials(observed);
+                // Only show the report if the profile it describes is still active
+                self.permission_report_visible = report.profile == self.current_profile;
+                self.permission_reports
+                    .insert(report.profile.clone(), report);
+            }
+            Ok(OperationOutput::Clients(mut clients)) => {
+                // A profile switch while connecting starts another connection that supersedes this one
+                if clients.current_profile() == self.current_profile && self.aws_clients.is_none() {
+                    if clients.current_region() != self.current_region {
+                        clients.select_region(&self.current_region);
+                    }
+                    self.aws_clients = Some(*clients);
+                    self.credentials_expiry_warned = false;
+                    self.refresh_available_regions();
+                }
+            }
+            Ok(OperationOutput::Regions { profile, regions }) => {
+                // Region availability is per account; a late answer for another profile is stale
+                if profile == self.current_profile && !regions.is_empty() {
+                    self.apply_available_regions(regions);
+                }
+            }
+            Ok(OperationOutput::AssumedRole(profile)) => {
+                let expires_at = profile.ephemeral_expiry().unwrap_or_else(chrono::Utc::now);
+                self.add_notification(
+                    format!(
+                        "Assumed {} until {}",
+                        profile.name,
+                        expires_at.with_timezone(&chrono::Local).format("%H:%M")
+                    ),
+                    NotificationLevel::Success,
+                );
+                // Assuming the same role again replaces the old session
+                self.available_profiles.retain(|p| p.name != profile.name);
+                self.pending_profile_switch = Some(profile.name.clone());
+                self.available_profiles.push(*profile);
+            }
+            Ok(OperationOutput::Zones(zones)) => {
+                let viewing = self.current_page == operation.origin_page
+                    && self.current_region == operation.region;
+                match operation.service_type {
+                    Some(service_type) if viewing && !zones.is_empty() => {
+                        let current = self.zone_filters.get(&service_type).map(String::as_str);
+                        self.zone_picker = Some(ZonePicker::new(service_type, zones, current));
+                    }
+                    Some(_) if viewing => {
+                        self.add_notification(
+                            format!("No availability zones found in {}", operation.region),
+                            NotificationLevel::Warning,
+                        );
+                    }
+                    _ => {}
+                }
+            }
+            Err(e) if e.is_permission_error() => {
+                let message = match operation.service_type {
+                    Some(service_type) => {
+                        self.permission_denied
+                            .insert((operation.region.clone(), service_type));
+                        format!(
+                            "{}: the active profile has no permission for {} in {}",
+                            operation.label,
+                            service_type.display_name(),
+                            operation.region
+                        )
+                    }
+                    None => format!("{}: permission denied ({})", operation.label, e),
+                };
+                self.add_notification_with_action(
+                    message,
+                    NotificationLevel::Warning,
+                    NotificationAction::RunCommand {
+                        label: "Check permissions",
+                        command_id: "general.permissions",
+                    },
+                );
+            }
+            Err(e) if e.is_connectivity_error() => self.go_offline(&operation.label, &e),
+            Err(e) => {
+                let message = format!("{} failed: {}", operation.label, e);
+                match operation.retry {
+                    Some(retry) => {
+                        self.add_notification_with_action(message, NotificationLevel::Error, retry)
+                    }
+                    None => self.add_notification(message, NotificationLevel::Error),
+                }
+            }
+        }
+
+        // The cancel command is only offered while something is running
+        self.update_command_context();
+    }
+
+    /// Enter offline mode on the first connectivity error, with one warning in
+    /// place of an error per failed call; later failures are only logged
+    fn go_offline(&mut self, label: &str, error: &AppError) {
+        if self.is_offline() {
+            tracing::debug!("{} failed while offline: {}", label, error);
+            return;
+        }
+        self.offline_since = Some(Instant::now());
+        self.last_connectivity_probe = Some(Instant::now());
+        self.add_notification(
+            format!(
+                "AWS is unreachable ({} failed: {}); refreshes are paused until the connection returns",
+                label, error
+            ),
+            NotificationLevel::Warning,
+        );
+    }
+
+    fn come_back_online(&mut self) {
+        let Some(since) = self.offline_since.take() else {
+            return;
+        };
+        self.add_notification(
+            format!("Back online after {}s", since.elapsed().as_secs()),
+            NotificationLevel::Success,
+        );
+        if self.user_config.behavior.auto_refresh_resources {
+            self.refresh_current_view();
+        }
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline_since.is_some()
+    }
+
+    /// While offline, probe every `CONNECTIVITY_PROBE_INTERVAL` with no probe in flight
+    fn connectivity_probe_due(&self) -> bool {
+        self.is_offline()
+            && self
+                .last_connectivity_probe
+                .is_none_or(|at| at.elapsed() >= CONNECTIVITY_PROBE_INTERVAL)
+            && !self
+                .operations
+                .in_flight()
+                .iter()
+                .any(|op| op.label == CONNECTIVITY_PROBE_LABEL)
+    }
+
+    /// While a log tail is open, read every `LOG_TAIL_POLL_INTERVAL` with no read in flight
+    fn log_tail_poll_due(&self) -> bool {
+        self.log_tail.as_ref().is_some_and(|tail| {
+            tail.polled_at
+                .is_none_or(|at| at.elapsed() >= LOG_TAIL_POLL_INTERVAL)
+        }) && !self
+            .operations
+            .in_flight()
+            .iter()
+            .any(|op| op.label == LOG_TAIL_LABEL)
+    }
+
+    /// Read the tail's log groups from where the last read stopped
+    fn poll_log_tail(&mut self) {
+        let Some(tail) = self.log_tail.as_mut() else {
+            return;
+        };
+        tail.polled_at = Some(Instant::now());
+        let resource_id = tail.resource_id.clone();
+        let log_groups = tail.log_groups.clone();
+        let start = tail.next_start;
+        let region = tail.region.clone();
+        let clients = self.clients_for_region(&region);
+        self.start_operation(None, LOG_TAIL_LABEL, "", async move {
+            let events = match CloudWatchLogsService::new(&clients)
+                .tail(&log_groups, start)
+                .await
+            {
+                Ok(events) => Ok(events),
+                // Shown in the tail itself rather than as a notification every poll
+                Err(e) if !e.is_connectivity_error() => Err(e.to_string()),
+                Err(e) => return Err(e),
+            };
+            Ok(OperationOutput::LogEvents {
+                resource_id,
+                events,
+            })
+        });
+    }
+
+    /// Whether a cluster being created has gone `CLUSTER_CREATION_POLL_INTERVAL`
+    /// without a check, with no check in flight
+    fn cluster_creation_poll_due(&self) -> bool {
+        self.cluster_creations.iter().any(|creation| {
+            creation
+                .polled_at
+                .is_none_or(|at| at.elapsed() >= CLUSTER_CREATION_POLL_INTERVAL)
+        }) && !self
+            .operations
+            .in_flight()
+            .iter()
+            .any(|op| op.label == CLUSTER_STATUS_LABEL)
+    }
+
+    /// Describe the cluster being created that has waited longest for a check
+    fn poll_cluster_creation(&mut self) {
+        let Some(creation) = self
+            .cluster_creations
+            .iter_mut()
+            .min_by_key(|creation| creation.polled_at)
+        else {
+            return;
+        };
+        creation.polled_at = Some(Instant::now());
+        let region = creation.region.clone();
+        let cluster = creation.name().to_string();
+        let clients = self.clients_for_region(&region);
+        self.start_operation(None, CLUSTER_STATUS_LABEL, "", async move {
+            let resource = describe_resource(&clients, ServiceType::EKS, &cluster).await?;
+            Ok(OperationOutput::ClusterStatus {
+                region,
+                cluster,
+                resource: resource.map(Box::new),
+            })
+        });
+    }
+
+    /// Track a cluster's creation until it becomes active or fails
+    fn apply_cluster_status(
+        &mut self,
+        region: String,
+        cluster: ResourceId,
+        resource: Option<Resource>,
+    ) {
+        let Some(index) = self
+            .cluster_creations
+            .iter()
+            .position(|creation| creation.region == region && creation.name() == cluster)
+        else {
+            return;
+        };
+
+        let Some(resource) = resource else {
+            // Newly created clusters can take a moment to be reported
+            if self.cluster_creations[index].timed_out() {
+                self.cluster_creations.remove(index);
+                self.add_notification(
+                    format!("EKS cluster {} never appeared in {}", cluster, region),
+                    NotificationLevel::Error,
+                );
+            }
+            return;
+        };
+
+        if !is_terminal_status(&resource.state) {
+            if self.cluster_creations[index].timed_out() {
+                self.cluster_creations.remove(index);
+                self.add_notification(
+                    format!(
+                        "Stopped tracking EKS cluster {}, still {} after 30 minutes",
+                        cluster, resource.state
+                    ),
+                    NotificationLevel::Warning,
+                );
+            } else {
+                self.cluster_creations[index].resource = resource;
+            }
+            return;
+        }
+
+        let creation = self.cluster_creations.remove(index);
+        let elapsed = chrono::Duration::from_std(creation.started_at.elapsed()).unwrap_or_default();
+        if resource.state == "ACTIVE" {
+            self.add_notification(
+                format!(
+                    "EKS cluster {} is active after {}",
+                    cluster,
+                    format_duration(&elapsed)
+                ),
+                NotificationLevel::Success,
+            );
+        } else {
+            self.add_notification(
+                format!(
+                    "EKS cluster {} failed to create after {}",
+                    cluster,
+                    format_duration(&elapsed)
+                ),
+                NotificationLevel::Error,
+            );
+        }
+        self.replace_resource(region, ServiceType::EKS, resource);
+    }
+
+    /// The creation being tracked for a cluster of the current region
+    pub fn cluster_creation(&self, cluster: &str) -> Option<&ClusterCreation> {
+        self.cluster_creations
+            .iter()
+            .find(|creation| creation.region == self.current_region && creation.name() == cluster)
+    }
+
+    /// Check reachability with a cheap STS GetCallerIdentity call
+    fn probe_connectivity(&mut self) {
+        self.last_connectivity_probe = Some(Instant::now());
+        let profile = self.current_profile.clone();
+        self.start_operation(None, CONNECTIVITY_PROBE_LABEL, "", async move {
+            CredentialsValidator::for_profile(&profile)
+                .validate_credentials()
+                .await?;
+            Ok(OperationOutput::Done)
+        });
+    }
+
+    /// Forget a listing left loading once no other operation for the same
+    /// (region, service) remains, e.g. after it was cancelled
+    fn clear_operation_loading_state(&mut self, operation: &InFlightOperation) {
+        if let Some(service_type) = operation.service_type {
+            if !self.operations.is_loading(&operation.region, service_type) {
+                if let Some(listing) = self
+                    .resources
+                    .get_mut(&(operation.region.clone(), service_type))
+                {
+                    listing.abandon();
+                }
+            }
+        }
+    }
+
+    /// Stop every background task before the terminal is restored, so none
+    /// outlives the UI
+    pub async fn shutdown(&mut self) {
+        let stopped = self.operations.shutdown(SHUTDOWN_GRACE).await;
+        if stopped > 0 {
+            tracing::debug!("Stopped {} background operations on quit", stopped);
+        }
+        if let Err(e) = self.dashboard_layout.save() {
+            tracing::warn!("Failed to save dashboard layout: {}", e);
+        }
+    }
+
+    /// Cancel the most recently started operation; returns false if none was running
+    fn cancel_operation(&mut self) -> bool {
+        let Some(operation) = self.operations.cancel_latest() else {
+            return false;
+        };
+
+        self.clear_operation_loading_state(&operation);
+        self.add_notification(
+            format!("{} cancelled", operation.label),
+            NotificationLevel::Warning,
+        );
+        self.update_command_context();
+        true
+    }
+
+    /// Run an AWS operation in the background against the current region
+    fn spawn_operation<F>(
+        &mut self,
+        service_type: ServiceType,
+        label: &str,
+        success_message: &str,
+        future: F,
+    ) where
+        F: std::future::Future<Output = Result<OperationOutput>> + Send + 'static,
+    {
+        self.start_operation(Some(service_type), label, success_message, future);
+    }
+
+    /// Like `spawn_operation`, for operations that advance `progress` as they go
+    fn spawn_tracked_operation<F>(
+        &mut self,
+        service_type: ServiceType,
+        label: &str,
+        success_message: &str,
+        progress: OperationProgress,
+        future: F,
+    ) where
+        F: std::future::Future<Output = Result<OperationOutput>> + Send + 'static,
+    {
+        let mut operation = self.in_flight_operation(Some(service_type), label, success_message);
+        operation.progress = Some(progress);
+        self.operations.spawn(operation, future);
+    }
+
+    fn start_operation<F>(
+        &mut self,
+        service_type: Option<ServiceType>,
+        label: &str,
+        success_message: &str,
+        future: F,
+    ) where
+        F: std::future::Future<Output = Result<OperationOutput>> + Send + 'static,
+    {
+        let operation = self.in_flight_operation(service_type, label, success_message);
+        self.operations.spawn(operation, future);
+    }
+
+    fn in_flight_operation(
+        &self,
+        service_type: Option<ServiceType>,
+        label: &str,
+        success_message: &str,
+    ) -> InFlightOperation {
+        InFlightOperation {
+            label: label.to_string(),
+            success_message: success_message.to_string(),
+            service_type,
+            region: self.current_region.clone(),
+            origin_page: self.current_page.clone(),
+            started_at: Instant::now(),
+            progress: None,
+            retry: self.retry_action.clone(),
+            background: self.starting_background,
+        }
+    }
+
+    /// Cache a fresh listing for (region, service). When `viewing` the list,
+    /// the highlight follows its resource to the new row, or stays on the
+    /// nearest row when the resource is gone.
+    fn store_resources(
+        &mut self,
+        region: String,
+        service_type: ServiceType,
+        mut resources: Vec<Resource>,
+        viewing: bool,
+    ) {
+        // API order is arbitrary, so sort to keep rows still across refreshes
+        resources.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+
+        let now = SystemTime::now();
+        for resource in &resources {
+            self.resource_baselines
+                .entry((region.clone(), service_type, resource.id.clone()))
+                .or_insert_with(|| ResourceBaseline::new(resource, now));
+        }
+
+        let key = (region, service_type);
+        self.permission_denied.remove(&key);
+        self.resources
+            .insert(key.clone(), LoadState::Loaded(resources));
+        self.last_refresh.insert(key, SystemTime::now());
+
+        // Rows are indexed within the filtered list, so relocate against it
+        if viewing {
+            let visible = self.visible_resources(service_type);
+            let count = visible.len();
+            let relocated = self
+                .selected_resource_id
+                .as_ref()
+                .and_then(|id| visible.iter().position(|resource| &resource.id == id));
+            let index = relocated
+                .unwrap_or_else(|| self.selected_resource_index.min(count.saturating_sub(1)));
+            self.select_resource_at(index);
+        }
+    }
+
+    /// Swap one re-described resource into its cached listing, leaving the
+    /// rest of the listing as it was
+    fn replace_resource(&mut self, region: String, service_type: ServiceType, resource: Resource) {
+        self.missing_resources
+            .remove(&(region.clone(), service_type, resource.id.clone()));
+        self.resource_baselines
+            .entry((region.clone(), service_type, resource.id.clone()))
+            .or_insert_with(|| ResourceBaseline::new(&resource, SystemTime::now()));
+
+        // A service never listed in this region has no listing to update
+        let Some(resources) = self
+            .resources
+            .get_mut(&(region, service_type))
+            .and_then(LoadState::loaded_mut)
+        else {
+            return;
+        };
+        match resources.iter_mut().find(|r| r.id == resource.id) {
+            Some(existing) => *existing = resource,
+            None => {
+                resources.push(resource);
+                resources.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+            }
+        }
+    }
+
+    /// Drop every trace of a resource that was deleted elsewhere, so the
+    /// detail page can say so instead of showing stale data
+    fn forget_missing_resource(
+        &mut self,
+        region: String,
+        service_type: ServiceType,
+        resource_id: ResourceId,
+    ) {
+        if let Some(resources) = self
+            .resources
+            .get_mut(&(region.clone(), service_type))
+            .and_then(LoadState::loaded_mut)
+        {
+            resources.retain(|r| r.id != resource_id);
+        }
+        self.resource_baselines
+            .remove(&(region.clone(), service_type, resource_id.clone()));
+
+        let mut removed_from = vec![];
+        if self.favorites_manager.is_favorite(&resource_id) {
+            match self.favorites_manager.remove_favorite(&resource_id) {
+                Ok(()) => removed_from.push("favorites"),
+                Err(e) => tracing::warn!("Failed to remove missing favorite: {}", e),
+            }
+        }
+        match self
+            .recently_viewed
+            .remove(&resource_id, service_type, &region)
+        {
+            Ok(true) => removed_from.push("recently viewed"),
+            Ok(false) => {}
+            Err(e) => tracing::warn!("Failed to remove missing recently viewed resource: {}", e),
+        }
+
+        let mut message = format!("{} no longer exists", resource_id);
+        if !removed_from.is_empty() {
+            message.push_str(&format!("; removed it from {}", removed_from.join(" and ")));
+        }
+        self.add_notification(message, NotificationLevel::Warning);
+        self.missing_resources
+            .insert((region, service_type, resource_id));
+    }
+
+    /// Whether the last describe of a resource in the current region found it gone
+    pub fn is_resource_missing(&self, service_type: ServiceType, resource_id: &str) -> bool {
+        self.missing_resources.contains(&(
+            self.current_region.clone(),
+            service_type,
+            resource_id.to_string(),
+        ))
+    }
+
+    /// The region overview loads once, then again every dashboard refresh
+    /// interval when auto-refresh is on
+    fn region_overview_due(&self) -> bool {
+        if self.dashboard_layout.region_overview_config().is_none() {
+            return false;
+        }
+        match self.region_overview_refreshed_at {
+            None => true,
+            Some(at) => self
+                .user_config
+                .refresh_interval(&AppPage::Dashboard)
+                .is_some_and(|interval| at.elapsed() >= interval),
+        }
+    }
+
+    /// List the overview's service in every available region at once
+    fn refresh_region_overview(&mut self) {
+        let Some((service_type, _)) = self.dashboard_layout.region_overview_config() else {
+            return;
+        };
+        self.region_overview_refreshed_at = Some(Instant::now());
+
+        let regions: Vec<String> = self
+            .available_regions
+            .iter()
+            .map(|region| region.name.clone())
+            .collect();
+        let clients: Vec<RegionClients> = regions
+            .iter()
+            .map(|region| self.clients_for_region(region))
+            .collect();
+        let limiter = self.operations.limiter().clone();
+        let progress = OperationProgress::new(ProgressDisplay::StatusBar);
+        let mut operation = self.in_flight_operation(None, "Region overview", "");
+        operation.progress = Some(progress.clone());
+        self.operations.spawn_fan_out(operation, async move {
+            let listings = list_in_regions(limiter, clients, service_type, progress).await;
+            Ok(OperationOutput::RegionResources {
+                service_type,
+                listings,
+            })
+        });
+    }
+
+    /// Validate two profiles concurrently and show the results side by side
+    fn compare_profiles(&mut self, left: &str, right: &str) {
+        let region_of = |name: &str| {
+            self.available_profiles
+                .iter()
+                .find(|p| p.name == name)
+                .and_then(|p| p.region.clone())
+        };
+        let (left, left_region) = (left.to_string(), region_of(left));
+        let (right, right_region) = (right.to_string(), region_of(right));
+
+        self.start_operation(None, "Compare profiles", "", async move {
+            // A failure on one side is part of the comparison, not a reason to abort it
+            let (left, right) = tokio::join!(
+                CredentialsValidator::check_profile(&left, left_region),
+                CredentialsValidator::check_profile(&right, right_region),
+            );
+            Ok(OperationOutput::ProfileComparison(Box::new((left, right))))
+        });
+    }
+
+    /// Check every profile against STS at once, bounded by the request limit,
+    /// and show the results as a report
+    fn validate_all_profiles(&mut self) {
+        let running = self
+            .operations
+            .in_flight()
+            .iter()
+            .any(|op| op.label == VALIDATE_PROFILES_LABEL);
+        if running {
+            return;
+        }
+
+        let profiles: Vec<(String, Option<String>)> = self
+            .available_profiles
+            .iter()
+            .map(|profile| (profile.name.clone(), profile.region.clone()))
+            .collect();
+        let limiter = self.operations.limiter().clone();
+        let progress = OperationProgress::new(ProgressDisplay::StatusBar);
+        let mut operation = self.in_flight_operation(None, VALIDATE_PROFILES_LABEL, "");
+        operation.progress = Some(progress.clone());
+        self.operations.spawn_fan_out(operation, async move {
+            let validation = validate_profiles(limiter, profiles, progress).await;
+            Ok(OperationOutput::ProfileValidation(Box::new(validation)))
+        });
+    }
+
+    fn handle_profile_validation_input(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                self.profile_validation = None;
+                self.profile_validation_scroll = 0;
+            }
+            KeyCode::Up => {
+                self.profile_validation_scroll = self.profile_validation_scroll.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                // Clamped to the content height when the overlay is drawn
+                self.profile_validation_scroll = self.profile_validation_scroll.saturating_add(1);
+            }
+            KeyCode::Char('r') => self.validate_all_profiles(),
+            KeyCode::Char('c') => {
+                let Some(validation) = &self.profile_validation else {
+                    return;
+                };
+                match crate::utils::clipboard::copy_to_clipboard(&validation.to_tsv()) {
+                    Ok(()) => self.add_notification(
+                        format!(
+                            "Copied the report for {} profile(s)",
+                            validation.checks.len()
+                        ),
+                        NotificationLevel::Success,
+                    ),
+                    Err(e) => self.add_notification(e.to_string(), NotificationLevel::Error),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Look up the account behind the active profile for the dashboard summary
+    /// The cost widget loads once, then again every dashboard refresh interval
+    /// when auto-refresh is on
+    fn cost_summary_due(&self) -> bool {
+        if self.dashboard_layout.cost_summary_config().is_none() {
+            return false;
+        }
+        match self.cost_refreshed_at {
+            None => true,
+            Some(at) => self
+                .user_config
+                .refresh_interval(&AppPage::Dashboard)
+                .is_some_and(|interval| at.elapsed() >= interval),
+        }
+    }
+
+    fn refresh_cost_summary(&mut self) {
+        self.cost_refreshed_at = Some(Instant::now());
+        let clients = self.clients_for_region(COST_EXPLORER_REGION);
+        self.start_operation(None, "Cost summary", "", async move {
+            let today = chrono::Utc::now().date_naive();
+            let outcome = match CostExplorerService::new(&clients)
+                .month_to_date(today)
+                .await
+            {
+                Ok(summary) => Ok(summary),
+                // Many roles have no ce: permissions; that is a state to show, not an error
+                Err(e) if e.is_permission_error() => {
+                    Err("Billing access unavailable for this profile".to_string())
+                }
+                Err(e) => return Err(e),
+            };
+            Ok(OperationOutput::Cost(outcome))
+        });
+    }
+
+    fn refresh_account_summary(&mut self) {
+        self.account_summary = None;
+        let clients = self.region_clients();
+        let profile = self.current_profile.clone();
+        self.start_operation(None, "Account summary", "", async move {
+            let account_id = CredentialsValidator::for_profile(&profile)
+                .validate_credentials()
+                .await?
+                .account;
+            let alias = IamService::new(&clients)
+                .list_account_aliases()
+                .await?
+                .into_iter()
+                .next();
+            Ok(OperationOutput::Account {
+                profile,
+                summary: AccountSummary { account_id, alias },
+            })
+        });
+    }
+
+    /// Show the cached permission report for the current profile, running the
+    /// diagnostics the first time
+    fn show_permission_report(&mut self) {
+        if self.permission_reports.contains_key(&self.current_profile) {
+            self.permission_report_visible = true;
+        } else {
+            self.run_permission_diagnostics();
+        }
+    }
+
+    fn run_permission_diagnostics(&mut self) {
+        let clients = self.region_clients();
+        let profile = self.current_profile.clone();
+        self.start_operation(None, "Check permissions", "", async move {
+            let report = diagnose_permissions(&clients, &profile).await;
+            Ok(OperationOutput::Permissions(Box::new(report)))
+        });
+    }
+
+    /// Services the current identity is expected to be denied, from the cached
+    /// diagnostics and from listings that already failed with AccessDenied
+    fn denied_services(&self) -> HashSet<ServiceType> {
+        let mut denied: HashSet<ServiceType> = self
+            .permission_denied
+            .iter()
+            .filter(|(region, _)| *region == self.current_region)
+            .map(|(_, service_type)| *service_type)
+            .collect();
+        if let Some(report) = self.permission_reports.get(&self.current_profile) {
+            denied.extend(report.denied_services());
+        }
+        denied
+    }
+
+    /// Build the AWS clients for the current profile in the background; they are
+    /// swapped in by `apply_completed_operation` once ready
+    /// Ask EC2 which regions the current account has enabled. The embedded
+    /// list stays in place if the call fails.
+    fn refresh_available_regions(&mut self) {
+        let profile = self.current_profile.clone();
+        let clients = self.region_clients();
+        self.start_operation(None, REGIONS_LABEL, "", async move {
+            match Ec2Service::new(&clients).enabled_regions().await {
+                Ok(regions) => Ok(OperationOutput::Regions { profile, regions }),
+                Err(e) => {
+                    tracing::warn!("DescribeRegions failed, keeping the embedded list: {}", e);
+                    Ok(OperationOutput::Done)
+                }
+            }
+        });
+    }
+
+    fn apply_available_regions(&mut self, regions: Vec<AwsRegion>) {
+        if !regions.iter().any(|r| r.name == self.current_region) {
+            self.add_notification(
+                format!(
+                    "Region {} is not enabled for this account",
+                    self.current_region
+                ),
+                NotificationLevel::Warning,
+            );
+        }
+        self.available_regions = regions;
+        self.update_command_context();
+    }
+
+    /// Build clients for the active profile in the background. Esc never
+    /// cancels the connection, since every service command waits for it, and
+    /// a failed one is retried every `RECONNECT_INTERVAL`.
+    fn connect_aws_clients(&mut self) {
+        self.last_connect_attempt = Some(Instant::now());
+        let profile = self.current_profile.clone();
+        let region = self.current_region.clone();
+        let expiry = self.temporary_credentials_expiry();
+        let demo = self.demo_mode;
+        let mut operation = self.in_flight_operation(None, CONNECT_LABEL, "Connected to AWS");
+        operation.background = true;
+        operation.retry = Some(NotificationAction::RunCommand {
+            label: "Reconnect",
+            command_id: "general.reconnect",
+        });
+        self.operations.spawn(operation, async move {
+            if demo {
+                return Ok(OperationOutput::Clients(Box::new(
+                    MultiRegionAwsClients::demo(&profile, &region),
+                )));
+            }
+            let mut clients = MultiRegionAwsClients::new(&profile, &region).await?;
+            clients.set_credentials_expiry(expiry);
+            Ok(OperationOutput::Clients(Box::new(clients)))
+        });
+    }
+
+    /// Whether the last connection failed `RECONNECT_INTERVAL` ago with no
+    /// clients and no connection in flight since
+    fn reconnect_due(&self) -> bool {
+        self.aws_clients.is_none()
+            && !self.aws_connecting()
+            && self
+                .last_connect_attempt
+                .is_some_and(|at| at.elapsed() >= RECONNECT_INTERVAL)
+    }
+
+    /// Drop the clients and connect again as the active profile
+    fn reconnect(&mut self) {
+        self.add_notification(
+            format!("Reconnecting to AWS as {}", self.current_profile),
+            NotificationLevel::Info,
+        );
+        self.aws_clients = None;
+        self.connect_aws_clients();
+        self.update_command_context();
+    }
+
+    /// Warn when the current profile configures no credentials of its own.
+    /// The SDK still falls back to its default chain (environment, SSO cache,
+    /// instance role), so the profile stays usable; the warning only explains
+    /// the SDK errors should that chain come up empty too.
+    fn warn_if_no_credentials(&mut self) {
+        if self.demo_mode {
+            return;
+        }
+        let configured = self
+            .available_profiles
+            .iter()
+            .find(|p| p.name == self.current_profile)
+            .is_none_or(|profile| profile.has_credentials(&self.available_profiles));
+        if configured {
+            return;
+        }
+        self.add_notification_with_action(
+            format!(
+                "Profile '{}' configures no credentials; using the default credential chain. Add keys with `aws configure --profile {}` or set up SSO if calls fail",
+                self.current_profile, self.current_profile
+            ),
+            NotificationLevel::Warning,
+            NotificationAction::RunCommand {
+                label: "Switch profile",
+                command_id: "profile.selector",
+            },
+        );
+    }
+
+    /// When credentials resolved now for the current profile would expire
+    fn temporary_credentials_expiry(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let profile = self
+            .available_profiles
+            .iter()
+            .find(|p| p.name == self.current_profile)?;
+        if let Some(expiry) = profile.ephemeral_expiry() {
+            return Some(expiry);
+        }
+        if !profile.uses_temporary_credentials() {
+            return None;
+        }
+        // This would read the Expiration of the resolved STS credentials
+        // For Phase 1, assume a default one-hour session
+        Some(chrono::Utc::now() + TEMPORARY_CREDENTIALS_LIFETIME)
+    }
+
+    /// Time left before the active profile's temporary credentials expire
+    pub fn credentials_time_left(&self) -> Option<chrono::Duration> {
+        let expiry = self.aws_clients.as_ref()?.credentials_expiry()?;
+        Some(expiry - chrono::Utc::now())
+    }
+
+    /// Re-authenticate shortly before temporary credentials expire instead of
+    /// waiting for calls to start failing
+    fn check_credentials_expiry(&mut self) {
+        let Some(time_left) = self.credentials_time_left() else {
+            return;
+        };
+        if time_left > CREDENTIALS_REFRESH_LEAD {
+            return;
+        }
+
+        // Assumed roles cannot be renewed without asking again; `expire_assumed_roles` drops them
+        let assumed = self
+            .available_profiles
+            .iter()
+            .find(|p| p.name == self.current_profile)
+            .is_some_and(|p| p.ephemeral_expiry().is_some());
+        if assumed {
+            return;
+        }
+
+        let mfa = self
+            .available_profiles
+            .iter()
+            .find(|p| p.name == self.current_profile)
+            .is_some_and(|p| p.mfa_serial.is_some());
+        if mfa {
+            // A new MFA session needs a token code we cannot ask for here
+            if !self.credentials_expiry_warned {
+                self.credentials_expiry_warned = true;
+                self.add_notification(
+                    format!(
+                        "Credentials for {} expire in under a minute; renew the MFA session and switch profile again",
+                        self.current_profile
+                    ),
+                    NotificationLevel::Warning,
+                );
+            }
+            return;
+        }
+
+        self.add_notification(
+            format!("Refreshing credentials for {}", self.current_profile),
+            NotificationLevel::Info,
+        );
+        // Dropping the clients makes every command wait for the new connection
+        self.aws_clients = None;
+        self.connect_aws_clients();
+        self.update_command_context();
+    }
+
+    /// Whether the clients for the active profile are ready, rather than still connecting
+    pub fn aws_client_ready(&self) -> bool {
+        self.aws_clients.is_some()
+    }
+
+    pub fn aws_connecting(&self) -> bool {
+        !self.aws_client_ready()
+            && self
+                .operations
+                .in_flight()
+                .iter()
+                .any(|op| op.label == CONNECT_LABEL)
+    }
+
+    /// Clients for `region`, to be moved into a background operation. Without a
+    /// connection they are built on the spot, answering with demo data in demo mode.
+    fn clients_for_region(&mut self, region: &str) -> RegionClients {
+        match self.aws_clients.as_mut() {
+            Some(clients) => clients.client_for_region(region).clone(),
+            None if self.demo_mode => RegionClients::demo(region),
+            None => RegionClients::new(region),
+        }
+    }
+
+    /// Clients for the current region, to be moved into a background operation
+    fn region_clients(&self) -> RegionClients {
+        self.aws_clients
+            .as_ref()
+            .and_then(|clients| clients.get_current_clients())
+            .unwrap_or_else(|| {
+                if self.demo_mode {
+                    RegionClients::demo(&self.current_region)
+                } else {
+                    RegionClients::new(&self.current_region)
+                }
+            })
+    }
+
+    fn navigate_to_dashboard(&mut self) {
+        self.page_history.push(self.current_page.clone());
+        self.current_page = AppPage::Dashboard;
+        self.selected_widget = None;
+    }
 
     fn handle_escape(&mut self) {
         if self.command_palette.is_visible() {
@@ -284,6 +3934,14 @@ impl AppState {
             self.profile_selector_visible = false;
         } else if self.region_selector_visible {
             self.region_selector_visible = false;
+        } else if self.current_page == AppPage::Dashboard
+            && self.dashboard_layout.get_selected_widget().is_some()
+        {
+            self.dashboard_layout.clear_selection();
+        } else if self.detail_fields_focused() && !self.detail_filter.is_empty() {
+            self.clear_detail_filter();
+        } else if self.operations.is_busy() {
+            self.cancel_operation();
         } else if let Some(prev_page) = self.page_history.pop() {
             self.current_page = prev_page;
             // Update selected service and resource based on new page
@@ -295,79 +3953,1239 @@ impl AppState {
                 AppPage::ResourceDetail(service_type, resource_id) => {
                     self.selected_service = Some(*service_type);
                     self.selected_resource = Some(resource_id.clone());
+                    let (service_type, resource_id) = (*service_type, resource_id.clone());
+                    self.detail_tab = DetailTab::Overview;
+                    self.clear_detail_filter();
+                    self.load_related_resources(service_type, resource_id.clone());
+                    if service_type == ServiceType::EKS {
+                        self.load_node_groups(resource_id);
+                    }
+                }
+                AppPage::Dashboard | AppPage::Settings | AppPage::ActivityLog => {
+                    self.selected_service = None;
+                    self.selected_resource = None;
+                }
+            }
+            // Update command context when navigating back
+            self.update_command_context();
+        }
+    }
+
+    /// `alternate` (Shift+Enter) swaps a resource list's configured Enter action
+    async fn handle_enter(&mut self, alternate: bool) -> Result<()> {
+        match &self.current_page {
+            AppPage::ResourceList(service_type) => {
+                let service_type = *service_type;
+                let Some(resource_id) =
+                    self.resource_id_at(service_type, self.selected_resource_index)
+                else {
+                    return Ok(());
+                };
+
+                let mut action = self.user_config.resource_list.enter_action(service_type);
+                if alternate {
+                    action = action.alternate();
+                }
+                if action == EnterAction::Contents {
+                    if let Some(command) =
+                        crate::command::ServiceCommand::contents_command(service_type)
+                    {
+                        self.selected_service = Some(service_type);
+                        self.set_selected_resource(Some(resource_id));
+                        return self.execute_service_command(service_type, &command).await;
+                    }
+                }
+
+                self.enter_resource_detail(service_type, resource_id);
+            }
+            AppPage::Dashboard if self.favorites_focused() => {
+                self.open_selected_favorite().await?;
+            }
+            AppPage::Dashboard if self.recently_viewed_focused() => {
+                self.open_selected_recently_viewed().await?;
+            }
+            AppPage::Dashboard if self.recent_activity_focused() => {
+                self.navigate_to_page(AppPage::ActivityLog);
+            }
+            AppPage::ResourceDetail(service_type, resource_id)
+                if self.is_resource_missing(*service_type, resource_id) =>
+            {
+                self.navigate_to_page(AppPage::ResourceList(*service_type));
+            }
+            AppPage::ResourceDetail(_, _) if self.detail_tab == DetailTab::Overview => {
+                self.open_selected_related()
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_tab(&mut self) {
+        match &self.current_page {
+            AppPage::Dashboard => {
+                self.dashboard_layout.select_next_widget();
+            }
+            // Node groups and related resources only show on the Overview tab
+            AppPage::ResourceDetail(_, _) if self.detail_tab != DetailTab::Overview => {}
+            AppPage::ResourceDetail(ServiceType::EKS, cluster) => {
+                let count = self
+                    .node_groups
+                    .get(&(self.current_region.clone(), cluster.clone()))
+                    .map_or(0, Vec::len);
+                if count > 0 {
+                    self.selected_node_group = (self.selected_node_group + 1) % count;
+                }
+            }
+            AppPage::ResourceDetail(_, _) => self.select_next_related(),
+            _ => {}
+        }
+    }
+
+    /// Step the highlight through the related resources, then back to none
+    fn select_next_related(&mut self) {
+        let count = self.related_resources.as_ref().map_or(0, Vec::len);
+        self.related_index = match self.related_index {
+            None if count > 0 => Some(0),
+            Some(i) if i + 1 < count => Some(i + 1),
+            _ => None,
+        };
+    }
+
+    fn open_selected_related(&mut self) {
+        let Some(related) = self
+            .related_index
+            .and_then(|i| self.related_resources.as_ref()?.get(i))
+            .cloned()
+        else {
+            return;
+        };
+        self.enter_resource_detail(related.service_type, related.id);
+    }
+
+    /// Fetch an EKS cluster's node groups for its detail page
+    fn load_node_groups(&mut self, cluster: ResourceId) {
+        let clients = self.region_clients();
+        self.spawn_operation(ServiceType::EKS, "List Node Groups", "", async move {
+            let node_groups = EksService::new(&clients).node_groups(&cluster).await?;
+            Ok(OperationOutput::NodeGroups {
+                cluster,
+                node_groups,
+            })
+        });
+    }
+
+    /// Describe the detail page's resource in the background to find what it references
+    fn load_related_resources(&mut self, service_type: ServiceType, resource_id: ResourceId) {
+        self.related_resources = None;
+        self.related_index = None;
+        if !RelatedResource::supported(service_type) {
+            return;
+        }
+
+        let clients = self.region_clients();
+        self.start_operation(None, "Load related resources", "", async move {
+            let related = match service_type {
+                ServiceType::EC2 => Ec2Service::new(&clients)
+                    .get_instance(&resource_id)
+                    .await?
+                    .map(|instance| instance.related_resources()),
+                ServiceType::RDS => RdsService::new(&clients)
+                    .get_instance(&resource_id)
+                    .await?
+                    .map(|instance| instance.related_resources()),
+                _ => None,
+            };
+            Ok(OperationOutput::Related {
+                service_type,
+                resource_id,
+                related: related.unwrap_or_default(),
+            })
+        });
+    }
+
+    fn recent_activity_focused(&self) -> bool {
+        self.dashboard_layout
+            .get_selected_widget()
+            .is_some_and(|w| w.id == "recent")
+    }
+
+    fn favorites_focused(&self) -> bool {
+        self.dashboard_layout
+            .get_selected_widget()
+            .is_some_and(|w| w.id == "favorites")
+    }
+
+    /// Favorites in the order the dashboard widget displays them
+    fn dashboard_favorites(&self) -> Vec<FavoriteResource> {
+        let (max_items, show_recent_first, limit_per_group) =
+            self.dashboard_layout.favorites_config();
+        self.favorites_manager
+            .get_grouped_favorites(max_items, show_recent_first, limit_per_group)
+            .into_iter()
+            .flat_map(|(_, favorites)| favorites.into_iter().cloned())
+            .collect()
+    }
+
+    fn recently_viewed_focused(&self) -> bool {
+        self.dashboard_layout
+            .get_selected_widget()
+            .is_some_and(|w| w.id == "recently_viewed")
+    }
+
+    /// Recently viewed resources in the order the dashboard widget displays them
+    fn dashboard_recently_viewed(&self) -> Vec<ViewedResource> {
+        let limit = self.dashboard_layout.recently_viewed_config().unwrap_or(0);
+        self.recently_viewed
+            .entries(limit)
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Jump to the detail page of the highlighted favorite
+    async fn open_selected_favorite(&mut self) -> Result<()> {
+        let favorites = self.dashboard_favorites();
+        match favorites.get(self.dashboard_layout.selected_item()) {
+            Some(favorite) => self.open_favorite(favorite).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Jump to a favorite's detail page, switching region first if needed
+    async fn open_favorite(&mut self, favorite: &FavoriteResource) -> Result<()> {
+        if !self
+            .switch_region_to_open(favorite.service_type, &favorite.region, &favorite.name)
+            .await?
+        {
+            return Ok(());
+        }
+
+        if let Err(e) = self.favorites_manager.update_access(&favorite.id) {
+            tracing::warn!("Failed to record favorite access: {}", e);
+        }
+
+        self.dashboard_layout.clear_selection();
+        self.enter_resource_detail(favorite.service_type, favorite.id.clone());
+        Ok(())
+    }
+
+    /// Jump back to the highlighted recently viewed resource
+    async fn open_selected_recently_viewed(&mut self) -> Result<()> {
+        let entries = self.dashboard_recently_viewed();
+        let Some(entry) = entries.get(self.dashboard_layout.selected_item()) else {
+            return Ok(());
+        };
+
+        if !self
+            .switch_region_to_open(entry.service_type, &entry.region, &entry.name)
+            .await?
+        {
+            return Ok(());
+        }
+
+        self.dashboard_layout.clear_selection();
+        self.enter_resource_detail(entry.service_type, entry.id.clone());
+        Ok(())
+    }
+
+    /// Switch to the region a resource lives in before opening it, returning
+    /// false if that region is not available
+    async fn switch_region_to_open(
+        &mut self,
+        service_type: ServiceType,
+        region: &str,
+        name: &str,
+    ) -> Result<bool> {
+        // IAM resources are global and open from any region
+        let global = matches!(service_type, ServiceType::IAM);
+        if global || region == self.current_region {
+            return Ok(true);
+        }
+
+        if !self.available_regions.iter().any(|r| r.name == region) {
+            self.add_notification(
+                format!("Cannot open {}: region {} is not available", name, region),
+                NotificationLevel::Error,
+            );
+            return Ok(false);
+        }
+
+        self.switch_region(region).await?;
+        self.add_notification(
+            format!("Switched to {} to open {}", region, name),
+            NotificationLevel::Info,
+        );
+        Ok(true)
+    }
+
+    /// Navigate to a resource's detail page and remember the visit
+    pub fn enter_resource_detail(&mut self, service_type: ServiceType, resource_id: ResourceId) {
+        self.page_history.push(self.current_page.clone());
+        self.current_page = AppPage::ResourceDetail(service_type, resource_id.clone());
+        self.detail_scroll = 0;
+        self.detail_tab = DetailTab::Overview;
+        self.clear_detail_filter();
+        // Events are fetched again the next time the tab is opened
+        self.resource_events
+            .remove(&(self.current_region.clone(), resource_id.clone()));
+        self.selected_node_group = 0;
+        // Resources deleted elsewhere are caught here rather than shown stale
+        self.load_resource_description(service_type, resource_id.clone(), "");
+        self.load_related_resources(service_type, resource_id.clone());
+        if service_type == ServiceType::EKS {
+            self.load_node_groups(resource_id.clone());
+        }
+        if service_type == ServiceType::IAM {
+            self.load_iam_user_details(&resource_id);
+        }
+        self.selected_service = Some(service_type);
+        self.selected_resource = Some(resource_id.clone());
+
+        let name = self
+            .cached_resource(service_type, &resource_id)
+            .map_or_else(|| resource_id.clone(), |r| r.name.clone());
+        let viewed = ViewedResource {
+            id: resource_id,
+            name,
+            service_type,
+            region: self.current_region.clone(),
+            viewed_at: chrono::Utc::now(),
+        };
+        if let Err(e) = self.recently_viewed.record(viewed) {
+            tracing::warn!("Failed to record recently viewed resource: {}", e);
+        }
+
+        // Update command context when navigating to resource detail
+        self.update_command_context();
+    }
+
+    /// Show the dashboard with the recently viewed widget focused
+    fn show_recently_viewed(&mut self) {
+        if self.dashboard_layout.recently_viewed_config().is_none() {
+            self.add_notification(
+                "The Recently Viewed widget is disabled".to_string(),
+                NotificationLevel::Info,
+            );
+            return;
+        }
+        if self.recently_viewed.is_empty() {
+            self.add_notification(
+                "No recently viewed resources yet".to_string(),
+                NotificationLevel::Info,
+            );
+            return;
+        }
+
+        if self.current_page != AppPage::Dashboard {
+            self.page_history.push(self.current_page.clone());
+            self.current_page = AppPage::Dashboard;
+            self.selected_service = None;
+            self.selected_resource = None;
+        }
+        self.dashboard_layout.focus_widget("recently_viewed");
+    }
+
+    /// Highlight a row of the current resource list, remembering which resource it is
+    fn select_resource_at(&mut self, index: usize) {
+        self.selected_resource_index = index;
+        self.selected_resource_id = match &self.current_page {
+            AppPage::ResourceList(service_type) => self.resource_id_at(*service_type, index),
+            _ => None,
+        };
+    }
+
+    fn handle_up(&mut self) {
+        match &self.current_page {
+            AppPage::Dashboard if self.favorites_focused() || self.recently_viewed_focused() => {
+                self.dashboard_layout.select_previous_item();
+            }
+            AppPage::ResourceDetail(_, _) if self.detail_tab == DetailTab::Overview => {
+                self.move_detail_field(false, 1);
+            }
+            AppPage::ResourceDetail(_, _) => {
+                self.detail_scroll = self.detail_scroll.saturating_sub(1);
+            }
+            AppPage::ActivityLog => {
+                self.activity_scroll = self.activity_scroll.saturating_sub(1);
+            }
+            AppPage::ResourceList(_) => {
+                if self.selected_resource_index > 0 {
+                    self.select_resource_at(self.selected_resource_index - 1);
+                    // Update command context when resource selection changes
+                    self.update_command_context();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_down(&mut self) {
+        match &self.current_page {
+            AppPage::Dashboard if self.favorites_focused() => {
+                let count = self.dashboard_favorites().len();
+                self.dashboard_layout.select_next_item(count);
+            }
+            AppPage::Dashboard if self.recently_viewed_focused() => {
+                let count = self.dashboard_recently_viewed().len();
+                self.dashboard_layout.select_next_item(count);
+            }
+            AppPage::ResourceDetail(_, _) if self.detail_tab == DetailTab::Overview => {
+                self.move_detail_field(true, 1);
+            }
+            AppPage::ResourceDetail(_, _) => {
+                // Clamped to the content height when the page is drawn
+                self.detail_scroll = self.detail_scroll.saturating_add(1);
+            }
+            AppPage::ActivityLog => {
+                // Clamped to the entry count when the page is drawn
+                self.activity_scroll = self.activity_scroll.saturating_add(1);
+            }
+            AppPage::ResourceList(service_type) => {
+                let count = self.visible_resources(*service_type).len();
+                if self.selected_resource_index + 1 < count {
+                    self.select_resource_at(self.selected_resource_index + 1);
+                    // Update command context when resource selection changes
+                    self.update_command_context();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Scroll the resource detail info panel by roughly a screenful
+    fn handle_page_scroll(&mut self, down: bool) {
+        if self.current_page == AppPage::ActivityLog {
+            // The header, filter line and table borders take about 8 rows
+            let page = usize::from(self.terminal_size.1.saturating_sub(8).max(1));
+            self.activity_scroll = if down {
+                self.activity_scroll.saturating_add(page)
+            } else {
+                self.activity_scroll.saturating_sub(page)
+            };
+            return;
+        }
+        if let AppPage::ResourceDetail(_, _) = self.current_page {
+            // The header, actions panel and borders take about 14 rows
+            let page = self.terminal_size.1.saturating_sub(14).max(1);
+            if self.detail_tab == DetailTab::Overview {
+                self.move_detail_field(down, usize::from(page));
+                return;
+            }
+            self.detail_scroll = if down {
+                self.detail_scroll.saturating_add(page)
+            } else {
+                self.detail_scroll.saturating_sub(page)
+            };
+        }
+    }
+
+    /// The service's cached resources in the current region, narrowed by the
+    /// list's zone and state filters, in the order the list shows them
+    pub fn visible_resources(&self, service_type: ServiceType) -> Vec<&Resource> {
+        let zone_filter = self.zone_filters.get(&service_type);
+        let state_filter = self.user_config.resource_list.state_filter(service_type);
+        let mut resources: Vec<&Resource> = self
+            .cached_resources(service_type)
+            .map(|resources| resources.iter().collect())
+            .unwrap_or_default();
+
+        // Clusters being created stay listed until a listing includes them
+        if service_type == ServiceType::EKS {
+            let pending: Vec<&Resource> = self
+                .cluster_creations
+                .iter()
+                .filter(|creation| creation.region == self.current_region)
+                .map(|creation| &creation.resource)
+                .filter(|pending| !resources.iter().any(|r| r.id == pending.id))
+                .collect();
+            resources.extend(pending);
+        }
+
+        resources
+            .into_iter()
+            .filter(|resource| {
+                zone_filter.is_none_or(|zone| resource.availability_zone.as_ref() == Some(zone))
+            })
+            .filter(|resource| state_filter.matches(&resource.state))
+            .collect()
+    }
+
+    /// Id of the resource list row at `index`
+    fn resource_id_at(&self, service_type: ServiceType, index: usize) -> Option<ResourceId> {
+        self.visible_resources(service_type)
+            .get(index)
+            .map(|resource| resource.id.clone())
+    }
+
+    /// The listing of `service_type` in the current region, whatever state it is in
+    pub fn listing(&self, service_type: ServiceType) -> LoadState<&Vec<Resource>> {
+        self.resources
+            .get(&(self.current_region.clone(), service_type))
+            .map_or(LoadState::NotLoaded, LoadState::as_ref)
+    }
+
+    /// The loaded listing of `service_type` in the current region
+    pub fn cached_resources(&self, service_type: ServiceType) -> Option<&Vec<Resource>> {
+        self.resources
+            .get(&(self.current_region.clone(), service_type))
+            .and_then(LoadState::loaded)
+    }
+
+    pub fn cached_resource(
+        &self,
+        service_type: ServiceType,
+        resource_id: &str,
+    ) -> Option<&Resource> {
+        self.cached_resources(service_type)
+            .and_then(|resources| resources.iter().find(|r| r.id == resource_id))
+    }
+
+    /// List a service the first time its page is opened in a region, or
+    /// again after the last attempt failed
+    fn ensure_service_listed(&mut self, service_type: ServiceType) {
+        if matches!(
+            self.listing(service_type),
+            LoadState::NotLoaded | LoadState::Failed(_)
+        ) {
+            self.list_service(service_type, "");
+        }
+    }
+
+    /// Where the detail page's describe of a resource stands: loaded once it
+    /// is cached, failed once the service reported it gone
+    pub fn describe_state(
+        &self,
+        service_type: ServiceType,
+        resource_id: &str,
+    ) -> LoadState<&Resource> {
+        if self.is_resource_missing(service_type, resource_id) {
+            return LoadState::Failed(format!("{} no longer exists", resource_id));
+        }
+        if let Some(resource) = self.cached_resource(service_type, resource_id) {
+            return LoadState::Loaded(resource);
+        }
+        let describing = format!("Describe {}", resource_id);
+        if self
+            .operations
+            .in_flight()
+            .iter()
+            .any(|op| op.label == describing)
+        {
+            LoadState::Loading
+        } else {
+            LoadState::NotLoaded
+        }
+    }
+
+    /// Mark or unmark the highlighted row for a batch action
+    fn toggle_mark(&mut self) {
+        let AppPage::ResourceList(service_type) = self.current_page else {
+            return;
+        };
+        if self.marked_service != Some(service_type) {
+            self.marked_resources.clear();
+            self.marked_service = Some(service_type);
+        }
+
+        let Some(resource_id) = self.resource_id_at(service_type, self.selected_resource_index)
+        else {
+            return;
+        };
+        if let Some(position) = self
+            .marked_resources
+            .iter()
+            .position(|id| *id == resource_id)
+        {
+            self.marked_resources.remove(position);
+        } else {
+            self.marked_resources.push(resource_id);
+        }
+        self.update_command_context();
+    }
+
+    /// Resources marked in `service_type`'s list
+    pub fn marked_for(&self, service_type: ServiceType) -> &[ResourceId] {
+        if self.marked_service == Some(service_type) {
+            &self.marked_resources
+        } else {
+            &[]
+        }
+    }
+
+    fn clear_marks(&mut self) {
+        self.marked_resources.clear();
+        self.marked_service = None;
+    }
+
+    /// Open the Resource Changes overlay for the selected resource
+    fn show_resource_changes(&mut self) {
+        let service_type = self.selected_service.or(match &self.current_page {
+            AppPage::ResourceList(service_type) | AppPage::ResourceDetail(service_type, _) => {
+                Some(*service_type)
+            }
+            _ => None,
+        });
+        let (Some(service_type), Some(resource_id)) =
+            (service_type, self.selected_resource.clone())
+        else {
+            self.add_notification("No resource selected".to_string(), NotificationLevel::Error);
+            return;
+        };
+
+        let key = (
+            self.current_region.clone(),
+            service_type,
+            resource_id.clone(),
+        );
+        let current = self
+            .resources
+            .get(&(key.0.clone(), service_type))
+            .and_then(LoadState::loaded)
+            .and_then(|resources| resources.iter().find(|r| r.id == resource_id));
+        let (Some(current), Some(baseline)) = (current, self.resource_baselines.get(&key)) else {
+            self.add_notification(
+                format!(
+                    "{} has not been loaded yet; refresh to record a baseline",
+                    resource_id
+                ),
+                NotificationLevel::Info,
+            );
+            return;
+        };
+
+        let changes = diff_describe(&baseline.describe, &describe(current));
+        self.resource_changes = Some(ResourceChanges {
+            title: resource_id,
+            since: baseline.captured_at,
+            changes,
+            scroll: 0,
+        });
+    }
+
+    /// Drop every baseline and record the cached describe output in its place
+    fn reset_change_baseline(&mut self) {
+        let now = SystemTime::now();
+        self.resource_baselines = self
+            .resources
+            .iter()
+            .filter_map(|(key, listing)| listing.loaded().map(|resources| (key, resources)))
+            .flat_map(|((region, service_type), resources)| {
+                resources.iter().map(move |resource| {
+                    (
+                        (region.clone(), *service_type, resource.id.clone()),
+                        ResourceBaseline::new(resource, now),
+                    )
+                })
+            })
+            .collect();
+        self.add_notification(
+            format!(
+                "Change baseline reset for {} resources",
+                self.resource_baselines.len()
+            ),
+            NotificationLevel::Success,
+        );
+    }
+
+    /// Favorite resources, most recently opened first
+    pub fn favorite_resources(&self) -> Vec<FavoriteResource> {
+        self.favorites_manager
+            .get_favorites()
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Favorite commands, each with whether it can run in the current context
+    pub fn favorite_commands(&self) -> Vec<(FavoriteCommand, bool)> {
+        let available = self.context_commands(&self.current_command_context());
+        self.favorites_manager
+            .get_favorite_commands()
+            .iter()
+            .map(|favorite| {
+                let runnable = available.iter().any(|cmd| cmd.id == favorite.id);
+                (favorite.clone(), runnable)
+            })
+            .collect()
+    }
+
+    async fn handle_favorites_overlay_input(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(overlay) = &mut self.favorites_overlay else {
+            return Ok(());
+        };
+        let resources = self.favorites_manager.count();
+        let total = resources + self.favorites_manager.get_favorite_commands().len();
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.favorites_overlay = None,
+            KeyCode::Up => overlay.selected = overlay.selected.saturating_sub(1),
+            KeyCode::Down if overlay.selected + 1 < total => overlay.selected += 1,
+            KeyCode::Enter if overlay.selected < resources => {
+                let selected = overlay.selected;
+                self.favorites_overlay = None;
+                let favorite = self.favorite_resources().remove(selected);
+                return self.open_favorite(&favorite).await;
+            }
+            KeyCode::Enter => {
+                let index = overlay.selected - resources;
+                let Some((command, runnable)) = self.favorite_commands().into_iter().nth(index)
+                else {
+                    return Ok(());
+                };
+                // Commands that would do nothing here stay listed but do not run
+                if !runnable {
+                    self.add_notification(
+                        format!("{} is not available here", command.name),
+                        NotificationLevel::Warning,
+                    );
+                    return Ok(());
                 }
-                AppPage::Dashboard | AppPage::Settings => {
-                    self.selected_service = None;
-                    self.selected_resource = None;
+                self.favorites_overlay = None;
+                return self.execute_command_by_id(&command.id).await;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Favorite the highlighted palette command, or unfavorite it
+    fn toggle_favorite_command(&mut self) {
+        let Some(command) = self.command_palette.get_selected_command() else {
+            return;
+        };
+        let (id, name) = (command.id.clone(), command.name.clone());
+
+        match self.favorites_manager.toggle_favorite_command(&id, &name) {
+            Ok(true) => self.add_notification(
+                format!("Added {} to favorites", name),
+                NotificationLevel::Info,
+            ),
+            Ok(false) => self.add_notification(
+                format!("Removed {} from favorites", name),
+                NotificationLevel::Info,
+            ),
+            Err(e) => self.add_notification(
+                format!("Failed to save favorite commands: {}", e),
+                NotificationLevel::Error,
+            ),
+        }
+    }
+
+    /// Favorite the selected resource, or unfavorite it
+    fn toggle_favorite_resource(&mut self) {
+        let (Some(service_type), Some(resource_id)) =
+            (self.selected_service, self.selected_resource.clone())
+        else {
+            self.add_notification("No resource selected".to_string(), NotificationLevel::Error);
+            return;
+        };
+
+        if self.favorites_manager.is_favorite(&resource_id) {
+            match self.favorites_manager.remove_favorite(&resource_id) {
+                Ok(()) => self.add_notification(
+                    format!("Removed {} from favorites", resource_id),
+                    NotificationLevel::Info,
+                ),
+                Err(e) => self.add_notification(
+                    format!("Failed to save favorites: {}", e),
+                    NotificationLevel::Error,
+                ),
+            }
+            return;
+        }
+
+        let cached = self.cached_resource(service_type, &resource_id);
+        let now = chrono::Utc::now();
+        let favorite = FavoriteResource {
+            id: resource_id.clone(),
+            name: cached.map_or_else(|| resource_id.clone(), |r| r.name.clone()),
+            service_type,
+            region: self.current_region.clone(),
+            arn: cached.map(|r| r.arn.clone()).unwrap_or_default(),
+            tags: cached.map(|r| r.tags.clone()).unwrap_or_default(),
+            added_at: now,
+            last_accessed: now,
+            access_count: 0,
+            note: None,
+        };
+        match self.favorites_manager.add_favorite(favorite) {
+            Ok(()) => self.add_notification(
+                format!("Added {} to favorites", resource_id),
+                NotificationLevel::Info,
+            ),
+            Err(e) => self.add_notification(
+                format!("Failed to save favorites: {}", e),
+                NotificationLevel::Error,
+            ),
+        }
+    }
+
+    /// Prompt for the selected resource's tags, starting from its cached ones
+    fn edit_tags(&mut self) {
+        let (Some(service_type), Some(resource_id)) =
+            (self.selected_service, self.selected_resource.clone())
+        else {
+            self.add_notification("No resource selected".to_string(), NotificationLevel::Error);
+            return;
+        };
+        match self.cached_resource(service_type, &resource_id) {
+            Some(resource) => self.tag_edit_form = Some(TagEditForm::new(resource)),
+            None => self.add_notification(
+                format!("{} is not loaded yet; refresh and try again", resource_id),
+                NotificationLevel::Warning,
+            ),
+        }
+    }
+
+    fn handle_tag_edit_input(&mut self, key: KeyEvent) {
+        let Some(form) = &mut self.tag_edit_form else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => self.tag_edit_form = None,
+            KeyCode::Enter => {
+                let edited = match form.edited_tags() {
+                    Ok(edited) => edited,
+                    Err(message) => {
+                        form.error = Some(message);
+                        return;
+                    }
+                };
+                let (service_type, resource_id) = (form.service_type, form.resource_id.clone());
+                let Some(resource) = self.cached_resource(service_type, &resource_id).cloned()
+                else {
+                    if let Some(form) = &mut self.tag_edit_form {
+                        form.error = Some(format!("{} is no longer listed", resource_id));
+                    }
+                    return;
+                };
+                self.tag_edit_form = None;
+                self.request_tag_changes(&resource, &edited);
+            }
+            KeyCode::Backspace => form.backspace(),
+            KeyCode::Char(c) => form.insert_char(c),
+            _ => {}
+        }
+    }
+
+    /// Prompt for the note of the selected resource, which must be a favorite
+    fn edit_favorite_note(&mut self) {
+        let Some(resource_id) = &self.selected_resource else {
+            self.add_notification("No resource selected".to_string(), NotificationLevel::Error);
+            return;
+        };
+        match self.favorites_manager.get_favorite(resource_id) {
+            Some(favorite) => self.favorite_note_form = Some(FavoriteNoteForm::new(favorite)),
+            None => self.add_notification(
+                format!("{} is not a favorite; favorite it first", resource_id),
+                NotificationLevel::Warning,
+            ),
+        }
+    }
+
+    fn handle_favorite_note_input(&mut self, key: KeyEvent) {
+        let Some(form) = &mut self.favorite_note_form else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => self.favorite_note_form = None,
+            KeyCode::Enter => {
+                let (resource_id, note) = (form.resource_id.clone(), form.note());
+                self.favorite_note_form = None;
+                let message = match &note {
+                    Some(_) => format!("Saved the note for {}", resource_id),
+                    None => format!("Cleared the note for {}", resource_id),
+                };
+                match self.favorites_manager.set_note(&resource_id, note) {
+                    Ok(()) => self.add_notification(message, NotificationLevel::Success),
+                    Err(e) => self.add_notification(
+                        format!("Failed to save favorites: {}", e),
+                        NotificationLevel::Error,
+                    ),
                 }
             }
-            // Update command context when navigating back
-            self.update_command_context();
+            KeyCode::Backspace => {
+                pop_grapheme(&mut form.note);
+            }
+            KeyCode::Char(c) => form.note.push(c),
+            _ => {}
         }
     }
 
-    async fn handle_enter(&mut self) -> Result<()> {
-        match &self.current_page {
-            AppPage::ResourceList(service_type) => {
-                // Navigate to resource detail
-                let resource_id = format!("resource-{}", self.selected_resource_index);
-                self.page_history.push(self.current_page.clone());
-                self.current_page = AppPage::ResourceDetail(*service_type, resource_id.clone());
-                self.selected_resource = Some(resource_id);
-                // Update command context when navigating to resource detail
+    fn handle_preset_input(&mut self, key: KeyEvent) {
+        let Some(form) = &mut self.preset_form else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc => self.preset_form = None,
+            KeyCode::Enter => self.save_preset(),
+            KeyCode::Backspace => form.backspace(),
+            KeyCode::Char(c) => form.insert_char(c),
+            _ => {}
+        }
+    }
+
+    /// Save the form's profile and region under its name, replacing any
+    /// preset of the same name; failures stay in the form
+    fn save_preset(&mut self) {
+        let Some(form) = &mut self.preset_form else {
+            return;
+        };
+        let name = form.name.trim().to_string();
+        if name.is_empty() {
+            form.error = Some("Enter a name for the preset".to_string());
+            return;
+        }
+
+        let preset = ContextPreset {
+            name: name.clone(),
+            profile: form.profile.clone(),
+            region: form.region.clone(),
+        };
+        let previous = self.user_config.presets.clone();
+        let replaced = match self.user_config.presets.iter_mut().find(|p| p.name == name) {
+            Some(existing) => {
+                *existing = preset;
+                true
+            }
+            None => {
+                self.user_config.presets.push(preset);
+                false
+            }
+        };
+
+        match self.user_config.save() {
+            Ok(()) => {
+                self.preset_form = None;
+                let verb = if replaced { "Updated" } else { "Saved" };
+                self.add_notification(
+                    format!("{} preset {}", verb, name),
+                    NotificationLevel::Success,
+                );
                 self.update_command_context();
             }
+            Err(e) => {
+                self.user_config.presets = previous;
+                if let Some(form) = &mut self.preset_form {
+                    form.error = Some(format!("Failed to save preset: {}", e));
+                }
+            }
+        }
+    }
+
+    async fn handle_setup_wizard_input(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(wizard) = &mut self.setup_wizard else {
+            return Ok(());
+        };
+
+        match key.code {
+            KeyCode::Esc => self.skip_setup_wizard(),
+            KeyCode::Enter if wizard.is_last_step() => return self.finish_setup_wizard().await,
+            KeyCode::Enter | KeyCode::Right | KeyCode::Tab => wizard.next_step(),
+            KeyCode::Left | KeyCode::BackTab => wizard.previous_step(),
+            KeyCode::Up | KeyCode::Char('k') => wizard.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => wizard.select_next(),
+            KeyCode::Char(' ') if wizard.step == SetupStep::Confirmations => {
+                wizard.toggle_confirmations()
+            }
             _ => {}
         }
         Ok(())
     }
 
-    fn handle_tab(&mut self) {
-        match &self.current_page {
-            AppPage::Dashboard => {
-                let widget_count = self.dashboard_layout.widgets.len();
-                if widget_count > 0 {
-                    self.selected_widget = Some(match self.selected_widget {
-                        Some(i) => (i + 1) % widget_count,
-                        None => 0,
-                    });
+    /// Write the default config so the wizard is not shown again
+    fn skip_setup_wizard(&mut self) {
+        match self.user_config.save() {
+            Ok(()) => self.setup_wizard = None,
+            Err(e) => {
+                if let Some(wizard) = &mut self.setup_wizard {
+                    wizard.error = Some(format!("Failed to save configuration: {}", e));
                 }
             }
+        }
+    }
+
+    /// Save the wizard's choices as the initial config and switch to the
+    /// chosen profile and region; a failed save keeps the wizard open
+    async fn finish_setup_wizard(&mut self) -> Result<()> {
+        let Some(wizard) = self.setup_wizard.clone() else {
+            return Ok(());
+        };
+
+        let previous = self.user_config.clone();
+        wizard.apply(&mut self.user_config);
+        if let Err(e) = self.user_config.save() {
+            self.user_config = previous;
+            if let Some(wizard) = &mut self.setup_wizard {
+                wizard.error = Some(format!("Failed to save configuration: {}", e));
+            }
+            return Ok(());
+        }
+        self.setup_wizard = None;
+
+        if let Some(profile) = wizard.selected_profile() {
+            if profile != self.current_profile {
+                self.switch_profile(profile).await?;
+            }
+        }
+        if let Some(region) = wizard.selected_region() {
+            if region.name != self.current_region {
+                self.switch_region(&region.name).await?;
+            }
+        }
+        self.update_command_context();
+
+        self.add_notification(
+            "Setup complete; change these any time on the Settings page".to_string(),
+            NotificationLevel::Success,
+        );
+        Ok(())
+    }
+
+    /// Switch to a preset's profile, then its region, reusing the regular
+    /// switch logic; the profile switch may move to that profile's own region
+    async fn apply_preset(&mut self, name: &str) -> Result<()> {
+        let Some(preset) = self
+            .user_config
+            .presets
+            .iter()
+            .find(|p| p.name == name)
+            .cloned()
+        else {
+            self.add_notification(
+                format!("No preset named {}", name),
+                NotificationLevel::Error,
+            );
+            return Ok(());
+        };
+        if !self
+            .available_profiles
+            .iter()
+            .any(|p| p.name == preset.profile)
+        {
+            self.add_notification(
+                format!(
+                    "Preset {} uses profile {}, which is not configured",
+                    preset.name, preset.profile
+                ),
+                NotificationLevel::Error,
+            );
+            return Ok(());
+        }
+
+        if preset.profile != self.current_profile {
+            self.switch_profile(&preset.profile).await?;
+        }
+        if preset.region != self.current_region {
+            self.switch_region(&preset.region).await?;
+        }
+        self.update_command_context();
+
+        if self.current_profile == preset.profile && self.current_region == preset.region {
+            self.add_notification(
+                format!("Applied preset {}", preset.name),
+                NotificationLevel::Success,
+            );
+        }
+        Ok(())
+    }
+
+    fn handle_resource_changes_input(&mut self, key: KeyEvent) {
+        let Some(changes) = &mut self.resource_changes else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => self.resource_changes = None,
+            KeyCode::Up => changes.scroll = changes.scroll.saturating_sub(1),
+            // Clamped to the content height when the overlay is drawn
+            KeyCode::Down => changes.scroll = changes.scroll.saturating_add(1),
+            KeyCode::PageUp => changes.scroll = changes.scroll.saturating_sub(10),
+            KeyCode::PageDown => changes.scroll = changes.scroll.saturating_add(10),
+            _ => {}
+        }
+    }
+
+    fn handle_security_group_inspector_input(&mut self, key: KeyEvent) {
+        let Some(inspector) = &mut self.security_group_inspector else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                self.security_group_inspector = None
+            }
+            KeyCode::Up => inspector.scroll = inspector.scroll.saturating_sub(1),
+            // Clamped to the content height when the overlay is drawn
+            KeyCode::Down => inspector.scroll = inspector.scroll.saturating_add(1),
+            KeyCode::PageUp => inspector.scroll = inspector.scroll.saturating_sub(10),
+            KeyCode::PageDown => inspector.scroll = inspector.scroll.saturating_add(10),
             _ => {}
         }
     }
 
-    fn handle_up(&mut self) {
-        match &self.current_page {
-            AppPage::ResourceList(_) => {
-                if self.selected_resource_index > 0 {
-                    self.selected_resource_index -= 1;
-                    // Update command context when resource selection changes
-                    self.update_command_context();
-                }
+    fn handle_log_tail_input(&mut self, key: KeyEvent) {
+        let Some(tail) = &mut self.log_tail else {
+            return;
+        };
+
+        match key.code {
+            // Closing the view stops the polling; a read still in flight is discarded
+            KeyCode::Esc | KeyCode::Char('q') => self.log_tail = None,
+            KeyCode::Up => {
+                tail.follow = false;
+                tail.scroll = tail.scroll.saturating_sub(1);
+            }
+            KeyCode::PageUp => {
+                tail.follow = false;
+                tail.scroll = tail.scroll.saturating_sub(10);
             }
+            // Scrolling back to the bottom resumes following when the view is drawn
+            KeyCode::Down => tail.scroll = tail.scroll.saturating_add(1),
+            KeyCode::PageDown => tail.scroll = tail.scroll.saturating_add(10),
+            KeyCode::Home => {
+                tail.follow = false;
+                tail.scroll = 0;
+            }
+            KeyCode::End => tail.follow = true,
             _ => {}
         }
     }
 
-    fn handle_down(&mut self) {
-        match &self.current_page {
-            AppPage::ResourceList(_) => {
-                // This would be bounded by actual resource count
-                self.selected_resource_index += 1;
-                // Update command context when resource selection changes
-                self.update_command_context();
+    fn handle_batch_results_input(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                self.batch_results = None;
+                self.batch_results_scroll = 0;
             }
+            KeyCode::Up => {
+                self.batch_results_scroll = self.batch_results_scroll.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                // Clamped to the content height when the overlay is drawn
+                self.batch_results_scroll = self.batch_results_scroll.saturating_add(1);
+            }
+            KeyCode::PageUp => {
+                self.batch_results_scroll = self.batch_results_scroll.saturating_sub(10);
+            }
+            KeyCode::PageDown => {
+                self.batch_results_scroll = self.batch_results_scroll.saturating_add(10);
+            }
+            KeyCode::Char('c') => self.copy_batch_failures(),
             _ => {}
         }
     }
 
+    /// Copy each failed resource and its error, one per line
+    fn copy_batch_failures(&mut self) {
+        let Some(results) = &self.batch_results else {
+            return;
+        };
+        let failures: Vec<String> = results
+            .failures()
+            .map(|(resource_id, message)| format!("{}\t{}", resource_id, message))
+            .collect();
+        if failures.is_empty() {
+            self.add_notification("No failures to copy".to_string(), NotificationLevel::Info);
+            return;
+        }
+
+        match crate::utils::clipboard::copy_to_clipboard(&failures.join("\n")) {
+            Ok(()) => self.add_notification(
+                format!("Copied {} failure(s)", failures.len()),
+                NotificationLevel::Success,
+            ),
+            Err(e) => self.add_notification(e.to_string(), NotificationLevel::Error),
+        }
+    }
+
+    /// Run an EC2 instance action on each instance, one at a time, and report
+    /// each outcome rather than stopping at the first failure
+    fn spawn_ec2_batch(
+        &mut self,
+        command: &crate::command::ServiceCommand,
+        instance_ids: Vec<ResourceId>,
+    ) {
+        use crate::command::ServiceCommand;
+
+        let clients = self.region_clients();
+        let command = command.clone();
+        let action = command.display_name().to_string();
+
+        self.add_notification(
+            format!("{} on {} instances...", action, instance_ids.len()),
+            NotificationLevel::Info,
+        );
+        let progress = OperationProgress::new(ProgressDisplay::Overlay);
+        let tracker = progress.clone();
+        self.spawn_tracked_operation(
+            ServiceType::EC2,
+            &action.clone(),
+            "",
+            progress,
+            async move {
+                let ec2 = Ec2Service::new(&clients);
+                let total = instance_ids.len();
+                let mut items = Vec::with_capacity(total);
+                for instance_id in instance_ids {
+                    let outcome = match command {
+                        ServiceCommand::StartInstance => ec2.start_instance(&instance_id).await,
+                        ServiceCommand::StopInstance => ec2.stop_instance(&instance_id).await,
+                        ServiceCommand::RebootInstance => ec2.reboot_instance(&instance_id).await,
+                        ServiceCommand::TerminateInstance => {
+                            ec2.terminate_instance(&instance_id).await
+                        }
+                        _ => Ok(()),
+                    };
+                    items.push(BatchItemResult {
+                        resource_id: instance_id,
+                        outcome: outcome.map_err(|e| e.to_string()),
+                    });
+                    tracker.set_steps(items.len(), total);
+                }
+                Ok(OperationOutput::Batch(BatchResults { action, items }))
+            },
+        );
+    }
+
     fn handle_left(&mut self) {
-        // Handle left navigation based on current page
+        self.cycle_service(false);
     }
 
     fn handle_right(&mut self) {
-        // Handle right navigation based on current page
+        self.cycle_service(true);
+    }
+
+    /// Move to the previous or next service's resource list, wrapping around.
+    /// The current page is replaced so Esc still returns to where the sweep began
+    fn cycle_service(&mut self, forward: bool) {
+        let current = match &self.current_page {
+            AppPage::ResourceList(service_type) | AppPage::ResourceDetail(service_type, _) => {
+                *service_type
+            }
+            _ => return,
+        };
+
+        let services = ServiceType::all();
+        let Some(index) = services.iter().position(|s| *s == current) else {
+            return;
+        };
+        let next = if forward {
+            (index + 1) % services.len()
+        } else {
+            (index + services.len() - 1) % services.len()
+        };
+        let service_type = services[next];
+
+        self.current_page = AppPage::ResourceList(service_type);
+        self.selected_service = Some(service_type);
+        self.selected_resource = None;
+        self.select_resource_at(0);
+        self.ensure_service_listed(service_type);
+        self.update_command_context();
     }
 
     fn execute_quick_action(&mut self, _action_index: usize) {
@@ -377,6 +5195,7 @@ impl AppState {
             message: "Quick action executed".to_string(),
             level: NotificationLevel::Info,
             timestamp: chrono::Utc::now(),
+            action: None,
         });
     }
 
@@ -385,29 +5204,189 @@ impl AppState {
             message,
             level,
             timestamp: chrono::Utc::now(),
+            action: None,
+        });
+    }
+
+    pub fn add_notification_with_action(
+        &mut self,
+        message: String,
+        level: NotificationLevel,
+        action: NotificationAction,
+    ) {
+        self.notifications.push(Notification {
+            message,
+            level,
+            timestamp: chrono::Utc::now(),
+            action: Some(action),
         });
     }
 
+    /// Run the action of the notification at `position` (oldest first),
+    /// dismissing it
+    async fn run_notification_action(&mut self, position: usize) -> Result<()> {
+        let Some(action) = self
+            .notifications
+            .get(position)
+            .and_then(|notification| notification.action.clone())
+        else {
+            return Ok(());
+        };
+        self.notifications.remove(position);
+
+        match action {
+            NotificationAction::ViewResource {
+                service_type,
+                resource_id,
+                region,
+            } => {
+                if self
+                    .switch_region_to_open(service_type, &region, &resource_id)
+                    .await?
+                {
+                    self.enter_resource_detail(service_type, resource_id);
+                }
+            }
+            NotificationAction::Retry {
+                service_type,
+                command,
+                resource_id,
+                region,
+            } => {
+                // The resource may not exist in whatever region is shown now
+                if region != self.current_region {
+                    self.add_notification(
+                        format!(
+                            "Switch back to {} to retry {}",
+                            region,
+                            command.display_name()
+                        ),
+                        NotificationLevel::Warning,
+                    );
+                    return Ok(());
+                }
+                self.set_selected_resource(resource_id);
+                self.execute_service_command(service_type, &command).await?;
+            }
+            NotificationAction::RunCommand { command_id, .. } => {
+                self.execute_command_by_id(command_id).await?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn clear_notifications(&mut self) {
         self.notifications.clear();
     }
 
+    /// Drop notifications older than their lifetime; errors are kept until
+    /// dismissed so they cannot be missed
+    fn expire_notifications(&mut self) {
+        let now = chrono::Utc::now();
+        self.notifications.retain(|notification| {
+            matches!(notification.level, NotificationLevel::Error)
+                || now - notification.timestamp < NOTIFICATION_LIFETIME
+        });
+    }
+
+    /// Dismiss the notification at `index` in the overlay, which lists them newest first
+    fn dismiss_notification(&mut self, index: usize) {
+        let Some(position) = self.notifications.len().checked_sub(index + 1) else {
+            return;
+        };
+        self.notifications.remove(position);
+    }
+
+    async fn handle_notification_overlay_input(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(overlay) = &mut self.notification_overlay else {
+            return Ok(());
+        };
+        let count = self.notifications.len();
+
+        match key.code {
+            KeyCode::Enter => {
+                // The overlay lists newest first
+                let Some(position) = count.checked_sub(overlay.selected + 1) else {
+                    return Ok(());
+                };
+                if self.notifications[position].action.is_some() {
+                    self.notification_overlay = None;
+                    return self.run_notification_action(position).await;
+                }
+            }
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('N') => {
+                self.notification_overlay = None;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                overlay.selected = overlay.selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if overlay.selected + 1 < count => {
+                overlay.selected += 1;
+            }
+            KeyCode::Char('d') | KeyCode::Delete => {
+                let selected = overlay.selected;
+                overlay.selected = selected.min(count.saturating_sub(2));
+                self.dismiss_notification(selected);
+            }
+            KeyCode::Char('D') => {
+                self.clear_notifications();
+                self.notification_overlay = None;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
     pub async fn switch_profile(&mut self, profile_name: &str) -> Result<()> {
         if let Some(profile) = self
             .available_profiles
             .iter()
             .find(|p| p.name == profile_name)
         {
+            let region = self.region_for_profile(profile);
             self.current_profile = profile.name.clone();
+            self.clear_marks();
 
-            // Reinitialize AWS clients with new profile
-            match MultiRegionAwsClients::new(&self.current_profile, &self.current_region).await {
-                Ok(clients) => {
-                    self.aws_clients = Some(clients);
+            // Reinitialize AWS clients with new profile; existing clients drop their per-region cache
+            let expiry = self.temporary_credentials_expiry();
+            self.credentials_expiry_warned = false;
+            let switched = match self.aws_clients.as_mut() {
+                Some(clients) => clients
+                    .switch_profile(&self.current_profile)
+                    .await
+                    .map(|()| clients.set_credentials_expiry(expiry)),
+                None => {
+                    self.connect_aws_clients();
+                    Ok(())
+                }
+            };
+            match switched {
+                Ok(()) => {
                     self.add_notification(
                         format!("Switched to profile: {}", profile_name),
                         NotificationLevel::Success,
                     );
+                    self.warn_if_no_credentials();
+                    if region != self.current_region {
+                        self.switch_region(&region).await?;
+                    }
+                    if self.dashboard_layout.account_summary_widget().is_some() {
+                        self.refresh_account_summary();
+                    }
+                    // Spend belongs to the old account; reload on the next tick
+                    self.cost_summary = None;
+                    self.cost_refreshed_at = None;
+                    // Failures belong to the old credentials
+                    self.resources
+                        .retain(|_, listing| !matches!(listing, LoadState::Failed(_)));
+                    self.service_health.clear();
+                    // A fresh connection loads them once it is ready
+                    if self.aws_clients.is_some() {
+                        self.refresh_available_regions();
+                    }
+                    self.region_overview_refreshed_at = None;
+                    self.resource_baselines.clear();
+                    self.missing_resources.clear();
                 }
                 Err(e) => {
                     self.add_notification(
@@ -426,6 +5405,8 @@ impl AppState {
     pub async fn switch_region(&mut self, region_name: &str) -> Result<()> {
         if self.available_regions.iter().any(|r| r.name == region_name) {
             self.current_region = region_name.to_string();
+            // Marked ids belong to the old region's listing
+            self.clear_marks();
 
             // Update AWS clients for new region
             if let Some(clients) = &mut self.aws_clients {
@@ -442,6 +5423,7 @@ impl AppState {
                 format!("Switched to region: {}", region_name),
                 NotificationLevel::Success,
             );
+            self.remember_profile_region();
 
             // Update command context after region change
             self.update_command_context();
@@ -449,6 +5431,51 @@ impl AppState {
         Ok(())
     }
 
+    /// Raise or lower how many AWS requests may run at once; operations that
+    /// already hold a permit are left to finish
+    fn adjust_max_concurrent_requests(&mut self, increase: bool) {
+        let aws = &mut self.user_config.aws;
+        aws.max_concurrent_requests = if increase {
+            aws.max_concurrent_requests.saturating_add(1)
+        } else {
+            aws.max_concurrent_requests.saturating_sub(1).max(1)
+        };
+        self.operations
+            .limiter()
+            .resize(aws.max_concurrent_requests);
+        if let Err(e) = self.user_config.save() {
+            tracing::warn!("Failed to save request limit: {}", e);
+        }
+    }
+
+    /// Persist the current region as the one to restore for the current profile
+    fn remember_profile_region(&mut self) {
+        let remembered = &mut self.user_config.aws.profile_regions;
+        if remembered.get(&self.current_profile) == Some(&self.current_region) {
+            return;
+        }
+        remembered.insert(self.current_profile.clone(), self.current_region.clone());
+        if let Err(e) = self.user_config.save() {
+            tracing::warn!("Failed to remember region for profile: {}", e);
+        }
+    }
+
+    /// Region to use after switching to `profile`: the one it was last used in,
+    /// unless `follow_profile_region` is set, then its configured region, then the default
+    fn region_for_profile(&self, profile: &AwsProfile) -> String {
+        let aws = &self.user_config.aws;
+        let remembered = aws.profile_regions.get(&profile.name);
+        let configured = profile.region.as_ref();
+        let preferred = if aws.follow_profile_region {
+            configured.or(remembered)
+        } else {
+            remembered.or(configured)
+        };
+        preferred
+            .cloned()
+            .unwrap_or_else(|| aws.default_region.clone())
+    }
+
     // Quick Navigation Methods
     fn toggle_quick_nav(&mut self) {
         self.quick_nav_visible = !self.quick_nav_visible;
@@ -503,7 +5530,7 @@ impl AppState {
                 Ok(())
             }
             KeyCode::Backspace => {
-                self.quick_nav_input.pop();
+                pop_grapheme(&mut self.quick_nav_input);
                 self.update_quick_nav_suggestions();
                 self.quick_nav_selected_index = 0;
                 Ok(())
@@ -512,8 +5539,9 @@ impl AppState {
         }
     }
 
+    /// Services first, then favorites and recently viewed resources
     fn create_navigation_items(&self) -> Vec<NavigationItem> {
-        ServiceType::all()
+        let mut items: Vec<NavigationItem> = ServiceType::all()
             .into_iter()
             .map(|service| NavigationItem {
                 name: service.display_name().to_string(),
@@ -522,7 +5550,44 @@ impl AppState {
                 icon: service.icon().to_string(),
                 keywords: self.get_service_keywords(service),
             })
-            .collect()
+            .collect();
+
+        let favorites = self
+            .favorites_manager
+            .get_favorites()
+            .into_iter()
+            .map(|f| (&f.id, &f.name, f.service_type, &f.region, "Favorite"));
+        let recent = self
+            .recently_viewed
+            .entries(usize::MAX)
+            .into_iter()
+            .map(|r| (&r.id, &r.name, r.service_type, &r.region, "Recently viewed"));
+
+        let mut seen = HashSet::new();
+        for (id, name, service_type, region, source) in favorites.chain(recent) {
+            if !seen.insert((id.clone(), service_type, region.clone())) {
+                continue;
+            }
+            items.push(NavigationItem {
+                name: name.clone(),
+                description: format!(
+                    "{} · {} {} in {}",
+                    source,
+                    service_type.display_name(),
+                    id,
+                    region
+                ),
+                action: NavigationAction::NavigateToResource(
+                    service_type,
+                    id.clone(),
+                    region.clone(),
+                ),
+                icon: service_type.icon().to_string(),
+                keywords: vec![id.clone(), service_type.display_name().to_lowercase()],
+            });
+        }
+
+        items
     }
 
     fn get_service_keywords(&self, service: ServiceType) -> Vec<String> {
@@ -587,6 +5652,10 @@ impl AppState {
                     name_match || desc_match || keyword_match
                 })
                 .collect();
+            // Typing a resource's name should put it above services that only
+            // match on a keyword
+            self.quick_nav_suggestions
+                .sort_by_key(|item| !item.name.to_lowercase().contains(&query));
         }
     }
 
@@ -595,12 +5664,23 @@ impl AppState {
             NavigationAction::NavigateToService(service_type) => {
                 self.page_history.push(self.current_page.clone());
                 self.current_page = AppPage::ResourceList(*service_type);
-                self.selected_resource_index = 0;
+                self.select_resource_at(0);
+                self.ensure_service_listed(*service_type);
                 Ok(())
             }
-            NavigationAction::NavigateToResource(service_type, resource_id) => {
-                self.page_history.push(self.current_page.clone());
-                self.current_page = AppPage::ResourceDetail(*service_type, resource_id.clone());
+            NavigationAction::NavigateToResource(service_type, resource_id, region) => {
+                if !self
+                    .switch_region_to_open(*service_type, region, resource_id)
+                    .await?
+                {
+                    return Ok(());
+                }
+                if self.favorites_manager.is_favorite(resource_id) {
+                    if let Err(e) = self.favorites_manager.update_access(resource_id) {
+                        tracing::warn!("Failed to record favorite access: {}", e);
+                    }
+                }
+                self.enter_resource_detail(*service_type, resource_id.clone());
                 Ok(())
             }
         }
@@ -616,19 +5696,27 @@ impl AppState {
     }
 
     fn populate_command_palette(&mut self) {
-        let context = CommandContext::new(
-            self.current_page.clone(),
-            self.selected_service,
-            self.selected_resource.clone(),
-            self.available_profiles.clone(),
-            self.available_regions.clone(),
-            self.current_profile.clone(),
-            self.current_region.clone(),
-        );
-        let commands = CommandRegistry::get_context_aware_commands(&context);
+        let context = self.current_command_context();
+        let commands = self.context_commands(&context);
         self.command_palette.set_commands(commands);
     }
 
+    /// Built-in commands for the context plus any enabled custom commands
+    fn context_commands(&self, context: &CommandContext) -> Vec<crate::command::Command> {
+        let mut commands = CommandRegistry::get_context_aware_commands(context);
+        commands.extend(create_preset_commands_for_context(
+            context,
+            &self.user_config.presets,
+        ));
+        if self.user_config.custom_commands.enabled {
+            commands.extend(create_custom_commands_for_context(
+                context,
+                &self.user_config.custom_commands.commands,
+            ));
+        }
+        commands
+    }
+
     async fn handle_command_palette_input(&mut self, key: KeyEvent) -> Result<()> {
         match key.code {
             KeyCode::Esc => {
@@ -643,6 +5731,18 @@ impl AppState {
                 }
                 Ok(())
             }
+            KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.toggle_pinned_command();
+                Ok(())
+            }
+            KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.toggle_favorite_command();
+                Ok(())
+            }
+            KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.explained_command = self.command_palette.get_selected_command().cloned();
+                Ok(())
+            }
             KeyCode::Up => {
                 self.command_palette.select_previous();
                 Ok(())
@@ -663,8 +5763,125 @@ impl AppState {
         }
     }
 
+    /// The explain overlay sits over the palette; closing it returns there
+    /// Service commands for the resource on the detail page, as the palette
+    /// offers them in the current context
+    pub fn resource_actions(&self) -> Vec<crate::command::Command> {
+        use crate::command::CommandAction;
+
+        let AppPage::ResourceDetail(service_type, _) = &self.current_page else {
+            return vec![];
+        };
+        self.context_commands(&self.current_command_context())
+            .into_iter()
+            .filter(|command| {
+                matches!(
+                    &command.action,
+                    CommandAction::ExecuteServiceCommand(command_service, _)
+                        if command_service == service_type
+                )
+            })
+            .collect()
+    }
+
+    fn open_action_menu(&mut self) {
+        let AppPage::ResourceDetail(service_type, resource_id) = &self.current_page else {
+            return;
+        };
+        let resource_name = self
+            .cached_resource(*service_type, resource_id)
+            .map_or_else(|| resource_id.to_string(), |r| r.name.clone());
+        let commands = self.resource_actions();
+        if commands.is_empty() {
+            self.add_notification(
+                format!("No actions available for {} right now", resource_name),
+                NotificationLevel::Info,
+            );
+            return;
+        }
+        self.action_menu = Some(ActionMenu::new(resource_name, commands));
+    }
+
+    async fn handle_action_menu_input(&mut self, key: KeyEvent) -> Result<()> {
+        let Some(menu) = &mut self.action_menu else {
+            return Ok(());
+        };
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('a') => self.action_menu = None,
+            KeyCode::Up | KeyCode::Char('k') => menu.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => menu.select_next(),
+            KeyCode::Enter => {
+                let command = menu.selected_command().cloned();
+                self.action_menu = None;
+                if let Some(command) = command {
+                    self.execute_command(&command).await?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn handle_command_explanation_input(&mut self, key: KeyEvent) -> Result<()> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.explained_command = None,
+            KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.explained_command = None;
+            }
+            KeyCode::Enter => {
+                if let Some(command) = self.explained_command.take() {
+                    self.command_palette.hide();
+                    self.execute_command(&command).await?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Pin the highlighted palette command to the top, or unpin it
+    fn toggle_pinned_command(&mut self) {
+        let Some(command) = self.command_palette.get_selected_command() else {
+            return;
+        };
+        let (id, name) = (command.id.clone(), command.name.clone());
+
+        let pinned = &mut self.user_config.command_palette.pinned_commands;
+        let message = match pinned.iter().position(|pinned_id| *pinned_id == id) {
+            Some(index) => {
+                pinned.remove(index);
+                format!("Unpinned {}", name)
+            }
+            None => {
+                pinned.push(id.clone());
+                format!("Pinned {}", name)
+            }
+        };
+        self.command_palette.set_pinned(pinned.clone());
+        // Keep the highlight on the command as it moves
+        self.command_palette.select_command(&id);
+
+        if let Err(e) = self.user_config.save() {
+            tracing::warn!("Failed to save pinned commands: {}", e);
+        }
+        self.add_notification(message, NotificationLevel::Info);
+    }
+
     /// Update command context and refresh available commands based on current application state
     pub fn update_command_context(&mut self) {
+        let context = self.current_command_context();
+
+        // Update command palette context
+        self.command_palette.update_context(context.clone());
+
+        // Refresh commands with new context-aware filtering
+        let commands = self.context_commands(&context);
+        self.command_palette.set_commands(commands);
+    }
+
+    /// Context describing the current application state
+    fn current_command_context(&self) -> CommandContext {
         // Determine selected service from current page if not explicitly set
         let selected_service = self.selected_service.or_else(|| match &self.current_page {
             AppPage::ResourceList(service_type) => Some(*service_type),
@@ -672,8 +5889,7 @@ impl AppState {
             _ => None,
         });
 
-        // Create updated context with current application state
-        let context = CommandContext::new(
+        CommandContext::new(
             self.current_page.clone(),
             selected_service,
             self.selected_resource.clone(),
@@ -681,14 +5897,31 @@ impl AppState {
             self.available_regions.clone(),
             self.current_profile.clone(),
             self.current_region.clone(),
-        );
+        )
+        .with_operation_in_flight(self.operations.is_busy())
+        .with_aws_client_ready(self.aws_client_ready())
+        .with_denied_services(self.denied_services())
+        .with_unhealthy_services(self.unhealthy_services())
+    }
 
-        // Update command palette context
-        self.command_palette.update_context(context.clone());
+    /// Execute the command with the given id if it is available in the current context
+    async fn execute_command_by_id(&mut self, command_id: &str) -> Result<()> {
+        let context = self.current_command_context();
+        let command = self
+            .context_commands(&context)
+            .into_iter()
+            .find(|cmd| cmd.id == command_id);
 
-        // Refresh commands with new context-aware filtering
-        let commands = CommandRegistry::get_context_aware_commands(&context);
-        self.command_palette.set_commands(commands);
+        match command {
+            Some(command) => self.execute_command(&command).await,
+            None => {
+                self.add_notification(
+                    format!("Command '{}' is not available here", command_id),
+                    NotificationLevel::Warning,
+                );
+                Ok(())
+            }
+        }
     }
 
     /// Set the selected resource and update command context
@@ -713,12 +5946,13 @@ impl AppState {
             AppPage::ResourceList(service_type) => {
                 self.selected_service = Some(*service_type);
                 self.selected_resource = None;
+                self.ensure_service_listed(*service_type);
             }
             AppPage::ResourceDetail(service_type, resource_id) => {
                 self.selected_service = Some(*service_type);
                 self.selected_resource = Some(resource_id.clone());
             }
-            AppPage::Dashboard | AppPage::Settings => {
+            AppPage::Dashboard | AppPage::Settings | AppPage::ActivityLog => {
                 self.selected_service = None;
                 self.selected_resource = None;
             }
@@ -738,57 +5972,574 @@ impl AppState {
             CommandAction::SwitchRegion(region_name) => {
                 self.switch_region(region_name).await?;
             }
+            CommandAction::ApplyPreset(name) => {
+                self.apply_preset(name).await?;
+            }
+            CommandAction::SavePreset => {
+                self.preset_form =
+                    Some(PresetForm::new(&self.current_profile, &self.current_region));
+            }
             CommandAction::NavigateToService(service_type) => {
                 self.page_history.push(self.current_page.clone());
                 self.current_page = AppPage::ResourceList(*service_type);
-                self.selected_resource_index = 0;
+                self.select_resource_at(0);
                 self.selected_service = Some(*service_type);
                 self.selected_resource = None; // Clear resource selection when navigating to service list
+                self.ensure_service_listed(*service_type);
             }
             CommandAction::NavigateToPage(page) => {
                 self.page_history.push(self.current_page.clone());
                 self.current_page = page.clone();
                 // Clear service and resource selection when navigating to non-service pages
                 match page {
-                    AppPage::Dashboard | AppPage::Settings => {
+                    AppPage::Dashboard | AppPage::Settings | AppPage::ActivityLog => {
                         self.selected_service = None;
                         self.selected_resource = None;
                     }
-                    _ => {}
-                }
-            }
-            CommandAction::ExecuteServiceCommand(service_type, service_command) => {
-                self.execute_service_command(*service_type, service_command)
-                    .await?;
+                    _ => {}
+                }
+            }
+            CommandAction::ExecuteServiceCommand(service_type, service_command) => {
+                self.execute_service_command(*service_type, service_command)
+                    .await?;
+            }
+            CommandAction::ShowHelp => {
+                self.help_visible = true;
+            }
+            CommandAction::OpenSettings => {
+                self.page_history.push(self.current_page.clone());
+                self.current_page = AppPage::Settings;
+                self.selected_service = None;
+                self.selected_resource = None;
+            }
+            CommandAction::ToggleUI(ui_element) => match ui_element {
+                UIElement::ProfileSelector => {
+                    self.profile_selector_visible = !self.profile_selector_visible;
+                }
+                UIElement::RegionSelector => {
+                    self.region_selector_visible = !self.region_selector_visible;
+                }
+                UIElement::Help => {
+                    self.help_visible = !self.help_visible;
+                }
+                UIElement::Settings => {
+                    self.settings_visible = !self.settings_visible;
+                }
+            },
+            CommandAction::OpenInConsole => {
+                self.open_in_console();
+            }
+            CommandAction::RunCustomCommand(template) => {
+                self.run_custom_command(&command.name, template);
+            }
+            CommandAction::ReloadService => {
+                self.reload_current_service();
+            }
+            CommandAction::ClearCache => {
+                let running = self.operations.in_flight().len();
+                if running > 0 {
+                    self.pending_confirmation = Some(PendingConfirmation::new(
+                        "Clear Cache".to_string(),
+                        format!(
+                            "{} operation(s) still running will be cancelled. Clear all cached data?",
+                            running
+                        ),
+                        ConfirmAction::ClearCache,
+                    ));
+                } else {
+                    self.clear_cache();
+                }
+            }
+            CommandAction::CompareProfiles(left, right) => {
+                self.compare_profiles(left, right);
+            }
+            CommandAction::ValidateProfiles => {
+                self.validate_all_profiles();
+            }
+            CommandAction::ShowRecentResources => {
+                self.show_recently_viewed();
+            }
+            CommandAction::DiagnosePermissions => {
+                self.show_permission_report();
+            }
+            CommandAction::Reconnect => {
+                self.reconnect();
+            }
+            CommandAction::CopyViewState => {
+                self.copy_view_state();
+            }
+            CommandAction::GoToViewState => {
+                self.view_state_form = Some(ViewStateForm::default());
+            }
+            CommandAction::ShowResourceChanges => {
+                self.show_resource_changes();
+            }
+            CommandAction::ResetChangeBaseline => {
+                self.reset_change_baseline();
+            }
+            CommandAction::ExportKeybindings => {
+                self.cheat_sheet_form = Some(CheatSheetForm::new());
+            }
+            CommandAction::ExportResources => {
+                if let AppPage::ResourceList(service_type) = self.current_page {
+                    self.resource_export_form =
+                        Some(ResourceExportForm::new(service_type, &self.current_region));
+                }
+            }
+            CommandAction::CopySelectedIds => {
+                self.copy_selected_ids();
+            }
+            CommandAction::OpenDocs(service_type) => {
+                self.open_docs(*service_type);
+            }
+            CommandAction::ShowFavorites => {
+                self.favorites_overlay = Some(FavoritesOverlay::default());
+            }
+            CommandAction::ToggleFavoriteResource => {
+                self.toggle_favorite_resource();
+            }
+            CommandAction::EditFavoriteNote => {
+                self.edit_favorite_note();
+            }
+            CommandAction::EditTags => {
+                self.edit_tags();
+            }
+            CommandAction::AssumeRole => {
+                self.assume_role_form = Some(AssumeRoleForm::default());
+            }
+            CommandAction::CancelOperation => {
+                if !self.cancel_operation() {
+                    self.add_notification(
+                        "No operation in progress".to_string(),
+                        NotificationLevel::Info,
+                    );
+                }
+            }
+        }
+
+        // Update command context after executing command
+        self.update_command_context();
+        Ok(())
+    }
+
+    /// Step one of the Activity Log filters (1 service, 2 region, 3 action)
+    /// to its next value, wrapping back to showing everything
+    fn cycle_activity_filter(&mut self, key: char) {
+        let filter = &mut self.activity_filter;
+        match key {
+            '1' => filter.service = cycle_option(&filter.service, &self.recent_activity.services()),
+            '2' => filter.region = cycle_option(&filter.region, &self.recent_activity.regions()),
+            _ => filter.action = cycle_option(&filter.action, &self.recent_activity.actions()),
+        }
+        self.activity_scroll = 0;
+    }
+
+    fn request_clear_activity_log(&mut self) {
+        if self.recent_activity.is_empty() {
+            self.add_notification(
+                "The activity log is already empty".to_string(),
+                NotificationLevel::Info,
+            );
+            return;
+        }
+        self.pending_confirmation = Some(PendingConfirmation::new(
+            "Clear Activity Log".to_string(),
+            format!(
+                "Delete all {} activity log entries? This cannot be undone.",
+                self.recent_activity.len()
+            ),
+            ConfirmAction::ClearActivityLog,
+        ));
+    }
+
+    fn clear_activity_log(&mut self) {
+        match self.recent_activity.clear() {
+            Ok(()) => {
+                self.activity_filter = ActivityFilter {
+                    oldest_first: self.activity_filter.oldest_first,
+                    ..ActivityFilter::default()
+                };
+                self.activity_scroll = 0;
+                self.add_notification(
+                    "Cleared the activity log".to_string(),
+                    NotificationLevel::Success,
+                );
+            }
+            Err(e) => self.add_notification(
+                format!("Failed to clear the activity log: {}", e),
+                NotificationLevel::Error,
+            ),
+        }
+    }
+
+    /// Drop every cached listing, cancel running operations and reload the current view
+    fn clear_cache(&mut self) {
+        let cancelled = self.operations.cancel_all();
+        let listings = self.resources.values().filter_map(LoadState::loaded);
+        let resources: usize = listings.clone().map(Vec::len).sum();
+        let listings = listings.count();
+
+        self.resources.clear();
+        self.last_refresh.clear();
+        self.permission_denied.clear();
+
+        let mut message = format!(
+            "Cleared {} cached listing(s) with {} resource(s)",
+            listings, resources
+        );
+        if cancelled > 0 {
+            message.push_str(&format!(", cancelled {} operation(s)", cancelled));
+        }
+        self.add_notification(message, NotificationLevel::Success);
+
+        if let AppPage::ResourceList(service_type) | AppPage::ResourceDetail(service_type, _) =
+            &self.current_page
+        {
+            self.refresh_service(*service_type);
+        }
+    }
+
+    /// Drop the listed service's cache entry for the current region, leaving
+    /// other services and regions cached, and fetch it again from scratch
+    fn reload_current_service(&mut self) {
+        let AppPage::ResourceList(service_type) = self.current_page else {
+            return;
+        };
+        let key = (self.current_region.clone(), service_type);
+        self.resources.remove(&key);
+        self.last_refresh.remove(&key);
+        self.permission_denied.remove(&key);
+        self.clear_marks();
+        self.select_resource_at(0);
+
+        let success_message = format!(
+            "{} reloaded in {}",
+            service_type.display_name(),
+            self.current_region
+        );
+        self.list_service(service_type, &success_message);
+    }
+
+    /// The cached resource count of a service in the current region
+    pub fn resource_count(&self, service_type: ServiceType) -> LoadState<usize> {
+        self.listing(service_type).map(Vec::len)
+    }
+
+    /// How many of a service's listed resources are in each state, most
+    /// common first; empty until the service is listed
+    pub fn state_counts(&self, service_type: ServiceType) -> Vec<(String, usize)> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for resource in self.cached_resources(service_type).into_iter().flatten() {
+            if !resource.state.is_empty() {
+                *counts.entry(resource.state.clone()).or_default() += 1;
+            }
+        }
+        let mut counts: Vec<(String, usize)> = counts.into_iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        counts
+    }
+
+    /// Count a service's network and permission failures; any success clears them
+    fn record_service_health(
+        &mut self,
+        operation: &InFlightOperation,
+        outcome: &Result<OperationOutput>,
+    ) {
+        let Some(service_type) = operation.service_type else {
+            return;
+        };
+        let health = self.service_health.entry(service_type).or_default();
+        match outcome {
+            Ok(_) => health.record_success(),
+            Err(e) if e.is_permission_error() || e.is_connectivity_error() => {
+                let was_disabled = health.is_disabled();
+                health.record_failure();
+                if !was_disabled && health.is_disabled() {
+                    self.add_notification(
+                        format!(
+                            "{} keeps failing; its commands are paused for a while",
+                            service_type.display_name()
+                        ),
+                        NotificationLevel::Warning,
+                    );
+                }
+            }
+            Err(_) => {}
+        }
+    }
+
+    /// Time left before a service that kept failing gets its commands back
+    pub fn service_cooldown_left(&self, service_type: ServiceType) -> Option<Duration> {
+        self.service_health
+            .get(&service_type)
+            .and_then(ServiceHealth::cooldown_left)
+    }
+
+    fn unhealthy_services(&self) -> HashSet<ServiceType> {
+        self.service_health
+            .iter()
+            .filter(|(_, health)| health.is_disabled())
+            .map(|(service_type, _)| *service_type)
+            .collect()
+    }
+
+    /// Whether the last listing of `service_type` in the current region was refused
+    pub fn is_permission_denied(&self, service_type: ServiceType) -> bool {
+        self.permission_denied
+            .contains(&(self.current_region.clone(), service_type))
+    }
+
+    /// Fetch a fresh listing for a service in the current region
+    fn refresh_service(&mut self, service_type: ServiceType) {
+        let success_message = format!("{} refreshed", service_type.display_name());
+        self.list_service(service_type, &success_message);
+    }
+
+    /// List a service into the cache; an empty `success_message` refreshes silently
+    fn list_service(&mut self, service_type: ServiceType, success_message: &str) {
+        self.resources
+            .entry((self.current_region.clone(), service_type))
+            .or_default()
+            .start();
+        if service_type == ServiceType::IAM {
+            self.list_iam_page(IamEntity::Users, None, success_message);
+            return;
+        }
+        let clients = self.region_clients();
+        self.spawn_operation(
+            service_type,
+            &list_label(service_type),
+            success_message,
+            async move {
+                let resources = list_resources(&clients, service_type).await?;
+                Ok(OperationOutput::Resources(resources))
+            },
+        );
+    }
+
+    /// List one page of IAM users or roles. Each page is shown as it lands and
+    /// requests the next, users first, so large inventories never block the UI.
+    fn list_iam_page(&mut self, entity: IamEntity, marker: Option<String>, success_message: &str) {
+        let clients = self.region_clients();
+        let first = entity == IamEntity::Users && marker.is_none();
+        self.spawn_operation(
+            ServiceType::IAM,
+            &list_label(ServiceType::IAM),
+            success_message,
+            async move {
+                let iam = IamService::new(&clients);
+                let region = clients.region.as_str();
+                let (resources, marker) = match entity {
+                    IamEntity::Users => {
+                        let page = iam.list_users_page(marker.as_deref()).await?;
+                        let users = page.items.iter().map(|user| user.to_resource(region));
+                        (users.collect(), page.marker)
+                    }
+                    IamEntity::Roles => {
+                        let page = iam.list_roles_page(marker.as_deref()).await?;
+                        let roles = page.items.iter().map(|role| role.to_resource(region));
+                        (roles.collect(), page.marker)
+                    }
+                };
+                Ok(OperationOutput::IamPage {
+                    entity,
+                    first,
+                    resources,
+                    marker,
+                })
+            },
+        );
+    }
+
+    /// Fold a page of the IAM listing into the cache and request the next one
+    fn apply_iam_page(
+        &mut self,
+        operation: InFlightOperation,
+        entity: IamEntity,
+        first: bool,
+        mut resources: Vec<Resource>,
+        marker: Option<String>,
+    ) {
+        let key = (operation.region.clone(), ServiceType::IAM);
+        // The next page is requested for the current region, so a region
+        // switch mid-way would mix listings; drop the partial one instead
+        if operation.region != self.current_region {
+            self.resources.remove(&key);
+            return;
+        }
+
+        if !first {
+            if let Some(listed) = self.resources.get(&key).and_then(LoadState::loaded) {
+                resources.extend(listed.iter().cloned());
             }
-            CommandAction::ShowHelp => {
-                self.help_visible = true;
+        }
+        let viewing = self.current_page == operation.origin_page;
+        self.store_resources(operation.region, ServiceType::IAM, resources, viewing);
+
+        let next = match (entity, marker) {
+            (entity, Some(marker)) => Some((entity, Some(marker))),
+            (IamEntity::Users, None) => Some((IamEntity::Roles, None)),
+            (IamEntity::Roles, None) => None,
+        };
+        match next {
+            // The next page runs on the same terms as the listing it continues
+            Some((entity, marker)) => {
+                self.starting_background = operation.background;
+                self.list_iam_page(entity, marker, &operation.success_message);
+                self.starting_background = false;
             }
-            CommandAction::OpenSettings => {
-                self.page_history.push(self.current_page.clone());
-                self.current_page = AppPage::Settings;
-                self.selected_service = None;
-                self.selected_resource = None;
+            None if !operation.success_message.is_empty() => {
+                self.add_notification(operation.success_message, NotificationLevel::Success);
             }
-            CommandAction::ToggleUI(ui_element) => match ui_element {
-                UIElement::ProfileSelector => {
-                    self.profile_selector_visible = !self.profile_selector_visible;
-                }
-                UIElement::RegionSelector => {
-                    self.region_selector_visible = !self.region_selector_visible;
-                }
-                UIElement::Help => {
-                    self.help_visible = !self.help_visible;
-                }
-                UIElement::Settings => {
-                    self.settings_visible = !self.settings_visible;
+            None => {}
+        }
+    }
+
+    /// Fetch an IAM user's groups and attached policies for its detail page
+    fn load_iam_user_details(&mut self, resource_id: &ResourceId) {
+        if !self.user_config.aws.fetch_iam_user_details || iam::role_name(resource_id).is_some() {
+            return;
+        }
+        let clients = self.region_clients();
+        let user = resource_id.clone();
+        self.spawn_operation(
+            ServiceType::IAM,
+            "Load user groups and policies",
+            "",
+            async move {
+                let details = IamService::new(&clients).user_details(&user).await?;
+                Ok(OperationOutput::IamUserDetails { user, details })
+            },
+        );
+    }
+
+    /// Whether more pages of the IAM listing are still being fetched
+    pub fn is_paginating_iam(&self) -> bool {
+        let label = list_label(ServiceType::IAM);
+        self.operations
+            .in_flight()
+            .iter()
+            .any(|op| op.label == label && op.region == self.current_region)
+    }
+
+    /// Re-describe only the detail page's resource, which is far cheaper than
+    /// listing the whole service while watching one resource change state
+    fn refresh_detail_resource(&mut self) {
+        let AppPage::ResourceDetail(service_type, resource_id) = &self.current_page else {
+            return;
+        };
+        let (service_type, resource_id) = (*service_type, resource_id.clone());
+        let success_message = format!("{} refreshed", resource_id);
+        self.load_resource_description(service_type, resource_id, &success_message);
+    }
+
+    /// Re-describe one resource; one reported as not found is forgotten
+    /// everywhere. An empty `success_message` refreshes silently.
+    fn load_resource_description(
+        &mut self,
+        service_type: ServiceType,
+        resource_id: ResourceId,
+        success_message: &str,
+    ) {
+        let clients = self.region_clients();
+        self.spawn_operation(
+            service_type,
+            &format!("Describe {}", resource_id),
+            success_message,
+            async move {
+                // Only an explicit not-found means the resource was deleted
+                match describe_resource(&clients, service_type, &resource_id).await {
+                    Err(e) if e.is_not_found() => Ok(OperationOutput::Missing { resource_id }),
+                    resource => Ok(OperationOutput::Described {
+                        resource: resource?.map(Box::new),
+                    }),
                 }
             },
+        );
+    }
+
+    /// Open a service's documentation, copying the link when headless
+    fn open_docs(&mut self, service_type: ServiceType) {
+        let url = docs_url(service_type);
+        match open_url_or_copy(url) {
+            Ok(UrlHandoff::Opened) => self.add_notification(
+                format!("Opened {} documentation", service_type.display_name()),
+                NotificationLevel::Success,
+            ),
+            Ok(UrlHandoff::Copied) => self.add_notification(
+                format!("No browser available, copied docs link: {}", url),
+                NotificationLevel::Info,
+            ),
+            Err(e) => self.add_notification(
+                format!("Failed to open docs link {}: {}", url, e),
+                NotificationLevel::Error,
+            ),
         }
+    }
 
-        // Update command context after executing command
-        self.update_command_context();
-        Ok(())
+    /// Open the selected resource in the AWS Console, copying the link when headless
+    fn open_in_console(&mut self) {
+        let service_type = self.selected_service.or(match &self.current_page {
+            AppPage::ResourceList(service_type) | AppPage::ResourceDetail(service_type, _) => {
+                Some(*service_type)
+            }
+            _ => None,
+        });
+
+        let (Some(service_type), Some(resource_id)) =
+            (service_type, self.selected_resource.clone())
+        else {
+            self.add_notification("No resource selected".to_string(), NotificationLevel::Error);
+            return;
+        };
+
+        let url = console_url(service_type, &self.current_region, &resource_id);
+        match open_url_or_copy(&url) {
+            Ok(UrlHandoff::Opened) => self.add_notification(
+                format!("Opened {} in the AWS Console", resource_id),
+                NotificationLevel::Success,
+            ),
+            Ok(UrlHandoff::Copied) => self.add_notification(
+                format!("No browser available, copied console link: {}", url),
+                NotificationLevel::Info,
+            ),
+            Err(e) => self.add_notification(
+                format!("Failed to open console link {}: {}", url, e),
+                NotificationLevel::Error,
+            ),
+        }
+    }
+
+    /// Run a user-defined command template in the background and report its output
+    fn run_custom_command(&mut self, name: &str, template: &str) {
+        if !self.user_config.custom_commands.enabled {
+            self.add_notification(
+                "Custom commands are disabled in the configuration".to_string(),
+                NotificationLevel::Error,
+            );
+            return;
+        }
+
+        let resource_id = self.selected_resource.clone().unwrap_or_default();
+        let command = render_command_template(
+            template,
+            &[
+                ("resource_id", &resource_id),
+                ("region", &self.current_region),
+                ("profile", &self.current_profile),
+            ],
+        );
+
+        self.add_notification(format!("Running {}...", name), NotificationLevel::Info);
+        let label = name.to_string();
+        self.start_operation(None, name, &format!("{} finished", name), async move {
+            let output = run_shell_command(&command).await?;
+            Ok(OperationOutput::Message(if output.is_empty() {
+                format!("{} finished", label)
+            } else {
+                format!("{}: {}", label, output)
+            }))
+        });
     }
 
     /// Execute a service-specific command with proper routing and placeholder implementations
@@ -800,65 +6551,132 @@ impl AppState {
         use crate::command::ServiceCommand;
 
         // Add activity entry for command execution
-        self.recent_activity.push(ActivityEntry {
+        let resource_id = self.selected_resource.clone().unwrap_or_default();
+        let resource_name = self
+            .cached_resource(service_type, &resource_id)
+            .map_or_else(|| resource_id.clone(), |r| r.name.clone());
+        if let Err(e) = self.recent_activity.record(ActivityEntry {
             timestamp: chrono::Utc::now(),
             action: format!("Executed {}", service_command.display_name()),
-            resource_id: self.selected_resource.clone().unwrap_or_default(),
-            resource_name: format!("Resource {}", self.selected_resource_index),
+            resource_id,
+            resource_name,
             service_type,
             region: self.current_region.clone(),
-        });
+        }) {
+            tracing::warn!("Failed to save activity log: {}", e);
+        }
 
-        match service_type {
+        // A batch reports per-resource outcomes instead, and only the
+        // highlighted resource would be retried
+        if self.marked_for(service_type).is_empty() {
+            self.retry_action = Some(NotificationAction::Retry {
+                service_type,
+                command: service_command.clone(),
+                resource_id: self.selected_resource.clone(),
+                region: self.current_region.clone(),
+            });
+        }
+        let result = match service_type {
             ServiceType::EC2 => self.execute_ec2_command(service_command).await,
             ServiceType::S3 => self.execute_s3_command(service_command).await,
             ServiceType::RDS => self.execute_rds_command(service_command).await,
             ServiceType::IAM => self.execute_iam_command(service_command).await,
             ServiceType::Secrets => self.execute_secrets_command(service_command).await,
             ServiceType::EKS => self.execute_eks_command(service_command).await,
-        }
+        };
+        self.retry_action = None;
+        result
     }
 
     /// Execute EC2-specific commands
-    async fn execute_ec2_command(
+    pub async fn execute_ec2_command(
         &mut self,
         command: &crate::command::ServiceCommand,
     ) -> Result<()> {
         use crate::command::ServiceCommand;
 
         match command {
+            ServiceCommand::StartInstance
+            | ServiceCommand::StopInstance
+            | ServiceCommand::RebootInstance
+            | ServiceCommand::TerminateInstance
+                if !self.marked_for(ServiceType::EC2).is_empty() =>
+            {
+                if command.is_destructive() && self.user_config.behavior.confirm_destructive_actions
+                {
+                    self.request_batch_confirmation(ServiceType::EC2, command);
+                } else {
+                    let instance_ids = std::mem::take(&mut self.marked_resources);
+                    self.marked_service = None;
+                    self.spawn_ec2_batch(command, instance_ids);
+                }
+            }
             ServiceCommand::ListInstances => {
                 self.add_notification(
                     "Listing EC2 instances...".to_string(),
                     NotificationLevel::Info,
                 );
-                // TODO: Implement actual EC2 instance listing
-                self.add_notification(
-                    "EC2 instances listed successfully".to_string(),
-                    NotificationLevel::Success,
+                let clients = self.region_clients();
+                self.spawn_operation(
+                    ServiceType::EC2,
+                    command.display_name(),
+                    "EC2 instances listed successfully",
+                    async move {
+                        let resources = list_resources(&clients, ServiceType::EC2).await?;
+                        Ok(OperationOutput::Resources(resources))
+                    },
                 );
             }
             ServiceCommand::CreateInstance => {
-                self.add_notification(
-                    "Creating new EC2 instance...".to_string(),
-                    NotificationLevel::Info,
-                );
-                // TODO: Implement actual EC2 instance creation
-                self.add_notification(
-                    "EC2 instance creation initiated".to_string(),
-                    NotificationLevel::Success,
-                );
+                self.instance_form = Some(InstanceForm::new(&self.current_region));
+            }
+            ServiceCommand::CloneInstance => {
+                if let Some(instance_id) = self.selected_resource.clone() {
+                    // The clone launches next to its source, even if the region is
+                    // switched while the form is open
+                    let region = self
+                        .cached_resource(ServiceType::EC2, &instance_id)
+                        .map(|resource| resource.region.clone())
+                        .unwrap_or_else(|| self.current_region.clone());
+                    let clients = self.clients_for_region(&region);
+                    self.spawn_operation(
+                        ServiceType::EC2,
+                        command.display_name(),
+                        "",
+                        async move {
+                            let instance =
+                                Ec2Service::new(&clients).get_instance(&instance_id).await?;
+                            Ok(OperationOutput::InstanceConfig {
+                                region,
+                                instance_id,
+                                instance: instance.map(Box::new),
+                            })
+                        },
+                    );
+                } else {
+                    self.add_notification(
+                        "No EC2 instance selected".to_string(),
+                        NotificationLevel::Error,
+                    );
+                }
             }
             ServiceCommand::StartInstance => {
-                if self.selected_resource.is_some() {
+                if let Some(instance_id) = self.selected_resource.clone() {
                     self.add_notification(
-                        format!("Starting EC2 instance {}...", self.selected_resource_index),
+                        format!("Starting EC2 instance {}...", instance_id),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual EC2 instance start
-                    self.add_notification(
-                        "EC2 instance start initiated".to_string(),
-                        NotificationLevel::Success,
+                    let clients = self.region_clients();
+                    self.spawn_operation(
+                        ServiceType::EC2,
+                        command.display_name(),
+                        "EC2 instance start initiated",
+                        async move {
+                            Ec2Service::new(&clients)
+                                .start_instance(&instance_id)
+                                .await?;
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -868,15 +6686,22 @@ impl AppState {
                 }
             }
             ServiceCommand::StopInstance => {
-                if self.selected_resource.is_some() {
+                if let Some(instance_id) = self.selected_resource.clone() {
                     self.add_notification(
-                        format!("Stopping EC2 instance {}...", self.selected_resource_index),
+                        format!("Stopping EC2 instance {}...", instance_id),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual EC2 instance stop
-                    self.add_notification(
-                        "EC2 instance stop initiated".to_string(),
-                        NotificationLevel::Success,
+                    let clients = self.region_clients();
+                    self.spawn_operation(
+                        ServiceType::EC2,
+                        command.display_name(),
+                        "EC2 instance stop initiated",
+                        async move {
+                            Ec2Service::new(&clients)
+                                .stop_instance(&instance_id)
+                                .await?;
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -886,15 +6711,22 @@ impl AppState {
                 }
             }
             ServiceCommand::RebootInstance => {
-                if self.selected_resource.is_some() {
+                if let Some(instance_id) = self.selected_resource.clone() {
                     self.add_notification(
-                        format!("Rebooting EC2 instance {}...", self.selected_resource_index),
+                        format!("Rebooting EC2 instance {}...", instance_id),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual EC2 instance reboot
-                    self.add_notification(
-                        "EC2 instance reboot initiated".to_string(),
-                        NotificationLevel::Success,
+                    let clients = self.region_clients();
+                    self.spawn_operation(
+                        ServiceType::EC2,
+                        command.display_name(),
+                        "EC2 instance reboot initiated",
+                        async move {
+                            Ec2Service::new(&clients)
+                                .reboot_instance(&instance_id)
+                                .await?;
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -904,18 +6736,46 @@ impl AppState {
                 }
             }
             ServiceCommand::TerminateInstance => {
-                if self.selected_resource.is_some() {
+                if let Some(instance_id) = self.selected_resource.clone() {
                     self.add_notification(
-                        format!(
-                            "Terminating EC2 instance {}...",
-                            self.selected_resource_index
-                        ),
+                        format!("Terminating EC2 instance {}...", instance_id),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual EC2 instance termination
+                    let clients = self.region_clients();
+                    self.spawn_operation(
+                        ServiceType::EC2,
+                        command.display_name(),
+                        "EC2 instance termination initiated",
+                        async move {
+                            Ec2Service::new(&clients)
+                                .terminate_instance(&instance_id)
+                                .await?;
+                            Ok(OperationOutput::Done)
+                        },
+                    );
+                } else {
                     self.add_notification(
-                        "EC2 instance termination initiated".to_string(),
-                        NotificationLevel::Success,
+                        "No EC2 instance selected".to_string(),
+                        NotificationLevel::Error,
+                    );
+                }
+            }
+            ServiceCommand::InspectSecurityGroups => {
+                if let Some(instance_id) = self.selected_resource.clone() {
+                    let clients = self.region_clients();
+                    self.spawn_operation(
+                        ServiceType::EC2,
+                        command.display_name(),
+                        "",
+                        async move {
+                            let groups = Ec2Service::new(&clients)
+                                .instance_security_groups(&instance_id)
+                                .await?;
+                            Ok(OperationOutput::SecurityGroups {
+                                instance_id,
+                                groups,
+                            })
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -925,18 +6785,20 @@ impl AppState {
                 }
             }
             ServiceCommand::DescribeInstance => {
-                if self.selected_resource.is_some() {
+                if let Some(instance_id) = self.selected_resource.clone() {
                     self.add_notification(
-                        format!(
-                            "Describing EC2 instance {}...",
-                            self.selected_resource_index
-                        ),
+                        format!("Describing EC2 instance {}...", instance_id),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual EC2 instance description
-                    self.add_notification(
-                        "EC2 instance details retrieved".to_string(),
-                        NotificationLevel::Success,
+                    let clients = self.region_clients();
+                    self.spawn_operation(
+                        ServiceType::EC2,
+                        command.display_name(),
+                        "EC2 instance details retrieved",
+                        async move {
+                            Ec2Service::new(&clients).get_instance(&instance_id).await?;
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -945,6 +6807,17 @@ impl AppState {
                     );
                 }
             }
+            ServiceCommand::FilterByZone => self.open_zone_picker(ServiceType::EC2),
+            ServiceCommand::ClearZoneFilter => {
+                if self.zone_filters.contains_key(&ServiceType::EC2) {
+                    self.set_zone_filter(ServiceType::EC2, None);
+                } else {
+                    self.add_notification(
+                        "No availability zone filter is active".to_string(),
+                        NotificationLevel::Info,
+                    );
+                }
+            }
             _ => {
                 self.add_notification(
                     format!(
@@ -965,22 +6838,18 @@ impl AppState {
         match command {
             ServiceCommand::ListBuckets => {
                 self.add_notification("Listing S3 buckets...".to_string(), NotificationLevel::Info);
-                // TODO: Implement actual S3 bucket listing
-                self.add_notification(
-                    "S3 buckets listed successfully".to_string(),
-                    NotificationLevel::Success,
+                self.spawn_operation(
+                    ServiceType::S3,
+                    command.display_name(),
+                    "S3 buckets listed successfully",
+                    async move {
+                        // TODO: Implement actual S3 bucket listing
+                        Ok(OperationOutput::Done)
+                    },
                 );
             }
             ServiceCommand::CreateBucket => {
-                self.add_notification(
-                    "Creating new S3 bucket...".to_string(),
-                    NotificationLevel::Info,
-                );
-                // TODO: Implement actual S3 bucket creation
-                self.add_notification(
-                    "S3 bucket creation initiated".to_string(),
-                    NotificationLevel::Success,
-                );
+                self.bucket_form = Some(BucketForm::default());
             }
             ServiceCommand::DeleteBucket => {
                 if self.selected_resource.is_some() {
@@ -988,10 +6857,14 @@ impl AppState {
                         format!("Deleting S3 bucket {}...", self.selected_resource_index),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual S3 bucket deletion
-                    self.add_notification(
-                        "S3 bucket deletion initiated".to_string(),
-                        NotificationLevel::Success,
+                    self.spawn_operation(
+                        ServiceType::S3,
+                        command.display_name(),
+                        "S3 bucket deletion initiated",
+                        async move {
+                            // TODO: Implement actual S3 bucket deletion
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1006,10 +6879,14 @@ impl AppState {
                         format!("Getting S3 bucket {} info...", self.selected_resource_index),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual S3 bucket info retrieval
-                    self.add_notification(
-                        "S3 bucket info retrieved".to_string(),
-                        NotificationLevel::Success,
+                    self.spawn_operation(
+                        ServiceType::S3,
+                        command.display_name(),
+                        "S3 bucket info retrieved",
+                        async move {
+                            // TODO: Implement actual S3 bucket info retrieval
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1027,10 +6904,14 @@ impl AppState {
                         ),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual S3 object listing
-                    self.add_notification(
-                        "S3 objects listed successfully".to_string(),
-                        NotificationLevel::Success,
+                    self.spawn_operation(
+                        ServiceType::S3,
+                        command.display_name(),
+                        "S3 objects listed successfully",
+                        async move {
+                            // TODO: Implement actual S3 object listing
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1048,10 +6929,14 @@ impl AppState {
                         ),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual S3 object upload
-                    self.add_notification(
-                        "S3 object upload initiated".to_string(),
-                        NotificationLevel::Success,
+                    self.spawn_operation(
+                        ServiceType::S3,
+                        command.display_name(),
+                        "S3 object upload initiated",
+                        async move {
+                            // TODO: Implement actual S3 object upload
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1065,10 +6950,14 @@ impl AppState {
                     "Downloading S3 object...".to_string(),
                     NotificationLevel::Info,
                 );
-                // TODO: Implement actual S3 object download
-                self.add_notification(
-                    "S3 object download initiated".to_string(),
-                    NotificationLevel::Success,
+                self.spawn_operation(
+                    ServiceType::S3,
+                    command.display_name(),
+                    "S3 object download initiated",
+                    async move {
+                        // TODO: Implement actual S3 object download
+                        Ok(OperationOutput::Done)
+                    },
                 );
             }
             _ => {
@@ -1097,10 +6986,14 @@ impl AppState {
                     "Listing RDS databases...".to_string(),
                     NotificationLevel::Info,
                 );
-                // TODO: Implement actual RDS database listing
-                self.add_notification(
-                    "RDS databases listed successfully".to_string(),
-                    NotificationLevel::Success,
+                self.spawn_operation(
+                    ServiceType::RDS,
+                    command.display_name(),
+                    "RDS databases listed successfully",
+                    async move {
+                        // TODO: Implement actual RDS database listing
+                        Ok(OperationOutput::Done)
+                    },
                 );
             }
             ServiceCommand::StartDatabase => {
@@ -1109,10 +7002,14 @@ impl AppState {
                         format!("Starting RDS database {}...", self.selected_resource_index),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual RDS database start
-                    self.add_notification(
-                        "RDS database start initiated".to_string(),
-                        NotificationLevel::Success,
+                    self.spawn_operation(
+                        ServiceType::RDS,
+                        command.display_name(),
+                        "RDS database start initiated",
+                        async move {
+                            // TODO: Implement actual RDS database start
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1127,10 +7024,14 @@ impl AppState {
                         format!("Stopping RDS database {}...", self.selected_resource_index),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual RDS database stop
-                    self.add_notification(
-                        "RDS database stop initiated".to_string(),
-                        NotificationLevel::Success,
+                    self.spawn_operation(
+                        ServiceType::RDS,
+                        command.display_name(),
+                        "RDS database stop initiated",
+                        async move {
+                            // TODO: Implement actual RDS database stop
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1145,10 +7046,14 @@ impl AppState {
                         format!("Rebooting RDS database {}...", self.selected_resource_index),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual RDS database reboot
-                    self.add_notification(
-                        "RDS database reboot initiated".to_string(),
-                        NotificationLevel::Success,
+                    self.spawn_operation(
+                        ServiceType::RDS,
+                        command.display_name(),
+                        "RDS database reboot initiated",
+                        async move {
+                            // TODO: Implement actual RDS database reboot
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1166,10 +7071,38 @@ impl AppState {
                         ),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual RDS database description
+                    self.spawn_operation(
+                        ServiceType::RDS,
+                        command.display_name(),
+                        "RDS database details retrieved",
+                        async move {
+                            // TODO: Implement actual RDS database description
+                            Ok(OperationOutput::Done)
+                        },
+                    );
+                } else {
                     self.add_notification(
-                        "RDS database details retrieved".to_string(),
-                        NotificationLevel::Success,
+                        "No RDS database selected".to_string(),
+                        NotificationLevel::Error,
+                    );
+                }
+            }
+            ServiceCommand::ViewDatabaseLogs => {
+                if let Some(instance_id) = self.selected_resource.clone() {
+                    let clients = self.region_clients();
+                    self.spawn_operation(
+                        ServiceType::RDS,
+                        command.display_name(),
+                        "",
+                        async move {
+                            let log_groups =
+                                RdsService::new(&clients).log_groups(&instance_id).await?;
+                            Ok(OperationOutput::LogGroups {
+                                service_type: ServiceType::RDS,
+                                resource_id: instance_id,
+                                log_groups,
+                            })
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1187,10 +7120,14 @@ impl AppState {
                         ),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual RDS snapshot creation
-                    self.add_notification(
-                        "RDS snapshot creation initiated".to_string(),
-                        NotificationLevel::Success,
+                    self.spawn_operation(
+                        ServiceType::RDS,
+                        command.display_name(),
+                        "RDS snapshot creation initiated",
+                        async move {
+                            // TODO: Implement actual RDS snapshot creation
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1204,10 +7141,14 @@ impl AppState {
                     "Restoring RDS database from snapshot...".to_string(),
                     NotificationLevel::Info,
                 );
-                // TODO: Implement actual RDS snapshot restoration
-                self.add_notification(
-                    "RDS snapshot restoration initiated".to_string(),
-                    NotificationLevel::Success,
+                self.spawn_operation(
+                    ServiceType::RDS,
+                    command.display_name(),
+                    "RDS snapshot restoration initiated",
+                    async move {
+                        // TODO: Implement actual RDS snapshot restoration
+                        Ok(OperationOutput::Done)
+                    },
                 );
             }
             _ => {
@@ -1233,18 +7174,26 @@ impl AppState {
         match command {
             ServiceCommand::ListUsers => {
                 self.add_notification("Listing IAM users...".to_string(), NotificationLevel::Info);
-                // TODO: Implement actual IAM user listing
-                self.add_notification(
-                    "IAM users listed successfully".to_string(),
-                    NotificationLevel::Success,
+                self.spawn_operation(
+                    ServiceType::IAM,
+                    command.display_name(),
+                    "IAM users listed successfully",
+                    async move {
+                        // TODO: Implement actual IAM user listing
+                        Ok(OperationOutput::Done)
+                    },
                 );
             }
             ServiceCommand::ListRoles => {
                 self.add_notification("Listing IAM roles...".to_string(), NotificationLevel::Info);
-                // TODO: Implement actual IAM role listing
-                self.add_notification(
-                    "IAM roles listed successfully".to_string(),
-                    NotificationLevel::Success,
+                self.spawn_operation(
+                    ServiceType::IAM,
+                    command.display_name(),
+                    "IAM roles listed successfully",
+                    async move {
+                        // TODO: Implement actual IAM role listing
+                        Ok(OperationOutput::Done)
+                    },
                 );
             }
             ServiceCommand::CreateUser => {
@@ -1252,10 +7201,14 @@ impl AppState {
                     "Creating new IAM user...".to_string(),
                     NotificationLevel::Info,
                 );
-                // TODO: Implement actual IAM user creation
-                self.add_notification(
-                    "IAM user creation initiated".to_string(),
-                    NotificationLevel::Success,
+                self.spawn_operation(
+                    ServiceType::IAM,
+                    command.display_name(),
+                    "IAM user creation initiated",
+                    async move {
+                        // TODO: Implement actual IAM user creation
+                        Ok(OperationOutput::Done)
+                    },
                 );
             }
             ServiceCommand::CreateRole => {
@@ -1263,10 +7216,14 @@ impl AppState {
                     "Creating new IAM role...".to_string(),
                     NotificationLevel::Info,
                 );
-                // TODO: Implement actual IAM role creation
-                self.add_notification(
-                    "IAM role creation initiated".to_string(),
-                    NotificationLevel::Success,
+                self.spawn_operation(
+                    ServiceType::IAM,
+                    command.display_name(),
+                    "IAM role creation initiated",
+                    async move {
+                        // TODO: Implement actual IAM role creation
+                        Ok(OperationOutput::Done)
+                    },
                 );
             }
             ServiceCommand::DeleteUser => {
@@ -1275,10 +7232,14 @@ impl AppState {
                         format!("Deleting IAM user {}...", self.selected_resource_index),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual IAM user deletion
-                    self.add_notification(
-                        "IAM user deletion initiated".to_string(),
-                        NotificationLevel::Success,
+                    self.spawn_operation(
+                        ServiceType::IAM,
+                        command.display_name(),
+                        "IAM user deletion initiated",
+                        async move {
+                            // TODO: Implement actual IAM user deletion
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1293,10 +7254,14 @@ impl AppState {
                         format!("Deleting IAM role {}...", self.selected_resource_index),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual IAM role deletion
-                    self.add_notification(
-                        "IAM role deletion initiated".to_string(),
-                        NotificationLevel::Success,
+                    self.spawn_operation(
+                        ServiceType::IAM,
+                        command.display_name(),
+                        "IAM role deletion initiated",
+                        async move {
+                            // TODO: Implement actual IAM role deletion
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1314,10 +7279,14 @@ impl AppState {
                         ),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual IAM policy attachment
-                    self.add_notification(
-                        "IAM policy attachment initiated".to_string(),
-                        NotificationLevel::Success,
+                    self.spawn_operation(
+                        ServiceType::IAM,
+                        command.display_name(),
+                        "IAM policy attachment initiated",
+                        async move {
+                            // TODO: Implement actual IAM policy attachment
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1335,10 +7304,14 @@ impl AppState {
                         ),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual IAM policy detachment
-                    self.add_notification(
-                        "IAM policy detachment initiated".to_string(),
-                        NotificationLevel::Success,
+                    self.spawn_operation(
+                        ServiceType::IAM,
+                        command.display_name(),
+                        "IAM policy detachment initiated",
+                        async move {
+                            // TODO: Implement actual IAM policy detachment
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1370,22 +7343,18 @@ impl AppState {
         match command {
             ServiceCommand::ListSecrets => {
                 self.add_notification("Listing secrets...".to_string(), NotificationLevel::Info);
-                // TODO: Implement actual secrets listing
-                self.add_notification(
-                    "Secrets listed successfully".to_string(),
-                    NotificationLevel::Success,
+                self.spawn_operation(
+                    ServiceType::Secrets,
+                    command.display_name(),
+                    "Secrets listed successfully",
+                    async move {
+                        // TODO: Implement actual secrets listing
+                        Ok(OperationOutput::Done)
+                    },
                 );
             }
             ServiceCommand::CreateSecret => {
-                self.add_notification(
-                    "Creating new secret...".to_string(),
-                    NotificationLevel::Info,
-                );
-                // TODO: Implement actual secret creation
-                self.add_notification(
-                    "Secret creation initiated".to_string(),
-                    NotificationLevel::Success,
-                );
+                self.secret_form = Some(SecretForm::new());
             }
             ServiceCommand::UpdateSecret => {
                 if self.selected_resource.is_some() {
@@ -1393,10 +7362,14 @@ impl AppState {
                         format!("Updating secret {}...", self.selected_resource_index),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual secret update
-                    self.add_notification(
-                        "Secret update initiated".to_string(),
-                        NotificationLevel::Success,
+                    self.spawn_operation(
+                        ServiceType::Secrets,
+                        command.display_name(),
+                        "Secret update initiated",
+                        async move {
+                            // TODO: Implement actual secret update
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1411,10 +7384,14 @@ impl AppState {
                         format!("Deleting secret {}...", self.selected_resource_index),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual secret deletion
-                    self.add_notification(
-                        "Secret deletion initiated".to_string(),
-                        NotificationLevel::Success,
+                    self.spawn_operation(
+                        ServiceType::Secrets,
+                        command.display_name(),
+                        "Secret deletion initiated",
+                        async move {
+                            // TODO: Implement actual secret deletion
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1432,10 +7409,14 @@ impl AppState {
                         ),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual secret value retrieval
-                    self.add_notification(
-                        "Secret value retrieved".to_string(),
-                        NotificationLevel::Success,
+                    self.spawn_operation(
+                        ServiceType::Secrets,
+                        command.display_name(),
+                        "Secret value retrieved",
+                        async move {
+                            // TODO: Implement actual secret value retrieval
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1450,10 +7431,45 @@ impl AppState {
                         format!("Describing secret {}...", self.selected_resource_index),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual secret description
+                    self.spawn_operation(
+                        ServiceType::Secrets,
+                        command.display_name(),
+                        "Secret details retrieved",
+                        async move {
+                            // TODO: Implement actual secret description
+                            Ok(OperationOutput::Done)
+                        },
+                    );
+                } else {
                     self.add_notification(
-                        "Secret details retrieved".to_string(),
-                        NotificationLevel::Success,
+                        "No secret selected".to_string(),
+                        NotificationLevel::Error,
+                    );
+                }
+            }
+            ServiceCommand::RotateSecret => {
+                if let Some(secret_name) = self.selected_resource.clone() {
+                    self.add_notification(
+                        format!("Rotating secret {}...", secret_name),
+                        NotificationLevel::Info,
+                    );
+                    let clients = self.region_clients();
+                    self.spawn_operation(
+                        ServiceType::Secrets,
+                        command.display_name(),
+                        "Secret rotation started",
+                        async move {
+                            let version_id = SecretsService::new(&clients)
+                                .rotate_secret(&secret_name)
+                                .await?;
+                            Ok(OperationOutput::Message(match version_id {
+                                Some(version_id) => format!(
+                                    "Rotation started for {} (pending version {})",
+                                    secret_name, version_id
+                                ),
+                                None => format!("Rotation started for {}", secret_name),
+                            }))
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1488,22 +7504,18 @@ impl AppState {
                     "Listing EKS clusters...".to_string(),
                     NotificationLevel::Info,
                 );
-                // TODO: Implement actual EKS cluster listing
-                self.add_notification(
-                    "EKS clusters listed successfully".to_string(),
-                    NotificationLevel::Success,
+                self.spawn_operation(
+                    ServiceType::EKS,
+                    command.display_name(),
+                    "EKS clusters listed successfully",
+                    async move {
+                        // TODO: Implement actual EKS cluster listing
+                        Ok(OperationOutput::Done)
+                    },
                 );
             }
             ServiceCommand::CreateCluster => {
-                self.add_notification(
-                    "Creating new EKS cluster...".to_string(),
-                    NotificationLevel::Info,
-                );
-                // TODO: Implement actual EKS cluster creation
-                self.add_notification(
-                    "EKS cluster creation initiated".to_string(),
-                    NotificationLevel::Success,
-                );
+                self.cluster_form = Some(ClusterForm::default());
             }
             ServiceCommand::DeleteCluster => {
                 if self.selected_resource.is_some() {
@@ -1511,10 +7523,14 @@ impl AppState {
                         format!("Deleting EKS cluster {}...", self.selected_resource_index),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual EKS cluster deletion
-                    self.add_notification(
-                        "EKS cluster deletion initiated".to_string(),
-                        NotificationLevel::Success,
+                    self.spawn_operation(
+                        ServiceType::EKS,
+                        command.display_name(),
+                        "EKS cluster deletion initiated",
+                        async move {
+                            // TODO: Implement actual EKS cluster deletion
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1529,10 +7545,39 @@ impl AppState {
                         format!("Describing EKS cluster {}...", self.selected_resource_index),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual EKS cluster description
+                    self.spawn_operation(
+                        ServiceType::EKS,
+                        command.display_name(),
+                        "EKS cluster details retrieved",
+                        async move {
+                            // TODO: Implement actual EKS cluster description
+                            Ok(OperationOutput::Done)
+                        },
+                    );
+                } else {
                     self.add_notification(
-                        "EKS cluster details retrieved".to_string(),
-                        NotificationLevel::Success,
+                        "No EKS cluster selected".to_string(),
+                        NotificationLevel::Error,
+                    );
+                }
+            }
+            ServiceCommand::ViewClusterLogs => {
+                if let Some(cluster_name) = self.selected_resource.clone() {
+                    let clients = self.region_clients();
+                    self.spawn_operation(
+                        ServiceType::EKS,
+                        command.display_name(),
+                        "",
+                        async move {
+                            let log_group = EksService::new(&clients)
+                                .control_plane_log_group(&cluster_name)
+                                .await?;
+                            Ok(OperationOutput::LogGroups {
+                                service_type: ServiceType::EKS,
+                                resource_id: cluster_name,
+                                log_groups: vec![log_group],
+                            })
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1550,10 +7595,14 @@ impl AppState {
                         ),
                         NotificationLevel::Info,
                     );
-                    // TODO: Implement actual kubeconfig update
-                    self.add_notification(
-                        "Kubeconfig update initiated".to_string(),
-                        NotificationLevel::Success,
+                    self.spawn_operation(
+                        ServiceType::EKS,
+                        command.display_name(),
+                        "Kubeconfig update initiated",
+                        async move {
+                            // TODO: Implement actual kubeconfig update
+                            Ok(OperationOutput::Done)
+                        },
                     );
                 } else {
                     self.add_notification(
@@ -1562,20 +7611,39 @@ impl AppState {
                     );
                 }
             }
-            ServiceCommand::ListNodeGroups => {
-                if self.selected_resource.is_some() {
-                    self.add_notification(
-                        format!(
-                            "Listing node groups for EKS cluster {}...",
-                            self.selected_resource_index
-                        ),
-                        NotificationLevel::Info,
-                    );
-                    // TODO: Implement actual node group listing
+            ServiceCommand::UpdateNodeGroupSize => {
+                let AppPage::ResourceDetail(ServiceType::EKS, cluster) = &self.current_page else {
                     self.add_notification(
-                        "EKS node groups listed successfully".to_string(),
-                        NotificationLevel::Success,
+                        "Open a cluster and highlight a node group to scale it".to_string(),
+                        NotificationLevel::Warning,
                     );
+                    return Ok(());
+                };
+                let node_group = self
+                    .node_groups
+                    .get(&(self.current_region.clone(), cluster.clone()))
+                    .and_then(|groups| groups.get(self.selected_node_group));
+                match node_group {
+                    Some(node_group) => {
+                        self.node_group_size_form =
+                            Some(NodeGroupSizeForm::new(cluster, node_group));
+                    }
+                    None => self.add_notification(
+                        format!("Cluster {} has no managed node groups to scale", cluster),
+                        NotificationLevel::Warning,
+                    ),
+                }
+            }
+            ServiceCommand::ListNodeGroups => {
+                if let Some(cluster) = self.selected_resource.clone() {
+                    // Node groups are shown on the cluster's detail page, which
+                    // loads them on entry
+                    let page = AppPage::ResourceDetail(ServiceType::EKS, cluster.clone());
+                    if self.current_page == page {
+                        self.load_node_groups(cluster);
+                    } else {
+                        self.enter_resource_detail(ServiceType::EKS, cluster);
+                    }
                 } else {
                     self.add_notification(
                         "No EKS cluster selected".to_string(),
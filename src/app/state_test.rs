@@ -0,0 +1,229 @@
+use crate::app::activity_log::ActivityLog;
+use crate::app::load_state::LoadState;
+use crate::app::state::{AppState, NotificationLevel, SavedData};
+use crate::aws::client::{ApiFuture, MultiRegionAwsClients, RegionClients};
+use crate::aws::profiles::ProfileManager;
+use crate::aws::services::ec2::Ec2Api;
+use crate::aws::services::s3::S3Client;
+use crate::aws::types::{
+    Ec2Instance, InstanceLaunch, RegionStatus, Resource, ResourceEvent, SecurityGroup, ServiceType,
+};
+use crate::command::ServiceCommand;
+use crate::config::user_config::UserConfig;
+use crate::ui::pages::dashboard::favorites::{FavoriteResource, FavoritesManager};
+use crate::ui::pages::dashboard::recently_viewed::RecentlyViewed;
+use crate::ui::pages::dashboard::widgets::DashboardLayout;
+use crate::utils::temp_dir::TempDir;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Records the instances it was asked to stop instead of calling EC2
+#[derive(Default)]
+struct FakeEc2 {
+    stopped: Mutex<Vec<String>>,
+}
+
+impl Ec2Api for FakeEc2 {
+    fn list_instances(&self) -> ApiFuture<'_, Vec<Ec2Instance>> {
+        Box::pin(async { Ok(vec![]) })
+    }
+
+    fn describe_availability_zones(&self) -> ApiFuture<'_, Vec<String>> {
+        Box::pin(async { Ok(vec![]) })
+    }
+
+    fn describe_regions(&self) -> ApiFuture<'_, Vec<RegionStatus>> {
+        Box::pin(async { Ok(vec![]) })
+    }
+
+    fn get_instance<'a>(&'a self, _instance_id: &'a str) -> ApiFuture<'a, Option<Ec2Instance>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    fn run_instance<'a>(&'a self, _launch: &'a InstanceLaunch) -> ApiFuture<'a, String> {
+        Box::pin(async { Ok("i-new".to_string()) })
+    }
+
+    fn start_instance<'a>(&'a self, _instance_id: &'a str) -> ApiFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn stop_instance<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, ()> {
+        self.stopped.lock().unwrap().push(instance_id.to_string());
+        Box::pin(async { Ok(()) })
+    }
+
+    fn terminate_instance<'a>(&'a self, _instance_id: &'a str) -> ApiFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn reboot_instance<'a>(&'a self, _instance_id: &'a str) -> ApiFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn describe_instance_status<'a>(
+        &'a self,
+        _instance_id: &'a str,
+    ) -> ApiFuture<'a, Vec<ResourceEvent>> {
+        Box::pin(async { Ok(vec![]) })
+    }
+
+    fn describe_security_groups<'a>(
+        &'a self,
+        _group_ids: &'a [String],
+    ) -> ApiFuture<'a, Vec<SecurityGroup>> {
+        Box::pin(async { Ok(vec![]) })
+    }
+}
+
+/// A state whose files all live in a fresh temporary directory, so tests
+/// never read or write the user's AWS or app configuration. Keep the returned
+/// directory alive for as long as the state is used.
+fn state_with_ec2(ec2: Arc<FakeEc2>) -> (AppState, TempDir) {
+    let dir = TempDir::new("nimbus-ctl-state");
+    let region = "us-east-1";
+    let clients = RegionClients {
+        ec2,
+        ..RegionClients::new(region)
+    };
+
+    let state = AppState::from_parts(
+        UserConfig::default(),
+        ProfileManager::with_paths(dir.join("credentials"), dir.join("config")).unwrap(),
+        SavedData {
+            favorites_manager: FavoritesManager::with_dir(dir.path().to_path_buf()).unwrap(),
+            recently_viewed: RecentlyViewed::empty(10, dir.join("recently_viewed.json")),
+            recent_activity: ActivityLog::empty(10, dir.join("activity_log.json")),
+            dashboard_layout: DashboardLayout::new(),
+        },
+        Some(MultiRegionAwsClients::with_region_clients(
+            "default", clients,
+        )),
+        "default".to_string(),
+        region.to_string(),
+    );
+    (state, dir)
+}
+
+/// Run the update loop until every spawned operation has reported back
+async fn settle(state: &mut AppState) {
+    while state.operations.is_busy() {
+        tokio::task::yield_now().await;
+        state.update().await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn stop_instance_without_selection_reports_an_error() {
+    let ec2 = Arc::new(FakeEc2::default());
+    let (mut state, _dir) = state_with_ec2(ec2.clone());
+
+    state
+        .execute_ec2_command(&ServiceCommand::StopInstance)
+        .await
+        .unwrap();
+    settle(&mut state).await;
+
+    assert!(ec2.stopped.lock().unwrap().is_empty());
+    let last = state.notifications.last().unwrap();
+    assert!(matches!(last.level, NotificationLevel::Error));
+    assert_eq!(last.message, "No EC2 instance selected");
+}
+
+#[tokio::test]
+async fn stop_instance_with_selection_calls_the_api_once() {
+    let ec2 = Arc::new(FakeEc2::default());
+    let (mut state, _dir) = state_with_ec2(ec2.clone());
+    state.selected_resource = Some("i-0123456789abcdef0".to_string());
+
+    state
+        .execute_ec2_command(&ServiceCommand::StopInstance)
+        .await
+        .unwrap();
+    settle(&mut state).await;
+
+    assert_eq!(
+        *ec2.stopped.lock().unwrap(),
+        vec!["i-0123456789abcdef0".to_string()]
+    );
+    let last = state.notifications.last().unwrap();
+    assert!(matches!(last.level, NotificationLevel::Success));
+}
+
+#[tokio::test]
+async fn describe_without_a_result_keeps_the_resource_and_saved_lists() {
+    let (mut state, _dir) = state_with_ec2(Arc::new(FakeEc2::default()));
+    let id = "i-0123456789abcdef0".to_string();
+    let listed = Resource {
+        id: id.clone(),
+        name: "web".to_string(),
+        service_type: ServiceType::EC2,
+        region: "us-east-1".to_string(),
+        arn: String::new(),
+        state: "running".to_string(),
+        availability_zone: None,
+        tags: HashMap::new(),
+        created_at: None,
+        last_modified: None,
+        instance_type: None,
+    };
+    state.resources.insert(
+        ("us-east-1".to_string(), ServiceType::EC2),
+        LoadState::Loaded(vec![listed]),
+    );
+    state
+        .favorites_manager
+        .add_favorite(FavoriteResource {
+            id: id.clone(),
+            name: "web".to_string(),
+            service_type: ServiceType::EC2,
+            region: "us-east-1".to_string(),
+            arn: String::new(),
+            tags: HashMap::new(),
+            added_at: chrono::Utc::now(),
+            last_accessed: chrono::Utc::now(),
+            access_count: 0,
+            note: None,
+        })
+        .unwrap();
+
+    // FakeEc2::get_instance answers Ok(None), like the unimplemented describes
+    state.enter_resource_detail(ServiceType::EC2, id.clone());
+    settle(&mut state).await;
+
+    assert!(state.cached_resource(ServiceType::EC2, &id).is_some());
+    assert!(state.favorites_manager.is_favorite(&id));
+    assert_eq!(state.recently_viewed.entries(10)[0].id, id);
+    assert!(!state
+        .notifications
+        .iter()
+        .any(|n| n.message.contains("no longer exists")));
+}
+
+#[test]
+fn configured_default_is_kept_when_it_exists() {
+    let (name, warning) = AppState::validated_default("profile", "work", ["default", "work"]);
+
+    assert_eq!(name, "work");
+    assert!(warning.is_none());
+}
+
+#[test]
+fn unknown_default_falls_back_to_the_first_available() {
+    let (name, warning) =
+        AppState::validated_default("region", "mars-north-1", ["us-east-1", "eu-west-1"]);
+
+    assert_eq!(name, "us-east-1");
+    assert_eq!(
+        warning.as_deref(),
+        Some("Default region 'mars-north-1' not found; using 'us-east-1' instead")
+    );
+}
+
+#[test]
+fn unknown_default_without_any_available_falls_back_to_default() {
+    let (name, warning) = AppState::validated_default("profile", "work", []);
+
+    assert_eq!(name, "default");
+    assert!(warning.is_some());
+}
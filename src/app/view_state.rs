@@ -0,0 +1,122 @@
+use crate::app::state::AppPage;
+use crate::aws::types::ServiceType;
+
+/// Prefix that marks a string as a shareable view
+pub const VIEW_STATE_SCHEME: &str = "nimbus://";
+
+/// Where the user is looking, in a form that can be copied and opened again:
+/// `nimbus://<profile>/<region>[/settings | /<service>[/<resource id>]]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViewState {
+    pub profile: String,
+    pub region: String,
+    pub page: AppPage,
+}
+
+impl ViewState {
+    pub fn encode(&self) -> String {
+        let mut encoded = format!(
+            "{}{}/{}",
+            VIEW_STATE_SCHEME,
+            escape(&self.profile),
+            escape(&self.region)
+        );
+        match &self.page {
+            AppPage::Dashboard => {}
+            AppPage::Settings => encoded.push_str("/settings"),
+            AppPage::ActivityLog => encoded.push_str("/activity"),
+            AppPage::ResourceList(service_type) => {
+                encoded.push('/');
+                encoded.push_str(service_slug(*service_type));
+            }
+            AppPage::ResourceDetail(service_type, resource_id) => {
+                encoded.push('/');
+                encoded.push_str(service_slug(*service_type));
+                encoded.push('/');
+                encoded.push_str(&escape(resource_id));
+            }
+        }
+        encoded
+    }
+
+    /// Parse a string produced by `encode`; the message names the part that is wrong
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let path = input
+            .trim()
+            .strip_prefix(VIEW_STATE_SCHEME)
+            .ok_or_else(|| format!("View links start with {}", VIEW_STATE_SCHEME))?;
+
+        let mut parts = path.split('/');
+        let profile = parts
+            .next()
+            .filter(|profile| !profile.is_empty())
+            .ok_or("View link has no profile")?;
+        let region = parts
+            .next()
+            .filter(|region| !region.is_empty())
+            .ok_or("View link has no region")?;
+
+        let page = match parts.next().filter(|part| !part.is_empty()) {
+            None => AppPage::Dashboard,
+            Some("settings") => AppPage::Settings,
+            Some("activity") => AppPage::ActivityLog,
+            Some(service) => {
+                let service_type = service_from_slug(service)
+                    .ok_or_else(|| format!("Unknown service '{}' in view link", service))?;
+                match parts.next().filter(|id| !id.is_empty()) {
+                    Some(resource_id) => {
+                        AppPage::ResourceDetail(service_type, unescape(resource_id)?)
+                    }
+                    None => AppPage::ResourceList(service_type),
+                }
+            }
+        };
+        if parts.next().is_some() {
+            return Err("View link has too many parts".to_string());
+        }
+
+        Ok(Self {
+            profile: unescape(profile)?,
+            region: unescape(region)?,
+            page,
+        })
+    }
+}
+
+fn service_slug(service_type: ServiceType) -> &'static str {
+    match service_type {
+        ServiceType::EC2 => "ec2",
+        ServiceType::S3 => "s3",
+        ServiceType::RDS => "rds",
+        ServiceType::IAM => "iam",
+        ServiceType::Secrets => "secrets",
+        ServiceType::EKS => "eks",
+    }
+}
+
+fn service_from_slug(slug: &str) -> Option<ServiceType> {
+    ServiceType::all()
+        .into_iter()
+        .find(|service_type| service_slug(*service_type) == slug)
+}
+
+/// Percent-encode the characters that would break the path apart
+fn escape(component: &str) -> String {
+    component.replace('%', "%25").replace('/', "%2F")
+}
+
+fn unescape(component: &str) -> Result<String, String> {
+    let mut unescaped = String::with_capacity(component.len());
+    let mut rest = component;
+    while let Some(index) = rest.find('%') {
+        unescaped.push_str(&rest[..index]);
+        match rest.get(index + 1..index + 3) {
+            Some("25") => unescaped.push('%'),
+            Some("2F") | Some("2f") => unescaped.push('/'),
+            _ => return Err(format!("Invalid escape in view link part '{}'", component)),
+        }
+        rest = &rest[index + 3..];
+    }
+    unescaped.push_str(rest);
+    Ok(unescaped)
+}
@@ -1,44 +1,173 @@
+use crate::aws::demo::{DemoEc2, DemoEks, DemoIam, DemoRds, DemoS3, DemoSecrets};
+use crate::aws::services::ec2::{Ec2Api, Ec2Client};
+use crate::aws::services::eks::{EksApi, EksClient};
+use crate::aws::services::iam::{IamApi, IamClient};
+use crate::aws::services::rds::{RdsApi, RdsClient};
+use crate::aws::services::s3::{S3Api, S3Client};
+use crate::aws::services::secrets::{SecretsApi, SecretsClient};
 use crate::utils::error::Result;
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
 
+/// Future returned by the service API traits, boxed so the traits stay object safe
+pub type ApiFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+
+#[derive(Debug)]
 pub struct MultiRegionAwsClients {
     current_region: String,
     current_profile: String,
+    /// Clients already built for the current profile, keyed by region
+    clients: HashMap<String, RegionClients>,
+    /// When the credentials the clients were built with stop working; `None`
+    /// for long-lived access keys
+    credentials_expiry: Option<DateTime<Utc>>,
+    /// Build demo clients instead of real ones for every region
+    demo: bool,
 }
 
+/// Service clients bound to one region. Each client sits behind its API trait so
+/// command logic can be exercised against fakes in tests.
+#[derive(Clone)]
 pub struct RegionClients {
-    // AWS clients will be added back in Phase 2
     pub region: String,
+    pub ec2: Arc<dyn Ec2Api>,
+    pub s3: Arc<dyn S3Api>,
+    pub rds: Arc<dyn RdsApi>,
+    pub eks: Arc<dyn EksApi>,
+    pub iam: Arc<dyn IamApi>,
+    pub secrets: Arc<dyn SecretsApi>,
+}
+
+impl RegionClients {
+    pub fn new(region: &str) -> Self {
+        // This would resolve credentials and construct the SDK clients
+        // For Phase 1, the clients only record the region
+        Self {
+            region: region.to_string(),
+            ec2: Arc::new(Ec2Client::new(region)),
+            s3: Arc::new(S3Client::new(region)),
+            rds: Arc::new(RdsClient::new(region)),
+            eks: Arc::new(EksClient::new(region)),
+            iam: Arc::new(IamClient::new()),
+            secrets: Arc::new(SecretsClient::new(region)),
+        }
+    }
+
+    /// Clients that never reach AWS and answer with fixed demo data
+    pub fn demo(region: &str) -> Self {
+        Self {
+            region: region.to_string(),
+            ec2: Arc::new(DemoEc2::new(region)),
+            s3: Arc::new(DemoS3::new(region)),
+            rds: Arc::new(DemoRds::new(region)),
+            eks: Arc::new(DemoEks::new(region)),
+            iam: Arc::new(DemoIam),
+            secrets: Arc::new(DemoSecrets::new(region)),
+        }
+    }
+}
+
+impl fmt::Debug for RegionClients {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RegionClients")
+            .field("region", &self.region)
+            .finish_non_exhaustive()
+    }
 }
 
 impl MultiRegionAwsClients {
     pub async fn new(profile: &str, region: &str) -> Result<Self> {
-        Ok(Self {
+        let mut clients = Self {
             current_region: region.to_string(),
             current_profile: profile.to_string(),
-        })
+            clients: HashMap::new(),
+            credentials_expiry: None,
+            demo: false,
+        };
+        clients.client_for_region(region);
+        Ok(clients)
+    }
+
+    /// Demo clients for every region, built without resolving credentials
+    pub fn demo(profile: &str, region: &str) -> Self {
+        let mut clients = Self {
+            current_region: region.to_string(),
+            current_profile: profile.to_string(),
+            clients: HashMap::new(),
+            credentials_expiry: None,
+            demo: true,
+        };
+        clients.client_for_region(region);
+        clients
     }
 
     pub async fn switch_region(&mut self, region: &str) -> Result<()> {
-        self.current_region = region.to_string();
+        self.select_region(region);
         Ok(())
     }
 
+    /// Make `region` current, building its clients if needed
+    pub fn select_region(&mut self, region: &str) {
+        self.client_for_region(region);
+        self.current_region = region.to_string();
+    }
+
+    /// Switching profile drops every cached client, since they carry the old credentials
     pub async fn switch_profile(&mut self, profile: &str) -> Result<()> {
         self.current_profile = profile.to_string();
+        self.clients.clear();
+        self.credentials_expiry = None;
+        let region = self.current_region.clone();
+        self.client_for_region(&region);
         Ok(())
     }
 
-    pub fn get_current_clients(&self) -> Option<RegionClients> {
-        Some(RegionClients {
-            region: self.current_region.clone(),
+    /// Clients for `region`, built on first use and reused afterwards
+    pub fn client_for_region(&mut self, region: &str) -> &RegionClients {
+        let profile = &self.current_profile;
+        let demo = self.demo;
+        self.clients.entry(region.to_string()).or_insert_with(|| {
+            tracing::debug!("Building AWS clients for {} ({})", region, profile);
+            if demo {
+                RegionClients::demo(region)
+            } else {
+                RegionClients::new(region)
+            }
         })
     }
 
+    /// Use prebuilt clients for their region, e.g. fakes in tests
+    #[cfg(test)]
+    pub fn with_region_clients(profile: &str, clients: RegionClients) -> Self {
+        let region = clients.region.clone();
+        Self {
+            current_region: region.clone(),
+            current_profile: profile.to_string(),
+            clients: HashMap::from([(region, clients)]),
+            credentials_expiry: None,
+            demo: false,
+        }
+    }
+
+    pub fn credentials_expiry(&self) -> Option<DateTime<Utc>> {
+        self.credentials_expiry
+    }
+
+    /// Record when the temporary credentials behind the clients expire
+    pub fn set_credentials_expiry(&mut self, expiry: Option<DateTime<Utc>>) {
+        self.credentials_expiry = expiry;
+    }
+
+    pub fn get_current_clients(&self) -> Option<RegionClients> {
+        self.clients.get(&self.current_region).cloned()
+    }
+
     pub fn get_clients_for_region(&self, region: &str) -> Option<RegionClients> {
-        Some(RegionClients {
-            region: region.to_string(),
-        })
+        self.clients.get(region).cloned()
     }
 
     pub fn current_region(&self) -> &str {
@@ -0,0 +1,56 @@
+use crate::aws::types::ServiceType;
+
+/// Build the AWS Console deep-link for a resource
+pub fn console_url(service_type: ServiceType, region: &str, resource_id: &str) -> String {
+    let id = encode_component(resource_id);
+
+    match service_type {
+        ServiceType::EC2 => format!(
+            "https://{}.console.aws.amazon.com/ec2/home?region={}#InstanceDetails:instanceId={}",
+            region, region, id
+        ),
+        ServiceType::S3 => format!(
+            "https://s3.console.aws.amazon.com/s3/buckets/{}?region={}",
+            id, region
+        ),
+        ServiceType::RDS => format!(
+            "https://{}.console.aws.amazon.com/rds/home?region={}#database:id={}",
+            region, region, id
+        ),
+        // IAM is a global service, so the console link carries no region
+        ServiceType::IAM => format!("https://console.aws.amazon.com/iam/home#/users/{}", id),
+        ServiceType::Secrets => format!(
+            "https://{}.console.aws.amazon.com/secretsmanager/secret?name={}&region={}",
+            region, id, region
+        ),
+        ServiceType::EKS => format!(
+            "https://{}.console.aws.amazon.com/eks/home?region={}#/clusters/{}",
+            region, region, id
+        ),
+    }
+}
+
+/// Landing page of a service's AWS documentation
+pub fn docs_url(service_type: ServiceType) -> &'static str {
+    match service_type {
+        ServiceType::EC2 => "https://docs.aws.amazon.com/ec2/",
+        ServiceType::S3 => "https://docs.aws.amazon.com/s3/",
+        ServiceType::RDS => "https://docs.aws.amazon.com/rds/",
+        ServiceType::IAM => "https://docs.aws.amazon.com/iam/",
+        ServiceType::Secrets => "https://docs.aws.amazon.com/secretsmanager/",
+        ServiceType::EKS => "https://docs.aws.amazon.com/eks/",
+    }
+}
+
+/// Percent-encode a resource id so names like `prod/db-password` survive in a URL
+fn encode_component(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
@@ -1,16 +1,49 @@
-use crate::utils::error::Result;
+use crate::aws::types::{AwsProfile, CredentialSource};
+use crate::utils::error::{AppError, Result};
+use chrono::{DateTime, Utc};
 
 pub struct CredentialsValidator {
     // STS client will be added back in Phase 2
+    profile: String,
 }
 
 impl CredentialsValidator {
-    pub fn new() -> Self {
-        Self {}
+    /// Validate a named profile rather than whatever the default credential chain resolves
+    pub fn for_profile(profile: &str) -> Self {
+        Self {
+            profile: profile.to_string(),
+        }
+    }
+
+    /// Validate the profile, keeping a failure as part of the result
+    pub async fn check_profile(profile: &str, region: Option<String>) -> ProfileCheck {
+        let outcome = Self::for_profile(profile)
+            .validate_credentials()
+            .await
+            .map_err(|e| e.to_string());
+        ProfileCheck {
+            profile: profile.to_string(),
+            region,
+            outcome,
+        }
+    }
+
+    /// Assume a role with the validator's profile as the source credentials
+    pub async fn assume_role(&self, request: &AssumeRoleRequest) -> Result<AssumedRoleCredentials> {
+        // This would call sts:AssumeRole with the source credentials
+        // For Phase 1, we'll return mock credentials valid for one hour
+        tracing::debug!("Assuming {} from {}", request.role_arn, self.profile);
+        Ok(AssumedRoleCredentials {
+            access_key_id: "ASIAMOCKASSUMEDROLE".to_string(),
+            secret_access_key: "mock-secret".to_string(),
+            session_token: "mock-session-token".to_string(),
+            expiration: Utc::now() + chrono::Duration::hours(1),
+        })
     }
 
     pub async fn validate_credentials(&self) -> Result<CredentialsInfo> {
         // For Phase 1, return mock credentials info
+        tracing::debug!("Validating credentials for {}", self.profile);
         Ok(CredentialsInfo {
             user_id: "mock-user".to_string(),
             account: "123456789012".to_string(),
@@ -20,6 +53,79 @@ impl CredentialsValidator {
     }
 }
 
+/// An STS AssumeRole call to make from the validator's profile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssumeRoleRequest {
+    pub role_arn: String,
+    pub external_id: Option<String>,
+    /// MFA device serial and the current token code
+    pub mfa: Option<(String, String)>,
+}
+
+/// Temporary credentials returned by STS AssumeRole
+#[derive(Debug, Clone)]
+pub struct AssumedRoleCredentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub session_token: String,
+    pub expiration: DateTime<Utc>,
+}
+
+impl AssumedRoleCredentials {
+    /// An in-memory profile named `<role>@<account>` that holds these credentials
+    pub fn into_profile(
+        self,
+        request: &AssumeRoleRequest,
+        source_profile: String,
+        region: Option<String>,
+    ) -> Result<AwsProfile> {
+        let (account, role) = parse_role_arn(&request.role_arn).map_err(AppError::Validation)?;
+        Ok(AwsProfile {
+            name: format!("{}@{}", role, account),
+            region,
+            access_key_id: Some(self.access_key_id),
+            secret_access_key: Some(self.secret_access_key),
+            session_token: Some(self.session_token),
+            role_arn: Some(request.role_arn.clone()),
+            source_profile: Some(source_profile.clone()),
+            mfa_serial: request.mfa.as_ref().map(|(serial, _)| serial.clone()),
+            external_id: request.external_id.clone(),
+            credential_provider: None,
+            credential_source: CredentialSource::AssumedRole {
+                source_profile,
+                expires_at: self.expiration,
+            },
+        })
+    }
+}
+
+/// The account id and role name of `arn:<partition>:iam::<account>:role/<path/name>`
+pub fn parse_role_arn(role_arn: &str) -> std::result::Result<(&str, &str), String> {
+    let invalid = || {
+        format!(
+            "'{}' is not a role ARN (arn:aws:iam::<account>:role/<name>)",
+            role_arn
+        )
+    };
+    let parts: Vec<&str> = role_arn.splitn(6, ':').collect();
+    let [arn, _partition, service, region, account, resource] = parts.as_slice() else {
+        return Err(invalid());
+    };
+    if *arn != "arn" || *service != "iam" || !region.is_empty() {
+        return Err(invalid());
+    }
+    if account.len() != 12 || !account.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    match resource
+        .strip_prefix("role/")
+        .and_then(|path| path.rsplit('/').next())
+    {
+        Some(name) if !name.is_empty() => Ok((account, name)),
+        _ => Err(invalid()),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CredentialsInfo {
     pub user_id: String,
@@ -27,3 +133,82 @@ pub struct CredentialsInfo {
     pub arn: String,
     pub valid: bool,
 }
+
+/// Validation result for one profile, e.g. one side of a profile comparison
+#[derive(Debug, Clone)]
+pub struct ProfileCheck {
+    pub profile: String,
+    pub region: Option<String>,
+    pub outcome: std::result::Result<CredentialsInfo, String>,
+}
+
+impl ProfileCheck {
+    pub fn status(&self) -> ProfileStatus {
+        match &self.outcome {
+            Ok(info) if info.valid => ProfileStatus::Valid,
+            Ok(_) => ProfileStatus::Invalid,
+            // STS answers ExpiredToken for session credentials past their expiration
+            Err(message) if message.contains("ExpiredToken") || message.contains("expired") => {
+                ProfileStatus::Expired
+            }
+            Err(_) => ProfileStatus::Invalid,
+        }
+    }
+}
+
+/// How a profile's credentials fared against STS GetCallerIdentity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileStatus {
+    Valid,
+    Invalid,
+    Expired,
+}
+
+impl ProfileStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProfileStatus::Valid => "valid",
+            ProfileStatus::Invalid => "invalid",
+            ProfileStatus::Expired => "expired",
+        }
+    }
+}
+
+/// Every profile validated in one run, sorted by profile name
+#[derive(Debug, Clone)]
+pub struct ProfileValidation {
+    pub checks: Vec<ProfileCheck>,
+    pub checked_at: DateTime<Utc>,
+}
+
+impl ProfileValidation {
+    pub fn count(&self, status: ProfileStatus) -> usize {
+        self.checks
+            .iter()
+            .filter(|check| check.status() == status)
+            .count()
+    }
+
+    /// The report as tab-separated lines with a header, for pasting elsewhere
+    pub fn to_tsv(&self) -> String {
+        let mut lines = vec!["profile\taccount\tarn\tregion\tstatus\terror".to_string()];
+        for check in &self.checks {
+            let (account, arn, error) = match &check.outcome {
+                Ok(info) => (info.account.as_str(), info.arn.as_str(), ""),
+                Err(message) => ("", "", message.as_str()),
+            };
+            lines.push(
+                [
+                    check.profile.as_str(),
+                    account,
+                    arn,
+                    check.region.as_deref().unwrap_or(""),
+                    check.status().label(),
+                    error,
+                ]
+                .join("\t"),
+            );
+        }
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,589 @@
+use crate::aws::client::ApiFuture;
+use crate::aws::services::ec2::{default_region_statuses, Ec2Api};
+use crate::aws::services::eks::{creating_cluster, EksApi};
+use crate::aws::services::iam::{IamApi, IamPage};
+use crate::aws::services::rds::RdsApi;
+use crate::aws::services::s3::{CreateBucketRequest, S3Api};
+use crate::aws::services::secrets::SecretsApi;
+use crate::aws::types::{
+    AwsProfile, CredentialSource, Ec2Instance, EksCluster, EksNodeGroup, IamRole, IamUser,
+    InstanceLaunch, RdsInstance, RegionStatus, ResourceEvent, S3Bucket, Secret, SecurityGroup,
+    SecurityGroupRule,
+};
+use chrono::{DateTime, TimeZone, Utc};
+
+/// Name of the profile demo mode runs under
+pub const DEMO_PROFILE: &str = "demo";
+
+/// The only profile offered in demo mode, so no real credentials are read
+pub fn profile() -> AwsProfile {
+    AwsProfile {
+        name: DEMO_PROFILE.to_string(),
+        region: None,
+        access_key_id: None,
+        secret_access_key: None,
+        session_token: None,
+        role_arn: None,
+        source_profile: None,
+        mfa_serial: None,
+        external_id: None,
+        credential_provider: None,
+        credential_source: CredentialSource::ConfigFile(DEMO_PROFILE.to_string()),
+    }
+}
+
+/// Fixed timestamps keep screenshots identical between runs
+fn at(month: u32, day: u32, hour: u32) -> Option<DateTime<Utc>> {
+    Utc.with_ymd_and_hms(2024, month, day, hour, 30, 0).single()
+}
+
+pub fn ec2_instances(region: &str) -> Vec<Ec2Instance> {
+    let instance = |id: &str, instance_type: &str, state: &str, zone: &str, ip: u8| Ec2Instance {
+        instance_id: id.to_string(),
+        instance_type: instance_type.to_string(),
+        state: state.to_string(),
+        availability_zone: format!("{}{}", region, zone),
+        public_ip: (state == "running").then(|| format!("54.1.2.{}", ip)),
+        private_ip: Some(format!("10.0.1.{}", ip)),
+        vpc_id: Some("vpc-12345678".to_string()),
+        subnet_id: Some(format!("subnet-abcdef1{}", zone)),
+        security_groups: vec!["sg-web-servers".to_string(), "sg-default".to_string()],
+        image_id: Some("ami-0abcdef1234567890".to_string()),
+        tags: [
+            ("Name".to_string(), format!("web-{}", ip)),
+            ("Environment".to_string(), "demo".to_string()),
+        ]
+        .into_iter()
+        .collect(),
+        volume_ids: vec![format!("vol-0{}a1b2c3d4e5f6", ip)],
+        launch_time: at(1, 15, 10),
+        state_transition_reason: (state == "stopped")
+            .then(|| "User initiated (2024-02-01 18:00:00 GMT)".to_string()),
+    };
+    vec![
+        instance("i-1234567890abcdef0", "t3.medium", "running", "a", 5),
+        instance("i-0987654321fedcba9", "t3.large", "running", "b", 6),
+        instance("i-abcdef1234567890", "m5.xlarge", "stopped", "a", 7),
+    ]
+}
+
+pub fn security_groups(group_ids: &[String]) -> Vec<SecurityGroup> {
+    group_ids
+        .iter()
+        .map(|group_id| {
+            let inbound_rules = if group_id == "sg-web-servers" {
+                vec![
+                    rule("tcp", 443, "0.0.0.0/0", "HTTPS"),
+                    rule("tcp", 80, "0.0.0.0/0", "HTTP"),
+                ]
+            } else {
+                vec![rule("tcp", 22, "10.0.0.0/16", "SSH from the VPC")]
+            };
+            SecurityGroup {
+                group_id: group_id.clone(),
+                group_name: group_id.trim_start_matches("sg-").to_string(),
+                description: format!("Demo group {}", group_id),
+                inbound_rules,
+                outbound_rules: vec![SecurityGroupRule {
+                    protocol: "-1".to_string(),
+                    from_port: None,
+                    to_port: None,
+                    sources: vec!["0.0.0.0/0".to_string()],
+                    description: None,
+                }],
+            }
+        })
+        .collect()
+}
+
+fn rule(protocol: &str, port: i32, source: &str, description: &str) -> SecurityGroupRule {
+    SecurityGroupRule {
+        protocol: protocol.to_string(),
+        from_port: Some(port),
+        to_port: Some(port),
+        sources: vec![source.to_string()],
+        description: Some(description.to_string()),
+    }
+}
+
+pub fn s3_buckets(region: &str) -> Vec<S3Bucket> {
+    let bucket = |name: &str, versioning: bool, objects: u64| S3Bucket {
+        name: name.to_string(),
+        region: region.to_string(),
+        creation_date: at(1, 1, 9),
+        versioning,
+        encryption: true,
+        public_read: false,
+        size: Some(objects * 512 * 1024),
+        object_count: Some(objects),
+    };
+    vec![
+        bucket("assets-prod-bucket", true, 1_250),
+        bucket("logs-bucket", false, 48_000),
+    ]
+}
+
+pub fn rds_instances(region: &str) -> Vec<RdsInstance> {
+    vec![RdsInstance {
+        db_instance_identifier: "db-prod-mysql".to_string(),
+        db_instance_class: "db.r6g.large".to_string(),
+        engine: "mysql".to_string(),
+        engine_version: "8.0.35".to_string(),
+        db_instance_status: "available".to_string(),
+        allocated_storage: 100,
+        availability_zone: format!("{}a", region),
+        endpoint: Some(format!("db-prod-mysql.abc123.{}.rds.amazonaws.com", region)),
+        port: Some(3306),
+        vpc_security_groups: vec!["sg-default".to_string()],
+        db_subnet_group: Some("prod-db-subnets".to_string()),
+        enabled_cloudwatch_logs_exports: vec!["error".to_string(), "slowquery".to_string()],
+    }]
+}
+
+pub fn iam_users() -> Vec<IamUser> {
+    vec![IamUser {
+        user_name: "admin-user".to_string(),
+        user_id: "AIDAEXAMPLE1234567890".to_string(),
+        arn: "arn:aws:iam::123456789012:user/admin-user".to_string(),
+        path: "/".to_string(),
+        create_date: at(1, 2, 8),
+        password_last_used: at(3, 10, 14),
+        attached_policies: vec!["AdministratorAccess".to_string()],
+        groups: vec!["admins".to_string()],
+    }]
+}
+
+pub fn iam_roles() -> Vec<IamRole> {
+    let role = |name: &str, month: u32| IamRole {
+        role_name: name.to_string(),
+        arn: format!("arn:aws:iam::123456789012:role/{}", name),
+        create_date: at(month, 5, 9),
+    };
+    vec![
+        role("eks-cluster-role", 1),
+        role("eks-node-role", 1),
+        role("ci-deploy", 2),
+    ]
+}
+
+pub fn secrets(region: &str) -> Vec<Secret> {
+    vec![Secret {
+        name: "db-password".to_string(),
+        arn: format!(
+            "arn:aws:secretsmanager:{}:123456789012:secret:db-password-AbCdEf",
+            region
+        ),
+        description: Some("Password of the production database".to_string()),
+        created_date: at(1, 15, 11),
+        last_accessed_date: at(3, 10, 0),
+        last_changed_date: at(2, 1, 12),
+        version_id: Some("a1b2c3d4-5678-90ab-cdef-EXAMPLE11111".to_string()),
+        rotation_enabled: false,
+        rotation_lambda_arn: None,
+    }]
+}
+
+pub fn eks_clusters(region: &str) -> Vec<EksCluster> {
+    vec![EksCluster {
+        name: "production-cluster".to_string(),
+        arn: format!(
+            "arn:aws:eks:{}:123456789012:cluster/production-cluster",
+            region
+        ),
+        version: Some("1.29".to_string()),
+        endpoint: Some("https://ABCDEF1234567890.gr7.eks.amazonaws.com".to_string()),
+        role_arn: Some("arn:aws:iam::123456789012:role/eks-cluster-role".to_string()),
+        status: "ACTIVE".to_string(),
+        created_at: at(1, 20, 16),
+        platform_version: Some("eks.6".to_string()),
+        vpc_config: Some("vpc-12345678".to_string()),
+        enabled_log_types: vec!["api".to_string(), "audit".to_string()],
+    }]
+}
+
+pub fn eks_node_groups(cluster_name: &str) -> Vec<EksNodeGroup> {
+    if cluster_name != "production-cluster" {
+        return vec![];
+    }
+    vec![
+        EksNodeGroup {
+            name: "general".to_string(),
+            status: "ACTIVE".to_string(),
+            desired_size: 3,
+            min_size: 2,
+            max_size: 6,
+            instance_types: vec!["m5.large".to_string()],
+            capacity_type: Some("ON_DEMAND".to_string()),
+        },
+        EksNodeGroup {
+            name: "batch-spot".to_string(),
+            status: "ACTIVE".to_string(),
+            desired_size: 2,
+            min_size: 0,
+            max_size: 10,
+            instance_types: vec!["c5.xlarge".to_string(), "c5a.xlarge".to_string()],
+            capacity_type: Some("SPOT".to_string()),
+        },
+    ]
+}
+
+/// EC2 API answering from `ec2_instances`; actions succeed without changing anything
+pub struct DemoEc2 {
+    region: String,
+}
+
+impl DemoEc2 {
+    pub fn new(region: &str) -> Self {
+        Self {
+            region: region.to_string(),
+        }
+    }
+}
+
+impl Ec2Api for DemoEc2 {
+    fn list_instances(&self) -> ApiFuture<'_, Vec<Ec2Instance>> {
+        Box::pin(async move { Ok(ec2_instances(&self.region)) })
+    }
+
+    fn describe_availability_zones(&self) -> ApiFuture<'_, Vec<String>> {
+        Box::pin(async move {
+            Ok(["a", "b", "c"]
+                .iter()
+                .map(|suffix| format!("{}{}", self.region, suffix))
+                .collect())
+        })
+    }
+
+    fn describe_regions(&self) -> ApiFuture<'_, Vec<RegionStatus>> {
+        // The demo account has opted in to Milan
+        Box::pin(async {
+            let mut statuses = default_region_statuses();
+            if let Some(status) = statuses.iter_mut().find(|s| s.name == "eu-south-1") {
+                status.opt_in_status = "opted-in".to_string();
+            }
+            Ok(statuses)
+        })
+    }
+
+    fn get_instance<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, Option<Ec2Instance>> {
+        Box::pin(async move {
+            Ok(ec2_instances(&self.region)
+                .into_iter()
+                .find(|instance| instance.instance_id == instance_id))
+        })
+    }
+
+    fn run_instance<'a>(&'a self, _launch: &'a InstanceLaunch) -> ApiFuture<'a, String> {
+        Box::pin(async { Ok("i-0c1a2b3c4d5e6f708".to_string()) })
+    }
+
+    fn start_instance<'a>(&'a self, _instance_id: &'a str) -> ApiFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn stop_instance<'a>(&'a self, _instance_id: &'a str) -> ApiFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn terminate_instance<'a>(&'a self, _instance_id: &'a str) -> ApiFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn reboot_instance<'a>(&'a self, _instance_id: &'a str) -> ApiFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn describe_instance_status<'a>(
+        &'a self,
+        _instance_id: &'a str,
+    ) -> ApiFuture<'a, Vec<ResourceEvent>> {
+        Box::pin(async {
+            Ok(vec![
+                ResourceEvent::new(
+                    at(3, 10, 12),
+                    "Status check",
+                    "Instance reachability passed",
+                ),
+                ResourceEvent::new(at(3, 10, 12), "Status check", "System reachability passed"),
+            ])
+        })
+    }
+
+    fn describe_security_groups<'a>(
+        &'a self,
+        group_ids: &'a [String],
+    ) -> ApiFuture<'a, Vec<SecurityGroup>> {
+        Box::pin(async move { Ok(security_groups(group_ids)) })
+    }
+}
+
+/// S3 API answering from `s3_buckets`; actions succeed without changing anything
+pub struct DemoS3 {
+    region: String,
+}
+
+impl DemoS3 {
+    pub fn new(region: &str) -> Self {
+        Self {
+            region: region.to_string(),
+        }
+    }
+}
+
+impl S3Api for DemoS3 {
+    fn list_buckets(&self) -> ApiFuture<'_, Vec<S3Bucket>> {
+        Box::pin(async move { Ok(s3_buckets(&self.region)) })
+    }
+
+    fn get_bucket<'a>(&'a self, bucket_name: &'a str) -> ApiFuture<'a, Option<S3Bucket>> {
+        Box::pin(async move {
+            Ok(s3_buckets(&self.region)
+                .into_iter()
+                .find(|bucket| bucket.name == bucket_name))
+        })
+    }
+
+    fn create_bucket<'a>(&'a self, _request: &'a CreateBucketRequest) -> ApiFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn delete_bucket<'a>(&'a self, _bucket_name: &'a str) -> ApiFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// RDS API answering from `rds_instances`; actions succeed without changing anything
+pub struct DemoRds {
+    region: String,
+}
+
+impl DemoRds {
+    pub fn new(region: &str) -> Self {
+        Self {
+            region: region.to_string(),
+        }
+    }
+}
+
+impl RdsApi for DemoRds {
+    fn list_instances(&self) -> ApiFuture<'_, Vec<RdsInstance>> {
+        Box::pin(async move { Ok(rds_instances(&self.region)) })
+    }
+
+    fn get_instance<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, Option<RdsInstance>> {
+        Box::pin(async move {
+            Ok(rds_instances(&self.region)
+                .into_iter()
+                .find(|instance| instance.db_instance_identifier == instance_id))
+        })
+    }
+
+    fn describe_events<'a>(&'a self, _instance_id: &'a str) -> ApiFuture<'a, Vec<ResourceEvent>> {
+        Box::pin(async { Ok(vec![]) })
+    }
+
+    fn create_instance<'a>(&'a self, _instance_id: &'a str) -> ApiFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn delete_instance<'a>(&'a self, _instance_id: &'a str) -> ApiFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// EKS API answering from `eks_clusters` and `eks_node_groups`; actions succeed without
+/// changing anything
+pub struct DemoEks {
+    region: String,
+}
+
+impl DemoEks {
+    pub fn new(region: &str) -> Self {
+        Self {
+            region: region.to_string(),
+        }
+    }
+}
+
+impl EksApi for DemoEks {
+    fn list_clusters(&self) -> ApiFuture<'_, Vec<EksCluster>> {
+        Box::pin(async move { Ok(eks_clusters(&self.region)) })
+    }
+
+    fn get_cluster<'a>(&'a self, cluster_name: &'a str) -> ApiFuture<'a, Option<EksCluster>> {
+        Box::pin(async move {
+            Ok(eks_clusters(&self.region)
+                .into_iter()
+                .find(|cluster| cluster.name == cluster_name))
+        })
+    }
+
+    fn list_nodegroups<'a>(&'a self, cluster_name: &'a str) -> ApiFuture<'a, Vec<String>> {
+        Box::pin(async move {
+            Ok(eks_node_groups(cluster_name)
+                .into_iter()
+                .map(|group| group.name)
+                .collect())
+        })
+    }
+
+    fn describe_nodegroup<'a>(
+        &'a self,
+        cluster_name: &'a str,
+        nodegroup_name: &'a str,
+    ) -> ApiFuture<'a, Option<EksNodeGroup>> {
+        Box::pin(async move {
+            Ok(eks_node_groups(cluster_name)
+                .into_iter()
+                .find(|group| group.name == nodegroup_name))
+        })
+    }
+
+    fn update_nodegroup_size<'a>(
+        &'a self,
+        _cluster_name: &'a str,
+        nodegroup_name: &'a str,
+        desired_size: i32,
+    ) -> ApiFuture<'a, String> {
+        Box::pin(async move { Ok(format!("update-{}-{}", nodegroup_name, desired_size)) })
+    }
+
+    fn describe_update<'a>(
+        &'a self,
+        _cluster_name: &'a str,
+        _nodegroup_name: &'a str,
+        _update_id: &'a str,
+    ) -> ApiFuture<'a, String> {
+        Box::pin(async { Ok("InProgress".to_string()) })
+    }
+
+    fn cluster_updates<'a>(&'a self, _cluster_name: &'a str) -> ApiFuture<'a, Vec<ResourceEvent>> {
+        Box::pin(async { Ok(vec![]) })
+    }
+
+    fn create_cluster<'a>(&'a self, cluster_name: &'a str) -> ApiFuture<'a, EksCluster> {
+        Box::pin(async move { Ok(creating_cluster(&self.region, cluster_name)) })
+    }
+
+    fn delete_cluster<'a>(&'a self, _cluster_name: &'a str) -> ApiFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// IAM API answering from `iam_users` and `iam_roles`; actions succeed without changing
+/// anything
+pub struct DemoIam;
+
+impl IamApi for DemoIam {
+    fn list_account_aliases(&self) -> ApiFuture<'_, Vec<String>> {
+        Box::pin(async { Ok(vec![]) })
+    }
+
+    fn simulate_principal_policy<'a>(
+        &'a self,
+        _policy_source_arn: &'a str,
+        actions: &'a [String],
+    ) -> ApiFuture<'a, Vec<(String, bool)>> {
+        Box::pin(async move {
+            Ok(actions
+                .iter()
+                .map(|action| (action.clone(), true))
+                .collect())
+        })
+    }
+
+    fn list_users_page<'a>(&'a self, marker: Option<&'a str>) -> ApiFuture<'a, IamPage<IamUser>> {
+        Box::pin(async move { Ok(IamPage::slice(&iam_users(), marker)) })
+    }
+
+    fn list_roles_page<'a>(&'a self, marker: Option<&'a str>) -> ApiFuture<'a, IamPage<IamRole>> {
+        Box::pin(async move { Ok(IamPage::slice(&iam_roles(), marker)) })
+    }
+
+    fn get_user<'a>(&'a self, user_name: &'a str) -> ApiFuture<'a, Option<IamUser>> {
+        Box::pin(async move {
+            Ok(iam_users()
+                .into_iter()
+                .find(|user| user.user_name == user_name))
+        })
+    }
+
+    fn get_role<'a>(&'a self, role_name: &'a str) -> ApiFuture<'a, Option<IamRole>> {
+        Box::pin(async move {
+            Ok(iam_roles()
+                .into_iter()
+                .find(|role| role.role_name == role_name))
+        })
+    }
+
+    fn list_groups_for_user<'a>(&'a self, user_name: &'a str) -> ApiFuture<'a, Vec<String>> {
+        Box::pin(async move {
+            Ok(iam_users()
+                .into_iter()
+                .find(|user| user.user_name == user_name)
+                .map(|user| user.groups)
+                .unwrap_or_default())
+        })
+    }
+
+    fn list_attached_user_policies<'a>(&'a self, user_name: &'a str) -> ApiFuture<'a, Vec<String>> {
+        Box::pin(async move {
+            Ok(iam_users()
+                .into_iter()
+                .find(|user| user.user_name == user_name)
+                .map(|user| user.attached_policies)
+                .unwrap_or_default())
+        })
+    }
+
+    fn create_user<'a>(&'a self, _user_name: &'a str) -> ApiFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn delete_user<'a>(&'a self, _user_name: &'a str) -> ApiFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Secrets Manager API answering from `secrets`; actions succeed without changing anything
+pub struct DemoSecrets {
+    region: String,
+}
+
+impl DemoSecrets {
+    pub fn new(region: &str) -> Self {
+        Self {
+            region: region.to_string(),
+        }
+    }
+}
+
+impl SecretsApi for DemoSecrets {
+    fn list_secrets(&self) -> ApiFuture<'_, Vec<Secret>> {
+        Box::pin(async move { Ok(secrets(&self.region)) })
+    }
+
+    fn get_secret<'a>(&'a self, secret_name: &'a str) -> ApiFuture<'a, Option<Secret>> {
+        Box::pin(async move {
+            Ok(secrets(&self.region)
+                .into_iter()
+                .find(|secret| secret.name == secret_name))
+        })
+    }
+
+    fn create_secret<'a>(
+        &'a self,
+        _secret_name: &'a str,
+        _secret_value: &'a str,
+    ) -> ApiFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn delete_secret<'a>(&'a self, _secret_name: &'a str) -> ApiFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+
+    fn rotate_secret<'a>(&'a self, _secret_name: &'a str) -> ApiFuture<'a, Option<String>> {
+        Box::pin(async { Ok(None) })
+    }
+}
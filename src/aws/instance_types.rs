@@ -0,0 +1,82 @@
+//! Specs and list prices of common EC2 instance types, embedded so the detail
+//! page can describe an instance without a DescribeInstanceTypes call
+
+/// Region whose Linux on-demand prices the table carries
+pub const PRICE_REGION: &str = "us-east-1";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InstanceTypeSpec {
+    pub name: &'static str,
+    pub vcpus: u32,
+    pub memory_gib: f32,
+    /// Approximate on-demand price in USD per hour in `PRICE_REGION`
+    pub hourly_usd: f64,
+}
+
+const fn spec(
+    name: &'static str,
+    vcpus: u32,
+    memory_gib: f32,
+    hourly_usd: f64,
+) -> InstanceTypeSpec {
+    InstanceTypeSpec {
+        name,
+        vcpus,
+        memory_gib,
+        hourly_usd,
+    }
+}
+
+const INSTANCE_TYPES: &[InstanceTypeSpec] = &[
+    spec("t2.nano", 1, 0.5, 0.0058),
+    spec("t2.micro", 1, 1.0, 0.0116),
+    spec("t2.small", 1, 2.0, 0.023),
+    spec("t2.medium", 2, 4.0, 0.0464),
+    spec("t2.large", 2, 8.0, 0.0928),
+    spec("t2.xlarge", 4, 16.0, 0.1856),
+    spec("t3.nano", 2, 0.5, 0.0052),
+    spec("t3.micro", 2, 1.0, 0.0104),
+    spec("t3.small", 2, 2.0, 0.0208),
+    spec("t3.medium", 2, 4.0, 0.0416),
+    spec("t3.large", 2, 8.0, 0.0832),
+    spec("t3.xlarge", 4, 16.0, 0.1664),
+    spec("t3.2xlarge", 8, 32.0, 0.3328),
+    spec("t3a.micro", 2, 1.0, 0.0094),
+    spec("t3a.small", 2, 2.0, 0.0188),
+    spec("t3a.medium", 2, 4.0, 0.0376),
+    spec("t3a.large", 2, 8.0, 0.0752),
+    spec("t4g.micro", 2, 1.0, 0.0084),
+    spec("t4g.small", 2, 2.0, 0.0168),
+    spec("t4g.medium", 2, 4.0, 0.0336),
+    spec("t4g.large", 2, 8.0, 0.0672),
+    spec("m5.large", 2, 8.0, 0.096),
+    spec("m5.xlarge", 4, 16.0, 0.192),
+    spec("m5.2xlarge", 8, 32.0, 0.384),
+    spec("m5.4xlarge", 16, 64.0, 0.768),
+    spec("m6i.large", 2, 8.0, 0.096),
+    spec("m6i.xlarge", 4, 16.0, 0.192),
+    spec("m6i.2xlarge", 8, 32.0, 0.384),
+    spec("m7g.large", 2, 8.0, 0.0816),
+    spec("m7g.xlarge", 4, 16.0, 0.1632),
+    spec("c5.large", 2, 4.0, 0.085),
+    spec("c5.xlarge", 4, 8.0, 0.17),
+    spec("c5.2xlarge", 8, 16.0, 0.34),
+    spec("c5.4xlarge", 16, 32.0, 0.68),
+    spec("c5a.xlarge", 4, 8.0, 0.154),
+    spec("c6i.large", 2, 4.0, 0.085),
+    spec("c6i.xlarge", 4, 8.0, 0.17),
+    spec("c7g.large", 2, 4.0, 0.0725),
+    spec("c7g.xlarge", 4, 8.0, 0.145),
+    spec("r5.large", 2, 16.0, 0.126),
+    spec("r5.xlarge", 4, 32.0, 0.252),
+    spec("r5.2xlarge", 8, 64.0, 0.504),
+    spec("r6i.large", 2, 16.0, 0.126),
+    spec("r6i.xlarge", 4, 32.0, 0.252),
+];
+
+/// The spec of a known instance type; `None` for types missing from the table
+pub fn lookup(instance_type: &str) -> Option<&'static InstanceTypeSpec> {
+    INSTANCE_TYPES
+        .iter()
+        .find(|spec| spec.name == instance_type)
+}
@@ -1,8 +1,18 @@
 pub mod client;
+pub mod console;
 pub mod credentials;
+/// Deterministic data in place of AWS responses, for `--demo`
+pub mod demo;
+pub mod instance_types;
+pub mod permissions;
 pub mod profiles;
 pub mod regions;
 pub mod tagging;
 pub mod types;
 
 pub mod services;
+
+#[cfg(test)]
+mod profiles_test;
+#[cfg(test)]
+mod tagging_test;
@@ -0,0 +1,128 @@
+use crate::aws::client::RegionClients;
+use crate::aws::credentials::CredentialsValidator;
+use crate::aws::services::iam::IamService;
+use crate::aws::types::ServiceType;
+
+/// Whether the current identity is expected to be allowed an action
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermissionVerdict {
+    Allowed,
+    Denied,
+    Unknown,
+}
+
+/// The verdict for the action a service's resource listing needs
+#[derive(Debug, Clone)]
+pub struct PermissionCheck {
+    pub service_type: ServiceType,
+    pub action: &'static str,
+    pub verdict: PermissionVerdict,
+    /// Why the verdict was reached when it did not come from the policy simulation
+    pub note: Option<String>,
+}
+
+/// Best-effort summary of what the active identity can do in NimbusCTL
+#[derive(Debug, Clone)]
+pub struct PermissionReport {
+    pub profile: String,
+    pub principal_arn: Option<String>,
+    /// Whether the verdicts come from SimulatePrincipalPolicy; when it is not
+    /// allowed either, only previously observed denials are known
+    pub simulated: bool,
+    pub checks: Vec<PermissionCheck>,
+    pub checked_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl PermissionReport {
+    /// Services whose commands are expected to fail
+    pub fn denied_services(&self) -> impl Iterator<Item = ServiceType> + '_ {
+        self.checks
+            .iter()
+            .filter(|check| check.verdict == PermissionVerdict::Denied)
+            .map(|check| check.service_type)
+    }
+
+    /// Mark services that already answered with AccessDenied, which the simulation
+    /// misses when the denial comes from an SCP or permissions boundary
+    pub fn merge_observed_denials(&mut self, denied: impl IntoIterator<Item = ServiceType>) {
+        for service_type in denied {
+            if let Some(check) = self
+                .checks
+                .iter_mut()
+                .find(|check| check.service_type == service_type)
+            {
+                if check.verdict != PermissionVerdict::Denied {
+                    check.verdict = PermissionVerdict::Denied;
+                    check.note = Some("AccessDenied seen while listing".to_string());
+                }
+            }
+        }
+    }
+}
+
+/// The IAM action listing a service's resources requires
+pub fn probe_action(service_type: ServiceType) -> &'static str {
+    match service_type {
+        ServiceType::EC2 => "ec2:DescribeInstances",
+        ServiceType::S3 => "s3:ListAllMyBuckets",
+        ServiceType::RDS => "rds:DescribeDBInstances",
+        ServiceType::IAM => "iam:ListUsers",
+        ServiceType::Secrets => "secretsmanager:ListSecrets",
+        ServiceType::EKS => "eks:ListClusters",
+    }
+}
+
+/// Simulate each service's probe action for the identity behind `profile`
+pub async fn diagnose_permissions(clients: &RegionClients, profile: &str) -> PermissionReport {
+    let principal_arn = CredentialsValidator::for_profile(profile)
+        .validate_credentials()
+        .await
+        .ok()
+        .map(|info| info.arn);
+
+    let actions: Vec<String> = ServiceType::all()
+        .into_iter()
+        .map(|service_type| probe_action(service_type).to_string())
+        .collect();
+
+    let decisions = match &principal_arn {
+        Some(arn) => IamService::new(clients)
+            .simulate_principal_policy(arn, &actions)
+            .await
+            .map_err(|e| e.to_string()),
+        None => Err("could not resolve the caller identity".to_string()),
+    };
+
+    let simulated = decisions.is_ok();
+    let checks = ServiceType::all()
+        .into_iter()
+        .map(|service_type| {
+            let action = probe_action(service_type);
+            let (verdict, note) = match &decisions {
+                Ok(decisions) => match decisions.iter().find(|(name, _)| name == action) {
+                    Some((_, true)) => (PermissionVerdict::Allowed, None),
+                    Some((_, false)) => (PermissionVerdict::Denied, None),
+                    None => (PermissionVerdict::Unknown, None),
+                },
+                Err(e) => (
+                    PermissionVerdict::Unknown,
+                    Some(format!("Simulation unavailable: {}", e)),
+                ),
+            };
+            PermissionCheck {
+                service_type,
+                action,
+                verdict,
+                note,
+            }
+        })
+        .collect();
+
+    PermissionReport {
+        profile: profile.to_string(),
+        principal_arn,
+        simulated,
+        checks,
+        checked_at: chrono::Utc::now(),
+    }
+}
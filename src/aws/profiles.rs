@@ -1,32 +1,55 @@
 use crate::aws::types::{AwsProfile, CredentialSource, ProfileMetadata, ValidationStatus};
-use crate::utils::error::Result;
+use crate::utils::error::{AppError, Result};
 use configparser::ini::Ini;
 use std::collections::HashMap;
 use std::env;
 use std::path::PathBuf;
 
+/// Display name of the profile built from `AWS_*` environment variables. It is
+/// only a label: the profile is told apart by `CredentialSource::Environment`,
+/// so a file profile with the same name never collides with it.
+pub const ENVIRONMENT_PROFILE_NAME: &str = "Environment Variables";
+
+/// Config keys that give a profile credentials without static keys: SSO, an
+/// external process, web identity, or the base credentials of a role
+const CREDENTIAL_PROVIDER_KEYS: [&str; 5] = [
+    "sso_session",
+    "sso_start_url",
+    "credential_process",
+    "web_identity_token_file",
+    "credential_source",
+];
+
 pub struct ProfileManager {
+    /// Profiles from the credentials and config files, keyed by name
     profiles: HashMap<String, AwsProfile>,
-    environment_profile: Option<AwsProfile>,
+    /// Kept apart from `profiles` so it cannot shadow a file profile
+    environment_profile: Option<(AwsProfile, ProfileMetadata)>,
     credentials_path: PathBuf,
     config_path: PathBuf,
     profile_metadata: HashMap<String, ProfileMetadata>,
+    /// Files skipped by the last load because they could not be parsed
+    load_errors: Vec<AppError>,
 }
 
 impl ProfileManager {
     pub fn new() -> Result<Self> {
-        let home = dirs::home_dir().ok_or("Cannot find home directory")?;
+        let home = dirs::home_dir()
+            .ok_or_else(|| AppError::Config("Cannot find home directory".to_string()))?;
         let aws_dir = home.join(".aws");
 
-        let credentials_path = aws_dir.join("credentials");
-        let config_path = aws_dir.join("config");
+        Self::with_paths(aws_dir.join("credentials"), aws_dir.join("config"))
+    }
 
+    /// Load profiles from the given credentials and config files
+    pub fn with_paths(credentials_path: PathBuf, config_path: PathBuf) -> Result<Self> {
         let mut manager = Self {
             profiles: HashMap::new(),
             environment_profile: None,
             credentials_path,
             config_path,
             profile_metadata: HashMap::new(),
+            load_errors: Vec::new(),
         };
 
         manager.load_all_profiles()?;
@@ -37,12 +60,15 @@ impl ProfileManager {
         // Clear existing profiles
         self.profiles.clear();
         self.profile_metadata.clear();
+        self.environment_profile = None;
+        self.load_errors.clear();
 
-        // Load profiles from credentials file
-        self.load_credentials_file()?;
+        // A malformed file is skipped so the profiles in the other one stay usable
+        let credentials = self.load_credentials_file();
+        self.skip_unparseable(credentials)?;
 
-        // Load profiles from config file
-        self.load_config_file()?;
+        let config = self.load_config_file();
+        self.skip_unparseable(config)?;
 
         // Detect environment credentials
         self.detect_environment_credentials()?;
@@ -50,15 +76,36 @@ impl ProfileManager {
         Ok(())
     }
 
+    /// Record a parse failure instead of failing the whole load
+    fn skip_unparseable(&mut self, loaded: Result<()>) -> Result<()> {
+        match loaded {
+            Err(e) if e.is_parse_error() => {
+                tracing::warn!("Skipping unparseable AWS file: {}", e);
+                self.load_errors.push(e);
+                Ok(())
+            }
+            other => other,
+        }
+    }
+
+    /// Parse errors of the files the last load skipped
+    pub fn load_errors(&self) -> &[AppError] {
+        &self.load_errors
+    }
+
     fn load_credentials_file(&mut self) -> Result<()> {
         if !self.credentials_path.exists() {
             return Ok(());
         }
 
-        let mut config = Ini::new();
+        // Profile names are case-sensitive, as they are for the AWS CLI
+        let mut config = Ini::new_cs();
         config
             .load(&self.credentials_path)
-            .map_err(|e| format!("Failed to load credentials file: {}", e))?;
+            .map_err(|message| AppError::Ini {
+                path: self.credentials_path.clone(),
+                message,
+            })?;
 
         for section_name in config.sections() {
             if let Some(section) = config.get_map_ref().get(&section_name) {
@@ -81,6 +128,7 @@ impl ProfileManager {
                         source_profile: None,
                         mfa_serial: None,
                         external_id: None,
+                        credential_provider: None,
                         credential_source: CredentialSource::ConfigFile(profile_name.clone()),
                     };
 
@@ -109,23 +157,23 @@ impl ProfileManager {
             return Ok(());
         }
 
-        let mut config = Ini::new();
+        let mut config = Ini::new_cs();
         config
             .load(&self.config_path)
-            .map_err(|e| format!("Failed to load config file: {}", e))?;
+            .map_err(|message| AppError::Ini {
+                path: self.config_path.clone(),
+                message,
+            })?;
 
         for section_name in config.sections() {
             if let Some(section) = config.get_map_ref().get(&section_name) {
-                // Handle both "default" and "profile xxx" sections
-                let profile_name = if section_name == "default" {
-                    "default".to_string()
-                } else if section_name.starts_with("profile ") {
-                    section_name.strip_prefix("profile ").unwrap().to_string()
-                } else {
+                let Some(profile_name) = config_profile_name(&section_name) else {
                     continue;
                 };
 
-                // Get or create profile (might already exist from credentials file)
+                // Get or create profile (might already exist from credentials file).
+                // Profiles that only live here, e.g. SSO or role profiles without
+                // static keys, are kept just the same.
                 let mut profile = self
                     .profiles
                     .get(&profile_name)
@@ -140,6 +188,7 @@ impl ProfileManager {
                         source_profile: None,
                         mfa_serial: None,
                         external_id: None,
+                        credential_provider: None,
                         credential_source: CredentialSource::ConfigFile(profile_name.clone()),
                     });
 
@@ -159,6 +208,19 @@ impl ProfileManager {
                 if let Some(Some(external_id)) = section.get("external_id") {
                     profile.external_id = Some(external_id.clone());
                 }
+                // The CLI also reads static keys from the config file
+                if profile.access_key_id.is_none() {
+                    profile.access_key_id =
+                        section.get("aws_access_key_id").and_then(|s| s.clone());
+                    profile.secret_access_key =
+                        section.get("aws_secret_access_key").and_then(|s| s.clone());
+                }
+                if let Some(provider) = CREDENTIAL_PROVIDER_KEYS
+                    .iter()
+                    .find(|key| matches!(section.get(**key), Some(Some(_))))
+                {
+                    profile.credential_provider = Some(provider.to_string());
+                }
 
                 // Initialize or update metadata
                 let mut metadata = self
@@ -188,15 +250,21 @@ impl ProfileManager {
     }
 
     pub fn detect_environment_credentials(&mut self) -> Result<()> {
-        let access_key_id = env::var("AWS_ACCESS_KEY_ID").ok();
-        let secret_access_key = env::var("AWS_SECRET_ACCESS_KEY").ok();
-        let session_token = env::var("AWS_SESSION_TOKEN").ok();
-        let region = env::var("AWS_DEFAULT_REGION").ok();
+        self.detect_environment_credentials_from(|key| env::var(key).ok());
+        Ok(())
+    }
+
+    /// Build the environment profile from `lookup`, which reads one variable
+    pub fn detect_environment_credentials_from(&mut self, lookup: impl Fn(&str) -> Option<String>) {
+        let access_key_id = lookup("AWS_ACCESS_KEY_ID");
+        let secret_access_key = lookup("AWS_SECRET_ACCESS_KEY");
+        let session_token = lookup("AWS_SESSION_TOKEN");
+        let region = lookup("AWS_DEFAULT_REGION");
 
         // Only create environment profile if we have at least access key and secret
         if let (Some(access_key), Some(secret_key)) = (access_key_id, secret_access_key) {
             let profile = AwsProfile {
-                name: "Environment Variables".to_string(),
+                name: ENVIRONMENT_PROFILE_NAME.to_string(),
                 region,
                 access_key_id: Some(access_key),
                 secret_access_key: Some(secret_key),
@@ -205,6 +273,7 @@ impl ProfileManager {
                 source_profile: None,
                 mfa_serial: None,
                 external_id: None,
+                credential_provider: None,
                 credential_source: CredentialSource::Environment,
             };
 
@@ -218,18 +287,43 @@ impl ProfileManager {
                 validation_status: ValidationStatus::Unknown,
             };
 
-            self.environment_profile = Some(profile.clone());
-            self.profiles
-                .insert("Environment Variables".to_string(), profile);
-            self.profile_metadata
-                .insert("Environment Variables".to_string(), metadata);
+            self.environment_profile = Some((profile, metadata));
         }
-
-        Ok(())
     }
 
+    /// The environment profile in a group of its own, then file profiles with
+    /// validated ones first, `default` leading, and the rest alphabetical
     pub fn get_profiles(&self) -> Vec<&AwsProfile> {
-        self.profiles.values().collect()
+        let mut file_profiles: Vec<&AwsProfile> = self.profiles.values().collect();
+        file_profiles.sort_by_cached_key(|profile| {
+            let validity = match self
+                .profile_metadata
+                .get(&profile.name)
+                .map(|metadata| &metadata.validation_status)
+            {
+                Some(ValidationStatus::Valid) => 0,
+                Some(ValidationStatus::Unknown) | None => 1,
+                Some(_) => 2,
+            };
+            (
+                validity,
+                profile.name != "default",
+                profile.name.to_lowercase(),
+                profile.name.clone(),
+            )
+        });
+
+        self.environment_profile
+            .iter()
+            .map(|(profile, _)| profile)
+            .chain(file_profiles)
+            .collect()
+    }
+
+    pub fn get_environment_profile(&self) -> Option<&AwsProfile> {
+        self.environment_profile
+            .as_ref()
+            .map(|(profile, _)| profile)
     }
 
     pub fn get_profile(&self, name: &str) -> Option<&AwsProfile> {
@@ -258,16 +352,14 @@ impl ProfileManager {
         let mut sources = Vec::new();
 
         // Add environment credentials if available
-        if self.environment_profile.is_some() {
+        if self.get_environment_profile().is_some() {
             sources.push(CredentialSource::Environment);
         }
 
         // Add file-based profiles
         for profile in self.profiles.values() {
             if let CredentialSource::ConfigFile(profile_name) = &profile.credential_source {
-                if profile_name != "Environment Variables" {
-                    sources.push(CredentialSource::ConfigFile(profile_name.clone()));
-                }
+                sources.push(CredentialSource::ConfigFile(profile_name.clone()));
             }
         }
 
@@ -279,6 +371,8 @@ impl ProfileManager {
             (CredentialSource::Environment, _) => std::cmp::Ordering::Less,
             (_, CredentialSource::Environment) => std::cmp::Ordering::Greater,
             (CredentialSource::ConfigFile(a), CredentialSource::ConfigFile(b)) => a.cmp(b),
+            // Assumed roles live only in the app state, never in the manager
+            _ => std::cmp::Ordering::Equal,
         });
         sources.dedup();
 
@@ -293,3 +387,19 @@ impl ProfileManager {
         self.profile_metadata.insert(name.to_string(), metadata);
     }
 }
+
+/// The profile a config file section defines: `[default]`, `[profile default]`
+/// or `[profile name]`, ignoring extra spaces around the name. Other sections
+/// (e.g. `[sso-session name]`) are not profiles.
+fn config_profile_name(section_name: &str) -> Option<String> {
+    let section_name = section_name.trim();
+    if section_name == "default" {
+        return Some("default".to_string());
+    }
+    let name = section_name.strip_prefix("profile")?;
+    if !name.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let name = name.trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
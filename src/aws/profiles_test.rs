@@ -0,0 +1,180 @@
+use crate::aws::profiles::{ProfileManager, ENVIRONMENT_PROFILE_NAME};
+use crate::aws::types::CredentialSource;
+use crate::utils::temp_dir::TempDir;
+
+fn fake_environment(key: &str) -> Option<String> {
+    match key {
+        "AWS_ACCESS_KEY_ID" => Some("AKIAENV".to_string()),
+        "AWS_SECRET_ACCESS_KEY" => Some("env-secret".to_string()),
+        _ => None,
+    }
+}
+
+#[test]
+fn file_profile_named_like_environment_profile_does_not_collide() {
+    // One file profile with the environment profile's display name
+    let dir = TempDir::new("nimbus-ctl-profiles");
+    let credentials = dir.write(
+        "credentials",
+        &format!(
+            "[{}]\naws_access_key_id = AKIAFILE\naws_secret_access_key = file-secret\n",
+            ENVIRONMENT_PROFILE_NAME
+        ),
+    );
+    let mut manager = ProfileManager::with_paths(credentials, dir.join("config")).unwrap();
+    manager.detect_environment_credentials_from(fake_environment);
+
+    let file_profile = manager
+        .get_profiles()
+        .into_iter()
+        .find(|p| matches!(p.credential_source, CredentialSource::ConfigFile(_)))
+        .expect("file profile is kept");
+    assert_eq!(file_profile.name, ENVIRONMENT_PROFILE_NAME);
+    assert_eq!(file_profile.access_key_id.as_deref(), Some("AKIAFILE"));
+
+    let environment = manager.get_environment_profile().unwrap();
+    assert_eq!(environment.credential_source, CredentialSource::Environment);
+    assert_eq!(environment.access_key_id.as_deref(), Some("AKIAENV"));
+    assert_eq!(manager.get_profiles().len(), 2);
+
+    let sources = manager.get_available_credential_sources();
+    assert_eq!(sources[0], CredentialSource::Environment);
+    assert_eq!(
+        sources[1],
+        CredentialSource::ConfigFile(file_profile.name.clone())
+    );
+}
+
+#[test]
+fn profiles_are_listed_default_first_then_alphabetically() {
+    let dir = TempDir::new("nimbus-ctl-profile-order");
+    let mut contents = String::new();
+    for name in ["staging", "Zeta", "default", "alpha", "prod"] {
+        contents.push_str(&format!(
+            "[{}]\naws_access_key_id = AKIA{}\naws_secret_access_key = secret\n",
+            name, name
+        ));
+    }
+    let credentials = dir.write("credentials", &contents);
+
+    let mut manager = ProfileManager::with_paths(credentials, dir.join("config")).unwrap();
+    manager.detect_environment_credentials_from(fake_environment);
+
+    let names = |manager: &ProfileManager| -> Vec<String> {
+        manager
+            .get_profiles()
+            .into_iter()
+            .map(|p| p.name.clone())
+            .collect()
+    };
+    let first = names(&manager);
+    assert_eq!(
+        first,
+        [
+            ENVIRONMENT_PROFILE_NAME,
+            "default",
+            "alpha",
+            "prod",
+            "staging",
+            "Zeta"
+        ]
+    );
+    for _ in 0..5 {
+        assert_eq!(names(&manager), first);
+    }
+}
+
+#[test]
+fn malformed_credentials_file_is_skipped_and_reported() {
+    let dir = TempDir::new("nimbus-ctl-profile-parse");
+    let credentials = dir.write("credentials", "[broken\naws_access_key_id = AKIABROKEN\n");
+    let config = dir.write("config", "[profile staging]\nregion = eu-west-1\n");
+
+    let manager = ProfileManager::with_paths(credentials.clone(), config).unwrap();
+
+    assert!(manager.get_profile("staging").is_some());
+    assert_eq!(manager.load_errors().len(), 1);
+    assert!(manager.load_errors()[0]
+        .to_string()
+        .contains(&credentials.display().to_string()));
+}
+
+#[test]
+fn config_only_profiles_are_kept_without_static_keys() {
+    let dir = TempDir::new("nimbus-ctl-config-only");
+    let config = dir.write(
+        "config",
+        "[default]\nregion = eu-west-1\n\n\
+         [profile sso-dev]\nsso_session = corp\nsso_account_id = 111122223333\n\n\
+         [profile bare]\n\n\
+         [sso-session corp]\nsso_start_url = https://corp.awsapps.com/start\n",
+    );
+
+    // No credentials file at all
+    let manager = ProfileManager::with_paths(dir.join("credentials"), config).unwrap();
+
+    let default = manager.get_profile("default").expect("default is kept");
+    assert_eq!(default.region.as_deref(), Some("eu-west-1"));
+    assert!(default.access_key_id.is_none());
+    assert!(manager.get_profile("sso-dev").is_some());
+    assert!(manager.get_profile("bare").is_some());
+    // SSO sessions are shared settings, not profiles
+    assert!(manager.get_profile("corp").is_none());
+    assert_eq!(manager.get_profiles().len(), 3);
+}
+
+#[test]
+fn config_sections_name_profiles_without_stray_whitespace() {
+    let dir = TempDir::new("nimbus-ctl-config-names");
+    // The keys come from credentials; only the region is in config
+    let credentials = dir.write(
+        "credentials",
+        "[default]\naws_access_key_id = AKIADEFAULT\naws_secret_access_key = secret\n",
+    );
+    let config = dir.write(
+        "config",
+        "[profile default]\nregion = us-west-2\n\n[profile   staging ]\nregion = eu-west-1\n",
+    );
+
+    let manager = ProfileManager::with_paths(credentials, config).unwrap();
+
+    let default = manager.get_profile("default").unwrap();
+    assert_eq!(default.access_key_id.as_deref(), Some("AKIADEFAULT"));
+    assert_eq!(default.region.as_deref(), Some("us-west-2"));
+    assert_eq!(
+        manager.get_profile("staging").unwrap().region.as_deref(),
+        Some("eu-west-1")
+    );
+    assert_eq!(manager.get_profiles().len(), 2);
+}
+
+#[test]
+fn profiles_without_any_credential_source_are_detected() {
+    let dir = TempDir::new("nimbus-ctl-profile-creds");
+    let credentials = dir.write(
+        "credentials",
+        "[base]\naws_access_key_id = AKIABASE\naws_secret_access_key = secret\n",
+    );
+    let config = dir.write(
+        "config",
+        "[profile sso]\nsso_session = corp\n\
+         [profile admin]\nrole_arn = arn:aws:iam::123456789012:role/Admin\nsource_profile = base\n\
+         [profile orphan]\nrole_arn = arn:aws:iam::123456789012:role/Admin\nsource_profile = missing\n\
+         [profile region-only]\nregion = eu-west-1\n",
+    );
+
+    let manager = ProfileManager::with_paths(credentials, config).unwrap();
+    let profiles: Vec<_> = manager.get_profiles().into_iter().cloned().collect();
+    let has_credentials = |name: &str| {
+        manager
+            .get_profile(name)
+            .unwrap()
+            .has_credentials(&profiles)
+    };
+
+    assert!(has_credentials("base"));
+    assert!(has_credentials("sso"));
+    assert!(has_credentials("admin"));
+    assert!(!has_credentials("orphan"));
+    assert!(!has_credentials("region-only"));
+}
@@ -1,67 +1,85 @@
 use crate::aws::types::AwsRegion;
 
+/// Every commercial region with its console display name. Adding a region is
+/// one line here.
+const REGIONS: &[(&str, &str)] = &[
+    ("us-east-1", "US East (N. Virginia)"),
+    ("us-east-2", "US East (Ohio)"),
+    ("us-west-1", "US West (N. California)"),
+    ("us-west-2", "US West (Oregon)"),
+    ("af-south-1", "Africa (Cape Town)"),
+    ("ap-east-1", "Asia Pacific (Hong Kong)"),
+    ("ap-east-2", "Asia Pacific (Taipei)"),
+    ("ap-south-1", "Asia Pacific (Mumbai)"),
+    ("ap-south-2", "Asia Pacific (Hyderabad)"),
+    ("ap-southeast-1", "Asia Pacific (Singapore)"),
+    ("ap-southeast-2", "Asia Pacific (Sydney)"),
+    ("ap-southeast-3", "Asia Pacific (Jakarta)"),
+    ("ap-southeast-4", "Asia Pacific (Melbourne)"),
+    ("ap-southeast-5", "Asia Pacific (Malaysia)"),
+    ("ap-southeast-7", "Asia Pacific (Thailand)"),
+    ("ap-northeast-1", "Asia Pacific (Tokyo)"),
+    ("ap-northeast-2", "Asia Pacific (Seoul)"),
+    ("ap-northeast-3", "Asia Pacific (Osaka)"),
+    ("ca-central-1", "Canada (Central)"),
+    ("ca-west-1", "Canada West (Calgary)"),
+    ("eu-central-1", "Europe (Frankfurt)"),
+    ("eu-central-2", "Europe (Zurich)"),
+    ("eu-west-1", "Europe (Ireland)"),
+    ("eu-west-2", "Europe (London)"),
+    ("eu-west-3", "Europe (Paris)"),
+    ("eu-south-1", "Europe (Milan)"),
+    ("eu-south-2", "Europe (Spain)"),
+    ("eu-north-1", "Europe (Stockholm)"),
+    ("il-central-1", "Israel (Tel Aviv)"),
+    ("me-south-1", "Middle East (Bahrain)"),
+    ("me-central-1", "Middle East (UAE)"),
+    ("mx-central-1", "Mexico (Central)"),
+    ("sa-east-1", "South America (São Paulo)"),
+];
+
+/// Regions disabled until an account opts in; every other region is enabled
+/// in every account
+const OPT_IN_REGIONS: &[&str] = &[
+    "af-south-1",
+    "ap-east-1",
+    "ap-east-2",
+    "ap-south-2",
+    "ap-southeast-3",
+    "ap-southeast-4",
+    "ap-southeast-5",
+    "ap-southeast-7",
+    "ca-west-1",
+    "eu-central-2",
+    "eu-south-1",
+    "eu-south-2",
+    "il-central-1",
+    "me-south-1",
+    "me-central-1",
+    "mx-central-1",
+];
+
+/// Whether an account has to opt in before using the region
+pub fn requires_opt_in(code: &str) -> bool {
+    OPT_IN_REGIONS.contains(&code)
+}
+
+/// Console name of a region, or the code itself for regions not yet in the map
+pub fn region_display_name(code: &str) -> &str {
+    REGIONS
+        .iter()
+        .find(|(name, _)| *name == code)
+        .map_or(code, |(_, display_name)| display_name)
+}
+
 pub struct RegionManager;
 
 impl RegionManager {
     pub fn get_all_regions() -> Vec<AwsRegion> {
-        vec![
-            AwsRegion {
-                name: "us-east-1".to_string(),
-                display_name: "US East (N. Virginia)".to_string(),
-            },
-            AwsRegion {
-                name: "us-east-2".to_string(),
-                display_name: "US East (Ohio)".to_string(),
-            },
-            AwsRegion {
-                name: "us-west-1".to_string(),
-                display_name: "US West (N. California)".to_string(),
-            },
-            AwsRegion {
-                name: "us-west-2".to_string(),
-                display_name: "US West (Oregon)".to_string(),
-            },
-            AwsRegion {
-                name: "eu-west-1".to_string(),
-                display_name: "Europe (Ireland)".to_string(),
-            },
-            AwsRegion {
-                name: "eu-west-2".to_string(),
-                display_name: "Europe (London)".to_string(),
-            },
-            AwsRegion {
-                name: "eu-west-3".to_string(),
-                display_name: "Europe (Paris)".to_string(),
-            },
-            AwsRegion {
-                name: "eu-central-1".to_string(),
-                display_name: "Europe (Frankfurt)".to_string(),
-            },
-            AwsRegion {
-                name: "ap-northeast-1".to_string(),
-                display_name: "Asia Pacific (Tokyo)".to_string(),
-            },
-            AwsRegion {
-                name: "ap-northeast-2".to_string(),
-                display_name: "Asia Pacific (Seoul)".to_string(),
-            },
-            AwsRegion {
-                name: "ap-southeast-1".to_string(),
-                display_name: "Asia Pacific (Singapore)".to_string(),
-            },
-            AwsRegion {
-                name: "ap-southeast-2".to_string(),
-                display_name: "Asia Pacific (Sydney)".to_string(),
-            },
-            AwsRegion {
-                name: "ap-south-1".to_string(),
-                display_name: "Asia Pacific (Mumbai)".to_string(),
-            },
-            AwsRegion {
-                name: "sa-east-1".to_string(),
-                display_name: "South America (São Paulo)".to_string(),
-            },
-        ]
+        REGIONS
+            .iter()
+            .map(|(name, _)| AwsRegion::new(name))
+            .collect()
     }
 
     pub fn get_region_by_name(name: &str) -> Option<AwsRegion> {
@@ -0,0 +1,41 @@
+use crate::aws::client::RegionClients;
+use crate::aws::types::LogEvent;
+use crate::utils::error::Result;
+use chrono::{DateTime, Utc};
+
+pub struct CloudWatchLogsService<'a> {
+    clients: &'a RegionClients,
+}
+
+impl<'a> CloudWatchLogsService<'a> {
+    pub fn new(clients: &'a RegionClients) -> Self {
+        Self { clients }
+    }
+
+    /// Events of every stream in `log_group` at or after `start`, oldest first
+    pub async fn filter_log_events(
+        &self,
+        log_group: &str,
+        start: DateTime<Utc>,
+    ) -> Result<Vec<LogEvent>> {
+        // This would implement actual CloudWatch Logs FilterLogEvents, following nextToken
+        // For Phase 1, we'll return mock data
+        tracing::debug!(
+            "Reading {} from {} in {}",
+            log_group,
+            start,
+            self.clients.region
+        );
+        Ok(vec![])
+    }
+
+    /// Events of several log groups at or after `start`, merged oldest first
+    pub async fn tail(&self, log_groups: &[String], start: DateTime<Utc>) -> Result<Vec<LogEvent>> {
+        let mut events = vec![];
+        for log_group in log_groups {
+            events.extend(self.filter_log_events(log_group, start).await?);
+        }
+        events.sort_by_key(|event| event.timestamp);
+        Ok(events)
+    }
+}
@@ -0,0 +1,32 @@
+use crate::aws::client::RegionClients;
+use crate::aws::types::CostSummary;
+use crate::utils::error::Result;
+use chrono::{Datelike, NaiveDate};
+
+/// Cost Explorer only has an endpoint in us-east-1, whatever region is selected
+pub const COST_EXPLORER_REGION: &str = "us-east-1";
+
+pub struct CostExplorerService<'a> {
+    clients: &'a RegionClients,
+}
+
+impl<'a> CostExplorerService<'a> {
+    /// `clients` should be bound to `COST_EXPLORER_REGION`
+    pub fn new(clients: &'a RegionClients) -> Self {
+        Self { clients }
+    }
+
+    /// Unblended cost from the first of the month through `today`, grouped by service
+    pub async fn month_to_date(&self, today: NaiveDate) -> Result<CostSummary> {
+        // This would implement actual Cost Explorer GetCostAndUsage
+        // For Phase 1, we'll return mock data
+        tracing::debug!("Fetching month-to-date cost via {}", self.clients.region);
+        Ok(CostSummary {
+            period_start: today.with_day(1).unwrap_or(today),
+            period_end: today,
+            currency: "USD".to_string(),
+            total: 0.0,
+            top_services: vec![],
+        })
+    }
+}
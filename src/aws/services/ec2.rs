@@ -1,53 +1,252 @@
-use crate::aws::client::RegionClients;
-use crate::aws::types::Ec2Instance;
-use crate::utils::error::Result;
+use crate::aws::client::{ApiFuture, RegionClients};
+use crate::aws::regions::RegionManager;
+use crate::aws::types::{
+    AwsRegion, Ec2Instance, InstanceLaunch, RegionStatus, ResourceEvent, SecurityGroup,
+};
+use crate::utils::error::{AppError, Result};
 
-pub struct Ec2Service<'a> {
-    clients: &'a RegionClients,
+/// The EC2 calls the app makes, implemented by the real client and by test fakes
+pub trait Ec2Api: Send + Sync {
+    fn list_instances(&self) -> ApiFuture<'_, Vec<Ec2Instance>>;
+    fn describe_availability_zones(&self) -> ApiFuture<'_, Vec<String>>;
+    /// Every region with the account's opt-in status, disabled ones included
+    fn describe_regions(&self) -> ApiFuture<'_, Vec<RegionStatus>>;
+    fn get_instance<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, Option<Ec2Instance>>;
+    /// Launches one instance, returning its id
+    fn run_instance<'a>(&'a self, launch: &'a InstanceLaunch) -> ApiFuture<'a, String>;
+    fn start_instance<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, ()>;
+    fn stop_instance<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, ()>;
+    fn terminate_instance<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, ()>;
+    fn reboot_instance<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, ()>;
+    /// Status check results and scheduled events of one instance
+    fn describe_instance_status<'a>(
+        &'a self,
+        instance_id: &'a str,
+    ) -> ApiFuture<'a, Vec<ResourceEvent>>;
+    fn describe_security_groups<'a>(
+        &'a self,
+        group_ids: &'a [String],
+    ) -> ApiFuture<'a, Vec<SecurityGroup>>;
 }
 
-impl<'a> Ec2Service<'a> {
-    pub fn new(clients: &'a RegionClients) -> Self {
-        Self { clients }
+/// EC2 client for one region
+pub struct Ec2Client {
+    // The SDK client will be added back in Phase 2
+    region: String,
+}
+
+impl Ec2Client {
+    pub fn new(region: &str) -> Self {
+        Self {
+            region: region.to_string(),
+        }
     }
+}
 
-    pub async fn list_instances(&self) -> Result<Vec<Ec2Instance>> {
+impl Ec2Api for Ec2Client {
+    fn list_instances(&self) -> ApiFuture<'_, Vec<Ec2Instance>> {
         // This would implement actual EC2 instance listing
         // For Phase 1, we'll return mock data
-        Ok(vec![])
+        Box::pin(async { Ok(vec![]) })
     }
 
-    pub async fn get_instance(&self, instance_id: &str) -> Result<Option<Ec2Instance>> {
+    fn describe_availability_zones(&self) -> ApiFuture<'_, Vec<String>> {
+        // This would implement actual EC2 DescribeAvailabilityZones
+        // For Phase 1, we'll return mock data
+        Box::pin(async move {
+            Ok(["a", "b", "c"]
+                .iter()
+                .map(|suffix| format!("{}{}", self.region, suffix))
+                .collect())
+        })
+    }
+
+    fn describe_regions(&self) -> ApiFuture<'_, Vec<RegionStatus>> {
+        // This would implement actual EC2 DescribeRegions with AllRegions set
+        // For Phase 1, we'll return mock data
+        Box::pin(async { Ok(default_region_statuses()) })
+    }
+
+    fn get_instance<'a>(&'a self, _instance_id: &'a str) -> ApiFuture<'a, Option<Ec2Instance>> {
         // This would implement actual EC2 instance retrieval
         // For Phase 1, we'll return None
-        Ok(None)
+        Box::pin(async { Ok(None) })
     }
 
-    pub async fn start_instance(&self, instance_id: &str) -> Result<()> {
+    fn run_instance<'a>(&'a self, launch: &'a InstanceLaunch) -> ApiFuture<'a, String> {
+        // This would implement actual EC2 RunInstances
+        // For Phase 1, we'll just log the action
+        Box::pin(async move {
+            tracing::info!(
+                "Launching {} instance from {} in {}",
+                launch.instance_type,
+                launch.image_id,
+                self.region
+            );
+            Ok("i-0000000000000000".to_string())
+        })
+    }
+
+    fn start_instance<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, ()> {
         // This would implement actual EC2 instance start
         // For Phase 1, we'll just log the action
-        tracing::info!("Starting EC2 instance: {}", instance_id);
-        Ok(())
+        Box::pin(async move {
+            tracing::info!("Starting EC2 instance {} in {}", instance_id, self.region);
+            Ok(())
+        })
     }
 
-    pub async fn stop_instance(&self, instance_id: &str) -> Result<()> {
+    fn stop_instance<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, ()> {
         // This would implement actual EC2 instance stop
         // For Phase 1, we'll just log the action
-        tracing::info!("Stopping EC2 instance: {}", instance_id);
-        Ok(())
+        Box::pin(async move {
+            tracing::info!("Stopping EC2 instance {} in {}", instance_id, self.region);
+            Ok(())
+        })
     }
 
-    pub async fn terminate_instance(&self, instance_id: &str) -> Result<()> {
+    fn terminate_instance<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, ()> {
         // This would implement actual EC2 instance termination
         // For Phase 1, we'll just log the action
-        tracing::info!("Terminating EC2 instance: {}", instance_id);
-        Ok(())
+        Box::pin(async move {
+            tracing::info!(
+                "Terminating EC2 instance {} in {}",
+                instance_id,
+                self.region
+            );
+            Ok(())
+        })
     }
 
-    pub async fn reboot_instance(&self, instance_id: &str) -> Result<()> {
+    fn reboot_instance<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, ()> {
         // This would implement actual EC2 instance reboot
         // For Phase 1, we'll just log the action
-        tracing::info!("Rebooting EC2 instance: {}", instance_id);
-        Ok(())
+        Box::pin(async move {
+            tracing::info!("Rebooting EC2 instance {} in {}", instance_id, self.region);
+            Ok(())
+        })
+    }
+
+    fn describe_instance_status<'a>(
+        &'a self,
+        _instance_id: &'a str,
+    ) -> ApiFuture<'a, Vec<ResourceEvent>> {
+        // This would implement actual EC2 DescribeInstanceStatus
+        // For Phase 1, we'll return mock data
+        Box::pin(async { Ok(vec![]) })
+    }
+
+    fn describe_security_groups<'a>(
+        &'a self,
+        _group_ids: &'a [String],
+    ) -> ApiFuture<'a, Vec<SecurityGroup>> {
+        // This would implement actual EC2 DescribeSecurityGroups for the group ids
+        // For Phase 1, we'll return mock data
+        Box::pin(async { Ok(vec![]) })
+    }
+}
+
+/// The canonical regions as a fresh account sees them: opt-in regions disabled
+pub fn default_region_statuses() -> Vec<RegionStatus> {
+    RegionManager::get_all_regions()
+        .into_iter()
+        .map(|region| RegionStatus {
+            opt_in_status: if region.opt_in {
+                "not-opted-in"
+            } else {
+                "opt-in-not-required"
+            }
+            .to_string(),
+            name: region.name,
+        })
+        .collect()
+}
+
+pub struct Ec2Service<'a> {
+    clients: &'a RegionClients,
+}
+
+impl<'a> Ec2Service<'a> {
+    pub fn new(clients: &'a RegionClients) -> Self {
+        Self { clients }
+    }
+
+    pub async fn list_instances(&self) -> Result<Vec<Ec2Instance>> {
+        self.clients.ec2.list_instances().await
+    }
+
+    pub async fn describe_availability_zones(&self) -> Result<Vec<String>> {
+        self.clients.ec2.describe_availability_zones().await
+    }
+
+    /// The regions enabled for the account, opt-in ones marked
+    pub async fn enabled_regions(&self) -> Result<Vec<AwsRegion>> {
+        Ok(self
+            .clients
+            .ec2
+            .describe_regions()
+            .await?
+            .iter()
+            .filter(|status| status.is_enabled())
+            .map(RegionStatus::to_region)
+            .collect())
+    }
+
+    pub async fn get_instance(&self, instance_id: &str) -> Result<Option<Ec2Instance>> {
+        self.clients.ec2.get_instance(instance_id).await
+    }
+
+    pub async fn run_instance(&self, launch: &InstanceLaunch) -> Result<String> {
+        self.clients.ec2.run_instance(launch).await
+    }
+
+    pub async fn start_instance(&self, instance_id: &str) -> Result<()> {
+        self.clients.ec2.start_instance(instance_id).await
+    }
+
+    pub async fn stop_instance(&self, instance_id: &str) -> Result<()> {
+        self.clients.ec2.stop_instance(instance_id).await
+    }
+
+    pub async fn terminate_instance(&self, instance_id: &str) -> Result<()> {
+        self.clients.ec2.terminate_instance(instance_id).await
+    }
+
+    pub async fn reboot_instance(&self, instance_id: &str) -> Result<()> {
+        self.clients.ec2.reboot_instance(instance_id).await
+    }
+
+    /// The security groups attached to an instance, with their rules
+    pub async fn instance_security_groups(&self, instance_id: &str) -> Result<Vec<SecurityGroup>> {
+        let instance = self
+            .get_instance(instance_id)
+            .await?
+            .ok_or_else(|| AppError::ResourceNotFound(instance_id.to_string()))?;
+        if instance.security_groups.is_empty() {
+            return Ok(vec![]);
+        }
+        self.clients
+            .ec2
+            .describe_security_groups(&instance.security_groups)
+            .await
+    }
+
+    /// Status checks, scheduled events and the last state change, newest first
+    pub async fn instance_events(&self, instance_id: &str) -> Result<Vec<ResourceEvent>> {
+        let mut events = self
+            .clients
+            .ec2
+            .describe_instance_status(instance_id)
+            .await?;
+        if let Some(reason) = self
+            .get_instance(instance_id)
+            .await?
+            .and_then(|instance| instance.state_transition_reason)
+            .filter(|reason| !reason.is_empty())
+        {
+            events.push(ResourceEvent::new(None, "State change", reason));
+        }
+        ResourceEvent::sort_newest_first(&mut events);
+        Ok(events)
     }
 }
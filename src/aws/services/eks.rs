@@ -1,6 +1,176 @@
-use crate::aws::client::RegionClients;
-use crate::aws::types::EksCluster;
-use crate::utils::error::Result;
+use crate::aws::client::{ApiFuture, RegionClients};
+use crate::aws::types::{EksCluster, EksNodeGroup, ResourceEvent};
+use crate::utils::error::{AppError, Result};
+
+/// The EKS calls the app makes, implemented by the real client and by demo data
+pub trait EksApi: Send + Sync {
+    fn list_clusters(&self) -> ApiFuture<'_, Vec<EksCluster>>;
+    fn get_cluster<'a>(&'a self, cluster_name: &'a str) -> ApiFuture<'a, Option<EksCluster>>;
+    /// Names of the cluster's managed node groups
+    fn list_nodegroups<'a>(&'a self, cluster_name: &'a str) -> ApiFuture<'a, Vec<String>>;
+    fn describe_nodegroup<'a>(
+        &'a self,
+        cluster_name: &'a str,
+        nodegroup_name: &'a str,
+    ) -> ApiFuture<'a, Option<EksNodeGroup>>;
+    /// Returns the id of the update
+    fn update_nodegroup_size<'a>(
+        &'a self,
+        cluster_name: &'a str,
+        nodegroup_name: &'a str,
+        desired_size: i32,
+    ) -> ApiFuture<'a, String>;
+    /// Status of a node group update: Pending, InProgress, Successful, Failed or Cancelled
+    fn describe_update<'a>(
+        &'a self,
+        cluster_name: &'a str,
+        nodegroup_name: &'a str,
+        update_id: &'a str,
+    ) -> ApiFuture<'a, String>;
+    /// Version, config and add-on updates of the cluster, newest first
+    fn cluster_updates<'a>(&'a self, cluster_name: &'a str) -> ApiFuture<'a, Vec<ResourceEvent>>;
+    /// Returns the cluster in the CREATING status
+    fn create_cluster<'a>(&'a self, cluster_name: &'a str) -> ApiFuture<'a, EksCluster>;
+    fn delete_cluster<'a>(&'a self, cluster_name: &'a str) -> ApiFuture<'a, ()>;
+}
+
+/// EKS client for one region
+pub struct EksClient {
+    // The SDK client will be added back in Phase 2
+    region: String,
+}
+
+impl EksClient {
+    pub fn new(region: &str) -> Self {
+        Self {
+            region: region.to_string(),
+        }
+    }
+}
+
+/// A cluster as CreateCluster returns it, before EKS has provisioned anything
+pub fn creating_cluster(region: &str, cluster_name: &str) -> EksCluster {
+    EksCluster {
+        name: cluster_name.to_string(),
+        arn: format!(
+            "arn:aws:eks:{}:123456789012:cluster/{}",
+            region, cluster_name
+        ),
+        version: None,
+        endpoint: None,
+        role_arn: None,
+        status: "CREATING".to_string(),
+        created_at: Some(chrono::Utc::now()),
+        platform_version: None,
+        vpc_config: None,
+        enabled_log_types: vec![],
+    }
+}
+
+impl EksApi for EksClient {
+    fn list_clusters(&self) -> ApiFuture<'_, Vec<EksCluster>> {
+        // This would implement actual EKS cluster listing
+        // For Phase 1, we'll return mock data
+        Box::pin(async { Ok(vec![]) })
+    }
+
+    fn get_cluster<'a>(&'a self, _cluster_name: &'a str) -> ApiFuture<'a, Option<EksCluster>> {
+        // This would implement actual EKS cluster retrieval
+        // For Phase 1, we'll return None
+        Box::pin(async { Ok(None) })
+    }
+
+    fn list_nodegroups<'a>(&'a self, cluster_name: &'a str) -> ApiFuture<'a, Vec<String>> {
+        // This would implement actual EKS ListNodegroups
+        // For Phase 1, we'll return mock data
+        Box::pin(async move {
+            tracing::debug!("Listing node groups for EKS cluster: {}", cluster_name);
+            Ok(vec![])
+        })
+    }
+
+    fn describe_nodegroup<'a>(
+        &'a self,
+        cluster_name: &'a str,
+        nodegroup_name: &'a str,
+    ) -> ApiFuture<'a, Option<EksNodeGroup>> {
+        // This would implement actual EKS DescribeNodegroup
+        // For Phase 1, we'll return None
+        Box::pin(async move {
+            tracing::debug!(
+                "Describing node group {} of EKS cluster {}",
+                nodegroup_name,
+                cluster_name
+            );
+            Ok(None)
+        })
+    }
+
+    fn update_nodegroup_size<'a>(
+        &'a self,
+        cluster_name: &'a str,
+        nodegroup_name: &'a str,
+        desired_size: i32,
+    ) -> ApiFuture<'a, String> {
+        // This would implement actual EKS UpdateNodegroupConfig
+        // For Phase 1, we'll just log the action
+        Box::pin(async move {
+            tracing::info!(
+                "Scaling node group {} of EKS cluster {} to {}",
+                nodegroup_name,
+                cluster_name,
+                desired_size
+            );
+            Ok(format!("update-{}-{}", nodegroup_name, desired_size))
+        })
+    }
+
+    fn describe_update<'a>(
+        &'a self,
+        cluster_name: &'a str,
+        nodegroup_name: &'a str,
+        update_id: &'a str,
+    ) -> ApiFuture<'a, String> {
+        // This would implement actual EKS DescribeUpdate
+        // For Phase 1, we'll return mock data
+        Box::pin(async move {
+            tracing::debug!(
+                "Describing update {} of node group {} in {}",
+                update_id,
+                nodegroup_name,
+                cluster_name
+            );
+            Ok("InProgress".to_string())
+        })
+    }
+
+    fn cluster_updates<'a>(&'a self, cluster_name: &'a str) -> ApiFuture<'a, Vec<ResourceEvent>> {
+        // This would implement actual EKS ListUpdates and DescribeUpdate
+        // For Phase 1, we'll return mock data
+        Box::pin(async move {
+            tracing::debug!("Listing updates of EKS cluster: {}", cluster_name);
+            Ok(vec![])
+        })
+    }
+
+    fn create_cluster<'a>(&'a self, cluster_name: &'a str) -> ApiFuture<'a, EksCluster> {
+        // This would implement actual EKS CreateCluster
+        // For Phase 1, we'll just log the action
+        Box::pin(async move {
+            tracing::info!("Creating EKS cluster {} in {}", cluster_name, self.region);
+            Ok(creating_cluster(&self.region, cluster_name))
+        })
+    }
+
+    fn delete_cluster<'a>(&'a self, cluster_name: &'a str) -> ApiFuture<'a, ()> {
+        // This would implement actual EKS cluster deletion
+        // For Phase 1, we'll just log the action
+        Box::pin(async move {
+            tracing::info!("Deleting EKS cluster {} in {}", cluster_name, self.region);
+            Ok(())
+        })
+    }
+}
 
 pub struct EksService<'a> {
     clients: &'a RegionClients,
@@ -12,28 +182,114 @@ impl<'a> EksService<'a> {
     }
 
     pub async fn list_clusters(&self) -> Result<Vec<EksCluster>> {
-        // This would implement actual EKS cluster listing
-        // For Phase 1, we'll return mock data
-        Ok(vec![])
+        self.clients.eks.list_clusters().await
     }
 
     pub async fn get_cluster(&self, cluster_name: &str) -> Result<Option<EksCluster>> {
-        // This would implement actual EKS cluster retrieval
-        // For Phase 1, we'll return None
-        Ok(None)
+        self.clients.eks.get_cluster(cluster_name).await
     }
 
-    pub async fn create_cluster(&self, cluster_name: &str) -> Result<()> {
-        // This would implement actual EKS cluster creation
-        // For Phase 1, we'll just log the action
-        tracing::info!("Creating EKS cluster: {}", cluster_name);
-        Ok(())
+    /// Names of the cluster's managed node groups
+    pub async fn list_nodegroups(&self, cluster_name: &str) -> Result<Vec<String>> {
+        self.clients.eks.list_nodegroups(cluster_name).await
+    }
+
+    pub async fn describe_nodegroup(
+        &self,
+        cluster_name: &str,
+        nodegroup_name: &str,
+    ) -> Result<Option<EksNodeGroup>> {
+        self.clients
+            .eks
+            .describe_nodegroup(cluster_name, nodegroup_name)
+            .await
+    }
+
+    /// Every managed node group of the cluster with its scaling configuration
+    pub async fn node_groups(&self, cluster_name: &str) -> Result<Vec<EksNodeGroup>> {
+        let mut node_groups = vec![];
+        for name in self.list_nodegroups(cluster_name).await? {
+            // A group deleted between the two calls is simply skipped
+            if let Some(node_group) = self.describe_nodegroup(cluster_name, &name).await? {
+                node_groups.push(node_group);
+            }
+        }
+        Ok(node_groups)
+    }
+
+    /// Request a new desired size, returning the id of the update
+    pub async fn update_nodegroup_size(
+        &self,
+        cluster_name: &str,
+        nodegroup_name: &str,
+        desired_size: i32,
+    ) -> Result<String> {
+        self.clients
+            .eks
+            .update_nodegroup_size(cluster_name, nodegroup_name, desired_size)
+            .await
+    }
+
+    /// Status of a node group update: Pending, InProgress, Successful, Failed or Cancelled
+    pub async fn describe_update(
+        &self,
+        cluster_name: &str,
+        nodegroup_name: &str,
+        update_id: &str,
+    ) -> Result<String> {
+        self.clients
+            .eks
+            .describe_update(cluster_name, nodegroup_name, update_id)
+            .await
+    }
+
+    /// Version, config and add-on updates of the cluster, newest first
+    pub async fn cluster_updates(&self, cluster_name: &str) -> Result<Vec<ResourceEvent>> {
+        self.clients.eks.cluster_updates(cluster_name).await
+    }
+
+    /// The CloudWatch Logs group holding the cluster's control plane logs
+    pub async fn control_plane_log_group(&self, cluster_name: &str) -> Result<String> {
+        let cluster = self
+            .get_cluster(cluster_name)
+            .await?
+            .ok_or_else(|| AppError::ResourceNotFound(cluster_name.to_string()))?;
+        if cluster.enabled_log_types.is_empty() {
+            return Err(AppError::LoggingNotEnabled(format!(
+                "EKS cluster {}",
+                cluster_name
+            )));
+        }
+        Ok(format!("/aws/eks/{}/cluster", cluster_name))
+    }
+
+    /// Start creating a cluster; EKS returns it in the CREATING status and
+    /// takes several minutes to make it ACTIVE
+    pub async fn create_cluster(&self, cluster_name: &str) -> Result<EksCluster> {
+        self.clients.eks.create_cluster(cluster_name).await
     }
 
     pub async fn delete_cluster(&self, cluster_name: &str) -> Result<()> {
-        // This would implement actual EKS cluster deletion
-        // For Phase 1, we'll just log the action
-        tracing::info!("Deleting EKS cluster: {}", cluster_name);
-        Ok(())
+        self.clients.eks.delete_cluster(cluster_name).await
+    }
+}
+
+/// Check a name against the EKS cluster naming rules
+pub fn validate_cluster_name(name: &str) -> std::result::Result<(), String> {
+    if !(1..=100).contains(&name.len()) {
+        return Err("must be between 1 and 100 characters".to_string());
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || *c == '-' || *c == '_'))
+    {
+        return Err(format!(
+            "cannot contain '{}'; use letters, digits, hyphens and underscores",
+            c
+        ));
+    }
+    if !name.starts_with(|c: char| c.is_ascii_alphanumeric()) {
+        return Err("must begin with a letter or digit".to_string());
     }
+    Ok(())
 }
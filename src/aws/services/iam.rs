@@ -1,7 +1,162 @@
-use crate::aws::client::RegionClients;
-use crate::aws::types::IamUser;
+use crate::aws::client::{ApiFuture, RegionClients};
+use crate::aws::types::{IamRole, IamUser, IamUserDetails, IAM_ROLE_ID_PREFIX};
 use crate::utils::error::Result;
 
+/// Most items IAM returns per list call (`MaxItems`)
+pub const IAM_PAGE_SIZE: usize = 100;
+
+/// The IAM entities listed together on the IAM page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IamEntity {
+    Users,
+    Roles,
+}
+
+/// One page of an IAM list call; `marker` is set while more pages remain
+#[derive(Debug, Clone)]
+pub struct IamPage<T> {
+    pub items: Vec<T>,
+    pub marker: Option<String>,
+}
+
+impl<T: Clone> IamPage<T> {
+    /// Cut a page out of a complete list, using the index of the next item as the marker
+    pub fn slice(all: &[T], marker: Option<&str>) -> Self {
+        let start = marker.and_then(|m| m.parse().ok()).unwrap_or(0);
+        let end = (start + IAM_PAGE_SIZE).min(all.len());
+        Self {
+            items: all.get(start..end).unwrap_or_default().to_vec(),
+            marker: (end < all.len()).then(|| end.to_string()),
+        }
+    }
+}
+
+/// The IAM calls the app makes, implemented by the real client and by demo data
+pub trait IamApi: Send + Sync {
+    fn list_account_aliases(&self) -> ApiFuture<'_, Vec<String>>;
+    /// Evaluate `actions` against the principal's policies, as (action, allowed) pairs
+    fn simulate_principal_policy<'a>(
+        &'a self,
+        policy_source_arn: &'a str,
+        actions: &'a [String],
+    ) -> ApiFuture<'a, Vec<(String, bool)>>;
+    fn list_users_page<'a>(&'a self, marker: Option<&'a str>) -> ApiFuture<'a, IamPage<IamUser>>;
+    fn list_roles_page<'a>(&'a self, marker: Option<&'a str>) -> ApiFuture<'a, IamPage<IamRole>>;
+    fn get_user<'a>(&'a self, user_name: &'a str) -> ApiFuture<'a, Option<IamUser>>;
+    fn get_role<'a>(&'a self, role_name: &'a str) -> ApiFuture<'a, Option<IamRole>>;
+    fn list_groups_for_user<'a>(&'a self, user_name: &'a str) -> ApiFuture<'a, Vec<String>>;
+    fn list_attached_user_policies<'a>(&'a self, user_name: &'a str) -> ApiFuture<'a, Vec<String>>;
+    fn create_user<'a>(&'a self, user_name: &'a str) -> ApiFuture<'a, ()>;
+    fn delete_user<'a>(&'a self, user_name: &'a str) -> ApiFuture<'a, ()>;
+}
+
+/// IAM client; IAM is global, so every region's clients answer the same
+#[derive(Default)]
+pub struct IamClient {
+    // The SDK client will be added back in Phase 2
+}
+
+impl IamClient {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl IamApi for IamClient {
+    fn list_account_aliases(&self) -> ApiFuture<'_, Vec<String>> {
+        // This would implement actual IAM ListAccountAliases
+        // For Phase 1, we'll return mock data
+        Box::pin(async { Ok(vec![]) })
+    }
+
+    fn simulate_principal_policy<'a>(
+        &'a self,
+        policy_source_arn: &'a str,
+        actions: &'a [String],
+    ) -> ApiFuture<'a, Vec<(String, bool)>> {
+        // This would implement actual IAM SimulatePrincipalPolicy
+        // For Phase 1, we'll report every action as allowed
+        Box::pin(async move {
+            tracing::debug!(
+                "Simulating {} actions for {}",
+                actions.len(),
+                policy_source_arn
+            );
+            Ok(actions
+                .iter()
+                .map(|action| (action.clone(), true))
+                .collect())
+        })
+    }
+
+    fn list_users_page<'a>(&'a self, _marker: Option<&'a str>) -> ApiFuture<'a, IamPage<IamUser>> {
+        // This would implement actual IAM ListUsers with Marker and MaxItems
+        // For Phase 1, we'll return mock data
+        Box::pin(async {
+            Ok(IamPage {
+                items: vec![],
+                marker: None,
+            })
+        })
+    }
+
+    fn list_roles_page<'a>(&'a self, _marker: Option<&'a str>) -> ApiFuture<'a, IamPage<IamRole>> {
+        // This would implement actual IAM ListRoles with Marker and MaxItems
+        // For Phase 1, we'll return mock data
+        Box::pin(async {
+            Ok(IamPage {
+                items: vec![],
+                marker: None,
+            })
+        })
+    }
+
+    fn get_user<'a>(&'a self, _user_name: &'a str) -> ApiFuture<'a, Option<IamUser>> {
+        // This would implement actual IAM user retrieval
+        // For Phase 1, we'll return None
+        Box::pin(async { Ok(None) })
+    }
+
+    fn get_role<'a>(&'a self, _role_name: &'a str) -> ApiFuture<'a, Option<IamRole>> {
+        // This would implement actual IAM GetRole
+        // For Phase 1, we'll return None
+        Box::pin(async { Ok(None) })
+    }
+
+    fn list_groups_for_user<'a>(&'a self, _user_name: &'a str) -> ApiFuture<'a, Vec<String>> {
+        // This would implement actual IAM ListGroupsForUser, following its markers
+        // For Phase 1, we'll return mock data
+        Box::pin(async { Ok(vec![]) })
+    }
+
+    fn list_attached_user_policies<'a>(
+        &'a self,
+        _user_name: &'a str,
+    ) -> ApiFuture<'a, Vec<String>> {
+        // This would implement actual IAM ListAttachedUserPolicies, following its markers
+        // For Phase 1, we'll return mock data
+        Box::pin(async { Ok(vec![]) })
+    }
+
+    fn create_user<'a>(&'a self, user_name: &'a str) -> ApiFuture<'a, ()> {
+        // This would implement actual IAM user creation
+        // For Phase 1, we'll just log the action
+        Box::pin(async move {
+            tracing::info!("Creating IAM user: {}", user_name);
+            Ok(())
+        })
+    }
+
+    fn delete_user<'a>(&'a self, user_name: &'a str) -> ApiFuture<'a, ()> {
+        // This would implement actual IAM user deletion
+        // For Phase 1, we'll just log the action
+        Box::pin(async move {
+            tracing::info!("Deleting IAM user: {}", user_name);
+            Ok(())
+        })
+    }
+}
+
 pub struct IamService<'a> {
     clients: &'a RegionClients,
 }
@@ -11,29 +166,88 @@ impl<'a> IamService<'a> {
         Self { clients }
     }
 
+    pub async fn list_account_aliases(&self) -> Result<Vec<String>> {
+        self.clients.iam.list_account_aliases().await
+    }
+
+    /// Evaluate `actions` against the principal's policies, as (action, allowed) pairs
+    pub async fn simulate_principal_policy(
+        &self,
+        policy_source_arn: &str,
+        actions: &[String],
+    ) -> Result<Vec<(String, bool)>> {
+        self.clients
+            .iam
+            .simulate_principal_policy(policy_source_arn, actions)
+            .await
+    }
+
+    /// Every user, following the markers through all pages
     pub async fn list_users(&self) -> Result<Vec<IamUser>> {
-        // This would implement actual IAM user listing
-        // For Phase 1, we'll return mock data
-        Ok(vec![])
+        let mut users = Vec::new();
+        let mut marker = None;
+        loop {
+            let page = self.list_users_page(marker.as_deref()).await?;
+            users.extend(page.items);
+            marker = page.marker;
+            if marker.is_none() {
+                return Ok(users);
+            }
+        }
+    }
+
+    pub async fn list_users_page(&self, marker: Option<&str>) -> Result<IamPage<IamUser>> {
+        self.clients.iam.list_users_page(marker).await
+    }
+
+    /// Every role, following the markers through all pages
+    pub async fn list_roles(&self) -> Result<Vec<IamRole>> {
+        let mut roles = Vec::new();
+        let mut marker = None;
+        loop {
+            let page = self.list_roles_page(marker.as_deref()).await?;
+            roles.extend(page.items);
+            marker = page.marker;
+            if marker.is_none() {
+                return Ok(roles);
+            }
+        }
+    }
+
+    pub async fn list_roles_page(&self, marker: Option<&str>) -> Result<IamPage<IamRole>> {
+        self.clients.iam.list_roles_page(marker).await
     }
 
     pub async fn get_user(&self, user_name: &str) -> Result<Option<IamUser>> {
-        // This would implement actual IAM user retrieval
-        // For Phase 1, we'll return None
-        Ok(None)
+        self.clients.iam.get_user(user_name).await
+    }
+
+    pub async fn get_role(&self, role_name: &str) -> Result<Option<IamRole>> {
+        self.clients.iam.get_role(role_name).await
+    }
+
+    /// The user's groups and attached managed policies, fetched together
+    pub async fn user_details(&self, user_name: &str) -> Result<IamUserDetails> {
+        let (groups, attached_policies) = tokio::try_join!(
+            self.clients.iam.list_groups_for_user(user_name),
+            self.clients.iam.list_attached_user_policies(user_name),
+        )?;
+        Ok(IamUserDetails {
+            groups,
+            attached_policies,
+        })
     }
 
     pub async fn create_user(&self, user_name: &str) -> Result<()> {
-        // This would implement actual IAM user creation
-        // For Phase 1, we'll just log the action
-        tracing::info!("Creating IAM user: {}", user_name);
-        Ok(())
+        self.clients.iam.create_user(user_name).await
     }
 
     pub async fn delete_user(&self, user_name: &str) -> Result<()> {
-        // This would implement actual IAM user deletion
-        // For Phase 1, we'll just log the action
-        tracing::info!("Deleting IAM user: {}", user_name);
-        Ok(())
+        self.clients.iam.delete_user(user_name).await
     }
 }
+
+/// The role name behind an IAM resource id, or `None` for a user
+pub fn role_name(resource_id: &str) -> Option<&str> {
+    resource_id.strip_prefix(IAM_ROLE_ID_PREFIX)
+}
@@ -1,6 +1,114 @@
+pub mod cloudwatch_logs;
+pub mod cost_explorer;
 pub mod ec2;
 pub mod eks;
 pub mod iam;
 pub mod rds;
 pub mod s3;
 pub mod secrets;
+
+#[cfg(test)]
+mod s3_test;
+
+use crate::aws::client::RegionClients;
+use crate::aws::types::{Resource, ServiceType};
+use crate::utils::error::Result;
+
+/// List every resource of a service in the clients' region as generic resources
+pub async fn list_resources(
+    clients: &RegionClients,
+    service_type: ServiceType,
+) -> Result<Vec<Resource>> {
+    let region = clients.region.as_str();
+
+    let resources = match service_type {
+        ServiceType::EC2 => ec2::Ec2Service::new(clients)
+            .list_instances()
+            .await?
+            .iter()
+            .map(|instance| instance.to_resource(region))
+            .collect(),
+        ServiceType::S3 => s3::S3Service::new(clients)
+            .list_buckets()
+            .await?
+            .iter()
+            .map(|bucket| bucket.to_resource())
+            .collect(),
+        ServiceType::RDS => rds::RdsService::new(clients)
+            .list_instances()
+            .await?
+            .iter()
+            .map(|instance| instance.to_resource(region))
+            .collect(),
+        ServiceType::IAM => {
+            let iam = iam::IamService::new(clients);
+            let (users, roles) = tokio::try_join!(iam.list_users(), iam.list_roles())?;
+            users
+                .iter()
+                .map(|user| user.to_resource(region))
+                .chain(roles.iter().map(|role| role.to_resource(region)))
+                .collect()
+        }
+        ServiceType::Secrets => secrets::SecretsService::new(clients)
+            .list_secrets()
+            .await?
+            .iter()
+            .map(|secret| secret.to_resource(region))
+            .collect(),
+        ServiceType::EKS => eks::EksService::new(clients)
+            .list_clusters()
+            .await?
+            .iter()
+            .map(|cluster| cluster.to_resource(region))
+            .collect(),
+    };
+
+    Ok(resources)
+}
+
+/// Describe a single resource by id, or `None` when the service no longer has it
+pub async fn describe_resource(
+    clients: &RegionClients,
+    service_type: ServiceType,
+    resource_id: &str,
+) -> Result<Option<Resource>> {
+    let region = clients.region.as_str();
+
+    let resource = match service_type {
+        ServiceType::EC2 => ec2::Ec2Service::new(clients)
+            .get_instance(resource_id)
+            .await?
+            .map(|instance| instance.to_resource(region)),
+        ServiceType::S3 => s3::S3Service::new(clients)
+            .get_bucket(resource_id)
+            .await?
+            .map(|bucket| bucket.to_resource()),
+        ServiceType::RDS => rds::RdsService::new(clients)
+            .get_instance(resource_id)
+            .await?
+            .map(|instance| instance.to_resource(region)),
+        ServiceType::IAM => {
+            let iam = iam::IamService::new(clients);
+            match iam::role_name(resource_id) {
+                Some(role_name) => iam
+                    .get_role(role_name)
+                    .await?
+                    .map(|role| role.to_resource(region)),
+                None => iam
+                    .get_user(resource_id)
+                    .await?
+                    .map(|user| user.to_resource(region)),
+            }
+        }
+        ServiceType::Secrets => secrets::SecretsService::new(clients)
+            .get_secret(resource_id)
+            .await?
+            .map(|secret| secret.to_resource(region)),
+        ServiceType::EKS => eks::EksService::new(clients)
+            .get_cluster(resource_id)
+            .await?
+            .map(|cluster| cluster.to_resource(region)),
+    };
+
+    Ok(resource)
+}
@@ -1,6 +1,75 @@
-use crate::aws::client::RegionClients;
-use crate::aws::types::RdsInstance;
-use crate::utils::error::Result;
+use crate::aws::client::{ApiFuture, RegionClients};
+use crate::aws::types::{RdsInstance, ResourceEvent};
+use crate::utils::error::{AppError, Result};
+
+/// The RDS calls the app makes, implemented by the real client and by demo data
+pub trait RdsApi: Send + Sync {
+    fn list_instances(&self) -> ApiFuture<'_, Vec<RdsInstance>>;
+    fn get_instance<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, Option<RdsInstance>>;
+    /// Recent events of one DB instance, newest first
+    fn describe_events<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, Vec<ResourceEvent>>;
+    fn create_instance<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, ()>;
+    fn delete_instance<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, ()>;
+}
+
+/// RDS client for one region
+pub struct RdsClient {
+    // The SDK client will be added back in Phase 2
+    region: String,
+}
+
+impl RdsClient {
+    pub fn new(region: &str) -> Self {
+        Self {
+            region: region.to_string(),
+        }
+    }
+}
+
+impl RdsApi for RdsClient {
+    fn list_instances(&self) -> ApiFuture<'_, Vec<RdsInstance>> {
+        // This would implement actual RDS instance listing
+        // For Phase 1, we'll return mock data
+        Box::pin(async { Ok(vec![]) })
+    }
+
+    fn get_instance<'a>(&'a self, _instance_id: &'a str) -> ApiFuture<'a, Option<RdsInstance>> {
+        // This would implement actual RDS instance retrieval
+        // For Phase 1, we'll return None
+        Box::pin(async { Ok(None) })
+    }
+
+    fn describe_events<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, Vec<ResourceEvent>> {
+        // This would implement actual RDS DescribeEvents for the db-instance source
+        // For Phase 1, we'll return mock data
+        Box::pin(async move {
+            tracing::debug!(
+                "Describing events of RDS instance {} in {}",
+                instance_id,
+                self.region
+            );
+            Ok(vec![])
+        })
+    }
+
+    fn create_instance<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, ()> {
+        // This would implement actual RDS instance creation
+        // For Phase 1, we'll just log the action
+        Box::pin(async move {
+            tracing::info!("Creating RDS instance {} in {}", instance_id, self.region);
+            Ok(())
+        })
+    }
+
+    fn delete_instance<'a>(&'a self, instance_id: &'a str) -> ApiFuture<'a, ()> {
+        // This would implement actual RDS instance deletion
+        // For Phase 1, we'll just log the action
+        Box::pin(async move {
+            tracing::info!("Deleting RDS instance {} in {}", instance_id, self.region);
+            Ok(())
+        })
+    }
+}
 
 pub struct RdsService<'a> {
     clients: &'a RegionClients,
@@ -12,28 +81,42 @@ impl<'a> RdsService<'a> {
     }
 
     pub async fn list_instances(&self) -> Result<Vec<RdsInstance>> {
-        // This would implement actual RDS instance listing
-        // For Phase 1, we'll return mock data
-        Ok(vec![])
+        self.clients.rds.list_instances().await
     }
 
     pub async fn get_instance(&self, instance_id: &str) -> Result<Option<RdsInstance>> {
-        // This would implement actual RDS instance retrieval
-        // For Phase 1, we'll return None
-        Ok(None)
+        self.clients.rds.get_instance(instance_id).await
+    }
+
+    /// Recent events of one DB instance, newest first
+    pub async fn describe_events(&self, instance_id: &str) -> Result<Vec<ResourceEvent>> {
+        self.clients.rds.describe_events(instance_id).await
+    }
+
+    /// CloudWatch Logs groups the instance exports to, one per enabled log type
+    pub async fn log_groups(&self, instance_id: &str) -> Result<Vec<String>> {
+        let instance = self
+            .get_instance(instance_id)
+            .await?
+            .ok_or_else(|| AppError::ResourceNotFound(instance_id.to_string()))?;
+        if instance.enabled_cloudwatch_logs_exports.is_empty() {
+            return Err(AppError::LoggingNotEnabled(format!(
+                "RDS instance {}",
+                instance_id
+            )));
+        }
+        Ok(instance
+            .enabled_cloudwatch_logs_exports
+            .iter()
+            .map(|log_type| format!("/aws/rds/instance/{}/{}", instance_id, log_type))
+            .collect())
     }
 
     pub async fn create_instance(&self, instance_id: &str) -> Result<()> {
-        // This would implement actual RDS instance creation
-        // For Phase 1, we'll just log the action
-        tracing::info!("Creating RDS instance: {}", instance_id);
-        Ok(())
+        self.clients.rds.create_instance(instance_id).await
     }
 
     pub async fn delete_instance(&self, instance_id: &str) -> Result<()> {
-        // This would implement actual RDS instance deletion
-        // For Phase 1, we'll just log the action
-        tracing::info!("Deleting RDS instance: {}", instance_id);
-        Ok(())
+        self.clients.rds.delete_instance(instance_id).await
     }
 }
@@ -1,6 +1,76 @@
-use crate::aws::client::RegionClients;
+use crate::aws::client::{ApiFuture, RegionClients};
 use crate::aws::types::S3Bucket;
-use crate::utils::error::Result;
+use crate::utils::error::{AppError, Result};
+
+/// The region S3 treats as the default; CreateBucket rejects it as a `LocationConstraint`
+pub const S3_DEFAULT_REGION: &str = "us-east-1";
+
+/// Parameters for an S3 CreateBucket call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateBucketRequest {
+    pub bucket: String,
+    /// `None` for us-east-1, where the constraint must be omitted
+    pub location_constraint: Option<String>,
+}
+
+/// The S3 calls the app makes, implemented by the real client and by test fakes
+pub trait S3Api: Send + Sync {
+    fn list_buckets(&self) -> ApiFuture<'_, Vec<S3Bucket>>;
+    fn get_bucket<'a>(&'a self, bucket_name: &'a str) -> ApiFuture<'a, Option<S3Bucket>>;
+    fn create_bucket<'a>(&'a self, request: &'a CreateBucketRequest) -> ApiFuture<'a, ()>;
+    fn delete_bucket<'a>(&'a self, bucket_name: &'a str) -> ApiFuture<'a, ()>;
+}
+
+/// S3 client for one region
+pub struct S3Client {
+    // The SDK client will be added back in Phase 2
+    region: String,
+}
+
+impl S3Client {
+    pub fn new(region: &str) -> Self {
+        Self {
+            region: region.to_string(),
+        }
+    }
+}
+
+impl S3Api for S3Client {
+    fn list_buckets(&self) -> ApiFuture<'_, Vec<S3Bucket>> {
+        // This would implement actual S3 bucket listing
+        // For Phase 1, we'll return mock data
+        Box::pin(async { Ok(vec![]) })
+    }
+
+    fn get_bucket<'a>(&'a self, _bucket_name: &'a str) -> ApiFuture<'a, Option<S3Bucket>> {
+        // This would implement actual S3 bucket retrieval
+        // For Phase 1, we'll return None
+        Box::pin(async { Ok(None) })
+    }
+
+    fn create_bucket<'a>(&'a self, request: &'a CreateBucketRequest) -> ApiFuture<'a, ()> {
+        // This would implement actual S3 bucket creation
+        // For Phase 1, we'll just log the action
+        Box::pin(async move {
+            tracing::info!(
+                "Creating S3 bucket {} in {} (location constraint: {})",
+                request.bucket,
+                self.region,
+                request.location_constraint.as_deref().unwrap_or("none")
+            );
+            Ok(())
+        })
+    }
+
+    fn delete_bucket<'a>(&'a self, bucket_name: &'a str) -> ApiFuture<'a, ()> {
+        // This would implement actual S3 bucket deletion
+        // For Phase 1, we'll just log the action
+        Box::pin(async move {
+            tracing::info!("Deleting S3 bucket: {}", bucket_name);
+            Ok(())
+        })
+    }
+}
 
 pub struct S3Service<'a> {
     clients: &'a RegionClients,
@@ -12,28 +82,70 @@ impl<'a> S3Service<'a> {
     }
 
     pub async fn list_buckets(&self) -> Result<Vec<S3Bucket>> {
-        // This would implement actual S3 bucket listing
-        // For Phase 1, we'll return mock data
-        Ok(vec![])
+        self.clients.s3.list_buckets().await
     }
 
     pub async fn get_bucket(&self, bucket_name: &str) -> Result<Option<S3Bucket>> {
-        // This would implement actual S3 bucket retrieval
-        // For Phase 1, we'll return None
-        Ok(None)
+        self.clients.s3.get_bucket(bucket_name).await
     }
 
     pub async fn create_bucket(&self, bucket_name: &str) -> Result<()> {
-        // This would implement actual S3 bucket creation
-        // For Phase 1, we'll just log the action
-        tracing::info!("Creating S3 bucket: {}", bucket_name);
-        Ok(())
+        create_bucket(self.clients.s3.as_ref(), bucket_name, &self.clients.region).await
     }
 
     pub async fn delete_bucket(&self, bucket_name: &str) -> Result<()> {
-        // This would implement actual S3 bucket deletion
-        // For Phase 1, we'll just log the action
-        tracing::info!("Deleting S3 bucket: {}", bucket_name);
-        Ok(())
+        self.clients.s3.delete_bucket(bucket_name).await
+    }
+}
+
+/// Validate the name and create the bucket in `region`
+pub async fn create_bucket(api: &dyn S3Api, bucket_name: &str, region: &str) -> Result<()> {
+    let request = create_bucket_request(bucket_name, region)?;
+    api.create_bucket(&request).await
+}
+
+/// Build a CreateBucket request, rejecting invalid names before any API call
+pub fn create_bucket_request(bucket_name: &str, region: &str) -> Result<CreateBucketRequest> {
+    validate_bucket_name(bucket_name).map_err(|reason| {
+        AppError::Validation(format!("Invalid bucket name '{}': {}", bucket_name, reason))
+    })?;
+
+    Ok(CreateBucketRequest {
+        bucket: bucket_name.to_string(),
+        location_constraint: (region != S3_DEFAULT_REGION).then(|| region.to_string()),
+    })
+}
+
+/// Check a name against the S3 general purpose bucket naming rules
+pub fn validate_bucket_name(name: &str) -> std::result::Result<(), String> {
+    if !(3..=63).contains(&name.len()) {
+        return Err("must be between 3 and 63 characters".to_string());
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| !(c.is_ascii_lowercase() || c.is_ascii_digit() || *c == '.' || *c == '-'))
+    {
+        return Err(format!(
+            "cannot contain '{}'; use lowercase letters, digits, dots and hyphens",
+            c
+        ));
+    }
+
+    let is_alphanumeric = |c: Option<char>| c.is_some_and(|c| c.is_ascii_alphanumeric());
+    if !is_alphanumeric(name.chars().next()) || !is_alphanumeric(name.chars().last()) {
+        return Err("must begin and end with a letter or digit".to_string());
+    }
+    if name.contains("..") {
+        return Err("cannot contain two adjacent dots".to_string());
+    }
+    if name.parse::<std::net::Ipv4Addr>().is_ok() {
+        return Err("cannot be formatted as an IP address".to_string());
+    }
+    if name.starts_with("xn--") || name.starts_with("sthree-") {
+        return Err("cannot start with a reserved prefix".to_string());
+    }
+    if name.ends_with("-s3alias") || name.ends_with("--ol-s3") {
+        return Err("cannot end with a reserved suffix".to_string());
     }
+    Ok(())
 }
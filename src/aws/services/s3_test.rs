@@ -0,0 +1,95 @@
+use crate::aws::client::ApiFuture;
+use crate::aws::services::s3::{
+    create_bucket, create_bucket_request, validate_bucket_name, CreateBucketRequest, S3Api,
+};
+use crate::aws::types::S3Bucket;
+use crate::utils::error::AppError;
+use std::sync::Mutex;
+
+/// Records every request instead of calling S3
+#[derive(Default)]
+struct RecordingS3 {
+    requests: Mutex<Vec<CreateBucketRequest>>,
+}
+
+impl S3Api for RecordingS3 {
+    fn list_buckets(&self) -> ApiFuture<'_, Vec<S3Bucket>> {
+        Box::pin(async { Ok(vec![]) })
+    }
+
+    fn get_bucket<'a>(&'a self, _bucket_name: &'a str) -> ApiFuture<'a, Option<S3Bucket>> {
+        Box::pin(async { Ok(None) })
+    }
+
+    fn create_bucket<'a>(&'a self, request: &'a CreateBucketRequest) -> ApiFuture<'a, ()> {
+        self.requests.lock().unwrap().push(request.clone());
+        Box::pin(async { Ok(()) })
+    }
+
+    fn delete_bucket<'a>(&'a self, _bucket_name: &'a str) -> ApiFuture<'a, ()> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+#[tokio::test]
+async fn us_east_1_omits_location_constraint() {
+    let s3 = RecordingS3::default();
+    create_bucket(&s3, "my-bucket", "us-east-1").await.unwrap();
+
+    let requests = s3.requests.lock().unwrap();
+    assert_eq!(
+        *requests,
+        vec![CreateBucketRequest {
+            bucket: "my-bucket".to_string(),
+            location_constraint: None,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn other_regions_include_location_constraint() {
+    let s3 = RecordingS3::default();
+    create_bucket(&s3, "my-bucket", "eu-west-1").await.unwrap();
+
+    let requests = s3.requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests[0].location_constraint.as_deref(),
+        Some("eu-west-1")
+    );
+}
+
+#[tokio::test]
+async fn invalid_names_are_rejected_before_any_call() {
+    let s3 = RecordingS3::default();
+    for name in [
+        "ab",
+        "My-Bucket",
+        "-bucket",
+        "bucket..name",
+        "192.168.1.1",
+        "xn--bucket",
+    ] {
+        let result = create_bucket(&s3, name, "eu-west-1").await;
+        assert!(
+            matches!(result, Err(AppError::Validation(_))),
+            "{} should be rejected",
+            name
+        );
+    }
+
+    assert!(s3.requests.lock().unwrap().is_empty());
+}
+
+#[test]
+fn valid_names_pass_validation() {
+    for name in ["abc", "my-bucket", "logs.example.com", "a1-b2"] {
+        assert!(
+            validate_bucket_name(name).is_ok(),
+            "{} should be valid",
+            name
+        );
+    }
+    assert!(validate_bucket_name(&"a".repeat(64)).is_err());
+    assert!(create_bucket_request(&"a".repeat(63), "us-east-1").is_ok());
+}
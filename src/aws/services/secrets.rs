@@ -1,6 +1,86 @@
-use crate::aws::client::RegionClients;
+use crate::aws::client::{ApiFuture, RegionClients};
 use crate::aws::types::Secret;
-use crate::utils::error::Result;
+use crate::utils::error::{AppError, Result};
+
+/// The Secrets Manager calls the app makes, implemented by the real client and by demo data
+pub trait SecretsApi: Send + Sync {
+    fn list_secrets(&self) -> ApiFuture<'_, Vec<Secret>>;
+    fn get_secret<'a>(&'a self, secret_name: &'a str) -> ApiFuture<'a, Option<Secret>>;
+    fn create_secret<'a>(
+        &'a self,
+        secret_name: &'a str,
+        secret_value: &'a str,
+    ) -> ApiFuture<'a, ()>;
+    fn delete_secret<'a>(&'a self, secret_name: &'a str) -> ApiFuture<'a, ()>;
+    /// Returns the version id of the pending secret, when the service reports it
+    fn rotate_secret<'a>(&'a self, secret_name: &'a str) -> ApiFuture<'a, Option<String>>;
+}
+
+/// Secrets Manager client for one region
+pub struct SecretsClient {
+    // The SDK client will be added back in Phase 2
+    region: String,
+}
+
+impl SecretsClient {
+    pub fn new(region: &str) -> Self {
+        Self {
+            region: region.to_string(),
+        }
+    }
+}
+
+impl SecretsApi for SecretsClient {
+    fn list_secrets(&self) -> ApiFuture<'_, Vec<Secret>> {
+        // This would implement actual Secrets Manager listing
+        // For Phase 1, we'll return mock data
+        Box::pin(async { Ok(vec![]) })
+    }
+
+    fn get_secret<'a>(&'a self, _secret_name: &'a str) -> ApiFuture<'a, Option<Secret>> {
+        // This would implement actual secret retrieval
+        // For Phase 1, we'll return None
+        Box::pin(async { Ok(None) })
+    }
+
+    fn create_secret<'a>(
+        &'a self,
+        secret_name: &'a str,
+        secret_value: &'a str,
+    ) -> ApiFuture<'a, ()> {
+        // This would implement actual secret creation; ResourceExistsException
+        // maps to AppError::ResourceExists
+        // For Phase 1, we'll just log the action
+        Box::pin(async move {
+            tracing::info!(
+                "Creating secret {} ({} bytes) in {}",
+                secret_name,
+                secret_value.len(),
+                self.region
+            );
+            Ok(())
+        })
+    }
+
+    fn delete_secret<'a>(&'a self, secret_name: &'a str) -> ApiFuture<'a, ()> {
+        // This would implement actual secret deletion
+        // For Phase 1, we'll just log the action
+        Box::pin(async move {
+            tracing::info!("Deleting secret {} in {}", secret_name, self.region);
+            Ok(())
+        })
+    }
+
+    fn rotate_secret<'a>(&'a self, secret_name: &'a str) -> ApiFuture<'a, Option<String>> {
+        // This would implement actual RotateSecret; an InvalidRequestException
+        // about missing rotation configuration maps to RotationNotConfigured
+        // For Phase 1, we'll just log the action
+        Box::pin(async move {
+            tracing::info!("Rotating secret {} in {}", secret_name, self.region);
+            Ok(None)
+        })
+    }
+}
 
 pub struct SecretsService<'a> {
     clients: &'a RegionClients,
@@ -12,28 +92,39 @@ impl<'a> SecretsService<'a> {
     }
 
     pub async fn list_secrets(&self) -> Result<Vec<Secret>> {
-        // This would implement actual Secrets Manager listing
-        // For Phase 1, we'll return mock data
-        Ok(vec![])
+        self.clients.secrets.list_secrets().await
     }
 
     pub async fn get_secret(&self, secret_name: &str) -> Result<Option<Secret>> {
-        // This would implement actual secret retrieval
-        // For Phase 1, we'll return None
-        Ok(None)
+        self.clients.secrets.get_secret(secret_name).await
     }
 
+    /// Create a secret; an existing name fails with `AppError::ResourceExists`
     pub async fn create_secret(&self, secret_name: &str, secret_value: &str) -> Result<()> {
-        // This would implement actual secret creation
-        // For Phase 1, we'll just log the action
-        tracing::info!("Creating secret: {}", secret_name);
-        Ok(())
+        if self.get_secret(secret_name).await?.is_some() {
+            return Err(AppError::ResourceExists(format!(
+                "A secret named {}",
+                secret_name
+            )));
+        }
+        self.clients
+            .secrets
+            .create_secret(secret_name, secret_value)
+            .await
     }
 
     pub async fn delete_secret(&self, secret_name: &str) -> Result<()> {
-        // This would implement actual secret deletion
-        // For Phase 1, we'll just log the action
-        tracing::info!("Deleting secret: {}", secret_name);
-        Ok(())
+        self.clients.secrets.delete_secret(secret_name).await
+    }
+
+    /// Start rotating a secret, returning the version id of the pending secret.
+    /// Secrets without a rotation Lambda fail with `AppError::RotationNotConfigured`.
+    pub async fn rotate_secret(&self, secret_name: &str) -> Result<Option<String>> {
+        if let Some(secret) = self.get_secret(secret_name).await? {
+            if secret.rotation_lambda_arn.is_none() {
+                return Err(AppError::RotationNotConfigured(secret_name.to_string()));
+            }
+        }
+        self.clients.secrets.rotate_secret(secret_name).await
     }
 }
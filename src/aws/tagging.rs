@@ -3,6 +3,91 @@ use crate::utils::error::Result;
 use crate::utils::helpers::{extract_region_from_arn, extract_resource_id};
 use std::collections::HashMap;
 
+/// One key's difference between a resource's current and edited tags
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TagChange {
+    Added {
+        key: String,
+        value: String,
+    },
+    Changed {
+        key: String,
+        old: String,
+        new: String,
+    },
+    Removed {
+        key: String,
+        value: String,
+    },
+}
+
+/// Every change an edit makes to a tag set, sorted by key
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagDiff {
+    pub changes: Vec<TagChange>,
+}
+
+impl TagDiff {
+    pub fn between(original: &HashMap<String, String>, edited: &HashMap<String, String>) -> Self {
+        let mut changes: Vec<TagChange> = edited
+            .iter()
+            .filter_map(|(key, value)| match original.get(key) {
+                None => Some(TagChange::Added {
+                    key: key.clone(),
+                    value: value.clone(),
+                }),
+                Some(old) if old != value => Some(TagChange::Changed {
+                    key: key.clone(),
+                    old: old.clone(),
+                    new: value.clone(),
+                }),
+                Some(_) => None,
+            })
+            .collect();
+        changes.extend(
+            original
+                .iter()
+                .filter(|(key, _)| !edited.contains_key(*key))
+                .map(|(key, value)| TagChange::Removed {
+                    key: key.clone(),
+                    value: value.clone(),
+                }),
+        );
+        changes.sort_by(|a, b| a.key().cmp(b.key()));
+
+        Self { changes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// e.g. "2 added, 1 removed"
+    pub fn summary(&self) -> String {
+        let count = |f: fn(&TagChange) -> bool| self.changes.iter().filter(|c| f(c)).count();
+        let parts: Vec<String> = [
+            (count(|c| matches!(c, TagChange::Added { .. })), "added"),
+            (count(|c| matches!(c, TagChange::Changed { .. })), "changed"),
+            (count(|c| matches!(c, TagChange::Removed { .. })), "removed"),
+        ]
+        .into_iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, label)| format!("{} {}", n, label))
+        .collect();
+        parts.join(", ")
+    }
+}
+
+impl TagChange {
+    pub fn key(&self) -> &str {
+        match self {
+            TagChange::Added { key, .. }
+            | TagChange::Changed { key, .. }
+            | TagChange::Removed { key, .. } => key,
+        }
+    }
+}
+
 pub struct TaggingService {
     // client: TaggingClient,
 }
@@ -33,6 +118,39 @@ impl TaggingService {
         Ok(())
     }
 
+    /// Send only the keys the diff touches, so an edit can never wipe tags it
+    /// did not mention the way a whole-set replace such as PutBucketTagging can
+    pub async fn apply_tag_diff(&self, resource_arn: &str, diff: &TagDiff) -> Result<()> {
+        let upserts: Vec<ResourceTag> = diff
+            .changes
+            .iter()
+            .filter_map(|change| match change {
+                TagChange::Added { key, value }
+                | TagChange::Changed {
+                    key, new: value, ..
+                } => Some(ResourceTag {
+                    key: key.clone(),
+                    value: value.clone(),
+                }),
+                TagChange::Removed { .. } => None,
+            })
+            .collect();
+        let removals: Vec<String> = diff
+            .changes
+            .iter()
+            .filter(|change| matches!(change, TagChange::Removed { .. }))
+            .map(|change| change.key().to_string())
+            .collect();
+
+        if !upserts.is_empty() {
+            self.tag_resource(resource_arn, &upserts).await?;
+        }
+        if !removals.is_empty() {
+            self.untag_resource(resource_arn, &removals).await?;
+        }
+        Ok(())
+    }
+
     pub async fn get_all_resources(&self) -> Result<Vec<TaggedResource>> {
         // For Phase 1, return empty vec - will implement in Phase 2
         Ok(vec![])
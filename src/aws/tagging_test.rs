@@ -0,0 +1,67 @@
+use crate::aws::tagging::{TagChange, TagDiff};
+use std::collections::HashMap;
+
+fn tags(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn diff_reports_added_changed_and_removed_keys_sorted() {
+    let original = tags(&[("team", "web"), ("env", "dev"), ("owner", "ana")]);
+    let edited = tags(&[("team", "web"), ("env", "prod"), ("cost", "42")]);
+
+    let diff = TagDiff::between(&original, &edited);
+
+    assert_eq!(
+        diff.changes,
+        [
+            TagChange::Added {
+                key: "cost".to_string(),
+                value: "42".to_string(),
+            },
+            TagChange::Changed {
+                key: "env".to_string(),
+                old: "dev".to_string(),
+                new: "prod".to_string(),
+            },
+            TagChange::Removed {
+                key: "owner".to_string(),
+                value: "ana".to_string(),
+            },
+        ]
+    );
+    assert_eq!(diff.summary(), "1 added, 1 changed, 1 removed");
+}
+
+#[test]
+fn same_key_is_one_change_whichever_way_it_moves() {
+    let with = tags(&[("env", "dev")]);
+    let without = tags(&[]);
+
+    assert_eq!(
+        TagDiff::between(&without, &with).changes,
+        [TagChange::Added {
+            key: "env".to_string(),
+            value: "dev".to_string(),
+        }]
+    );
+    assert_eq!(
+        TagDiff::between(&with, &tags(&[("env", "prod")])).changes,
+        [TagChange::Changed {
+            key: "env".to_string(),
+            old: "dev".to_string(),
+            new: "prod".to_string(),
+        }]
+    );
+    assert_eq!(
+        TagDiff::between(&with, &without).changes,
+        [TagChange::Removed {
+            key: "env".to_string(),
+            value: "dev".to_string(),
+        }]
+    );
+    assert!(TagDiff::between(&with, &with).is_empty());
+}
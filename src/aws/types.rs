@@ -83,9 +83,54 @@ pub struct AwsProfile {
     pub source_profile: Option<String>,
     pub mfa_serial: Option<String>,
     pub external_id: Option<String>,
+    /// The config key (`sso_session`, `credential_process`, ...) of a source
+    /// the SDK resolves credentials from instead of static keys
+    pub credential_provider: Option<String>,
     pub credential_source: CredentialSource,
 }
 
+impl AwsProfile {
+    /// Whether the profile resolves to short-lived credentials (assumed role,
+    /// MFA session or an explicit session token) that expire
+    pub fn uses_temporary_credentials(&self) -> bool {
+        self.role_arn.is_some() || self.mfa_serial.is_some() || self.session_token.is_some()
+    }
+
+    /// When an ad-hoc assumed role profile stops working; `None` for profiles
+    /// loaded from files or the environment
+    pub fn ephemeral_expiry(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        match &self.credential_source {
+            CredentialSource::AssumedRole { expires_at, .. } => Some(*expires_at),
+            _ => None,
+        }
+    }
+
+    /// Whether anything could produce credentials for the profile: static
+    /// keys, a credential provider, or a role whose `source_profile` chain in
+    /// `profiles` ends in one of those
+    pub fn has_credentials(&self, profiles: &[AwsProfile]) -> bool {
+        let mut profile = self;
+        // Bounded so a source_profile cycle cannot loop forever
+        for _ in 0..=profiles.len() {
+            if profile.access_key_id.is_some() && profile.secret_access_key.is_some()
+                || profile.credential_provider.is_some()
+                || !matches!(profile.credential_source, CredentialSource::ConfigFile(_))
+            {
+                return true;
+            }
+            let source = profile
+                .role_arn
+                .as_ref()
+                .and(profile.source_profile.as_ref());
+            match source.and_then(|name| profiles.iter().find(|p| &p.name == name)) {
+                Some(source) => profile = source,
+                None => return false,
+            }
+        }
+        false
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ProfileMetadata {
     pub account_id: Option<String>,
@@ -110,12 +155,68 @@ pub enum ValidationStatus {
 pub enum CredentialSource {
     ConfigFile(String), // profile name
     Environment,
+    /// Role assumed ad hoc from another profile, kept in memory until it expires
+    AssumedRole {
+        source_profile: String,
+        expires_at: chrono::DateTime<chrono::Utc>,
+    },
+}
+
+/// Month-to-date spend reported by Cost Explorer
+#[derive(Debug, Clone)]
+pub struct CostSummary {
+    pub period_start: chrono::NaiveDate,
+    pub period_end: chrono::NaiveDate,
+    pub currency: String,
+    pub total: f64,
+    /// (service, amount), highest cost first
+    pub top_services: Vec<(String, f64)>,
+}
+
+/// Identity of the account the active profile resolves to
+#[derive(Debug, Clone)]
+pub struct AccountSummary {
+    pub account_id: String,
+    pub alias: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct AwsRegion {
     pub name: String,
     pub display_name: String,
+    /// Enabled only because the account opted in
+    pub opt_in: bool,
+}
+
+impl AwsRegion {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            display_name: crate::aws::regions::region_display_name(name).to_string(),
+            opt_in: crate::aws::regions::requires_opt_in(name),
+        }
+    }
+}
+
+/// A region as EC2 DescribeRegions reports it for the account
+#[derive(Debug, Clone)]
+pub struct RegionStatus {
+    pub name: String,
+    /// "opt-in-not-required", "opted-in" or "not-opted-in"
+    pub opt_in_status: String,
+}
+
+impl RegionStatus {
+    pub fn is_enabled(&self) -> bool {
+        self.opt_in_status != "not-opted-in"
+    }
+
+    pub fn to_region(&self) -> AwsRegion {
+        AwsRegion {
+            opt_in: self.opt_in_status == "opted-in",
+            ..AwsRegion::new(&self.name)
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -124,7 +225,7 @@ pub struct ResourceTag {
     pub value: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Resource {
     pub id: ResourceId,
     pub name: String,
@@ -132,9 +233,83 @@ pub struct Resource {
     pub region: String,
     pub arn: String,
     pub state: String,
+    /// Zone the resource is placed in, for zonal services such as EC2 and RDS
+    pub availability_zone: Option<String>,
     pub tags: HashMap<String, String>,
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
     pub last_modified: Option<chrono::DateTime<chrono::Utc>>,
+    /// Hardware size of an EC2 instance, e.g. "t3.medium"
+    pub instance_type: Option<String>,
+}
+
+/// A resource referenced by another one's describe output, such as an
+/// instance's VPC, that the detail page offers to jump to
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelatedResource {
+    pub kind: &'static str,
+    pub id: ResourceId,
+    /// Service whose detail page shows the related resource
+    pub service_type: ServiceType,
+}
+
+impl RelatedResource {
+    fn new(kind: &'static str, id: &str, service_type: ServiceType) -> Self {
+        Self {
+            kind,
+            id: id.to_string(),
+            service_type,
+        }
+    }
+
+    /// Services whose describe output lists related resources
+    pub fn supported(service_type: ServiceType) -> bool {
+        matches!(service_type, ServiceType::EC2 | ServiceType::RDS)
+    }
+}
+
+/// Something that happened to a resource, listed on the detail page's Events tab
+#[derive(Debug, Clone)]
+pub struct ResourceEvent {
+    /// When the event happened, if the API reports it
+    pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// Where the event came from, e.g. "Status check" or "Cluster update"
+    pub source: String,
+    pub message: String,
+}
+
+impl ResourceEvent {
+    pub fn new(
+        timestamp: Option<chrono::DateTime<chrono::Utc>>,
+        source: &str,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            timestamp,
+            source: source.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Services with an API that reports recent events for one resource
+    pub fn supported(service_type: ServiceType) -> bool {
+        matches!(
+            service_type,
+            ServiceType::EC2 | ServiceType::RDS | ServiceType::EKS
+        )
+    }
+
+    /// Newest first, with undated events last
+    pub fn sort_newest_first(events: &mut [ResourceEvent]) {
+        events.sort_by_key(|event| std::cmp::Reverse(event.timestamp));
+    }
+}
+
+/// One event read from a CloudWatch Logs log group
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEvent {
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub log_stream: String,
+    pub message: String,
 }
 
 #[derive(Debug, Clone)]
@@ -176,7 +351,65 @@ pub struct Ec2Instance {
     pub vpc_id: Option<String>,
     pub subnet_id: Option<String>,
     pub security_groups: Vec<String>,
+    /// The AMI the instance was launched from
+    pub image_id: Option<String>,
+    pub tags: HashMap<String, String>,
+    /// EBS volumes attached to the instance
+    pub volume_ids: Vec<String>,
     pub launch_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Why the instance last changed state, e.g. "User initiated (2024-01-15 10:30:00 GMT)"
+    pub state_transition_reason: Option<String>,
+}
+
+impl Ec2Instance {
+    pub fn related_resources(&self) -> Vec<RelatedResource> {
+        let mut related = vec![];
+        if let Some(vpc_id) = &self.vpc_id {
+            related.push(RelatedResource::new("VPC", vpc_id, ServiceType::EC2));
+        }
+        if let Some(subnet_id) = &self.subnet_id {
+            related.push(RelatedResource::new("Subnet", subnet_id, ServiceType::EC2));
+        }
+        related.extend(
+            self.security_groups
+                .iter()
+                .map(|group| RelatedResource::new("Security Group", group, ServiceType::EC2)),
+        );
+        related.extend(
+            self.volume_ids
+                .iter()
+                .map(|volume| RelatedResource::new("Volume", volume, ServiceType::EC2)),
+        );
+        related
+    }
+
+    /// Generic view of the instance for the shared resource cache
+    pub fn to_resource(&self, region: &str) -> Resource {
+        Resource {
+            id: self.instance_id.clone(),
+            name: self.instance_id.clone(),
+            service_type: ServiceType::EC2,
+            region: region.to_string(),
+            arn: format!("arn:aws:ec2:{}::instance/{}", region, self.instance_id),
+            state: self.state.clone(),
+            availability_zone: Some(self.availability_zone.clone()),
+            tags: self.tags.clone(),
+            created_at: self.launch_time,
+            last_modified: None,
+            instance_type: Some(self.instance_type.clone()),
+        }
+    }
+}
+
+/// The settings a RunInstances call launches one instance with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstanceLaunch {
+    pub instance_type: String,
+    pub image_id: String,
+    /// The default VPC's default subnet when unset
+    pub subnet_id: Option<String>,
+    pub security_group_ids: Vec<String>,
+    pub tags: Vec<(String, String)>,
 }
 
 #[derive(Debug, Clone)]
@@ -191,6 +424,25 @@ pub struct S3Bucket {
     pub object_count: Option<u64>,
 }
 
+impl S3Bucket {
+    /// Generic view of the bucket for the shared resource cache
+    pub fn to_resource(&self) -> Resource {
+        Resource {
+            id: self.name.clone(),
+            name: self.name.clone(),
+            service_type: ServiceType::S3,
+            region: self.region.clone(),
+            arn: format!("arn:aws:s3:::{}", self.name),
+            state: "Available".to_string(),
+            availability_zone: None,
+            tags: HashMap::new(),
+            created_at: self.creation_date,
+            last_modified: None,
+            instance_type: None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct RdsInstance {
     pub db_instance_identifier: String,
@@ -203,6 +455,42 @@ pub struct RdsInstance {
     pub endpoint: Option<String>,
     pub port: Option<i32>,
     pub vpc_security_groups: Vec<String>,
+    pub db_subnet_group: Option<String>,
+    /// Log types exported to CloudWatch Logs, e.g. "error" or "postgresql"
+    pub enabled_cloudwatch_logs_exports: Vec<String>,
+}
+
+impl RdsInstance {
+    pub fn related_resources(&self) -> Vec<RelatedResource> {
+        let mut related: Vec<RelatedResource> = self
+            .db_subnet_group
+            .iter()
+            .map(|group| RelatedResource::new("Subnet Group", group, ServiceType::RDS))
+            .collect();
+        related.extend(
+            self.vpc_security_groups
+                .iter()
+                .map(|group| RelatedResource::new("Security Group", group, ServiceType::EC2)),
+        );
+        related
+    }
+
+    /// Generic view of the database instance for the shared resource cache
+    pub fn to_resource(&self, region: &str) -> Resource {
+        Resource {
+            id: self.db_instance_identifier.clone(),
+            name: self.db_instance_identifier.clone(),
+            service_type: ServiceType::RDS,
+            region: region.to_string(),
+            arn: format!("arn:aws:rds:{}::db:{}", region, self.db_instance_identifier),
+            state: self.db_instance_status.clone(),
+            availability_zone: Some(self.availability_zone.clone()),
+            tags: HashMap::new(),
+            created_at: None,
+            last_modified: None,
+            instance_type: None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -217,6 +505,62 @@ pub struct IamUser {
     pub groups: Vec<String>,
 }
 
+impl IamUser {
+    /// Generic view of the user for the shared resource cache
+    pub fn to_resource(&self, region: &str) -> Resource {
+        Resource {
+            id: self.user_name.clone(),
+            name: self.user_name.clone(),
+            service_type: ServiceType::IAM,
+            region: region.to_string(),
+            arn: self.arn.clone(),
+            state: "Active".to_string(),
+            availability_zone: None,
+            tags: HashMap::new(),
+            created_at: self.create_date,
+            last_modified: None,
+            instance_type: None,
+        }
+    }
+}
+
+/// Prefix of the resource id of an IAM role, which sets it apart from a user
+/// of the same name in the shared IAM listing
+pub const IAM_ROLE_ID_PREFIX: &str = "role/";
+
+#[derive(Debug, Clone)]
+pub struct IamRole {
+    pub role_name: String,
+    pub arn: String,
+    pub create_date: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl IamRole {
+    /// Generic view of the role for the shared resource cache
+    pub fn to_resource(&self, region: &str) -> Resource {
+        Resource {
+            id: format!("{}{}", IAM_ROLE_ID_PREFIX, self.role_name),
+            name: self.role_name.clone(),
+            service_type: ServiceType::IAM,
+            region: region.to_string(),
+            arn: self.arn.clone(),
+            state: "Role".to_string(),
+            availability_zone: None,
+            tags: HashMap::new(),
+            created_at: self.create_date,
+            last_modified: None,
+            instance_type: None,
+        }
+    }
+}
+
+/// What a user belongs to and has attached, fetched for its detail page
+#[derive(Debug, Clone, Default)]
+pub struct IamUserDetails {
+    pub groups: Vec<String>,
+    pub attached_policies: Vec<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Secret {
     pub name: String,
@@ -226,6 +570,27 @@ pub struct Secret {
     pub last_accessed_date: Option<chrono::DateTime<chrono::Utc>>,
     pub last_changed_date: Option<chrono::DateTime<chrono::Utc>>,
     pub version_id: Option<String>,
+    pub rotation_enabled: bool,
+    pub rotation_lambda_arn: Option<String>,
+}
+
+impl Secret {
+    /// Generic view of the secret for the shared resource cache
+    pub fn to_resource(&self, region: &str) -> Resource {
+        Resource {
+            id: self.name.clone(),
+            name: self.name.clone(),
+            service_type: ServiceType::Secrets,
+            region: region.to_string(),
+            arn: self.arn.clone(),
+            state: "Active".to_string(),
+            availability_zone: None,
+            tags: HashMap::new(),
+            created_at: self.created_date,
+            last_modified: self.last_changed_date,
+            instance_type: None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -239,4 +604,79 @@ pub struct EksCluster {
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
     pub platform_version: Option<String>,
     pub vpc_config: Option<String>,
+    /// Control plane log types sent to CloudWatch Logs, e.g. "api" or "audit"
+    pub enabled_log_types: Vec<String>,
+}
+
+impl EksCluster {
+    /// Generic view of the cluster for the shared resource cache
+    pub fn to_resource(&self, region: &str) -> Resource {
+        Resource {
+            id: self.name.clone(),
+            name: self.name.clone(),
+            service_type: ServiceType::EKS,
+            region: region.to_string(),
+            arn: self.arn.clone(),
+            state: self.status.clone(),
+            availability_zone: None,
+            tags: HashMap::new(),
+            created_at: self.created_at,
+            last_modified: None,
+            instance_type: None,
+        }
+    }
+}
+
+/// One inbound or outbound rule of a security group
+#[derive(Debug, Clone)]
+pub struct SecurityGroupRule {
+    /// IP protocol name or number; "-1" means every protocol
+    pub protocol: String,
+    pub from_port: Option<i32>,
+    pub to_port: Option<i32>,
+    /// CIDR ranges, prefix lists and referenced security group ids
+    pub sources: Vec<String>,
+    pub description: Option<String>,
+}
+
+impl SecurityGroupRule {
+    pub fn protocol_label(&self) -> &str {
+        match self.protocol.as_str() {
+            "-1" => "all",
+            protocol => protocol,
+        }
+    }
+
+    /// "22", "1024-2048", or "all" when the rule covers every port
+    pub fn port_range(&self) -> String {
+        match (self.from_port, self.to_port) {
+            (Some(from), Some(to)) if from == to && from >= 0 => from.to_string(),
+            (Some(0), Some(65535)) | (Some(-1), _) | (None, _) => "all".to_string(),
+            (Some(from), Some(to)) => format!("{}-{}", from, to),
+            (Some(from), None) => from.to_string(),
+        }
+    }
+}
+
+/// A security group as returned by DescribeSecurityGroups
+#[derive(Debug, Clone)]
+pub struct SecurityGroup {
+    pub group_id: String,
+    pub group_name: String,
+    pub description: String,
+    pub inbound_rules: Vec<SecurityGroupRule>,
+    pub outbound_rules: Vec<SecurityGroupRule>,
+}
+
+/// A managed node group of an EKS cluster, as returned by DescribeNodegroup
+#[derive(Debug, Clone)]
+pub struct EksNodeGroup {
+    pub name: String,
+    pub status: String,
+    pub desired_size: i32,
+    pub min_size: i32,
+    pub max_size: i32,
+    pub instance_types: Vec<String>,
+    /// ON_DEMAND or SPOT
+    pub capacity_type: Option<String>,
 }
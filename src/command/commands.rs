@@ -80,9 +80,46 @@ pub enum CommandCategory {
     Service(ServiceType),
     /// General application commands (help, settings, etc.)
     General,
+    /// User-defined commands loaded from the config file
+    Custom,
+    /// Commands the user pinned; assigned by the palette when grouping, never by the registry
+    Pinned,
 }
 
 impl CommandCategory {
+    /// Category names accepted in the palette config, in the default order
+    pub const NAMES: [&'static str; 7] = [
+        "Pinned",
+        "Navigation",
+        "Profile",
+        "Region",
+        "Service",
+        "General",
+        "Custom",
+    ];
+
+    /// Position in the palette: categories named in `order` come first, in
+    /// that order, and the rest follow in their default order
+    pub fn rank(&self, order: &[String]) -> usize {
+        let name = self.display_name();
+        match order.iter().position(|n| n.eq_ignore_ascii_case(name)) {
+            Some(index) => index,
+            None => {
+                order.len()
+                    + Self::NAMES
+                        .iter()
+                        .position(|n| *n == name)
+                        .unwrap_or(Self::NAMES.len())
+            }
+        }
+    }
+
+    /// Whether the category is one of `names`, compared case-insensitively
+    pub fn is_listed_in(&self, names: &[String]) -> bool {
+        let name = self.display_name();
+        names.iter().any(|n| n.eq_ignore_ascii_case(name))
+    }
+
     /// Get display name for the category
     pub fn display_name(&self) -> &'static str {
         match self {
@@ -91,6 +128,8 @@ impl CommandCategory {
             CommandCategory::Region => "Region",
             CommandCategory::Service(_) => "Service",
             CommandCategory::General => "General",
+            CommandCategory::Custom => "Custom",
+            CommandCategory::Pinned => "Pinned",
         }
     }
 
@@ -102,6 +141,8 @@ impl CommandCategory {
             CommandCategory::Region => "🌍",
             CommandCategory::Service(service) => service.icon(),
             CommandCategory::General => "⚙️",
+            CommandCategory::Custom => "🛠️",
+            CommandCategory::Pinned => "📌",
         }
     }
 }
@@ -113,6 +154,10 @@ pub enum CommandAction {
     SwitchProfile(String),
     /// Switch to a specific AWS region
     SwitchRegion(String),
+    /// Switch to a saved preset's profile and region
+    ApplyPreset(String),
+    /// Prompt for a name to save the current profile and region under
+    SavePreset,
     /// Navigate to a specific service page
     NavigateToService(ServiceType),
     /// Navigate to a specific page
@@ -125,6 +170,52 @@ pub enum CommandAction {
     OpenSettings,
     /// Toggle a UI element
     ToggleUI(UIElement),
+    /// Open the selected resource in the AWS Console
+    OpenInConsole,
+    /// Cancel the most recent in-flight operation
+    CancelOperation,
+    /// Run a user-defined shell command template
+    RunCustomCommand(String),
+    /// Drop all cached resource data and reload the current view
+    ClearCache,
+    /// Drop the listed service's cached data in the current region and fetch it again
+    ReloadService,
+    /// Validate two profiles side by side
+    CompareProfiles(String, String),
+    /// Validate every profile and report each one's identity and status
+    ValidateProfiles,
+    /// Focus the recently viewed resources on the dashboard
+    ShowRecentResources,
+    /// Summarize which operations the current identity is allowed to run
+    DiagnosePermissions,
+    /// Connect to AWS again as the active profile
+    Reconnect,
+    /// Copy a link to the current profile, region and page
+    CopyViewState,
+    /// Prompt for a view link and navigate to it
+    GoToViewState,
+    /// Diff the selected resource against its baseline describe output
+    ShowResourceChanges,
+    /// Make the current describe output the baseline for every cached resource
+    ResetChangeBaseline,
+    /// Write the effective keybindings to a file
+    ExportKeybindings,
+    /// Write the resource list on screen to a CSV or JSON file
+    ExportResources,
+    /// Copy the marked resource ids, or the highlighted one, to the clipboard
+    CopySelectedIds,
+    /// Open a service's AWS documentation
+    OpenDocs(ServiceType),
+    /// Prompt for a role to assume into a temporary profile
+    AssumeRole,
+    /// Show favorite resources and commands together
+    ShowFavorites,
+    /// Add the selected resource to favorites, or remove it
+    ToggleFavoriteResource,
+    /// Prompt for the note kept with the selected favorite resource
+    EditFavoriteNote,
+    /// Edit the selected resource's tags, confirmed as a diff before applying
+    EditTags,
 }
 
 /// UI elements that can be toggled
@@ -145,8 +236,12 @@ pub enum ServiceCommand {
     RebootInstance,
     TerminateInstance,
     CreateInstance,
+    CloneInstance,
     DescribeInstance,
     ListInstances,
+    FilterByZone,
+    ClearZoneFilter,
+    InspectSecurityGroups,
 
     // S3 Commands
     CreateBucket,
@@ -165,6 +260,7 @@ pub enum ServiceCommand {
     RestoreSnapshot,
     ListDatabases,
     DescribeDatabase,
+    ViewDatabaseLogs,
 
     // IAM Commands
     CreateUser,
@@ -183,14 +279,17 @@ pub enum ServiceCommand {
     GetSecretValue,
     ListSecrets,
     DescribeSecret,
+    RotateSecret,
 
     // EKS Commands
     DescribeCluster,
     UpdateKubeconfig,
     ListNodeGroups,
+    UpdateNodeGroupSize,
     ListClusters,
     CreateCluster,
     DeleteCluster,
+    ViewClusterLogs,
 }
 
 impl ServiceCommand {
@@ -203,8 +302,12 @@ impl ServiceCommand {
             ServiceCommand::RebootInstance => "Reboot Instance",
             ServiceCommand::TerminateInstance => "Terminate Instance",
             ServiceCommand::CreateInstance => "Create Instance",
+            ServiceCommand::CloneInstance => "Clone Instance",
             ServiceCommand::DescribeInstance => "Describe Instance",
             ServiceCommand::ListInstances => "List Instances",
+            ServiceCommand::FilterByZone => "Filter by Availability Zone",
+            ServiceCommand::ClearZoneFilter => "Clear Availability Zone Filter",
+            ServiceCommand::InspectSecurityGroups => "Inspect Security Groups",
 
             // S3 Commands
             ServiceCommand::CreateBucket => "Create Bucket",
@@ -223,6 +326,7 @@ impl ServiceCommand {
             ServiceCommand::RestoreSnapshot => "Restore Snapshot",
             ServiceCommand::ListDatabases => "List Databases",
             ServiceCommand::DescribeDatabase => "Describe Database",
+            ServiceCommand::ViewDatabaseLogs => "View Logs",
 
             // IAM Commands
             ServiceCommand::CreateUser => "Create User",
@@ -241,14 +345,17 @@ impl ServiceCommand {
             ServiceCommand::GetSecretValue => "Get Secret Value",
             ServiceCommand::ListSecrets => "List Secrets",
             ServiceCommand::DescribeSecret => "Describe Secret",
+            ServiceCommand::RotateSecret => "Rotate Secret",
 
             // EKS Commands
             ServiceCommand::DescribeCluster => "Describe Cluster",
             ServiceCommand::UpdateKubeconfig => "Update Kubeconfig",
             ServiceCommand::ListNodeGroups => "List Node Groups",
+            ServiceCommand::UpdateNodeGroupSize => "Update Node Group Size",
             ServiceCommand::ListClusters => "List Clusters",
             ServiceCommand::CreateCluster => "Create Cluster",
             ServiceCommand::DeleteCluster => "Delete Cluster",
+            ServiceCommand::ViewClusterLogs => "View Logs",
         }
     }
 
@@ -261,8 +368,16 @@ impl ServiceCommand {
             ServiceCommand::RebootInstance => "Reboot the selected EC2 instance",
             ServiceCommand::TerminateInstance => "Terminate the selected EC2 instance",
             ServiceCommand::CreateInstance => "Launch a new EC2 instance",
+            ServiceCommand::CloneInstance => {
+                "Launch a new instance configured like the selected one"
+            }
             ServiceCommand::DescribeInstance => "Show details of the selected instance",
             ServiceCommand::ListInstances => "List all EC2 instances",
+            ServiceCommand::FilterByZone => "Show only instances in one availability zone",
+            ServiceCommand::ClearZoneFilter => "Show instances from every availability zone",
+            ServiceCommand::InspectSecurityGroups => {
+                "List the inbound and outbound rules of the selected instance's security groups"
+            }
 
             // S3 Commands
             ServiceCommand::CreateBucket => "Create a new S3 bucket",
@@ -281,6 +396,9 @@ impl ServiceCommand {
             ServiceCommand::RestoreSnapshot => "Restore database from snapshot",
             ServiceCommand::ListDatabases => "List all RDS instances",
             ServiceCommand::DescribeDatabase => "Show details of the selected database",
+            ServiceCommand::ViewDatabaseLogs => {
+                "Tail the selected database's log exports from CloudWatch Logs"
+            }
 
             // IAM Commands
             ServiceCommand::CreateUser => "Create a new IAM user",
@@ -299,14 +417,21 @@ impl ServiceCommand {
             ServiceCommand::GetSecretValue => "Retrieve the secret value",
             ServiceCommand::ListSecrets => "List all secrets",
             ServiceCommand::DescribeSecret => "Show details of the selected secret",
+            ServiceCommand::RotateSecret => "Rotate the selected secret with its rotation Lambda",
 
             // EKS Commands
             ServiceCommand::DescribeCluster => "Show details of the selected cluster",
             ServiceCommand::UpdateKubeconfig => "Update kubeconfig for the cluster",
             ServiceCommand::ListNodeGroups => "List node groups in the cluster",
+            ServiceCommand::UpdateNodeGroupSize => {
+                "Change the desired size of the highlighted node group"
+            }
             ServiceCommand::ListClusters => "List all EKS clusters",
             ServiceCommand::CreateCluster => "Create a new EKS cluster",
             ServiceCommand::DeleteCluster => "Delete the selected EKS cluster",
+            ServiceCommand::ViewClusterLogs => {
+                "Tail the selected cluster's control plane logs from CloudWatch Logs"
+            }
         }
     }
 
@@ -318,8 +443,12 @@ impl ServiceCommand {
             | ServiceCommand::RebootInstance
             | ServiceCommand::TerminateInstance
             | ServiceCommand::CreateInstance
+            | ServiceCommand::CloneInstance
             | ServiceCommand::DescribeInstance
-            | ServiceCommand::ListInstances => ServiceType::EC2,
+            | ServiceCommand::ListInstances
+            | ServiceCommand::FilterByZone
+            | ServiceCommand::ClearZoneFilter
+            | ServiceCommand::InspectSecurityGroups => ServiceType::EC2,
 
             ServiceCommand::CreateBucket
             | ServiceCommand::DeleteBucket
@@ -335,7 +464,8 @@ impl ServiceCommand {
             | ServiceCommand::CreateSnapshot
             | ServiceCommand::RestoreSnapshot
             | ServiceCommand::ListDatabases
-            | ServiceCommand::DescribeDatabase => ServiceType::RDS,
+            | ServiceCommand::DescribeDatabase
+            | ServiceCommand::ViewDatabaseLogs => ServiceType::RDS,
 
             ServiceCommand::CreateUser
             | ServiceCommand::DeleteUser
@@ -351,14 +481,17 @@ impl ServiceCommand {
             | ServiceCommand::DeleteSecret
             | ServiceCommand::GetSecretValue
             | ServiceCommand::ListSecrets
-            | ServiceCommand::DescribeSecret => ServiceType::Secrets,
+            | ServiceCommand::DescribeSecret
+            | ServiceCommand::RotateSecret => ServiceType::Secrets,
 
             ServiceCommand::DescribeCluster
             | ServiceCommand::UpdateKubeconfig
             | ServiceCommand::ListNodeGroups
+            | ServiceCommand::UpdateNodeGroupSize
             | ServiceCommand::ListClusters
             | ServiceCommand::CreateCluster
-            | ServiceCommand::DeleteCluster => ServiceType::EKS,
+            | ServiceCommand::DeleteCluster
+            | ServiceCommand::ViewClusterLogs => ServiceType::EKS,
         }
     }
 
@@ -369,7 +502,9 @@ impl ServiceCommand {
             | ServiceCommand::StopInstance
             | ServiceCommand::RebootInstance
             | ServiceCommand::TerminateInstance
+            | ServiceCommand::CloneInstance
             | ServiceCommand::DescribeInstance
+            | ServiceCommand::InspectSecurityGroups
             | ServiceCommand::DeleteBucket
             | ServiceCommand::ListObjects
             | ServiceCommand::UploadObject
@@ -379,6 +514,7 @@ impl ServiceCommand {
             | ServiceCommand::RebootDatabase
             | ServiceCommand::CreateSnapshot
             | ServiceCommand::DescribeDatabase
+            | ServiceCommand::ViewDatabaseLogs
             | ServiceCommand::DeleteUser
             | ServiceCommand::DeleteRole
             | ServiceCommand::AttachPolicy
@@ -387,13 +523,18 @@ impl ServiceCommand {
             | ServiceCommand::DeleteSecret
             | ServiceCommand::GetSecretValue
             | ServiceCommand::DescribeSecret
+            | ServiceCommand::RotateSecret
             | ServiceCommand::DescribeCluster
             | ServiceCommand::UpdateKubeconfig
             | ServiceCommand::ListNodeGroups
-            | ServiceCommand::DeleteCluster => true,
+            | ServiceCommand::UpdateNodeGroupSize
+            | ServiceCommand::DeleteCluster
+            | ServiceCommand::ViewClusterLogs => true,
 
             ServiceCommand::CreateInstance
             | ServiceCommand::ListInstances
+            | ServiceCommand::FilterByZone
+            | ServiceCommand::ClearZoneFilter
             | ServiceCommand::CreateBucket
             | ServiceCommand::DownloadObject
             | ServiceCommand::ListBuckets
@@ -410,17 +551,220 @@ impl ServiceCommand {
         }
     }
 
+    /// The IAM action of the AWS API call the command makes; `None` for
+    /// commands that only change what is shown
+    pub fn aws_action(&self) -> Option<&'static str> {
+        let action = match self {
+            // EC2 Commands
+            ServiceCommand::StartInstance => "ec2:StartInstances",
+            ServiceCommand::StopInstance => "ec2:StopInstances",
+            ServiceCommand::RebootInstance => "ec2:RebootInstances",
+            ServiceCommand::TerminateInstance => "ec2:TerminateInstances",
+            ServiceCommand::CreateInstance | ServiceCommand::CloneInstance => "ec2:RunInstances",
+            ServiceCommand::DescribeInstance | ServiceCommand::ListInstances => {
+                "ec2:DescribeInstances"
+            }
+            ServiceCommand::FilterByZone => "ec2:DescribeAvailabilityZones",
+            ServiceCommand::ClearZoneFilter => return None,
+            ServiceCommand::InspectSecurityGroups => "ec2:DescribeSecurityGroups",
+
+            // S3 Commands
+            ServiceCommand::CreateBucket => "s3:CreateBucket",
+            ServiceCommand::DeleteBucket => "s3:DeleteBucket",
+            ServiceCommand::ListObjects => "s3:ListBucket",
+            ServiceCommand::UploadObject => "s3:PutObject",
+            ServiceCommand::DownloadObject => "s3:GetObject",
+            ServiceCommand::ListBuckets => "s3:ListAllMyBuckets",
+            ServiceCommand::GetBucketInfo => "s3:GetBucketLocation",
+
+            // RDS Commands
+            ServiceCommand::StartDatabase => "rds:StartDBInstance",
+            ServiceCommand::StopDatabase => "rds:StopDBInstance",
+            ServiceCommand::RebootDatabase => "rds:RebootDBInstance",
+            ServiceCommand::CreateSnapshot => "rds:CreateDBSnapshot",
+            ServiceCommand::RestoreSnapshot => "rds:RestoreDBInstanceFromDBSnapshot",
+            ServiceCommand::ListDatabases | ServiceCommand::DescribeDatabase => {
+                "rds:DescribeDBInstances"
+            }
+            ServiceCommand::ViewDatabaseLogs | ServiceCommand::ViewClusterLogs => {
+                "logs:FilterLogEvents"
+            }
+
+            // IAM Commands
+            ServiceCommand::CreateUser => "iam:CreateUser",
+            ServiceCommand::DeleteUser => "iam:DeleteUser",
+            ServiceCommand::CreateRole => "iam:CreateRole",
+            ServiceCommand::DeleteRole => "iam:DeleteRole",
+            ServiceCommand::AttachPolicy => "iam:AttachUserPolicy",
+            ServiceCommand::DetachPolicy => "iam:DetachUserPolicy",
+            ServiceCommand::ListUsers => "iam:ListUsers",
+            ServiceCommand::ListRoles => "iam:ListRoles",
+
+            // Secrets Manager Commands
+            ServiceCommand::CreateSecret => "secretsmanager:CreateSecret",
+            ServiceCommand::UpdateSecret => "secretsmanager:PutSecretValue",
+            ServiceCommand::DeleteSecret => "secretsmanager:DeleteSecret",
+            ServiceCommand::GetSecretValue => "secretsmanager:GetSecretValue",
+            ServiceCommand::ListSecrets => "secretsmanager:ListSecrets",
+            ServiceCommand::DescribeSecret => "secretsmanager:DescribeSecret",
+            ServiceCommand::RotateSecret => "secretsmanager:RotateSecret",
+
+            // EKS Commands
+            ServiceCommand::DescribeCluster | ServiceCommand::UpdateKubeconfig => {
+                "eks:DescribeCluster"
+            }
+            ServiceCommand::ListNodeGroups => "eks:ListNodegroups",
+            ServiceCommand::UpdateNodeGroupSize => "eks:UpdateNodegroupConfig",
+            ServiceCommand::ListClusters => "eks:ListClusters",
+            ServiceCommand::CreateCluster => "eks:CreateCluster",
+            ServiceCommand::DeleteCluster => "eks:DeleteCluster",
+        };
+        Some(action)
+    }
+
+    /// The AWS CLI invocation doing the same, with `<id>` standing for the
+    /// selected resource; `None` when there is no single equivalent
+    pub fn cli_equivalent(&self) -> Option<&'static str> {
+        let cli = match self {
+            // EC2 Commands
+            ServiceCommand::StartInstance => "aws ec2 start-instances --instance-ids <id>",
+            ServiceCommand::StopInstance => "aws ec2 stop-instances --instance-ids <id>",
+            ServiceCommand::RebootInstance => "aws ec2 reboot-instances --instance-ids <id>",
+            ServiceCommand::TerminateInstance => {
+                "aws ec2 terminate-instances --instance-ids <id>"
+            }
+            ServiceCommand::CreateInstance => "aws ec2 run-instances --image-id <ami>",
+            ServiceCommand::CloneInstance => {
+                "aws ec2 run-instances --image-id <ami> --instance-type <type> --subnet-id <subnet> --security-group-ids <group-id>"
+            }
+            ServiceCommand::DescribeInstance => "aws ec2 describe-instances --instance-ids <id>",
+            ServiceCommand::ListInstances => "aws ec2 describe-instances",
+            ServiceCommand::FilterByZone => "aws ec2 describe-availability-zones",
+            ServiceCommand::InspectSecurityGroups => {
+                "aws ec2 describe-security-groups --group-ids <group-id>"
+            }
+
+            // S3 Commands
+            ServiceCommand::CreateBucket => "aws s3api create-bucket --bucket <name>",
+            ServiceCommand::DeleteBucket => "aws s3api delete-bucket --bucket <id>",
+            ServiceCommand::ListObjects => "aws s3 ls s3://<id>",
+            ServiceCommand::UploadObject => "aws s3 cp <file> s3://<id>/",
+            ServiceCommand::DownloadObject => "aws s3 cp s3://<bucket>/<key> .",
+            ServiceCommand::ListBuckets => "aws s3api list-buckets",
+            ServiceCommand::GetBucketInfo => "aws s3api get-bucket-location --bucket <id>",
+
+            // RDS Commands
+            ServiceCommand::StartDatabase => {
+                "aws rds start-db-instance --db-instance-identifier <id>"
+            }
+            ServiceCommand::StopDatabase => "aws rds stop-db-instance --db-instance-identifier <id>",
+            ServiceCommand::RebootDatabase => {
+                "aws rds reboot-db-instance --db-instance-identifier <id>"
+            }
+            ServiceCommand::CreateSnapshot => {
+                "aws rds create-db-snapshot --db-instance-identifier <id> --db-snapshot-identifier <name>"
+            }
+            ServiceCommand::RestoreSnapshot => {
+                "aws rds restore-db-instance-from-db-snapshot --db-snapshot-identifier <snapshot>"
+            }
+            ServiceCommand::ListDatabases => "aws rds describe-db-instances",
+            ServiceCommand::DescribeDatabase => {
+                "aws rds describe-db-instances --db-instance-identifier <id>"
+            }
+            ServiceCommand::ViewDatabaseLogs => "aws logs tail /aws/rds/instance/<id>/<log> --follow",
+
+            // IAM Commands
+            ServiceCommand::CreateUser => "aws iam create-user --user-name <name>",
+            ServiceCommand::DeleteUser => "aws iam delete-user --user-name <id>",
+            ServiceCommand::CreateRole => {
+                "aws iam create-role --role-name <name> --assume-role-policy-document <file>"
+            }
+            ServiceCommand::DeleteRole => "aws iam delete-role --role-name <id>",
+            ServiceCommand::AttachPolicy => {
+                "aws iam attach-user-policy --user-name <id> --policy-arn <arn>"
+            }
+            ServiceCommand::DetachPolicy => {
+                "aws iam detach-user-policy --user-name <id> --policy-arn <arn>"
+            }
+            ServiceCommand::ListUsers => "aws iam list-users",
+            ServiceCommand::ListRoles => "aws iam list-roles",
+
+            // Secrets Manager Commands
+            ServiceCommand::CreateSecret => {
+                "aws secretsmanager create-secret --name <name> --secret-string <value>"
+            }
+            ServiceCommand::UpdateSecret => {
+                "aws secretsmanager put-secret-value --secret-id <id> --secret-string <value>"
+            }
+            ServiceCommand::DeleteSecret => "aws secretsmanager delete-secret --secret-id <id>",
+            ServiceCommand::GetSecretValue => {
+                "aws secretsmanager get-secret-value --secret-id <id>"
+            }
+            ServiceCommand::ListSecrets => "aws secretsmanager list-secrets",
+            ServiceCommand::DescribeSecret => "aws secretsmanager describe-secret --secret-id <id>",
+            ServiceCommand::RotateSecret => "aws secretsmanager rotate-secret --secret-id <id>",
+
+            // EKS Commands
+            ServiceCommand::DescribeCluster => "aws eks describe-cluster --name <id>",
+            ServiceCommand::UpdateKubeconfig => "aws eks update-kubeconfig --name <id>",
+            ServiceCommand::ListNodeGroups => "aws eks list-nodegroups --cluster-name <id>",
+            ServiceCommand::UpdateNodeGroupSize => {
+                "aws eks update-nodegroup-config --cluster-name <id> --nodegroup-name <group> --scaling-config desiredSize=<n>"
+            }
+            ServiceCommand::ListClusters => "aws eks list-clusters",
+            ServiceCommand::CreateCluster => {
+                "aws eks create-cluster --name <name> --role-arn <arn> --resources-vpc-config <config>"
+            }
+            ServiceCommand::DeleteCluster => "aws eks delete-cluster --name <id>",
+            ServiceCommand::ViewClusterLogs => "aws logs tail /aws/eks/<id>/cluster --follow",
+
+            ServiceCommand::ClearZoneFilter => return None,
+        };
+        Some(cli)
+    }
+
+    /// Whether the command stops, deletes or terminates what it runs on, so a
+    /// batch of it is reviewed before running
+    pub fn is_destructive(&self) -> bool {
+        matches!(
+            self,
+            ServiceCommand::StopInstance
+                | ServiceCommand::TerminateInstance
+                | ServiceCommand::DeleteBucket
+                | ServiceCommand::StopDatabase
+                | ServiceCommand::DeleteUser
+                | ServiceCommand::DeleteRole
+                | ServiceCommand::DeleteSecret
+                | ServiceCommand::DeleteCluster
+        )
+    }
+
+    /// The command that drills into a resource's contents, used when Enter on a
+    /// resource list is configured for contents. Services without one fall back
+    /// to the detail page; extend the mapping here.
+    pub fn contents_command(service_type: ServiceType) -> Option<ServiceCommand> {
+        match service_type {
+            ServiceType::S3 => Some(ServiceCommand::ListObjects),
+            ServiceType::EKS => Some(ServiceCommand::ListNodeGroups),
+            ServiceType::EC2 | ServiceType::RDS | ServiceType::IAM | ServiceType::Secrets => None,
+        }
+    }
+
     /// Get all service commands for a given service type
     pub fn for_service(service_type: ServiceType) -> Vec<ServiceCommand> {
         match service_type {
             ServiceType::EC2 => vec![
                 ServiceCommand::ListInstances,
                 ServiceCommand::CreateInstance,
+                ServiceCommand::CloneInstance,
                 ServiceCommand::StartInstance,
                 ServiceCommand::StopInstance,
                 ServiceCommand::RebootInstance,
                 ServiceCommand::TerminateInstance,
                 ServiceCommand::DescribeInstance,
+                ServiceCommand::InspectSecurityGroups,
+                ServiceCommand::FilterByZone,
+                ServiceCommand::ClearZoneFilter,
             ],
             ServiceType::S3 => vec![
                 ServiceCommand::ListBuckets,
@@ -437,6 +781,7 @@ impl ServiceCommand {
                 ServiceCommand::StopDatabase,
                 ServiceCommand::RebootDatabase,
                 ServiceCommand::DescribeDatabase,
+                ServiceCommand::ViewDatabaseLogs,
                 ServiceCommand::CreateSnapshot,
                 ServiceCommand::RestoreSnapshot,
             ],
@@ -457,6 +802,7 @@ impl ServiceCommand {
                 ServiceCommand::DeleteSecret,
                 ServiceCommand::DescribeSecret,
                 ServiceCommand::GetSecretValue,
+                ServiceCommand::RotateSecret,
             ],
             ServiceType::EKS => vec![
                 ServiceCommand::ListClusters,
@@ -465,6 +811,8 @@ impl ServiceCommand {
                 ServiceCommand::DescribeCluster,
                 ServiceCommand::UpdateKubeconfig,
                 ServiceCommand::ListNodeGroups,
+                ServiceCommand::UpdateNodeGroupSize,
+                ServiceCommand::ViewClusterLogs,
             ],
         }
     }
@@ -487,4 +835,60 @@ pub enum ContextRequirement {
     OnPage(AppPage),
     /// Requires not being on a specific page
     NotOnPage(AppPage),
+    /// Requires being on any service's resource list
+    OnResourceList,
+    /// Requires a background operation to be running
+    OperationInFlight,
+    /// Requires the AWS clients to have finished initializing
+    AwsClientReady,
+    /// Requires the service not to be known to deny the current identity
+    ServicePermitted(ServiceType),
+    /// Requires the service not to be cooling down after repeated failures
+    ServiceHealthy(ServiceType),
+}
+
+impl ContextRequirement {
+    /// What must hold, phrased for the command explanation
+    pub fn describe(&self) -> String {
+        match self {
+            ContextRequirement::ServiceSelected(service_type) => {
+                format!("{} is the selected service", service_type.display_name())
+            }
+            ContextRequirement::ResourceSelected => "A resource is selected".to_string(),
+            ContextRequirement::ResourceOfTypeSelected(service_type) => {
+                format!("A {} resource is selected", service_type.display_name())
+            }
+            ContextRequirement::ProfilesAvailable => "AWS profiles are configured".to_string(),
+            ContextRequirement::RegionsAvailable => "AWS regions are known".to_string(),
+            ContextRequirement::OnPage(page) => format!("On the {} page", page_name(page)),
+            ContextRequirement::NotOnPage(page) => {
+                format!("Not on the {} page", page_name(page))
+            }
+            ContextRequirement::OnResourceList => "On a resource list".to_string(),
+            ContextRequirement::OperationInFlight => {
+                "A background operation is running".to_string()
+            }
+            ContextRequirement::AwsClientReady => "The AWS clients are connected".to_string(),
+            ContextRequirement::ServicePermitted(service_type) => format!(
+                "{} has not denied the current identity",
+                service_type.display_name()
+            ),
+            ContextRequirement::ServiceHealthy(service_type) => format!(
+                "{} is not paused after repeated failures",
+                service_type.display_name()
+            ),
+        }
+    }
+}
+
+fn page_name(page: &AppPage) -> String {
+    match page {
+        AppPage::Dashboard => "Dashboard".to_string(),
+        AppPage::ResourceList(service_type) => format!("{} list", service_type.display_name()),
+        AppPage::ResourceDetail(service_type, _) => {
+            format!("{} detail", service_type.display_name())
+        }
+        AppPage::Settings => "Settings".to_string(),
+        AppPage::ActivityLog => "Activity Log".to_string(),
+    }
 }
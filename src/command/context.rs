@@ -1,6 +1,7 @@
 use crate::app::state::AppPage;
 use crate::aws::types::{AwsProfile, AwsRegion, ResourceId, ServiceType};
 use crate::command::commands::ContextRequirement;
+use std::collections::HashSet;
 
 /// Context information used to determine which commands are available
 #[derive(Debug, Clone)]
@@ -19,6 +20,14 @@ pub struct CommandContext {
     pub current_profile: String,
     /// Current AWS region
     pub current_region: String,
+    /// Whether a background operation is running
+    pub operation_in_flight: bool,
+    /// Whether the AWS clients have finished initializing
+    pub aws_client_ready: bool,
+    /// Services the current identity is known or expected to be denied
+    pub denied_services: HashSet<ServiceType>,
+    /// Services whose calls kept failing, disabled until their cooldown ends
+    pub unhealthy_services: HashSet<ServiceType>,
 }
 
 impl CommandContext {
@@ -40,6 +49,10 @@ impl CommandContext {
             available_regions,
             current_profile,
             current_region,
+            operation_in_flight: false,
+            aws_client_ready: true,
+            denied_services: HashSet::new(),
+            unhealthy_services: HashSet::new(),
         }
     }
 
@@ -57,6 +70,15 @@ impl CommandContext {
             ContextRequirement::RegionsAvailable => !self.available_regions.is_empty(),
             ContextRequirement::OnPage(page) => self.current_page == *page,
             ContextRequirement::NotOnPage(page) => self.current_page != *page,
+            ContextRequirement::OnResourceList => self.is_viewing_resource_list(),
+            ContextRequirement::OperationInFlight => self.operation_in_flight,
+            ContextRequirement::AwsClientReady => self.aws_client_ready,
+            ContextRequirement::ServicePermitted(service_type) => {
+                !self.denied_services.contains(service_type)
+            }
+            ContextRequirement::ServiceHealthy(service_type) => {
+                !self.unhealthy_services.contains(service_type)
+            }
         }
     }
 
@@ -148,4 +170,28 @@ impl CommandContext {
         self.selected_service = service_type;
         self
     }
+
+    /// Update the context with whether a background operation is running
+    pub fn with_operation_in_flight(mut self, operation_in_flight: bool) -> Self {
+        self.operation_in_flight = operation_in_flight;
+        self
+    }
+
+    /// Update the context with the services the current identity cannot use
+    pub fn with_denied_services(mut self, denied_services: HashSet<ServiceType>) -> Self {
+        self.denied_services = denied_services;
+        self
+    }
+
+    /// Update the context with the services disabled after repeated failures
+    pub fn with_unhealthy_services(mut self, unhealthy_services: HashSet<ServiceType>) -> Self {
+        self.unhealthy_services = unhealthy_services;
+        self
+    }
+
+    /// Update the context with whether the AWS clients are ready for use
+    pub fn with_aws_client_ready(mut self, aws_client_ready: bool) -> Self {
+        self.aws_client_ready = aws_client_ready;
+        self
+    }
 }
@@ -0,0 +1,72 @@
+use crate::command::commands::{Command, CommandAction};
+
+/// What a palette command does, gathered from its metadata for the explain overlay
+#[derive(Debug, Clone)]
+pub struct CommandExplanation {
+    pub title: String,
+    pub id: String,
+    pub category: &'static str,
+    pub description: String,
+    /// The longer description of a service command, when it adds to the palette text
+    pub details: Option<&'static str>,
+    /// IAM action of the AWS call made, `None` when the command only runs locally
+    pub aws_action: Option<String>,
+    pub destructive: bool,
+    pub requirements: Vec<String>,
+    /// Shell command doing the same outside NimbusCTL
+    pub cli: Option<String>,
+}
+
+impl CommandExplanation {
+    pub fn new(command: &Command) -> Self {
+        let mut explanation = Self {
+            title: format!("{} {}", command.icon, command.name),
+            id: command.id.clone(),
+            category: command.category.display_name(),
+            description: command.description.clone(),
+            details: None,
+            aws_action: None,
+            destructive: false,
+            requirements: command
+                .context_requirements
+                .iter()
+                .map(|requirement| requirement.describe())
+                .collect(),
+            cli: None,
+        };
+
+        match &command.action {
+            CommandAction::ExecuteServiceCommand(_, service_command) => {
+                let details = service_command.description();
+                explanation.details = (details != command.description).then_some(details);
+                explanation.aws_action = service_command.aws_action().map(str::to_string);
+                explanation.destructive = service_command.is_destructive();
+                explanation.cli = service_command.cli_equivalent().map(str::to_string);
+            }
+            CommandAction::SwitchProfile(profile) => {
+                explanation.cli = Some(format!("export AWS_PROFILE={}", profile));
+            }
+            CommandAction::SwitchRegion(region) => {
+                explanation.cli = Some(format!("export AWS_REGION={}", region));
+            }
+            CommandAction::ValidateProfiles => {
+                explanation.aws_action = Some("sts:GetCallerIdentity".to_string());
+                explanation.cli =
+                    Some("aws sts get-caller-identity --profile <profile>".to_string());
+            }
+            CommandAction::DiagnosePermissions => {
+                explanation.aws_action = Some("iam:SimulatePrincipalPolicy".to_string());
+                explanation.cli = Some(
+                    "aws iam simulate-principal-policy --policy-source-arn <arn> --action-names <action>"
+                        .to_string(),
+                );
+            }
+            CommandAction::RunCustomCommand(template) => {
+                explanation.cli = Some(template.clone());
+            }
+            _ => {}
+        }
+
+        explanation
+    }
+}
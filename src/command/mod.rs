@@ -1,9 +1,11 @@
 pub mod commands;
 pub mod context;
+pub mod explain;
 pub mod palette;
 pub mod registry;
 
 pub use commands::*;
 pub use context::*;
+pub use explain::CommandExplanation;
 pub use palette::*;
 pub use registry::CommandRegistry;
@@ -1,5 +1,6 @@
-use crate::command::commands::Command;
+use crate::command::commands::{Command, CommandCategory};
 use crate::command::context::CommandContext;
+use crate::utils::helpers::pop_grapheme;
 
 /// State management for the command palette UI
 #[derive(Debug, Clone)]
@@ -10,12 +11,20 @@ pub struct CommandPalette {
     pub input: String,
     /// All available commands (unfiltered)
     pub commands: Vec<Command>,
+    /// Lowercase searchable text per command, parallel to `commands`
+    search_index: Vec<String>,
     /// Commands filtered by current input
     pub filtered_commands: Vec<Command>,
     /// Index of currently selected command
     pub selected_index: usize,
     /// Current context for determining available commands
     pub context: CommandContext,
+    /// Ids of commands listed first, in the order they were pinned
+    pinned: Vec<String>,
+    /// Category names in the order they are listed
+    category_order: Vec<String>,
+    /// Categories whose unpinned commands are left out
+    hidden_categories: Vec<String>,
 }
 
 impl CommandPalette {
@@ -25,9 +34,13 @@ impl CommandPalette {
             visible: false,
             input: String::new(),
             commands: Vec::new(),
+            search_index: Vec::new(),
             filtered_commands: Vec::new(),
             selected_index: 0,
             context,
+            pinned: Vec::new(),
+            category_order: Vec::new(),
+            hidden_categories: Vec::new(),
         }
     }
 
@@ -74,7 +87,7 @@ impl CommandPalette {
 
     /// Remove the last character from input
     pub fn backspace(&mut self) {
-        self.input.pop();
+        pop_grapheme(&mut self.input);
         self.selected_index = 0;
         self.update_filtered_commands();
     }
@@ -104,34 +117,83 @@ impl CommandPalette {
         self.update_filtered_commands();
     }
 
-    /// Set all available commands
+    /// Set all available commands and rebuild the search index
     pub fn set_commands(&mut self, commands: Vec<Command>) {
+        self.search_index = commands.iter().map(Self::search_text).collect();
         self.commands = commands;
         self.update_filtered_commands();
     }
 
-    /// Update filtered commands based on current input and context
-    fn update_filtered_commands(&mut self) {
-        if self.input.is_empty() {
-            // Show all applicable commands when no input
-            self.filtered_commands = self
-                .commands
-                .iter()
-                .filter(|cmd| self.is_command_applicable(cmd))
-                .cloned()
-                .collect();
-        } else {
-            // Filter by fuzzy matching
-            let query = self.input.to_lowercase();
-            self.filtered_commands = self
-                .commands
-                .iter()
-                .filter(|cmd| self.is_command_applicable(cmd) && self.matches_query(cmd, &query))
-                .cloned()
-                .collect();
+    /// Set the pinned command ids and move those commands to the top
+    pub fn set_pinned(&mut self, pinned: Vec<String>) {
+        self.pinned = pinned;
+        self.update_filtered_commands();
+    }
+
+    /// Set the category order and the categories to leave out
+    pub fn set_category_layout(&mut self, order: Vec<String>, hidden: Vec<String>) {
+        self.category_order = order;
+        self.hidden_categories = hidden;
+        self.update_filtered_commands();
+    }
+
+    pub fn is_pinned(&self, command_id: &str) -> bool {
+        self.pinned.iter().any(|id| id == command_id)
+    }
+
+    /// Highlight the command with the given id if it is listed
+    pub fn select_command(&mut self, command_id: &str) {
+        if let Some(index) = self
+            .filtered_commands
+            .iter()
+            .position(|cmd| cmd.id == command_id)
+        {
+            self.selected_index = index;
         }
+    }
+
+    /// Lowercase name, description, keywords and category, separated so a
+    /// query cannot match across two fields
+    fn search_text(command: &Command) -> String {
+        let mut fields = vec![command.name.as_str(), command.description.as_str()];
+        fields.extend(command.keywords.iter().map(String::as_str));
+        fields.push(command.category.display_name());
+        fields.join("\n").to_lowercase()
+    }
 
-        // Ensure selected index is within bounds
+    /// Update filtered commands based on current input and context
+    fn update_filtered_commands(&mut self) {
+        // Show all applicable commands when no input, otherwise match against the index
+        let query = self.input.to_lowercase();
+        self.filtered_commands = self
+            .commands
+            .iter()
+            .zip(&self.search_index)
+            .filter(|(cmd, text)| {
+                self.is_command_applicable(cmd) && (query.is_empty() || text.contains(&query))
+            })
+            .filter(|(cmd, _)| {
+                self.is_pinned(&cmd.id) || !cmd.category.is_listed_in(&self.hidden_categories)
+            })
+            .map(|(cmd, _)| cmd.clone())
+            .collect();
+
+        // Commands are listed category by category, pinned ones in pin order;
+        // the stable sort keeps each category in registry order
+        let pinned = &self.pinned;
+        let order = &self.category_order;
+        self.filtered_commands.sort_by_key(|cmd| {
+            match pinned.iter().position(|id| *id == cmd.id) {
+                Some(index) => (CommandCategory::Pinned.rank(order), index),
+                None => (cmd.category.rank(order), 0),
+            }
+        });
+
+        self.clamp_selection();
+    }
+
+    /// Ensure selected index is within bounds of the filtered commands
+    pub fn clamp_selection(&mut self) {
         if self.selected_index >= self.filtered_commands.len() {
             self.selected_index = self.filtered_commands.len().saturating_sub(1);
         }
@@ -149,40 +211,6 @@ impl CommandPalette {
             .satisfies_all_requirements(&command.context_requirements)
     }
 
-    /// Check if a command matches the search query using fuzzy matching
-    fn matches_query(&self, command: &Command, query: &str) -> bool {
-        // Check name
-        if command.name.to_lowercase().contains(query) {
-            return true;
-        }
-
-        // Check description
-        if command.description.to_lowercase().contains(query) {
-            return true;
-        }
-
-        // Check keywords
-        if command
-            .keywords
-            .iter()
-            .any(|keyword| keyword.to_lowercase().contains(query))
-        {
-            return true;
-        }
-
-        // Check category
-        if command
-            .category
-            .display_name()
-            .to_lowercase()
-            .contains(query)
-        {
-            return true;
-        }
-
-        false
-    }
-
     /// Get the number of filtered commands
     pub fn filtered_count(&self) -> usize {
         self.filtered_commands.len()
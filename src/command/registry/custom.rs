@@ -0,0 +1,32 @@
+use crate::command::commands::{Command, CommandAction, CommandCategory, ContextRequirement};
+use crate::command::context::CommandContext;
+use crate::config::user_config::CustomCommandConfig;
+
+/// Create palette commands from the user's custom command definitions,
+/// keeping only those applicable in the given context
+pub fn create_custom_commands_for_context(
+    context: &CommandContext,
+    custom_commands: &[CustomCommandConfig],
+) -> Vec<Command> {
+    custom_commands
+        .iter()
+        .map(|custom| {
+            let mut requirements = vec![];
+            if custom.command.contains("{resource_id}") {
+                requirements.push(ContextRequirement::ResourceSelected);
+            }
+
+            Command::new(
+                format!("custom.{}", custom.id),
+                custom.name.clone(),
+                custom.description.clone(),
+                CommandCategory::Custom,
+                CommandAction::RunCustomCommand(custom.command.clone()),
+                "🛠️".to_string(),
+            )
+            .with_keywords(custom.keywords.clone())
+            .with_context_requirements(requirements)
+        })
+        .filter(|cmd| context.satisfies_all_requirements(&cmd.context_requirements))
+        .collect()
+}
@@ -49,6 +49,39 @@ pub fn create_ec2_commands() -> Vec<Command> {
         .with_context_requirements(vec![ContextRequirement::ServiceSelected(service_type)]),
     );
 
+    // Zone filter commands (no resource selection required)
+    for (service_command, extra_keywords) in [
+        (
+            ServiceCommand::FilterByZone,
+            vec!["filter".to_string(), "az".to_string(), "zone".to_string()],
+        ),
+        (
+            ServiceCommand::ClearZoneFilter,
+            vec![
+                "clear".to_string(),
+                "reset".to_string(),
+                "az".to_string(),
+                "zone".to_string(),
+            ],
+        ),
+    ] {
+        let mut keywords = vec!["ec2".to_string(), "availability".to_string()];
+        keywords.extend(extra_keywords);
+
+        commands.push(
+            Command::new(
+                format!("service.ec2.{:?}", service_command).to_lowercase(),
+                service_command.display_name().to_string(),
+                service_command.description().to_string(),
+                CommandCategory::Service(service_type),
+                CommandAction::ExecuteServiceCommand(service_type, service_command),
+                service_type.icon().to_string(),
+            )
+            .with_keywords(keywords)
+            .with_context_requirements(vec![ContextRequirement::ServiceSelected(service_type)]),
+        );
+    }
+
     // Resource-specific commands (require resource selection)
     let resource_commands = vec![
         (
@@ -79,6 +112,15 @@ pub fn create_ec2_commands() -> Vec<Command> {
                 "delete".to_string(),
             ],
         ),
+        (
+            ServiceCommand::CloneInstance,
+            vec![
+                "clone".to_string(),
+                "duplicate".to_string(),
+                "copy".to_string(),
+                "launch".to_string(),
+            ],
+        ),
         (
             ServiceCommand::DescribeInstance,
             vec![
@@ -87,6 +129,17 @@ pub fn create_ec2_commands() -> Vec<Command> {
                 "info".to_string(),
             ],
         ),
+        (
+            ServiceCommand::InspectSecurityGroups,
+            vec![
+                "security".to_string(),
+                "groups".to_string(),
+                "sg".to_string(),
+                "firewall".to_string(),
+                "ports".to_string(),
+                "rules".to_string(),
+            ],
+        ),
     ];
 
     for (service_command, extra_keywords) in resource_commands {
@@ -162,6 +215,40 @@ pub fn create_ec2_commands_with_context(context: &CommandContext) -> Vec<Command
         .with_enabled(is_service_selected),
     );
 
+    // Zone filter commands (no resource selection required)
+    for (service_command, extra_keywords) in [
+        (
+            ServiceCommand::FilterByZone,
+            vec!["filter".to_string(), "az".to_string(), "zone".to_string()],
+        ),
+        (
+            ServiceCommand::ClearZoneFilter,
+            vec![
+                "clear".to_string(),
+                "reset".to_string(),
+                "az".to_string(),
+                "zone".to_string(),
+            ],
+        ),
+    ] {
+        let mut keywords = vec!["ec2".to_string(), "availability".to_string()];
+        keywords.extend(extra_keywords);
+
+        commands.push(
+            Command::new(
+                format!("service.ec2.{:?}", service_command).to_lowercase(),
+                service_command.display_name().to_string(),
+                service_command.description().to_string(),
+                CommandCategory::Service(service_type),
+                CommandAction::ExecuteServiceCommand(service_type, service_command),
+                service_type.icon().to_string(),
+            )
+            .with_keywords(keywords)
+            .with_context_requirements(vec![ContextRequirement::ServiceSelected(service_type)])
+            .with_enabled(is_service_selected),
+        );
+    }
+
     // Resource-specific commands (require resource selection)
     let resource_commands = vec![
         (
@@ -192,6 +279,15 @@ pub fn create_ec2_commands_with_context(context: &CommandContext) -> Vec<Command
                 "delete".to_string(),
             ],
         ),
+        (
+            ServiceCommand::CloneInstance,
+            vec![
+                "clone".to_string(),
+                "duplicate".to_string(),
+                "copy".to_string(),
+                "launch".to_string(),
+            ],
+        ),
         (
             ServiceCommand::DescribeInstance,
             vec![
@@ -200,6 +296,17 @@ pub fn create_ec2_commands_with_context(context: &CommandContext) -> Vec<Command
                 "info".to_string(),
             ],
         ),
+        (
+            ServiceCommand::InspectSecurityGroups,
+            vec![
+                "security".to_string(),
+                "groups".to_string(),
+                "sg".to_string(),
+                "firewall".to_string(),
+                "ports".to_string(),
+                "rules".to_string(),
+            ],
+        ),
     ];
 
     for (service_command, extra_keywords) in resource_commands {
@@ -83,6 +83,24 @@ pub fn create_eks_commands() -> Vec<Command> {
                 "workers".to_string(),
             ],
         ),
+        (
+            ServiceCommand::UpdateNodeGroupSize,
+            vec![
+                "scale".to_string(),
+                "resize".to_string(),
+                "desired".to_string(),
+                "nodegroup".to_string(),
+            ],
+        ),
+        (
+            ServiceCommand::ViewClusterLogs,
+            vec![
+                "logs".to_string(),
+                "tail".to_string(),
+                "cloudwatch".to_string(),
+                "control plane".to_string(),
+            ],
+        ),
     ];
 
     for (service_command, extra_keywords) in resource_commands {
@@ -196,6 +214,24 @@ pub fn create_eks_commands_with_context(context: &CommandContext) -> Vec<Command
                 "workers".to_string(),
             ],
         ),
+        (
+            ServiceCommand::UpdateNodeGroupSize,
+            vec![
+                "scale".to_string(),
+                "resize".to_string(),
+                "desired".to_string(),
+                "nodegroup".to_string(),
+            ],
+        ),
+        (
+            ServiceCommand::ViewClusterLogs,
+            vec![
+                "logs".to_string(),
+                "tail".to_string(),
+                "cloudwatch".to_string(),
+                "control plane".to_string(),
+            ],
+        ),
     ];
 
     for (service_command, extra_keywords) in resource_commands {
@@ -1,8 +1,9 @@
-use crate::command::commands::{Command, CommandAction, CommandCategory};
+use crate::aws::types::ServiceType;
+use crate::command::commands::{Command, CommandAction, CommandCategory, ContextRequirement};
 
 /// Create general application commands
 pub fn create_general_commands() -> Vec<Command> {
-    vec![
+    let mut commands = vec![
         Command::new(
             "general.help".to_string(),
             "Show Help".to_string(),
@@ -31,5 +32,314 @@ pub fn create_general_commands() -> Vec<Command> {
             "preferences".to_string(),
             "options".to_string(),
         ]),
-    ]
+        Command::new(
+            "general.openinconsole".to_string(),
+            "Open in AWS Console".to_string(),
+            "Open the selected resource in the AWS Console".to_string(),
+            CommandCategory::General,
+            CommandAction::OpenInConsole,
+            "🌐".to_string(),
+        )
+        .with_keywords(vec![
+            "console".to_string(),
+            "browser".to_string(),
+            "web".to_string(),
+            "open".to_string(),
+            "link".to_string(),
+        ])
+        .with_context_requirements(vec![ContextRequirement::ResourceSelected]),
+        Command::new(
+            "general.canceloperation".to_string(),
+            "Cancel Operation".to_string(),
+            "Cancel the most recent running operation".to_string(),
+            CommandCategory::General,
+            CommandAction::CancelOperation,
+            "⛔".to_string(),
+        )
+        .with_keywords(vec![
+            "cancel".to_string(),
+            "abort".to_string(),
+            "stop".to_string(),
+            "operation".to_string(),
+        ])
+        .with_context_requirements(vec![ContextRequirement::OperationInFlight]),
+        Command::new(
+            "general.clearcache".to_string(),
+            "Clear Cache".to_string(),
+            "Drop all cached resource data and reload the current view".to_string(),
+            CommandCategory::General,
+            CommandAction::ClearCache,
+            "🧹".to_string(),
+        )
+        .with_keywords(vec![
+            "cache".to_string(),
+            "clear".to_string(),
+            "reload".to_string(),
+            "refresh".to_string(),
+            "stale".to_string(),
+        ]),
+        Command::new(
+            "general.reloadservice".to_string(),
+            "Reload This Service".to_string(),
+            "Drop this service's cached listing in the current region and fetch it again"
+                .to_string(),
+            CommandCategory::General,
+            CommandAction::ReloadService,
+            "🔄".to_string(),
+        )
+        .with_keywords(vec![
+            "cache".to_string(),
+            "clear".to_string(),
+            "reload".to_string(),
+            "refresh".to_string(),
+            "stale".to_string(),
+            "service".to_string(),
+        ])
+        .with_context_requirements(vec![ContextRequirement::OnResourceList]),
+        Command::new(
+            "general.recentresources".to_string(),
+            "Recent Resources".to_string(),
+            "Jump back to a recently viewed resource".to_string(),
+            CommandCategory::General,
+            CommandAction::ShowRecentResources,
+            "🕘".to_string(),
+        )
+        .with_keywords(vec![
+            "recent".to_string(),
+            "history".to_string(),
+            "viewed".to_string(),
+            "resources".to_string(),
+            "back".to_string(),
+        ]),
+        Command::new(
+            "general.permissions".to_string(),
+            "What Can I Do?".to_string(),
+            "Check which operations the current identity is allowed to run".to_string(),
+            CommandCategory::General,
+            CommandAction::DiagnosePermissions,
+            "🔑".to_string(),
+        )
+        .with_keywords(vec![
+            "permissions".to_string(),
+            "iam".to_string(),
+            "access".to_string(),
+            "denied".to_string(),
+            "policy".to_string(),
+            "diagnostics".to_string(),
+        ])
+        .with_context_requirements(vec![ContextRequirement::AwsClientReady]),
+        Command::new(
+            "general.reconnect".to_string(),
+            "Reconnect".to_string(),
+            "Connect to AWS again with the current profile".to_string(),
+            CommandCategory::General,
+            CommandAction::Reconnect,
+            "🔌".to_string(),
+        )
+        .with_keywords(vec![
+            "reconnect".to_string(),
+            "connect".to_string(),
+            "retry".to_string(),
+            "clients".to_string(),
+            "credentials".to_string(),
+        ]),
+        Command::new(
+            "general.copyviewstate".to_string(),
+            "Copy View Link".to_string(),
+            "Copy a link to the current profile, region and page".to_string(),
+            CommandCategory::General,
+            CommandAction::CopyViewState,
+            "🔗".to_string(),
+        )
+        .with_keywords(vec![
+            "share".to_string(),
+            "link".to_string(),
+            "bookmark".to_string(),
+            "copy".to_string(),
+            "url".to_string(),
+        ]),
+        Command::new(
+            "general.gotoviewstate".to_string(),
+            "Go to View Link".to_string(),
+            "Open a copied view link, switching profile and region as needed".to_string(),
+            CommandCategory::General,
+            CommandAction::GoToViewState,
+            "📍".to_string(),
+        )
+        .with_keywords(vec![
+            "share".to_string(),
+            "link".to_string(),
+            "bookmark".to_string(),
+            "open".to_string(),
+            "url".to_string(),
+            "goto".to_string(),
+        ]),
+        Command::new(
+            "general.showresourcechanges".to_string(),
+            "Show Changes Since Last Refresh".to_string(),
+            "Diff the selected resource against its baseline describe output".to_string(),
+            CommandCategory::General,
+            CommandAction::ShowResourceChanges,
+            "🔍".to_string(),
+        )
+        .with_keywords(vec![
+            "diff".to_string(),
+            "changes".to_string(),
+            "drift".to_string(),
+            "baseline".to_string(),
+            "compare".to_string(),
+        ])
+        .with_context_requirements(vec![ContextRequirement::ResourceSelected]),
+        Command::new(
+            "general.resetchangebaseline".to_string(),
+            "Reset Change Baseline".to_string(),
+            "Treat the current state of every cached resource as unchanged".to_string(),
+            CommandCategory::General,
+            CommandAction::ResetChangeBaseline,
+            "♻️".to_string(),
+        )
+        .with_keywords(vec![
+            "reset".to_string(),
+            "baseline".to_string(),
+            "diff".to_string(),
+            "drift".to_string(),
+            "changes".to_string(),
+        ]),
+        Command::new(
+            "general.exportkeybindings".to_string(),
+            "Export Keybinding Cheat Sheet".to_string(),
+            "Write the current keybindings to a Markdown or text file".to_string(),
+            CommandCategory::General,
+            CommandAction::ExportKeybindings,
+            "⌨️".to_string(),
+        )
+        .with_keywords(vec![
+            "keys".to_string(),
+            "keybindings".to_string(),
+            "shortcuts".to_string(),
+            "cheat sheet".to_string(),
+            "export".to_string(),
+            "print".to_string(),
+        ]),
+        Command::new(
+            "general.exportresources".to_string(),
+            "Export Resource List".to_string(),
+            "Write the listed resources, as filtered, to a CSV or JSON file".to_string(),
+            CommandCategory::General,
+            CommandAction::ExportResources,
+            "📤".to_string(),
+        )
+        .with_keywords(vec![
+            "export".to_string(),
+            "csv".to_string(),
+            "json".to_string(),
+            "download".to_string(),
+            "save".to_string(),
+            "resources".to_string(),
+        ])
+        .with_context_requirements(vec![ContextRequirement::OnResourceList]),
+        Command::new(
+            "general.copyselectedids".to_string(),
+            "Copy Selected Resource IDs".to_string(),
+            "Copy the ids of the marked resources, or the highlighted one, to the clipboard"
+                .to_string(),
+            CommandCategory::General,
+            CommandAction::CopySelectedIds,
+            "📋".to_string(),
+        )
+        .with_keywords(vec![
+            "copy".to_string(),
+            "ids".to_string(),
+            "selected".to_string(),
+            "marked".to_string(),
+            "clipboard".to_string(),
+            "batch".to_string(),
+        ])
+        .with_context_requirements(vec![ContextRequirement::OnResourceList]),
+        Command::new(
+            "general.showfavorites".to_string(),
+            "Show Favorites".to_string(),
+            "List favorite resources and commands to open or run".to_string(),
+            CommandCategory::General,
+            CommandAction::ShowFavorites,
+            "⭐".to_string(),
+        )
+        .with_keywords(vec![
+            "favorites".to_string(),
+            "starred".to_string(),
+            "bookmarks".to_string(),
+            "saved".to_string(),
+        ]),
+        Command::new(
+            "general.togglefavoriteresource".to_string(),
+            "Toggle Favorite Resource".to_string(),
+            "Add the selected resource to favorites, or remove it".to_string(),
+            CommandCategory::General,
+            CommandAction::ToggleFavoriteResource,
+            "⭐".to_string(),
+        )
+        .with_keywords(vec![
+            "favorite".to_string(),
+            "star".to_string(),
+            "bookmark".to_string(),
+            "save".to_string(),
+        ])
+        .with_context_requirements(vec![ContextRequirement::ResourceSelected]),
+        Command::new(
+            "general.editfavoritenote".to_string(),
+            "Edit Favorite Note…".to_string(),
+            "Label the selected favorite resource with a note".to_string(),
+            CommandCategory::General,
+            CommandAction::EditFavoriteNote,
+            "📝".to_string(),
+        )
+        .with_keywords(vec![
+            "favorite".to_string(),
+            "note".to_string(),
+            "label".to_string(),
+            "comment".to_string(),
+        ])
+        .with_context_requirements(vec![ContextRequirement::ResourceSelected]),
+        Command::new(
+            "general.edittags".to_string(),
+            "Edit Tags…".to_string(),
+            "Add, change or remove the selected resource's tags".to_string(),
+            CommandCategory::General,
+            CommandAction::EditTags,
+            "🏷️".to_string(),
+        )
+        .with_keywords(vec![
+            "tags".to_string(),
+            "tag".to_string(),
+            "label".to_string(),
+            "manage".to_string(),
+        ])
+        .with_context_requirements(vec![ContextRequirement::ResourceSelected]),
+    ];
+
+    // Docs need no credentials, so they are offered even before the clients connect
+    for service_type in ServiceType::all() {
+        commands.push(
+            Command::new(
+                format!("docs.{:?}", service_type).to_lowercase(),
+                format!("Open {} Docs", service_type.display_name()),
+                format!(
+                    "Open the AWS documentation for {}",
+                    service_type.display_name()
+                ),
+                CommandCategory::Service(service_type),
+                CommandAction::OpenDocs(service_type),
+                "📖".to_string(),
+            )
+            .with_keywords(vec![
+                "docs".to_string(),
+                "documentation".to_string(),
+                "reference".to_string(),
+                "manual".to_string(),
+                format!("{:?}", service_type).to_lowercase(),
+            ]),
+        );
+    }
+
+    commands
 }
@@ -5,22 +5,26 @@ use crate::command::commands::{
 };
 use crate::command::context::CommandContext;
 
+mod custom;
 mod ec2;
 mod eks;
 mod general;
 mod iam;
 mod navigation;
+mod preset;
 mod profile;
 mod rds;
 mod region;
 mod s3;
 mod secrets;
 
+pub use custom::*;
 pub use ec2::*;
 pub use eks::*;
 pub use general::*;
 pub use iam::*;
 pub use navigation::*;
+pub use preset::*;
 pub use profile::*;
 pub use rds::*;
 pub use region::*;
@@ -131,6 +135,21 @@ impl CommandRegistry {
         commands.extend(create_secrets_commands_with_context(context));
         commands.extend(create_eks_commands_with_context(context));
 
+        // Every service command talks to AWS, so none are offered while the clients
+        // connect, for services the identity is known to be denied, or for
+        // services that keep failing
+        for command in &mut commands {
+            command
+                .context_requirements
+                .push(ContextRequirement::AwsClientReady);
+            if let CommandCategory::Service(service_type) = command.category {
+                command.context_requirements.extend([
+                    ContextRequirement::ServicePermitted(service_type),
+                    ContextRequirement::ServiceHealthy(service_type),
+                ]);
+            }
+        }
+
         commands
     }
 
@@ -42,6 +42,25 @@ pub fn create_navigation_commands() -> Vec<Command> {
         .with_context_requirements(vec![ContextRequirement::NotOnPage(AppPage::Settings)]),
     );
 
+    // Activity log navigation
+    commands.push(
+        Command::new(
+            "nav.activitylog".to_string(),
+            "Go to Activity Log".to_string(),
+            "Browse, filter and clear every command run from the app".to_string(),
+            CommandCategory::Navigation,
+            CommandAction::NavigateToPage(AppPage::ActivityLog),
+            "📜".to_string(),
+        )
+        .with_keywords(vec![
+            "activity".to_string(),
+            "history".to_string(),
+            "log".to_string(),
+            "audit".to_string(),
+        ])
+        .with_context_requirements(vec![ContextRequirement::NotOnPage(AppPage::ActivityLog)]),
+    );
+
     // Service navigation commands
     for service_type in ServiceType::all() {
         commands.push(
@@ -0,0 +1,64 @@
+use crate::command::commands::{Command, CommandAction, CommandCategory};
+use crate::command::context::CommandContext;
+use crate::config::user_config::ContextPreset;
+
+/// Create the preset commands: one per saved preset that would change the
+/// current context, plus the command that saves a new one
+pub fn create_preset_commands_for_context(
+    context: &CommandContext,
+    presets: &[ContextPreset],
+) -> Vec<Command> {
+    let mut commands = vec![Command::new(
+        "preset.save".to_string(),
+        "Save Current Context as Preset…".to_string(),
+        format!(
+            "Save {} / {} under a name",
+            context.current_profile, context.current_region
+        ),
+        CommandCategory::Profile,
+        CommandAction::SavePreset,
+        "📌".to_string(),
+    )
+    .with_keywords(vec![
+        "preset".to_string(),
+        "save".to_string(),
+        "context".to_string(),
+        "profile".to_string(),
+        "region".to_string(),
+    ])];
+
+    for preset in presets {
+        // Applying the active context would be a no-op
+        if preset.profile == context.current_profile && preset.region == context.current_region {
+            continue;
+        }
+        // Presets for removed profiles cannot be applied
+        if !context
+            .available_profiles
+            .iter()
+            .any(|p| p.name == preset.profile)
+        {
+            continue;
+        }
+
+        commands.push(
+            Command::new(
+                format!("preset.apply.{}", preset.name),
+                format!("Switch to Preset: {}", preset.name),
+                format!("Switch to profile {} in {}", preset.profile, preset.region),
+                CommandCategory::Profile,
+                CommandAction::ApplyPreset(preset.name.clone()),
+                "📌".to_string(),
+            )
+            .with_keywords(vec![
+                "preset".to_string(),
+                "context".to_string(),
+                preset.name.clone(),
+                preset.profile.clone(),
+                preset.region.clone(),
+            ]),
+        );
+    }
+
+    commands
+}
@@ -26,6 +26,44 @@ pub fn create_profile_commands_for_context(context: &CommandContext) -> Vec<Comm
         .with_context_requirements(vec![ContextRequirement::ProfilesAvailable]),
     );
 
+    commands.push(
+        Command::new(
+            "profile.assumerole".to_string(),
+            "Assume Role…".to_string(),
+            "Assume a role from the current profile into a temporary profile".to_string(),
+            CommandCategory::Profile,
+            CommandAction::AssumeRole,
+            "🎭".to_string(),
+        )
+        .with_keywords(vec![
+            "assume".to_string(),
+            "role".to_string(),
+            "sts".to_string(),
+            "account".to_string(),
+            "cross-account".to_string(),
+        ]),
+    );
+
+    commands.push(
+        Command::new(
+            "profile.validateall".to_string(),
+            "Validate All Profiles".to_string(),
+            "Check every profile's credentials with STS and report the results".to_string(),
+            CommandCategory::Profile,
+            CommandAction::ValidateProfiles,
+            "✅".to_string(),
+        )
+        .with_keywords(vec![
+            "profile".to_string(),
+            "validate".to_string(),
+            "check".to_string(),
+            "credentials".to_string(),
+            "sts".to_string(),
+            "identity".to_string(),
+        ])
+        .with_context_requirements(vec![ContextRequirement::ProfilesAvailable]),
+    );
+
     // Add specific profile switching commands for each available profile
     for profile in &context.available_profiles {
         // Skip current profile
@@ -33,10 +71,16 @@ pub fn create_profile_commands_for_context(context: &CommandContext) -> Vec<Comm
             continue;
         }
 
+        // Assumed roles are marked so they are not mistaken for configured profiles
+        let temporary = if profile.ephemeral_expiry().is_some() {
+            " (temporary)"
+        } else {
+            ""
+        };
         commands.push(
             Command::new(
                 format!("profile.switch.{}", profile.name),
-                format!("Switch to Profile: {}", profile.name),
+                format!("Switch to Profile: {}{}", profile.name, temporary),
                 format!("Switch to AWS profile '{}'", profile.name),
                 CommandCategory::Profile,
                 CommandAction::SwitchProfile(profile.name.clone()),
@@ -53,6 +97,41 @@ pub fn create_profile_commands_for_context(context: &CommandContext) -> Vec<Comm
         );
     }
 
+    // Compare the current profile against each of the others
+    for profile in &context.available_profiles {
+        if profile.name == context.current_profile {
+            continue;
+        }
+
+        commands.push(
+            Command::new(
+                format!("profile.compare.{}", profile.name),
+                format!(
+                    "Compare Profiles: {} vs {}",
+                    context.current_profile, profile.name
+                ),
+                format!(
+                    "Show the accounts behind '{}' and '{}' side by side",
+                    context.current_profile, profile.name
+                ),
+                CommandCategory::Profile,
+                CommandAction::CompareProfiles(
+                    context.current_profile.clone(),
+                    profile.name.clone(),
+                ),
+                "⚖️".to_string(),
+            )
+            .with_keywords(vec![
+                "profile".to_string(),
+                "compare".to_string(),
+                "diff".to_string(),
+                "account".to_string(),
+                profile.name.clone(),
+            ])
+            .with_context_requirements(vec![ContextRequirement::ProfilesAvailable]),
+        );
+    }
+
     commands
 }
 
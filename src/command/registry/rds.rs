@@ -59,6 +59,14 @@ pub fn create_rds_commands() -> Vec<Command> {
                 "info".to_string(),
             ],
         ),
+        (
+            ServiceCommand::ViewDatabaseLogs,
+            vec![
+                "logs".to_string(),
+                "tail".to_string(),
+                "cloudwatch".to_string(),
+            ],
+        ),
         (
             ServiceCommand::CreateSnapshot,
             vec![
@@ -159,6 +167,14 @@ pub fn create_rds_commands_with_context(context: &CommandContext) -> Vec<Command
                 "info".to_string(),
             ],
         ),
+        (
+            ServiceCommand::ViewDatabaseLogs,
+            vec![
+                "logs".to_string(),
+                "tail".to_string(),
+                "cloudwatch".to_string(),
+            ],
+        ),
         (
             ServiceCommand::CreateSnapshot,
             vec![
@@ -33,13 +33,14 @@ pub fn create_region_commands_for_context(context: &CommandContext) -> Vec<Comma
             continue;
         }
 
+        let opt_in = if region.opt_in { ", opt-in" } else { "" };
         commands.push(
             Command::new(
                 format!("region.switch.{}", region.name),
                 format!("Switch to Region: {}", region.display_name),
                 format!(
-                    "Switch to AWS region '{}' ({})",
-                    region.display_name, region.name
+                    "Switch to AWS region '{}' ({}{})",
+                    region.display_name, region.name, opt_in
                 ),
                 CommandCategory::Region,
                 CommandAction::SwitchRegion(region.name.clone()),
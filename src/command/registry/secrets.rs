@@ -83,6 +83,14 @@ pub fn create_secrets_commands() -> Vec<Command> {
                 "value".to_string(),
             ],
         ),
+        (
+            ServiceCommand::RotateSecret,
+            vec![
+                "rotate".to_string(),
+                "rotation".to_string(),
+                "lambda".to_string(),
+            ],
+        ),
     ];
 
     for (service_command, extra_keywords) in resource_commands {
@@ -192,6 +200,14 @@ pub fn create_secrets_commands_with_context(context: &CommandContext) -> Vec<Com
                 "value".to_string(),
             ],
         ),
+        (
+            ServiceCommand::RotateSecret,
+            vec![
+                "rotate".to_string(),
+                "rotation".to_string(),
+                "lambda".to_string(),
+            ],
+        ),
     ];
 
     for (service_command, extra_keywords) in resource_commands {
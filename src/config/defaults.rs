@@ -4,6 +4,20 @@ pub fn get_default_config() -> UserConfig {
     UserConfig::default()
 }
 
+/// Keys that work on every page, in the order the help panel lists them
 pub fn get_default_keybindings() -> Vec<(&'static str, &'static str)> {
-    vec![("?", "Help")]
+    vec![
+        ("?", "Help"),
+        ("q", "Quit"),
+        ("Ctrl+O", "Command palette"),
+        ("Ctrl+P", "Quick navigation"),
+        ("N", "Notifications"),
+        ("A", "Run the latest notification's action"),
+        ("Esc", "Back / cancel operation"),
+        ("↑↓", "Move selection"),
+        ("←→", "Previous / next service"),
+        ("PgUp PgDn", "Scroll"),
+        ("Tab", "Next widget or section"),
+        ("Enter", "Open"),
+    ]
 }
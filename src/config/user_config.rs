@@ -1,7 +1,11 @@
 use crate::app::state::AppPage;
+use crate::aws::types::{ResourceId, ServiceType};
+use crate::command::CommandCategory;
+use crate::utils::error::{AppError, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserConfig {
@@ -9,6 +13,19 @@ pub struct UserConfig {
     pub display: DisplayConfig,
     pub behavior: BehaviorConfig,
     pub dashboard: DashboardConfig,
+    #[serde(default)]
+    pub custom_commands: CustomCommandsConfig,
+    #[serde(default)]
+    pub keybindings: KeybindingsConfig,
+    #[serde(default)]
+    pub resource_list: ResourceListConfig,
+    #[serde(default)]
+    pub command_palette: CommandPaletteConfig,
+    #[serde(default)]
+    pub refresh: RefreshConfig,
+    /// Named profile and region pairs, each offered as a palette command
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub presets: Vec<ContextPreset>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,6 +34,15 @@ pub struct AwsConfig {
     pub default_region: String,
     pub auto_refresh_interval: u64,
     pub max_concurrent_requests: usize,
+    /// Always switch to a profile's configured region instead of the one it was last used in
+    #[serde(default)]
+    pub follow_profile_region: bool,
+    /// Region each profile was last used in, keyed by profile name
+    #[serde(default)]
+    pub profile_regions: HashMap<String, String>,
+    /// Fetch a user's groups and attached policies when its detail page opens
+    #[serde(default = "default_true")]
+    pub fetch_iam_user_details: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,6 +59,14 @@ pub struct BehaviorConfig {
     pub confirm_destructive_actions: bool,
     pub remember_last_page: bool,
     pub save_favorites: bool,
+    /// Stop background refreshes while the terminal is unfocused; turn off for
+    /// terminals that never report focus changes
+    #[serde(default = "default_true")]
+    pub pause_when_unfocused: bool,
+}
+
+fn default_true() -> bool {
+    true
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -46,6 +80,210 @@ pub struct DashboardConfig {
     pub max_favorite_items: usize,
 }
 
+/// Multi-key sequences (e.g. `g` then `d`) resolved to command ids
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeybindingsConfig {
+    pub sequences: HashMap<String, String>,
+    /// How long to wait for the next key of a sequence before discarding it
+    pub sequence_timeout_ms: u64,
+}
+
+/// What Enter does on a resource list row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EnterAction {
+    /// Open the resource detail page
+    Details,
+    /// Drill into what the resource holds, e.g. a bucket's objects
+    Contents,
+}
+
+impl EnterAction {
+    /// The action Shift+Enter performs when this one is the default
+    pub fn alternate(self) -> Self {
+        match self {
+            EnterAction::Details => EnterAction::Contents,
+            EnterAction::Contents => EnterAction::Details,
+        }
+    }
+}
+
+/// Separator between resource ids copied together
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdSeparator {
+    /// One id per line
+    #[default]
+    Newline,
+    /// A single comma-separated line
+    Comma,
+}
+
+impl IdSeparator {
+    pub fn join(self, ids: &[ResourceId]) -> String {
+        match self {
+            IdSeparator::Newline => ids.join("\n"),
+            IdSeparator::Comma => ids.join(","),
+        }
+    }
+}
+
+/// Which resource states a list shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StateFilter {
+    All,
+    Running,
+    Available,
+    Stopped,
+}
+
+impl StateFilter {
+    /// The quick toggles offered for a service, bound to keys 1, 2, 3… in this
+    /// order; empty for services whose resources have no meaningful state
+    pub fn options(service_type: ServiceType) -> &'static [StateFilter] {
+        match service_type {
+            ServiceType::EC2 => &[StateFilter::All, StateFilter::Running, StateFilter::Stopped],
+            // RDS reports a running database as "available"
+            ServiceType::RDS => &[
+                StateFilter::All,
+                StateFilter::Available,
+                StateFilter::Stopped,
+            ],
+            _ => &[],
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            StateFilter::All => "all",
+            StateFilter::Running => "running",
+            StateFilter::Available => "available",
+            StateFilter::Stopped => "stopped",
+        }
+    }
+
+    pub fn matches(self, state: &str) -> bool {
+        match self {
+            StateFilter::All => true,
+            filter => state.eq_ignore_ascii_case(filter.label()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResourceListConfig {
+    /// Enter behavior per service; services not listed open the detail page
+    pub enter_actions: HashMap<ServiceType, EnterAction>,
+    /// Last chosen state filter per service; services not listed show all states
+    pub state_filters: HashMap<ServiceType, StateFilter>,
+    /// How Copy Selected Resource IDs joins the ids it copies
+    pub id_separator: IdSeparator,
+}
+
+impl ResourceListConfig {
+    pub fn enter_action(&self, service_type: ServiceType) -> EnterAction {
+        self.enter_actions
+            .get(&service_type)
+            .copied()
+            .unwrap_or(EnterAction::Details)
+    }
+
+    pub fn state_filter(&self, service_type: ServiceType) -> StateFilter {
+        self.state_filters
+            .get(&service_type)
+            .copied()
+            .unwrap_or(StateFilter::All)
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CommandPaletteConfig {
+    /// Ids of commands kept at the top of the palette, in the order they were pinned
+    pub pinned_commands: Vec<String>,
+    /// Category names in the order the palette lists them; categories not
+    /// listed follow in the default order
+    pub category_order: Vec<String>,
+    /// Categories left out of the palette; pinned commands still show
+    pub hidden_categories: Vec<String>,
+}
+
+impl CommandPaletteConfig {
+    /// Entries of `category_order` and `hidden_categories` that name no category
+    pub fn unknown_categories(&self) -> Vec<&str> {
+        self.category_order
+            .iter()
+            .chain(&self.hidden_categories)
+            .map(String::as_str)
+            .filter(|name| {
+                !CommandCategory::NAMES
+                    .iter()
+                    .any(|known| known.eq_ignore_ascii_case(name))
+            })
+            .collect()
+    }
+}
+
+/// Timed refreshes of the resource pages; the dashboard's are set under `[dashboard]`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RefreshConfig {
+    pub auto_refresh_list: bool,
+    /// Seconds between list refreshes; unset uses `aws.auto_refresh_interval`
+    pub list_refresh_interval: Option<u64>,
+    /// Re-describes the viewed resource, e.g. to watch a state transition
+    pub auto_refresh_detail: bool,
+    pub detail_refresh_interval: u64,
+}
+
+impl Default for RefreshConfig {
+    fn default() -> Self {
+        Self {
+            auto_refresh_list: true,
+            list_refresh_interval: None,
+            auto_refresh_detail: false,
+            detail_refresh_interval: 30,
+        }
+    }
+}
+
+fn interval_if(enabled: bool, seconds: u64) -> Option<Duration> {
+    // A zero interval would refresh on every tick
+    enabled.then(|| Duration::from_secs(seconds.max(1)))
+}
+
+/// A profile and region switched to together under one name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextPreset {
+    pub name: String,
+    pub profile: String,
+    pub region: String,
+}
+
+/// User-defined palette commands that shell out
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CustomCommandsConfig {
+    /// Custom commands run arbitrary programs, so they are ignored unless enabled
+    pub enabled: bool,
+    pub commands: Vec<CustomCommandConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomCommandConfig {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// Shell command template; `{resource_id}`, `{region}` and `{profile}` are substituted
+    pub command: String,
+}
+
 impl Default for UserConfig {
     fn default() -> Self {
         Self {
@@ -53,6 +291,12 @@ impl Default for UserConfig {
             display: DisplayConfig::default(),
             behavior: BehaviorConfig::default(),
             dashboard: DashboardConfig::default(),
+            custom_commands: CustomCommandsConfig::default(),
+            keybindings: KeybindingsConfig::default(),
+            resource_list: ResourceListConfig::default(),
+            command_palette: CommandPaletteConfig::default(),
+            refresh: RefreshConfig::default(),
+            presets: Vec::new(),
         }
     }
 }
@@ -64,10 +308,18 @@ impl Default for AwsConfig {
             default_region: "us-east-1".to_string(),
             auto_refresh_interval: 300,
             max_concurrent_requests: 10,
+            follow_profile_region: false,
+            profile_regions: HashMap::new(),
+            fetch_iam_user_details: true,
         }
     }
 }
 
+impl DisplayConfig {
+    /// Themes offered by the setup wizard
+    pub const THEMES: &'static [&'static str] = &["default", "dark", "light"];
+}
+
 impl Default for DisplayConfig {
     fn default() -> Self {
         Self {
@@ -86,6 +338,7 @@ impl Default for BehaviorConfig {
             confirm_destructive_actions: true,
             remember_last_page: true,
             save_favorites: true,
+            pause_when_unfocused: true,
         }
     }
 }
@@ -110,8 +363,61 @@ impl Default for DashboardConfig {
     }
 }
 
+impl Default for KeybindingsConfig {
+    fn default() -> Self {
+        let sequences = [
+            ("gd", "nav.dashboard"),
+            ("gs", "nav.settings"),
+            ("ge", "nav.service.ec2"),
+            ("g3", "nav.service.s3"),
+            ("gr", "nav.service.rds"),
+            ("gi", "nav.service.iam"),
+            ("gx", "nav.service.secrets"),
+            ("gk", "nav.service.eks"),
+        ]
+        .into_iter()
+        .map(|(keys, command_id)| (keys.to_string(), command_id.to_string()))
+        .collect();
+
+        Self {
+            sequences,
+            sequence_timeout_ms: 1000,
+        }
+    }
+}
+
 impl UserConfig {
-    pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
+    /// How often `page` refreshes on its own, or `None` when it does not.
+    /// Resource pages also stop while `behavior.auto_refresh_resources` is off.
+    pub fn refresh_interval(&self, page: &AppPage) -> Option<Duration> {
+        match page {
+            AppPage::Dashboard => interval_if(
+                self.dashboard.auto_refresh_dashboard,
+                self.dashboard.dashboard_refresh_interval,
+            ),
+            AppPage::ResourceList(_) => self.list_refresh_interval(),
+            AppPage::ResourceDetail(_, _) => self.detail_refresh_interval(),
+            AppPage::Settings | AppPage::ActivityLog => None,
+        }
+    }
+
+    pub fn list_refresh_interval(&self) -> Option<Duration> {
+        interval_if(
+            self.behavior.auto_refresh_resources && self.refresh.auto_refresh_list,
+            self.refresh
+                .list_refresh_interval
+                .unwrap_or(self.aws.auto_refresh_interval),
+        )
+    }
+
+    pub fn detail_refresh_interval(&self) -> Option<Duration> {
+        interval_if(
+            self.behavior.auto_refresh_resources && self.refresh.auto_refresh_detail,
+            self.refresh.detail_refresh_interval,
+        )
+    }
+
+    pub fn load() -> Result<Self> {
         let config_path = Self::get_config_path()?;
 
         if config_path.exists() {
@@ -119,13 +425,19 @@ impl UserConfig {
             let config: UserConfig = toml::from_str(&content)?;
             Ok(config)
         } else {
-            let default_config = Self::default();
-            default_config.save()?;
-            Ok(default_config)
+            // The setup wizard writes the first config file
+            Ok(Self::default())
         }
     }
 
-    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+    /// Whether no config file has been written yet, i.e. this is the first
+    /// launch. Without a config directory there is nowhere to save to, so
+    /// that does not count.
+    pub fn is_first_run() -> bool {
+        Self::get_config_path().is_ok_and(|path| !path.exists())
+    }
+
+    pub fn save(&self) -> Result<()> {
         let config_path = Self::get_config_path()?;
 
         if let Some(parent) = config_path.parent() {
@@ -138,9 +450,9 @@ impl UserConfig {
         Ok(())
     }
 
-    fn get_config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    fn get_config_path() -> Result<PathBuf> {
         let config_dir = dirs::config_dir()
-            .ok_or("Cannot find config directory")?
+            .ok_or_else(|| AppError::Config("Cannot find config directory".to_string()))?
             .join("nimbus-ctl");
 
         Ok(config_dir.join("config.toml"))
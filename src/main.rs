@@ -1,8 +1,15 @@
 use anyhow::Result;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
+    event::{
+        self, DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture,
+        Event, KeyCode, KeyModifiers, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags,
+        PushKeyboardEnhancementFlags,
+    },
     execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    terminal::{
+        disable_raw_mode, enable_raw_mode, supports_keyboard_enhancement, EnterAlternateScreen,
+        LeaveAlternateScreen,
+    },
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::io;
@@ -30,12 +37,35 @@ async fn main() -> Result<()> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
+    // `--demo` serves fixed mock data instead of calling AWS
+    let demo = std::env::args().skip(1).any(|arg| arg == "--demo");
+
     // Create app
-    let app = App::new().await?;
+    let mut app = App::new(demo).await?;
+    // Without the enhancement most terminals send Shift+Enter as plain Enter
+    let keyboard_enhanced = supports_keyboard_enhancement().unwrap_or(false);
+    if keyboard_enhanced {
+        execute!(
+            terminal.backend_mut(),
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES)
+        )?;
+        app.state.shift_enter_supported = true;
+    }
+    // Focus reporting lets auto-refresh pause while the terminal is in the background
+    let focus_reporting = app.state.user_config.behavior.pause_when_unfocused;
+    if focus_reporting {
+        execute!(terminal.backend_mut(), EnableFocusChange)?;
+    }
     let res = run_app(&mut terminal, app).await;
 
     // Restore terminal
     disable_raw_mode()?;
+    if focus_reporting {
+        execute!(terminal.backend_mut(), DisableFocusChange)?;
+    }
+    if keyboard_enhanced {
+        execute!(terminal.backend_mut(), PopKeyboardEnhancementFlags)?;
+    }
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
@@ -53,22 +83,41 @@ async fn main() -> Result<()> {
 async fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     mut app: App,
+) -> Result<()> {
+    let res = event_loop(terminal, &mut app).await;
+
+    // Stop background tasks while the alternate screen is still up, so none
+    // can write over the restored terminal
+    app.shutdown().await;
+    res
+}
+
+async fn event_loop<B: ratatui::backend::Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
 ) -> Result<()> {
     loop {
         terminal.draw(|f| draw_ui(f, &mut app.state))?;
 
         // Handle events with timeout
         if event::poll(Duration::from_millis(100))? {
-            let event = event::read()?;
-            if let Event::Key(key) = event {
-                match key.code {
+            match event::read()? {
+                Event::Key(key) => match key.code {
                     KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                         return Ok(());
                     }
                     _ => {
                         app.handle_input(key).await?;
                     }
+                },
+                Event::Resize(width, height) => {
+                    // Resize the backend buffers right away so the next draw re-centers overlays
+                    terminal.autoresize()?;
+                    app.handle_resize(width, height);
                 }
+                Event::FocusGained => app.handle_focus_change(true),
+                Event::FocusLost => app.handle_focus_change(false),
+                _ => {}
             }
         }
 
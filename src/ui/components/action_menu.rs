@@ -0,0 +1,80 @@
+use crate::app::forms::ActionMenu;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn draw_action_menu(f: &mut Frame, menu: &ActionMenu) {
+    let popup_area = centered_rect(60, 50, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!("Actions: {}", menu.resource_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(3),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let items: Vec<ListItem> = menu
+        .commands
+        .iter()
+        .map(|command| {
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("{} ", command.icon)),
+                Span::styled(command.name.as_str(), Style::default().fg(Color::White)),
+            ]))
+        })
+        .collect();
+    let list = List::new(items)
+        .highlight_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("▶ ");
+    let mut state = ListState::default().with_selected(Some(menu.selected));
+    f.render_stateful_widget(list, chunks[0], &mut state);
+
+    // The highlighted command's description, so the list stays one line per action
+    if let Some(command) = menu.selected_command() {
+        f.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                command.description.as_str(),
+                Style::default().fg(Color::Gray),
+            )))
+            .block(Block::default().borders(Borders::TOP))
+            .wrap(Wrap { trim: true }),
+            chunks[1],
+        );
+    }
+
+    let label = Style::default().fg(Color::Gray);
+    let footer = Line::from(vec![
+        Span::styled("↑↓", Style::default().fg(Color::Green)),
+        Span::styled(" Select  ", label),
+        Span::styled("Enter", Style::default().fg(Color::Green)),
+        Span::styled(" Run  ", label),
+        Span::styled("Esc", Style::default().fg(Color::Green)),
+        Span::styled(" Close", label),
+    ]);
+    f.render_widget(
+        Paragraph::new(footer).alignment(Alignment::Center),
+        chunks[2],
+    );
+}
@@ -0,0 +1,82 @@
+use crate::app::forms::AssumeRoleForm;
+use crate::ui::components::masked_input::masked_line;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn draw_assume_role_form(f: &mut Frame, form: &AssumeRoleForm, source_profile: &str) {
+    let popup_area = centered_rect(70, 45, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("From profile: ", Style::default().fg(Color::Gray)),
+            Span::styled(source_profile, Style::default().fg(Color::Cyan)),
+        ]),
+        Line::from(""),
+        field_line("Role ARN", &form.role_arn, form.focus == 0),
+        Line::from(Span::styled(
+            "  e.g. arn:aws:iam::123456789012:role/ReadOnly",
+            Style::default().fg(Color::DarkGray),
+        )),
+        field_line("External ID (optional)", &form.external_id, form.focus == 1),
+        field_line("MFA serial (optional)", &form.mfa_serial, form.focus == 2),
+        masked_line("MFA code", &form.mfa_code, form.focus == 3),
+        Line::from(""),
+    ];
+
+    if let Some(error) = &form.error {
+        lines.push(Line::from(Span::styled(
+            error.as_str(),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    lines.push(Line::from(vec![
+        Span::styled("Tab ", Style::default().fg(Color::Green)),
+        Span::styled("Next field  ", Style::default().fg(Color::Gray)),
+        Span::styled("Ctrl+R ", Style::default().fg(Color::Green)),
+        Span::styled("Reveal  ", Style::default().fg(Color::Gray)),
+        Span::styled("Enter ", Style::default().fg(Color::Green)),
+        Span::styled("Assume  ", Style::default().fg(Color::Gray)),
+        Span::styled("Esc ", Style::default().fg(Color::Green)),
+        Span::styled("Cancel", Style::default().fg(Color::Gray)),
+    ]));
+
+    let block = Block::default()
+        .title("Assume Role")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        popup_area,
+    );
+}
+
+fn field_line<'a>(label: &'a str, text: &'a str, focused: bool) -> Line<'a> {
+    let value = if focused {
+        Span::styled(
+            format!("{}▏", text),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else if text.is_empty() {
+        Span::styled("…", Style::default().fg(Color::DarkGray))
+    } else {
+        Span::styled(text.to_string(), Style::default().fg(Color::White))
+    };
+    Line::from(vec![
+        Span::styled(format!("{}: ", label), Style::default().fg(Color::Gray)),
+        value,
+    ])
+}
@@ -0,0 +1,85 @@
+use crate::app::operations::BatchResults;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub fn draw_batch_results(f: &mut Frame, results: &BatchResults, scroll: &mut u16) {
+    let popup_area = centered_rect(70, 60, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let failed = results.failures().count();
+    let succeeded = results.items.len() - failed;
+    let border_color = if failed > 0 { Color::Red } else { Color::Green };
+
+    let block = Block::default()
+        .title(format!(
+            "{}: {} succeeded, {} failed",
+            results.action, succeeded, failed
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border_color))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    let label = Style::default().fg(Color::Gray);
+    let mut lines = vec![];
+    for item in &results.items {
+        match &item.outcome {
+            Ok(()) => lines.push(Line::from(vec![
+                Span::styled(
+                    "✓ ",
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(item.resource_id.as_str(), Style::default().fg(Color::White)),
+            ])),
+            Err(message) => {
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        "✗ ",
+                        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(item.resource_id.as_str(), Style::default().fg(Color::White)),
+                ]));
+                lines.push(Line::from(Span::styled(
+                    format!("    {}", message),
+                    Style::default().fg(Color::Red),
+                )));
+            }
+        }
+    }
+
+    // Keep the last line reachable without scrolling past it
+    let max_scroll = (lines.len() as u16).saturating_sub(chunks[0].height);
+    *scroll = (*scroll).min(max_scroll);
+
+    f.render_widget(Paragraph::new(lines).scroll((*scroll, 0)), chunks[0]);
+
+    let mut footer = vec![
+        Span::styled("↑↓ PgUp PgDn", Style::default().fg(Color::Green)),
+        Span::styled(" Scroll   ", label),
+    ];
+    if failed > 0 {
+        footer.push(Span::styled("c", Style::default().fg(Color::Green)));
+        footer.push(Span::styled(" Copy failures   ", label));
+    }
+    footer.push(Span::styled("Esc", Style::default().fg(Color::Green)));
+    footer.push(Span::styled(" to close", label));
+    f.render_widget(
+        Paragraph::new(Line::from(footer)).alignment(Alignment::Center),
+        chunks[1],
+    );
+}
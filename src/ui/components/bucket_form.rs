@@ -0,0 +1,53 @@
+use crate::app::forms::BucketForm;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub fn draw_bucket_form(f: &mut Frame, form: &BucketForm, region: &str) {
+    let popup_area = centered_rect(50, 30, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Name: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("{}▏", form.name),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Region: ", Style::default().fg(Color::Gray)),
+            Span::styled(region, Style::default().fg(Color::Cyan)),
+        ]),
+        Line::from(""),
+    ];
+
+    if let Some(error) = &form.error {
+        lines.push(Line::from(Span::styled(
+            error.as_str(),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    lines.push(Line::from(vec![
+        Span::styled("Enter ", Style::default().fg(Color::Green)),
+        Span::styled("Create  ", Style::default().fg(Color::Gray)),
+        Span::styled("Esc ", Style::default().fg(Color::Green)),
+        Span::styled("Cancel", Style::default().fg(Color::Gray)),
+    ]));
+
+    let block = Block::default()
+        .title("Create Bucket")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    f.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
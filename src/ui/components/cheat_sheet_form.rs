@@ -0,0 +1,58 @@
+use crate::app::forms::CheatSheetForm;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn draw_cheat_sheet_form(f: &mut Frame, form: &CheatSheetForm) {
+    let popup_area = centered_rect(70, 30, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("File: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("{}▏", form.path),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(Span::styled(
+            "A .md file is written as Markdown, anything else as plain text",
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(""),
+    ];
+
+    if let Some(error) = &form.error {
+        lines.push(Line::from(Span::styled(
+            error.as_str(),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    lines.push(Line::from(vec![
+        Span::styled("Enter ", Style::default().fg(Color::Green)),
+        Span::styled("Export  ", Style::default().fg(Color::Gray)),
+        Span::styled("Esc ", Style::default().fg(Color::Green)),
+        Span::styled("Cancel", Style::default().fg(Color::Gray)),
+    ]));
+
+    let block = Block::default()
+        .title("Export Keybinding Cheat Sheet")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        popup_area,
+    );
+}
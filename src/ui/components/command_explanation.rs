@@ -0,0 +1,102 @@
+use crate::command::{Command, CommandExplanation};
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// What the highlighted palette command does, without running it
+pub fn draw_command_explanation(f: &mut Frame, command: &Command) {
+    let explanation = CommandExplanation::new(command);
+    let popup_area = centered_rect(60, 50, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let label = Style::default().fg(Color::Gray);
+    let value = Style::default().fg(Color::White);
+    let muted = Style::default().fg(Color::DarkGray);
+    let heading = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+
+    let mut lines = vec![Line::from(Span::styled(
+        explanation.description.as_str(),
+        value,
+    ))];
+    if let Some(details) = explanation.details {
+        lines.push(Line::from(Span::styled(details, label)));
+    }
+    lines.push(Line::from(""));
+
+    lines.push(Line::from(vec![
+        Span::styled("Category: ", label),
+        Span::styled(explanation.category, value),
+        Span::styled(format!("  ({})", explanation.id), muted),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("AWS API: ", label),
+        match &explanation.aws_action {
+            Some(action) => Span::styled(action.as_str(), value),
+            None => Span::styled("none, runs locally", muted),
+        },
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("Destructive: ", label),
+        if explanation.destructive {
+            Span::styled(
+                "yes",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            )
+        } else {
+            Span::styled("no", Style::default().fg(Color::Green))
+        },
+    ]));
+    lines.push(Line::from(""));
+
+    lines.push(Line::from(Span::styled("Requires", heading)));
+    if explanation.requirements.is_empty() {
+        lines.push(Line::from(Span::styled("  Available anywhere", muted)));
+    }
+    for requirement in &explanation.requirements {
+        lines.push(Line::from(Span::styled(
+            format!("  • {}", requirement),
+            value,
+        )));
+    }
+    lines.push(Line::from(""));
+
+    lines.push(Line::from(Span::styled("CLI equivalent", heading)));
+    lines.push(Line::from(match &explanation.cli {
+        Some(cli) => Span::styled(format!("  {}", cli), Style::default().fg(Color::Yellow)),
+        None => Span::styled("  No single CLI equivalent", muted),
+    }));
+    if !command.enabled {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Disabled in the current context",
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    let key = Style::default().fg(Color::Green);
+    let block = Block::default()
+        .title(explanation.title)
+        .title_bottom(Line::from(vec![
+            Span::styled(" Enter", key),
+            Span::styled(" Run  ", label),
+            Span::styled("Esc", key),
+            Span::styled(" Back ", label),
+        ]))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Magenta))
+        .style(Style::default().bg(Color::Black));
+
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        popup_area,
+    );
+}
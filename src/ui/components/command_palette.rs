@@ -1,13 +1,15 @@
 use crate::app::state::AppState;
 use crate::command::{Command, CommandCategory};
+use crate::ui::layout::centered_rect;
+use crate::utils::helpers::{pad_to_width, truncate_to_width};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
     Frame,
 };
-use std::collections::HashMap;
+use unicode_width::UnicodeWidthStr;
 
 /// Draw the command palette overlay
 pub fn draw_command_palette(f: &mut Frame, app_state: &AppState) {
@@ -93,8 +95,14 @@ fn draw_command_list(f: &mut Frame, area: Rect, app_state: &AppState) {
     }
 
     // Group commands by category
-    let grouped_commands = group_commands_by_category(commands);
-    let list_items = create_command_list_items(&grouped_commands, selected_index);
+    let palette = &app_state.command_palette;
+    let grouped_commands = group_commands_by_category(commands, |id| palette.is_pinned(id));
+    // Inside the borders
+    let row_width = area.width.saturating_sub(2) as usize;
+    let (list_items, selected_row) =
+        create_command_list_items(&grouped_commands, selected_index, row_width, |id| {
+            palette.is_pinned(id)
+        });
 
     let commands_block = Block::default()
         .borders(Borders::ALL)
@@ -104,52 +112,47 @@ fn draw_command_list(f: &mut Frame, area: Rect, app_state: &AppState) {
 
     let list = List::new(list_items).block(commands_block);
 
-    f.render_widget(list, area);
+    // Render statefully so the list scrolls to keep the selection visible at any terminal size
+    let mut list_state = ListState::default().with_selected(selected_row);
+    f.render_stateful_widget(list, area, &mut list_state);
 }
 
-/// Group commands by their category for organized display
-fn group_commands_by_category(commands: &[Command]) -> Vec<(CommandCategory, Vec<&Command>)> {
-    let mut category_map: HashMap<CommandCategory, Vec<&Command>> = HashMap::new();
+/// Group commands by their category for organized display, with pinned
+/// commands gathered under their own category. The palette already sorts
+/// commands in the configured category order, so groups keep the order in
+/// which they first appear and rows line up with the selection index.
+fn group_commands_by_category(
+    commands: &[Command],
+    is_pinned: impl Fn(&str) -> bool,
+) -> Vec<(CommandCategory, Vec<&Command>)> {
+    let mut grouped: Vec<(CommandCategory, Vec<&Command>)> = Vec::new();
 
-    // Group commands by category
     for command in commands {
-        category_map
-            .entry(command.category.clone())
-            .or_default()
-            .push(command);
+        let category = if is_pinned(&command.id) {
+            CommandCategory::Pinned
+        } else {
+            command.category.clone()
+        };
+        match grouped.iter_mut().find(|(c, _)| *c == category) {
+            Some((_, group)) => group.push(command),
+            None => grouped.push((category, vec![command])),
+        }
     }
 
-    // Convert to sorted vector with preferred category order
-    let mut grouped: Vec<(CommandCategory, Vec<&Command>)> = category_map.into_iter().collect();
-
-    // Sort categories by preference
-    grouped.sort_by(|a, b| {
-        let order_a = category_sort_order(&a.0);
-        let order_b = category_sort_order(&b.0);
-        order_a.cmp(&order_b)
-    });
-
     grouped
 }
 
-/// Define sort order for categories
-fn category_sort_order(category: &CommandCategory) -> u8 {
-    match category {
-        CommandCategory::Navigation => 0,
-        CommandCategory::Profile => 1,
-        CommandCategory::Region => 2,
-        CommandCategory::Service(_) => 3,
-        CommandCategory::General => 4,
-    }
-}
-
-/// Create list items from grouped commands
+/// Create list items from grouped commands, returning the row of the selected command.
+/// Names and descriptions are cut to `row_width` columns.
 fn create_command_list_items<'a>(
     grouped_commands: &'a [(CommandCategory, Vec<&'a Command>)],
     selected_index: usize,
-) -> Vec<ListItem<'a>> {
+    row_width: usize,
+    is_pinned: impl Fn(&str) -> bool,
+) -> (Vec<ListItem<'a>>, Option<usize>) {
     let mut items = Vec::new();
     let mut current_index = 0;
+    let mut selected_row = None;
 
     for (category, commands) in grouped_commands {
         // Add category header if there are multiple categories
@@ -164,6 +167,9 @@ fn create_command_list_items<'a>(
         // Add commands in this category
         for command in commands {
             let is_selected = current_index == selected_index;
+            if is_selected {
+                selected_row = Some(items.len());
+            }
 
             let style = if is_selected {
                 Style::default().fg(Color::Yellow).bg(Color::DarkGray)
@@ -184,15 +190,30 @@ fn create_command_list_items<'a>(
             };
 
             let enabled_indicator = if command.enabled { "" } else { " (disabled)" };
+            let pin_indicator = if is_pinned(&command.id) { " 📌" } else { "" };
+
+            let icon = format!("{} ", command.icon);
+            // The indicators stay visible in a column at the right edge; the
+            // name gives way to them
+            let name_width = row_width
+                .saturating_sub(icon.width())
+                .saturating_sub(enabled_indicator.width() + pin_indicator.width());
+            let name = pad_to_width(&command.name, name_width);
 
             let command_item = ListItem::new(vec![
                 Line::from(vec![
-                    Span::styled(format!("{} ", command.icon), icon_style),
-                    Span::styled(format!("{}{}", command.name, enabled_indicator), style),
+                    Span::styled(icon, icon_style),
+                    Span::styled(
+                        format!("{}{}{}", name, enabled_indicator, pin_indicator),
+                        style,
+                    ),
                 ]),
                 Line::from(vec![
                     Span::styled("  ", Style::default()), // Indent
-                    Span::styled(&command.description, desc_style),
+                    Span::styled(
+                        truncate_to_width(&command.description, row_width.saturating_sub(2)),
+                        desc_style,
+                    ),
                 ]),
             ]);
 
@@ -206,7 +227,7 @@ fn create_command_list_items<'a>(
         }
     }
 
-    items
+    (items, selected_row)
 }
 
 /// Draw help text and keyboard shortcuts
@@ -219,6 +240,12 @@ fn draw_command_help(f: &mut Frame, area: Rect, app_state: &AppState) {
             Span::styled("Navigate  ", Style::default().fg(Color::Gray)),
             Span::styled("Enter ", Style::default().fg(Color::Green)),
             Span::styled("Execute  ", Style::default().fg(Color::Gray)),
+            Span::styled("Ctrl+T ", Style::default().fg(Color::Green)),
+            Span::styled("Pin  ", Style::default().fg(Color::Gray)),
+            Span::styled("Ctrl+F ", Style::default().fg(Color::Green)),
+            Span::styled("Favorite  ", Style::default().fg(Color::Gray)),
+            Span::styled("Ctrl+E ", Style::default().fg(Color::Green)),
+            Span::styled("Explain  ", Style::default().fg(Color::Gray)),
             Span::styled("Esc ", Style::default().fg(Color::Green)),
             Span::styled("Cancel  ", Style::default().fg(Color::Gray)),
             Span::styled("Type ", Style::default().fg(Color::Green)),
@@ -243,24 +270,3 @@ fn draw_command_help(f: &mut Frame, area: Rect, app_state: &AppState) {
 
     f.render_widget(help_paragraph, area);
 }
-
-/// Create a centered rectangle for the command palette overlay
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
-}
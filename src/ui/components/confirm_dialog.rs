@@ -0,0 +1,104 @@
+use crate::app::state::{ConfirmAction, PendingConfirmation};
+use crate::aws::tagging::{TagChange, TagDiff};
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    layout::Alignment,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn draw_confirm_dialog(f: &mut Frame, confirmation: &PendingConfirmation) {
+    // Tag diffs and batches list one line per key or resource, so give them more room
+    let height = match confirmation.action {
+        ConfirmAction::ApplyTags { .. } | ConfirmAction::Batch { .. } => 60,
+        _ => 25,
+    };
+    let popup_area = centered_rect(50, height, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(confirmation.title.as_str())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().bg(Color::Black));
+
+    let mut content = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            confirmation.message.as_str(),
+            Style::default().fg(Color::White),
+        )),
+        Line::from(""),
+    ];
+    if let ConfirmAction::ApplyTags { diff, .. } = &confirmation.action {
+        content.extend(diff_lines(diff));
+        content.push(Line::from(""));
+    }
+    if let ConfirmAction::Batch { resources, .. } = &confirmation.action {
+        // The prompt comes first so a long list cannot push it out of view
+        content.push(Line::from(vec![
+            Span::styled(
+                format!("Type {} or yes to confirm: ", resources.len()),
+                Style::default().fg(Color::Gray),
+            ),
+            Span::styled(
+                format!("{}▏", confirmation.typed),
+                Style::default().fg(Color::Yellow),
+            ),
+        ]));
+        content.push(Line::from(vec![
+            Span::styled("Enter", Style::default().fg(Color::Green)),
+            Span::styled(" Confirm   ", Style::default().fg(Color::Gray)),
+            Span::styled("Esc", Style::default().fg(Color::Red)),
+            Span::styled(" Cancel", Style::default().fg(Color::Gray)),
+        ]));
+        content.push(Line::from(""));
+        content.extend(resources.iter().map(|resource| {
+            Line::from(vec![
+                Span::styled(resource.id.as_str(), Style::default().fg(Color::Red)),
+                Span::styled(
+                    format!("  {}", resource.state),
+                    Style::default().fg(Color::Gray),
+                ),
+            ])
+        }));
+    } else {
+        content.push(Line::from(vec![
+            Span::styled("y", Style::default().fg(Color::Green)),
+            Span::styled(" Confirm   ", Style::default().fg(Color::Gray)),
+            Span::styled("n", Style::default().fg(Color::Red)),
+            Span::styled(" Cancel", Style::default().fg(Color::Gray)),
+        ]));
+    }
+
+    let paragraph = Paragraph::new(content)
+        .block(block)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(paragraph, popup_area);
+}
+
+/// Additions in green, changes in yellow and removals in red
+fn diff_lines(diff: &TagDiff) -> Vec<Line<'_>> {
+    diff.changes
+        .iter()
+        .map(|change| match change {
+            TagChange::Added { key, value } => Line::from(Span::styled(
+                format!("+ {} = {}", key, value),
+                Style::default().fg(Color::Green),
+            )),
+            TagChange::Changed { key, old, new } => Line::from(Span::styled(
+                format!("~ {} = {} → {}", key, old, new),
+                Style::default().fg(Color::Yellow),
+            )),
+            TagChange::Removed { key, value } => Line::from(Span::styled(
+                format!("- {} = {}", key, value),
+                Style::default().fg(Color::Red),
+            )),
+        })
+        .collect()
+}
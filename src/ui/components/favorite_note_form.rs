@@ -0,0 +1,50 @@
+use crate::app::forms::FavoriteNoteForm;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn draw_favorite_note_form(f: &mut Frame, form: &FavoriteNoteForm) {
+    let popup_area = centered_rect(60, 25, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let lines = vec![
+        Line::from(vec![
+            Span::styled("Note: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("{}▏", form.note),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(Span::styled(
+            "Shown next to the favorite; leave empty to remove it",
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Enter ", Style::default().fg(Color::Green)),
+            Span::styled("Save  ", Style::default().fg(Color::Gray)),
+            Span::styled("Esc ", Style::default().fg(Color::Green)),
+            Span::styled("Cancel", Style::default().fg(Color::Gray)),
+        ]),
+    ];
+
+    let block = Block::default()
+        .title(format!("Favorite Note: {}", form.name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        popup_area,
+    );
+}
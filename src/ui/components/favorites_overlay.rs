@@ -0,0 +1,115 @@
+use crate::app::forms::FavoritesOverlay;
+use crate::ui::layout::centered_rect;
+use crate::ui::pages::dashboard::favorites::{FavoriteCommand, FavoriteResource};
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+pub fn draw_favorites_overlay(
+    f: &mut Frame,
+    overlay: &FavoritesOverlay,
+    resources: &[FavoriteResource],
+    commands: &[(FavoriteCommand, bool)],
+) {
+    let popup_area = centered_rect(60, 60, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let header = |title: &'static str| {
+        ListItem::new(Line::from(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )))
+    };
+    let empty = |text: &'static str| {
+        ListItem::new(Line::from(Span::styled(
+            text,
+            Style::default().fg(Color::DarkGray),
+        )))
+    };
+
+    // Section headers are not selectable, so track the row of the selection
+    let mut items = vec![header("Resources")];
+    let mut selected_row = None;
+    if resources.is_empty() {
+        items.push(empty("  No favorite resources"));
+    }
+    for (i, favorite) in resources.iter().enumerate() {
+        if i == overlay.selected {
+            selected_row = Some(items.len());
+        }
+        let mut spans = vec![
+            Span::raw(format!("  {} ", favorite.service_type.icon())),
+            Span::styled(favorite.name.as_str(), Style::default().fg(Color::White)),
+            Span::styled(
+                format!(
+                    "  {} · {}",
+                    favorite.service_type.display_name(),
+                    favorite.region
+                ),
+                Style::default().fg(Color::Gray),
+            ),
+        ];
+        if let Some(note) = &favorite.note {
+            spans.push(Span::styled(
+                format!("  {}", note),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::ITALIC),
+            ));
+        }
+        items.push(ListItem::new(Line::from(spans)));
+    }
+
+    items.push(header("Commands"));
+    if commands.is_empty() {
+        items.push(empty("  No favorite commands (Ctrl+F in the palette)"));
+    }
+    for (i, (command, runnable)) in commands.iter().enumerate() {
+        if resources.len() + i == overlay.selected {
+            selected_row = Some(items.len());
+        }
+        let line = if *runnable {
+            Line::from(Span::styled(
+                format!("  {}", command.name),
+                Style::default().fg(Color::White),
+            ))
+        } else {
+            Line::from(vec![
+                Span::styled(
+                    format!("  {}", command.name),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(
+                    " (not available here)",
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ])
+        };
+        items.push(ListItem::new(line));
+    }
+
+    let block = Block::default()
+        .title("Favorites")
+        .title_bottom(Line::from(vec![
+            Span::styled(" Enter", Style::default().fg(Color::Green)),
+            Span::styled(" to open or run, ", Style::default().fg(Color::Gray)),
+            Span::styled("Esc", Style::default().fg(Color::Green)),
+            Span::styled(" to close ", Style::default().fg(Color::Gray)),
+        ]))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().bg(Color::Black));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().bg(Color::DarkGray));
+
+    let mut list_state = ListState::default().with_selected(selected_row);
+    f.render_stateful_widget(list, popup_area, &mut list_state);
+}
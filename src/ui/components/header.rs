@@ -1,15 +1,29 @@
-use crate::app::state::AppState;
+use crate::app::state::{AppPage, AppState};
+use crate::ui::components::spinner::spinner_frame;
 use crate::ui::styles::get_default_block;
+use crate::utils::helpers::{format_duration, truncate_to_width};
 use ratatui::{
     layout::Rect,
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::Paragraph,
     Frame,
 };
+use unicode_width::UnicodeWidthStr;
 
 pub fn draw_header(f: &mut Frame, area: Rect, app_state: &AppState, page_title: &str) {
-    let header_text = vec![Line::from(vec![
+    let mut spans = vec![];
+    if app_state.demo_mode {
+        spans.push(Span::styled(
+            " DEMO MODE ",
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::raw("  "));
+    }
+    spans.extend([
         Span::styled(page_title, Style::default().fg(Color::Cyan)),
         Span::raw("    "),
         Span::styled("Profile: ", Style::default().fg(Color::Gray)),
@@ -23,9 +37,201 @@ pub fn draw_header(f: &mut Frame, area: Rect, app_state: &AppState, page_title:
             &app_state.current_region,
             Style::default().fg(Color::Yellow),
         ),
-    ])];
+    ]);
 
-    let header = Paragraph::new(header_text).block(get_default_block(""));
+    if app_state.aws_connecting() {
+        spans.push(Span::raw("    "));
+        spans.push(Span::styled(
+            "connecting…",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    if app_state.is_offline() {
+        spans.push(Span::raw("    "));
+        spans.push(Span::styled(
+            "● offline, retrying",
+            Style::default().fg(Color::Red),
+        ));
+    }
+
+    if let Some(freshness) = refresh_indicator(app_state) {
+        spans.push(Span::raw("    "));
+        spans.push(freshness);
+    }
+
+    let in_flight = app_state.operations.user_operations();
+    if let Some(oldest) = in_flight.first() {
+        let label = if in_flight.len() > 1 {
+            format!(
+                "{} {} (+{})",
+                spinner_frame(),
+                oldest.label,
+                in_flight.len() - 1
+            )
+        } else {
+            format!("{} {}", spinner_frame(), oldest.label)
+        };
+        spans.push(Span::raw("    "));
+        spans.push(Span::styled(label, Style::default().fg(Color::Magenta)));
+    }
+
+    let header_text = vec![Line::from(spans)];
+
+    let hints = key_hints_line(app_state, area.width.saturating_sub(4) as usize);
+    let trail = breadcrumbs_line(app_state, area.width.saturating_sub(4) as usize);
+    let header =
+        Paragraph::new(header_text).block(get_default_block("").title(trail).title_bottom(hints));
 
     f.render_widget(header, area);
 }
+
+/// Longest resource id shown in a breadcrumb before it is cut with an ellipsis
+const BREADCRUMB_ID_WIDTH: usize = 24;
+
+const BREADCRUMB_SEPARATOR: &str = " › ";
+
+/// The pages in `page_history` followed by the current one, e.g.
+/// "Dashboard › EC2 › i-12345". When the trail is wider than `max_width` the
+/// oldest pages are dropped behind a leading ellipsis.
+fn breadcrumbs_line(app_state: &AppState, max_width: usize) -> Line<'static> {
+    let mut pages: Vec<&AppPage> = app_state.page_history.iter().collect();
+    pages.push(&app_state.current_page);
+    // Revisiting a page without moving elsewhere adds nothing to the trail
+    pages.dedup();
+
+    let mut segments: Vec<String> = Vec::with_capacity(pages.len());
+    for (i, page) in pages.iter().enumerate() {
+        let segment = match page {
+            AppPage::Dashboard => "Dashboard".to_string(),
+            AppPage::Settings => "Settings".to_string(),
+            AppPage::ActivityLog => "Activity Log".to_string(),
+            AppPage::ResourceList(service_type) => service_type.display_name().to_string(),
+            AppPage::ResourceDetail(service_type, resource_id) => {
+                let id = truncate_to_width(resource_id, BREADCRUMB_ID_WIDTH);
+                // A detail page reached from its own list only needs the id
+                match i.checked_sub(1).map(|previous| pages[previous]) {
+                    Some(AppPage::ResourceList(listed)) if listed == service_type => {
+                        id.into_owned()
+                    }
+                    _ => format!("{} {}", service_type.display_name(), id),
+                }
+            }
+        };
+        segments.push(segment);
+    }
+
+    // Keep the newest segments that fit alongside the ellipsis and separators;
+    // the current page is always shown
+    let widths: Vec<usize> = segments
+        .iter()
+        .map(|segment| segment.width() + BREADCRUMB_SEPARATOR.width())
+        .collect();
+    let mut first = 0;
+    if widths.iter().sum::<usize>() > max_width {
+        let mut width = "…".width() + BREADCRUMB_SEPARATOR.width();
+        first = segments.len() - 1;
+        while first > 0 && width + widths[first] + widths[first - 1] <= max_width {
+            width += widths[first];
+            first -= 1;
+        }
+    }
+
+    let separator = Style::default().fg(Color::DarkGray);
+    let mut spans = vec![Span::raw(" ")];
+    if first > 0 {
+        spans.push(Span::styled("…", separator));
+        spans.push(Span::styled(BREADCRUMB_SEPARATOR, separator));
+    }
+    let last = segments.len() - 1;
+    for (i, segment) in segments.into_iter().enumerate().skip(first) {
+        let style = if i == last {
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+        spans.push(Span::styled(segment, style));
+        if i != last {
+            spans.push(Span::styled(BREADCRUMB_SEPARATOR, separator));
+        }
+    }
+    spans.push(Span::raw(" "));
+
+    Line::from(spans)
+}
+
+/// Active key bindings, right-aligned and cut with an ellipsis to fit `max_width`
+fn key_hints_line(app_state: &AppState, max_width: usize) -> Line<'static> {
+    let mut spans = vec![];
+    let mut width = 0;
+
+    for (key, description) in app_state.active_key_hints() {
+        let key_text = format!(" {} ", key);
+        let description_text = format!("{} ", description);
+        let entry_width = key_text.width() + description_text.width();
+
+        // Keep room for the ellipsis
+        if width + entry_width + 2 > max_width {
+            spans.push(Span::styled("… ", Style::default().fg(Color::DarkGray)));
+            break;
+        }
+
+        width += entry_width;
+        spans.push(Span::styled(key_text, Style::default().fg(Color::Green)));
+        spans.push(Span::styled(
+            description_text,
+            Style::default().fg(Color::Gray),
+        ));
+    }
+
+    Line::from(spans).right_aligned()
+}
+
+/// "Updated ... ago" for the service on screen, colored by how stale the data is
+/// relative to the page's auto-refresh interval
+fn refresh_indicator(app_state: &AppState) -> Option<Span<'static>> {
+    let service_type = match &app_state.current_page {
+        AppPage::ResourceList(service_type) | AppPage::ResourceDetail(service_type, _) => {
+            *service_type
+        }
+        _ => return None,
+    };
+
+    let key = (app_state.current_region.clone(), service_type);
+    let Some(last_refresh) = app_state.last_refresh.get(&key) else {
+        return Some(Span::styled(
+            "Not loaded",
+            Style::default().fg(Color::DarkGray),
+        ));
+    };
+
+    let age = last_refresh.elapsed().unwrap_or_default();
+    // Pages that do not refresh on their own are judged against the list interval
+    let interval = app_state
+        .user_config
+        .refresh_interval(&app_state.current_page)
+        .or_else(|| app_state.user_config.list_refresh_interval())
+        .map_or(
+            app_state.user_config.aws.auto_refresh_interval.max(1) as f64,
+            |interval| interval.as_secs_f64(),
+        );
+    let ratio = age.as_secs_f64() / interval;
+    let color = if ratio < 0.75 {
+        Color::Green
+    } else if ratio < 2.0 {
+        Color::Yellow
+    } else {
+        Color::Red
+    };
+
+    let text = if age.as_secs() < 60 {
+        "Updated just now".to_string()
+    } else {
+        let age = chrono::Duration::from_std(age).unwrap_or_default();
+        format!("Updated {} ago", format_duration(&age))
+    };
+
+    Some(Span::styled(text, Style::default().fg(color)))
+}
@@ -0,0 +1,106 @@
+use crate::app::forms::InstanceForm;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn draw_instance_form(f: &mut Frame, form: &InstanceForm, current_region: &str) {
+    let popup_area = centered_rect(70, 55, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let label = Style::default().fg(Color::Gray);
+    let mut region_line = vec![
+        Span::styled("Region: ", label),
+        Span::styled(form.region.as_str(), Style::default().fg(Color::Cyan)),
+    ];
+    if form.region != current_region {
+        region_line.push(Span::styled(
+            format!(" (the source's region, not {})", current_region),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+    let mut lines = vec![Line::from(region_line)];
+    if let Some(source) = &form.source {
+        lines.push(Line::from(vec![
+            Span::styled("Cloning: ", label),
+            Span::styled(
+                source.instance_id.as_str(),
+                Style::default().fg(Color::White),
+            ),
+        ]));
+    }
+    lines.push(Line::from(""));
+
+    for (index, (name, value)) in InstanceForm::LABELS.iter().zip(form.values()).enumerate() {
+        let mut line = field_line(name, value, form.focus == index);
+        if form.is_inherited(index) {
+            line.spans.push(Span::styled(
+                "  (inherited)",
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        lines.push(line);
+    }
+    lines.push(Line::from(Span::styled(
+        "  Lists are comma separated; tags as key=value",
+        Style::default().fg(Color::DarkGray),
+    )));
+    lines.push(Line::from(""));
+
+    if let Some(error) = &form.error {
+        lines.push(Line::from(Span::styled(
+            error.as_str(),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    lines.push(Line::from(vec![
+        Span::styled("Tab ", Style::default().fg(Color::Green)),
+        Span::styled("Next field  ", label),
+        Span::styled("Enter ", Style::default().fg(Color::Green)),
+        Span::styled("Launch  ", label),
+        Span::styled("Esc ", Style::default().fg(Color::Green)),
+        Span::styled("Cancel", label),
+    ]));
+
+    let title = if form.source.is_some() {
+        "Clone Instance"
+    } else {
+        "Create Instance"
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        popup_area,
+    );
+}
+
+fn field_line<'a>(label: &'a str, text: &'a str, focused: bool) -> Line<'a> {
+    let value = if focused {
+        Span::styled(
+            format!("{}▏", text),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else if text.is_empty() {
+        Span::styled("…", Style::default().fg(Color::DarkGray))
+    } else {
+        Span::styled(text.to_string(), Style::default().fg(Color::White))
+    };
+    Line::from(vec![
+        Span::styled(format!("{}: ", label), Style::default().fg(Color::Gray)),
+        value,
+    ])
+}
@@ -0,0 +1,102 @@
+use crate::app::log_tail::LogTail;
+use crate::ui::layout::centered_rect;
+use chrono::Local;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub fn draw_log_tail(f: &mut Frame, tail: &mut LogTail) {
+    let popup_area = centered_rect(90, 80, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(
+            "Logs: {} {} ({})",
+            tail.service_type.display_name(),
+            tail.resource_id,
+            tail.region
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let label = Style::default().fg(Color::Gray);
+    let mut lines = vec![];
+    if tail.events.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("Waiting for events in {}", tail.log_groups.join(", ")),
+            label,
+        )));
+    }
+    for event in &tail.events {
+        let timestamp = event.timestamp.with_timezone(&Local);
+        lines.push(Line::from(vec![
+            Span::styled(
+                timestamp.format("%H:%M:%S ").to_string(),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled(
+                format!("{} ", event.log_stream),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(event.message.as_str(), Style::default().fg(Color::White)),
+        ]));
+    }
+
+    let max_scroll = (lines.len() as u16).saturating_sub(chunks[0].height);
+    if tail.follow || tail.scroll >= max_scroll {
+        tail.follow = true;
+        tail.scroll = max_scroll;
+    }
+
+    f.render_widget(Paragraph::new(lines).scroll((tail.scroll, 0)), chunks[0]);
+
+    let status = match &tail.error {
+        Some(error) => Line::from(Span::styled(
+            format!("Last read failed: {}", error),
+            Style::default().fg(Color::Red),
+        )),
+        None if tail.follow => Line::from(Span::styled(
+            format!("Following, {} lines", tail.events.len()),
+            Style::default().fg(Color::Green),
+        )),
+        None => Line::from(Span::styled(
+            format!(
+                "Paused at line {} of {}",
+                tail.scroll + 1,
+                tail.events.len()
+            ),
+            Style::default().fg(Color::Yellow),
+        )),
+    };
+    f.render_widget(Paragraph::new(status), chunks[1]);
+
+    let footer = Line::from(vec![
+        Span::styled("↑↓ PgUp PgDn", Style::default().fg(Color::Green)),
+        Span::styled(" Scroll   ", label),
+        Span::styled("End", Style::default().fg(Color::Green)),
+        Span::styled(" Follow   ", label),
+        Span::styled("Esc", Style::default().fg(Color::Green)),
+        Span::styled(" Stop and close", label),
+    ]);
+    f.render_widget(
+        Paragraph::new(footer).alignment(Alignment::Center),
+        chunks[2],
+    );
+}
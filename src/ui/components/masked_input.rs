@@ -0,0 +1,39 @@
+use crate::app::forms::MaskedInput;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+/// A masked field's value, styled like the other form fields: bullets unless
+/// revealed, with a cursor when focused
+pub fn masked_input_span(input: &MaskedInput, focused: bool) -> Span<'static> {
+    if focused {
+        Span::styled(
+            format!("{}▏", input.display()),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else if input.is_empty() {
+        Span::styled("…", Style::default().fg(Color::DarkGray))
+    } else {
+        Span::styled(input.display(), Style::default().fg(Color::White))
+    }
+}
+
+/// A labelled masked field, for forms that lay out one field per line
+pub fn masked_line<'a>(label: &str, input: &MaskedInput, focused: bool) -> Line<'a> {
+    let mut spans = vec![
+        Span::styled(format!("{}: ", label), Style::default().fg(Color::Gray)),
+        masked_input_span(input, focused),
+    ];
+    spans.extend(reveal_marker(input));
+    Line::from(spans)
+}
+
+/// "(shown)" after a revealed field, so it is clear the text is on screen
+pub fn reveal_marker(input: &MaskedInput) -> Option<Span<'static>> {
+    input
+        .is_revealed()
+        .then(|| Span::styled(" (shown)", Style::default().fg(Color::DarkGray)))
+}
@@ -1,7 +1,39 @@
+pub mod action_menu;
+pub mod assume_role_form;
+pub mod batch_results;
+pub mod bucket_form;
+pub mod cheat_sheet_form;
+pub mod cluster_form;
+pub mod command_explanation;
 pub mod command_palette;
+pub mod confirm_dialog;
+pub mod favorite_note_form;
+pub mod favorites_overlay;
 pub mod header;
 pub mod help_panel;
+pub mod instance_form;
+pub mod log_tail;
+pub mod masked_input;
+pub mod node_group_size_form;
 pub mod notification;
+pub mod notification_overlay;
+pub mod permission_report;
+pub mod preset_form;
+pub mod profile_comparison;
 pub mod profile_selector;
+pub mod profile_validation;
+pub mod progress_bar;
 pub mod quick_nav;
+pub mod raw_inspector;
 pub mod region_selector;
+pub mod resource_changes;
+pub mod resource_export_form;
+pub mod secret_form;
+pub mod security_groups;
+pub mod setup_wizard;
+pub mod spinner;
+pub mod status_bar;
+pub mod tag_edit_form;
+pub mod view_state_form;
+pub mod which_key;
+pub mod zone_picker;
@@ -0,0 +1,61 @@
+use crate::app::forms::NodeGroupSizeForm;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub fn draw_node_group_size_form(f: &mut Frame, form: &NodeGroupSizeForm) {
+    let popup_area = centered_rect(50, 30, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Node group: ", Style::default().fg(Color::Gray)),
+            Span::styled(form.node_group.as_str(), Style::default().fg(Color::Cyan)),
+        ]),
+        Line::from(vec![
+            Span::styled("Current: ", Style::default().fg(Color::Gray)),
+            Span::styled(form.current.to_string(), Style::default().fg(Color::White)),
+            Span::styled(
+                format!("   (min {}, max {})", form.min_size, form.max_size),
+                Style::default().fg(Color::Gray),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Desired: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("{}▏", form.input),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(""),
+    ];
+
+    if let Some(error) = &form.error {
+        lines.push(Line::from(Span::styled(
+            error.as_str(),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    lines.push(Line::from(vec![
+        Span::styled("Enter ", Style::default().fg(Color::Green)),
+        Span::styled("Scale  ", Style::default().fg(Color::Gray)),
+        Span::styled("Esc ", Style::default().fg(Color::Green)),
+        Span::styled("Cancel", Style::default().fg(Color::Gray)),
+    ]));
+
+    let block = Block::default()
+        .title("Update Node Group Size")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    f.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
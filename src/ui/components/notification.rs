@@ -1,13 +1,16 @@
 use crate::app::state::{Notification, NotificationLevel};
+use crate::utils::helpers::truncate_to_width;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
+use unicode_width::UnicodeWidthStr;
 
-pub fn draw_notification(f: &mut Frame, area: Rect, notification: &Notification) {
+/// Draw one notification as a toast; `more` is how many others are still active
+pub fn draw_notification(f: &mut Frame, area: Rect, notification: &Notification, more: usize) {
     let popup_area = notification_rect(area);
 
     f.render_widget(Clear, popup_area);
@@ -24,10 +27,30 @@ pub fn draw_notification(f: &mut Frame, area: Rect, notification: &Notification)
         .border_style(Style::default().fg(border_color))
         .style(Style::default().bg(get_notification_bg(&notification.level)));
 
-    let content = vec![Line::from(vec![Span::styled(
-        &notification.message,
+    let action = notification
+        .action
+        .as_ref()
+        .map(|action| format!("  [A] {}", action.label()));
+    let hint = (more > 0).then(|| format!("  (+{} more, N to list)", more));
+    // The full message is in the Notifications overlay; the hint stays visible
+    let message_width = (popup_area.width.saturating_sub(2) as usize)
+        .saturating_sub(hint.as_deref().map_or(0, UnicodeWidthStr::width))
+        .saturating_sub(action.as_deref().map_or(0, UnicodeWidthStr::width));
+
+    let mut spans = vec![Span::styled(
+        truncate_to_width(&notification.message, message_width),
         Style::default().fg(text_color),
-    )])];
+    )];
+    if let Some(action) = action {
+        spans.push(Span::styled(
+            action,
+            Style::default().fg(text_color).add_modifier(Modifier::BOLD),
+        ));
+    }
+    if let Some(hint) = hint {
+        spans.push(Span::styled(hint, Style::default().fg(text_color)));
+    }
+    let content = vec![Line::from(spans)];
 
     let paragraph = Paragraph::new(content)
         .block(block)
@@ -43,7 +66,7 @@ pub fn draw_notifications(f: &mut Frame, area: Rect, notifications: &[Notificati
 
     // Show the most recent notification
     if let Some(notification) = notifications.last() {
-        draw_notification(f, area, notification);
+        draw_notification(f, area, notification, notifications.len() - 1);
     }
 }
 
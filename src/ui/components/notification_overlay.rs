@@ -0,0 +1,95 @@
+use crate::app::forms::NotificationOverlay;
+use crate::app::state::{Notification, NotificationLevel};
+use crate::ui::layout::centered_rect;
+use crate::utils::helpers::truncate_to_width;
+use chrono::{DateTime, Local};
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use unicode_width::UnicodeWidthStr;
+
+pub fn draw_notification_overlay(
+    f: &mut Frame,
+    overlay: &NotificationOverlay,
+    notifications: &[Notification],
+) {
+    let popup_area = centered_rect(70, 50, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let key = Style::default().fg(Color::Green);
+    let label = Style::default().fg(Color::Gray);
+    let block = Block::default()
+        .title(format!("Notifications ({})", notifications.len()))
+        .title_bottom(Line::from(vec![
+            Span::styled(" ↑↓", key),
+            Span::styled(" Move  ", label),
+            Span::styled("Enter", key),
+            Span::styled(" Run action  ", label),
+            Span::styled("d", key),
+            Span::styled(" Dismiss  ", label),
+            Span::styled("D", key),
+            Span::styled(" Dismiss all  ", label),
+            Span::styled("Esc", key),
+            Span::styled(" Close ", label),
+        ]))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().bg(Color::Black));
+
+    if notifications.is_empty() {
+        f.render_widget(
+            Paragraph::new(Span::styled(
+                "No notifications",
+                Style::default().fg(Color::DarkGray),
+            ))
+            .block(block),
+            popup_area,
+        );
+        return;
+    }
+
+    // Inside the borders, after the marker and the timestamp
+    let message_width = (popup_area.width.saturating_sub(2) as usize).saturating_sub(11);
+    let items: Vec<ListItem> = notifications
+        .iter()
+        .rev()
+        .map(|notification| {
+            let (marker, color) = match notification.level {
+                NotificationLevel::Info => ("ℹ", Color::Blue),
+                NotificationLevel::Warning => ("⚠", Color::Yellow),
+                NotificationLevel::Error => ("✗", Color::Red),
+                NotificationLevel::Success => ("✓", Color::Green),
+            };
+            let time: DateTime<Local> = notification.timestamp.into();
+            let action = notification
+                .action
+                .as_ref()
+                .map(|action| format!(" [{}]", action.label()));
+            let width =
+                message_width.saturating_sub(action.as_deref().map_or(0, UnicodeWidthStr::width));
+            let mut spans = vec![
+                Span::styled(format!("{} ", marker), Style::default().fg(color)),
+                Span::styled(format!("{} ", time.format("%H:%M:%S")), label),
+                Span::styled(
+                    truncate_to_width(&notification.message, width),
+                    Style::default().fg(Color::White),
+                ),
+            ];
+            if let Some(action) = action {
+                spans.push(Span::styled(action, Style::default().fg(Color::Cyan)));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let selected = overlay.selected.min(items.len() - 1);
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().bg(Color::DarkGray));
+    let mut list_state = ListState::default().with_selected(Some(selected));
+    f.render_stateful_widget(list, popup_area, &mut list_state);
+}
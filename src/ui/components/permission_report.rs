@@ -0,0 +1,105 @@
+use crate::aws::permissions::{PermissionReport, PermissionVerdict};
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn draw_permission_report(f: &mut Frame, report: &PermissionReport) {
+    let popup_area = centered_rect(70, 60, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title("What Can I Do?")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(2)])
+        .split(inner);
+
+    let label = Style::default().fg(Color::Gray);
+    let value = Style::default().fg(Color::White);
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Profile: ", label),
+            Span::styled(report.profile.as_str(), value),
+        ]),
+        Line::from(vec![
+            Span::styled("Identity: ", label),
+            Span::styled(
+                report.principal_arn.as_deref().unwrap_or("(unknown)"),
+                value,
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Checked: ", label),
+            Span::styled(
+                report
+                    .checked_at
+                    .format("%Y-%m-%d %H:%M:%S UTC")
+                    .to_string(),
+                value,
+            ),
+        ]),
+        Line::from(""),
+    ];
+
+    for check in &report.checks {
+        let (marker, style) = match check.verdict {
+            PermissionVerdict::Allowed => ("✓", Style::default().fg(Color::Green)),
+            PermissionVerdict::Denied => ("✗", Style::default().fg(Color::Red)),
+            PermissionVerdict::Unknown => ("?", Style::default().fg(Color::Yellow)),
+        };
+        let mut spans = vec![
+            Span::styled(format!("{} ", marker), style.add_modifier(Modifier::BOLD)),
+            Span::styled(
+                format!(
+                    "{} {:<16}",
+                    check.service_type.icon(),
+                    check.service_type.display_name()
+                ),
+                value,
+            ),
+            Span::styled(check.action, label),
+        ];
+        if let Some(note) = &check.note {
+            spans.push(Span::styled(format!("  {}", note), label));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    if !report.simulated {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Policy simulation was not possible; only services that already returned AccessDenied are marked",
+            Style::default().fg(Color::Yellow),
+        )));
+    }
+
+    f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), chunks[0]);
+
+    let footer = Paragraph::new(vec![
+        Line::from(Span::styled(
+            "Commands for denied services are hidden from the palette",
+            label,
+        )),
+        Line::from(vec![
+            Span::styled("r", Style::default().fg(Color::Green)),
+            Span::styled(" Re-check   ", label),
+            Span::styled("Esc", Style::default().fg(Color::Green)),
+            Span::styled(" to close", label),
+        ]),
+    ])
+    .alignment(Alignment::Center);
+    f.render_widget(footer, chunks[1]);
+}
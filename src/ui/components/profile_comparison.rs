@@ -0,0 +1,114 @@
+use crate::aws::credentials::ProfileCheck;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn draw_profile_comparison(f: &mut Frame, left: &ProfileCheck, right: &ProfileCheck) {
+    let popup_area = centered_rect(70, 50, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title("Compare Profiles")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(2)])
+        .split(inner);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[0]);
+
+    f.render_widget(profile_column(left), columns[0]);
+    f.render_widget(profile_column(right), columns[1]);
+
+    // The point of the comparison is spotting two profiles that hit the same account
+    let verdict = match (&left.outcome, &right.outcome) {
+        (Ok(l), Ok(r)) if l.account == r.account => Span::styled(
+            format!("⚠ Both profiles use account {}", l.account),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        (Ok(_), Ok(_)) => Span::styled(
+            "✓ The profiles use different accounts",
+            Style::default().fg(Color::Green),
+        ),
+        _ => Span::styled(
+            "Accounts could not be compared",
+            Style::default().fg(Color::Gray),
+        ),
+    };
+    let footer = Paragraph::new(vec![
+        Line::from(verdict),
+        Line::from(vec![
+            Span::styled("Esc", Style::default().fg(Color::Green)),
+            Span::styled(" to close", Style::default().fg(Color::Gray)),
+        ]),
+    ])
+    .alignment(Alignment::Center);
+    f.render_widget(footer, chunks[1]);
+}
+
+fn profile_column(check: &ProfileCheck) -> Paragraph<'_> {
+    let label = Style::default().fg(Color::Gray);
+    let value = Style::default().fg(Color::White);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            check.profile.as_str(),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Region: ", label),
+            Span::styled(check.region.as_deref().unwrap_or("(not set)"), value),
+        ]),
+    ];
+
+    match &check.outcome {
+        Ok(info) => {
+            lines.push(Line::from(vec![
+                Span::styled("Account: ", label),
+                Span::styled(info.account.as_str(), value),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled("Identity: ", label),
+                Span::styled(info.arn.as_str(), value),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled("Status: ", label),
+                if info.valid {
+                    Span::styled("Valid", Style::default().fg(Color::Green))
+                } else {
+                    Span::styled("Invalid", Style::default().fg(Color::Red))
+                },
+            ]));
+        }
+        Err(message) => {
+            lines.push(Line::from(vec![
+                Span::styled("Status: ", label),
+                Span::styled("Failed", Style::default().fg(Color::Red)),
+            ]));
+            lines.push(Line::from(Span::styled(
+                message.as_str(),
+                Style::default().fg(Color::Red),
+            )));
+        }
+    }
+
+    Paragraph::new(lines).wrap(Wrap { trim: true })
+}
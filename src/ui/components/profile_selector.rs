@@ -1,4 +1,5 @@
 use crate::app::state::AppState;
+use crate::ui::layout::centered_rect;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Style},
@@ -48,14 +49,21 @@ pub fn draw_profile_selector(f: &mut Frame, area: Rect, app_state: &AppState) {
                 Style::default().fg(Color::White)
             };
 
-            ListItem::new(Line::from(vec![
-                Span::styled(&profile.name, style),
-                if profile.name == app_state.current_profile {
-                    Span::styled(" (current)", Style::default().fg(Color::Green))
-                } else {
-                    Span::raw("")
-                },
-            ]))
+            let mut spans = vec![Span::styled(&profile.name, style)];
+            if let Some(expiry) = profile.ephemeral_expiry() {
+                let minutes = (expiry - chrono::Utc::now()).num_minutes().max(0);
+                spans.push(Span::styled(
+                    format!(" (temporary, {}m left)", minutes),
+                    Style::default().fg(Color::Yellow),
+                ));
+            }
+            if profile.name == app_state.current_profile {
+                spans.push(Span::styled(
+                    " (current)",
+                    Style::default().fg(Color::Green),
+                ));
+            }
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
@@ -77,23 +85,3 @@ pub fn draw_profile_selector(f: &mut Frame, area: Rect, app_state: &AppState) {
 
     f.render_widget(footer, inner_area[2]);
 }
-
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
-}
@@ -0,0 +1,103 @@
+use crate::aws::credentials::{ProfileStatus, ProfileValidation};
+use crate::ui::layout::centered_rect;
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub fn draw_profile_validation(f: &mut Frame, validation: &ProfileValidation, scroll: &mut u16) {
+    let popup_area = centered_rect(80, 60, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let failed = validation.checks.len() - validation.count(ProfileStatus::Valid);
+    let border_color = if failed > 0 { Color::Red } else { Color::Green };
+    let checked_at: DateTime<Local> = validation.checked_at.into();
+    let block = Block::default()
+        .title(format!(
+            "Profile Validation: {} valid, {} invalid, {} expired (at {})",
+            validation.count(ProfileStatus::Valid),
+            validation.count(ProfileStatus::Invalid),
+            validation.count(ProfileStatus::Expired),
+            checked_at.format("%H:%M:%S")
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border_color))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    let label = Style::default().fg(Color::Gray);
+    let value = Style::default().fg(Color::White);
+    let mut lines = vec![];
+    if validation.checks.is_empty() {
+        lines.push(Line::from(Span::styled("No profiles configured", label)));
+    }
+    for check in &validation.checks {
+        let (marker, color) = match check.status() {
+            ProfileStatus::Valid => ("✓", Color::Green),
+            ProfileStatus::Invalid => ("✗", Color::Red),
+            ProfileStatus::Expired => ("⌛", Color::Yellow),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{} ", marker),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                check.profile.as_str(),
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("  {}", check.status().label()),
+                Style::default().fg(color),
+            ),
+            Span::styled("  Region: ", label),
+            Span::styled(check.region.as_deref().unwrap_or("(not set)"), value),
+        ]));
+        match &check.outcome {
+            Ok(info) => lines.push(Line::from(vec![
+                Span::styled("    Account: ", label),
+                Span::styled(info.account.as_str(), value),
+                Span::styled("  Identity: ", label),
+                Span::styled(info.arn.as_str(), value),
+            ])),
+            Err(message) => lines.push(Line::from(Span::styled(
+                format!("    {}", message),
+                Style::default().fg(Color::Red),
+            ))),
+        }
+    }
+
+    // Keep the last line reachable without scrolling past it
+    let max_scroll = (lines.len() as u16).saturating_sub(chunks[0].height);
+    *scroll = (*scroll).min(max_scroll);
+
+    f.render_widget(Paragraph::new(lines).scroll((*scroll, 0)), chunks[0]);
+
+    let footer = Line::from(vec![
+        Span::styled("↑↓", Style::default().fg(Color::Green)),
+        Span::styled(" Scroll   ", label),
+        Span::styled("r", Style::default().fg(Color::Green)),
+        Span::styled(" Refresh   ", label),
+        Span::styled("c", Style::default().fg(Color::Green)),
+        Span::styled(" Copy report   ", label),
+        Span::styled("Esc", Style::default().fg(Color::Green)),
+        Span::styled(" to close", label),
+    ]);
+    f.render_widget(
+        Paragraph::new(footer).alignment(Alignment::Center),
+        chunks[1],
+    );
+}
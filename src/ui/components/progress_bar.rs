@@ -0,0 +1,49 @@
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    widgets::{Block, Borders, Clear, Gauge},
+    Frame,
+};
+
+/// A one-line gauge for an operation `fraction` (`0.0..=1.0`) of the way done
+pub fn draw_progress_bar(f: &mut Frame, area: Rect, label: &str, fraction: f64) {
+    let fraction = fraction.clamp(0.0, 1.0);
+    let percent = format!("{:.0}%", fraction * 100.0);
+    let text = if label.is_empty() {
+        percent
+    } else {
+        format!("{} {}", label, percent)
+    };
+    let gauge = Gauge::default()
+        .gauge_style(Style::default().fg(Color::Magenta).bg(Color::DarkGray))
+        .ratio(fraction)
+        .label(text);
+    f.render_widget(gauge, area);
+}
+
+/// A small centered box holding the progress bar of an operation the user
+/// is waiting on
+pub fn draw_progress_overlay(f: &mut Frame, label: &str, fraction: f64) {
+    let area = centered_rect(50, 20, f.area());
+    let popup_area = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(3),
+            Constraint::Min(0),
+        ])
+        .split(area)[1];
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(label.to_string())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Magenta))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    draw_progress_bar(f, inner, "", fraction);
+}
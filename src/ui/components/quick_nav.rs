@@ -1,9 +1,11 @@
-use crate::app::state::AppState;
+use crate::app::state::{AppState, NavigationAction};
+use crate::ui::components::spinner::count_badge;
+use crate::ui::layout::centered_rect;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
     Frame,
 };
 
@@ -31,7 +33,7 @@ pub fn draw_quick_nav(f: &mut Frame, app_state: &AppState) {
 
 fn draw_content_search_input(f: &mut Frame, area: Rect, app_state: &AppState) {
     let input_text = if app_state.quick_nav_input.is_empty() {
-        "Type to search services or resource..."
+        "Type to search services or resources..."
     } else {
         &app_state.quick_nav_input
     };
@@ -67,7 +69,7 @@ fn draw_content_suggestions(f: &mut Frame, area: Rect, app_state: &AppState) {
     if suggestions.is_empty() {
         // Show "No results" message
         let no_results_text = vec![Line::from(vec![Span::styled(
-            "No matching services found",
+            "No matching services or resources found",
             Style::default().fg(Color::Gray),
         )])];
 
@@ -107,10 +109,27 @@ fn draw_content_suggestions(f: &mut Frame, area: Rect, app_state: &AppState) {
                 Style::default().fg(Color::Gray)
             };
 
+            let name = match item.action {
+                NavigationAction::NavigateToService(service_type) => {
+                    let count = app_state.resource_count(service_type);
+                    match app_state.service_cooldown_left(service_type) {
+                        Some(left) => format!(
+                            "{} ({}) ⚠ paused {}s",
+                            item.name,
+                            count_badge(&count),
+                            left.as_secs()
+                        ),
+                        None => format!("{} ({})", item.name, count_badge(&count)),
+                    }
+                }
+                NavigationAction::NavigateToResource(..) => item.name.clone(),
+            };
+
             ListItem::new(vec![
                 Line::from(vec![
                     Span::styled(format!("{} ", item.icon), icon_style),
-                    Span::styled(&item.name, style),
+                    Span::styled(name, style),
+                    Span::styled(format!("  [{}]", item.kind_label()), desc_style),
                 ]),
                 Line::from(vec![
                     Span::styled("  ", Style::default()), // Indent
@@ -134,14 +153,16 @@ fn draw_content_suggestions(f: &mut Frame, area: Rect, app_state: &AppState) {
         .block(suggestions_block)
         .highlight_style(Style::default().bg(Color::DarkGray));
 
-    f.render_widget(list, area);
+    // Render statefully so the list scrolls to keep the selection visible after a resize
+    let mut list_state = ListState::default().with_selected(Some(selected_index));
+    f.render_stateful_widget(list, area, &mut list_state);
 
     // Draw usage hints at the bottom
     if area.height > 5 {
         let hints_area = Rect {
             x: area.x + 1,
             y: area.y + area.height - 2,
-            width: area.width - 2,
+            width: area.width.saturating_sub(2),
             height: 1,
         };
 
@@ -159,23 +180,3 @@ fn draw_content_suggestions(f: &mut Frame, area: Rect, app_state: &AppState) {
         f.render_widget(hints_paragraph, hints_area);
     }
 }
-
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
-}
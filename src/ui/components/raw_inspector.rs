@@ -0,0 +1,52 @@
+use crate::app::forms::RawInspector;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub fn draw_raw_inspector(f: &mut Frame, inspector: &mut RawInspector) {
+    let popup_area = centered_rect(80, 80, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!("Raw JSON: {}", inspector.title))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    let line_count = u16::try_from(inspector.json.lines().count()).unwrap_or(u16::MAX);
+    inspector.scroll = inspector
+        .scroll
+        .min(line_count.saturating_sub(chunks[0].height));
+
+    let json = Paragraph::new(inspector.json.as_str())
+        .style(Style::default().fg(Color::White))
+        .scroll((inspector.scroll, 0));
+    f.render_widget(json, chunks[0]);
+
+    let label = Style::default().fg(Color::Gray);
+    let footer = Line::from(vec![
+        Span::styled("↑↓ PgUp PgDn", Style::default().fg(Color::Green)),
+        Span::styled(" Scroll   ", label),
+        Span::styled("c", Style::default().fg(Color::Green)),
+        Span::styled(" Copy JSON   ", label),
+        Span::styled("Esc", Style::default().fg(Color::Green)),
+        Span::styled(" to close", label),
+    ]);
+    f.render_widget(
+        Paragraph::new(footer).alignment(Alignment::Center),
+        chunks[1],
+    );
+}
@@ -1,4 +1,5 @@
 use crate::app::state::AppState;
+use crate::ui::layout::centered_rect;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Style},
@@ -52,6 +53,11 @@ pub fn draw_region_selector(f: &mut Frame, area: Rect, app_state: &AppState) {
                 Span::styled(&region.name, style),
                 Span::raw(" - "),
                 Span::styled(&region.display_name, Style::default().fg(Color::Gray)),
+                if region.opt_in {
+                    Span::styled(" (opt-in)", Style::default().fg(Color::DarkGray))
+                } else {
+                    Span::raw("")
+                },
                 if region.name == app_state.current_region {
                     Span::styled(" (current)", Style::default().fg(Color::Green))
                 } else {
@@ -79,23 +85,3 @@ pub fn draw_region_selector(f: &mut Frame, area: Rect, app_state: &AppState) {
 
     f.render_widget(footer, inner_area[2]);
 }
-
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
-}
@@ -1,4 +1,5 @@
 use crate::aws::types::Resource;
+use crate::ui::styles::state_color;
 use ratatui::{
     layout::Rect,
     style::{Color, Style},
@@ -29,7 +30,10 @@ pub fn draw_resource_card(f: &mut Frame, area: Rect, resource: &Resource, select
         ]),
         Line::from(vec![
             Span::styled("State: ", Style::default().fg(Color::Gray)),
-            Span::styled(resource.state.as_str(), get_state_color(&resource.state)),
+            Span::styled(
+                resource.state.as_str(),
+                Style::default().fg(state_color(&resource.state)),
+            ),
         ]),
         Line::from(vec![
             Span::styled("Region: ", Style::default().fg(Color::Gray)),
@@ -46,12 +50,3 @@ pub fn draw_resource_card(f: &mut Frame, area: Rect, resource: &Resource, select
 
     f.render_widget(paragraph, area);
 }
-
-fn get_state_color(state: &str) -> Style {
-    match state.to_lowercase().as_str() {
-        "running" | "active" | "available" => Style::default().fg(Color::Green),
-        "stopped" | "inactive" | "unavailable" => Style::default().fg(Color::Red),
-        "starting" | "stopping" | "pending" => Style::default().fg(Color::Yellow),
-        _ => Style::default().fg(Color::Gray),
-    }
-}
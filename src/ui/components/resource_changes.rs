@@ -0,0 +1,90 @@
+use crate::app::resource_diff::{FieldChange, ResourceChanges};
+use crate::ui::layout::centered_rect;
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub fn draw_resource_changes(f: &mut Frame, changes: &mut ResourceChanges) {
+    let popup_area = centered_rect(70, 60, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let since: DateTime<Local> = changes.since.into();
+    let border_color = if changes.changes.is_empty() {
+        Color::Green
+    } else {
+        Color::Yellow
+    };
+    let block = Block::default()
+        .title(format!(
+            "Changes: {} (since {})",
+            changes.title,
+            since.format("%H:%M:%S")
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border_color))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    let label = Style::default().fg(Color::Gray);
+    let path_style = Style::default()
+        .fg(Color::White)
+        .add_modifier(Modifier::BOLD);
+    let mut lines = vec![];
+    if changes.changes.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No changes since the baseline",
+            label,
+        )));
+    }
+    for change in &changes.changes {
+        let line = match change {
+            FieldChange::Added { path, value } => Line::from(vec![
+                Span::styled("+ ", Style::default().fg(Color::Green)),
+                Span::styled(format!("{}: ", path), path_style),
+                Span::styled(value.as_str(), Style::default().fg(Color::Green)),
+            ]),
+            FieldChange::Changed { path, old, new } => Line::from(vec![
+                Span::styled("~ ", Style::default().fg(Color::Yellow)),
+                Span::styled(format!("{}: ", path), path_style),
+                Span::styled(old.as_str(), Style::default().fg(Color::Red)),
+                Span::styled(" → ", label),
+                Span::styled(new.as_str(), Style::default().fg(Color::Green)),
+            ]),
+            FieldChange::Removed { path, value } => Line::from(vec![
+                Span::styled("- ", Style::default().fg(Color::Red)),
+                Span::styled(format!("{}: ", path), path_style),
+                Span::styled(value.as_str(), Style::default().fg(Color::Red)),
+            ]),
+        };
+        lines.push(line);
+    }
+
+    // Keep the last line reachable without scrolling past it
+    let max_scroll = (lines.len() as u16).saturating_sub(chunks[0].height);
+    changes.scroll = changes.scroll.min(max_scroll);
+
+    f.render_widget(Paragraph::new(lines).scroll((changes.scroll, 0)), chunks[0]);
+
+    let footer = Line::from(vec![
+        Span::styled("↑↓ PgUp PgDn", Style::default().fg(Color::Green)),
+        Span::styled(" Scroll   ", label),
+        Span::styled("Esc", Style::default().fg(Color::Green)),
+        Span::styled(" to close", label),
+    ]);
+    f.render_widget(
+        Paragraph::new(footer).alignment(Alignment::Center),
+        chunks[1],
+    );
+}
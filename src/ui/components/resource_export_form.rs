@@ -0,0 +1,88 @@
+use crate::app::forms::ResourceExportForm;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// `filters` are the list's active filters; `shown` and `total` count the
+/// filtered rows and every cached resource
+pub fn draw_resource_export_form(
+    f: &mut Frame,
+    form: &ResourceExportForm,
+    filters: &[String],
+    shown: usize,
+    total: usize,
+) {
+    let popup_area = centered_rect(70, 35, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let scope = if form.include_all {
+        format!("all {} resources, ignoring filters", total)
+    } else if filters.is_empty() {
+        format!("{} resources (no filters active)", shown)
+    } else {
+        format!("{} of {} resources ({})", shown, total, filters.join(", "))
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("File: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("{}▏", form.path),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(Span::styled(
+            "A .json file is written as JSON, anything else as CSV",
+            Style::default().fg(Color::DarkGray),
+        )),
+        Line::from(vec![
+            Span::styled("Scope: ", Style::default().fg(Color::Gray)),
+            Span::styled(scope, Style::default().fg(Color::Cyan)),
+        ]),
+        Line::from(""),
+    ];
+
+    if let Some(error) = &form.error {
+        lines.push(Line::from(Span::styled(
+            error.as_str(),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    let toggle = if form.include_all {
+        "Filtered only  "
+    } else {
+        "Include all  "
+    };
+    lines.push(Line::from(vec![
+        Span::styled("Enter ", Style::default().fg(Color::Green)),
+        Span::styled("Export  ", Style::default().fg(Color::Gray)),
+        Span::styled("Ctrl+A ", Style::default().fg(Color::Green)),
+        Span::styled(toggle, Style::default().fg(Color::Gray)),
+        Span::styled("Esc ", Style::default().fg(Color::Green)),
+        Span::styled("Cancel", Style::default().fg(Color::Gray)),
+    ]));
+
+    let block = Block::default()
+        .title(format!(
+            "Export {} Resources",
+            form.service_type.display_name()
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        popup_area,
+    );
+}
@@ -0,0 +1,102 @@
+use crate::app::forms::{SecretForm, SecretValueMode};
+use crate::ui::components::masked_input::{masked_input_span, masked_line, reveal_marker};
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub fn draw_secret_form(f: &mut Frame, form: &SecretForm) {
+    let popup_area = centered_rect(60, 60, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let mode = match form.mode {
+        SecretValueMode::Plain => "Plain text",
+        SecretValueMode::KeyValue => "Key/value (JSON)",
+    };
+
+    let mut lines = vec![
+        field_line("Name", &form.name, form.focus == 0),
+        Line::from(vec![
+            Span::styled("Mode: ", Style::default().fg(Color::Gray)),
+            Span::styled(mode, Style::default().fg(Color::Cyan)),
+        ]),
+        Line::from(""),
+    ];
+
+    match form.mode {
+        SecretValueMode::Plain => {
+            lines.push(masked_line("Value", &form.value, form.focus == 1));
+        }
+        SecretValueMode::KeyValue => {
+            for (i, (key, value)) in form.pairs.iter().enumerate() {
+                let mut spans = vec![
+                    field_span(key, form.focus == 1 + i * 2),
+                    Span::styled(" = ", Style::default().fg(Color::Gray)),
+                    masked_input_span(value, form.focus == 2 + i * 2),
+                ];
+                spans.extend(reveal_marker(value));
+                lines.push(Line::from(spans));
+            }
+        }
+    }
+
+    lines.push(Line::from(""));
+    if let Some(error) = &form.error {
+        lines.push(Line::from(Span::styled(
+            error.as_str(),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    let mut help = vec![
+        Span::styled("Tab ", Style::default().fg(Color::Green)),
+        Span::styled("Next field  ", Style::default().fg(Color::Gray)),
+        Span::styled("Ctrl+T ", Style::default().fg(Color::Green)),
+        Span::styled("Toggle mode  ", Style::default().fg(Color::Gray)),
+        Span::styled("Ctrl+R ", Style::default().fg(Color::Green)),
+        Span::styled("Reveal  ", Style::default().fg(Color::Gray)),
+    ];
+    if form.mode == SecretValueMode::KeyValue {
+        help.push(Span::styled("Ctrl+N ", Style::default().fg(Color::Green)));
+        help.push(Span::styled("Add pair  ", Style::default().fg(Color::Gray)));
+    }
+    help.push(Span::styled("Enter ", Style::default().fg(Color::Green)));
+    help.push(Span::styled("Create  ", Style::default().fg(Color::Gray)));
+    help.push(Span::styled("Esc ", Style::default().fg(Color::Green)));
+    help.push(Span::styled("Cancel", Style::default().fg(Color::Gray)));
+    lines.push(Line::from(help));
+
+    let block = Block::default()
+        .title("Create Secret")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    f.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
+
+fn field_line<'a>(label: &'a str, text: &'a str, focused: bool) -> Line<'a> {
+    Line::from(vec![
+        Span::styled(format!("{}: ", label), Style::default().fg(Color::Gray)),
+        field_span(text, focused),
+    ])
+}
+
+fn field_span(text: &str, focused: bool) -> Span<'_> {
+    if focused {
+        Span::styled(
+            format!("{}▏", text),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else if text.is_empty() {
+        Span::styled("…", Style::default().fg(Color::DarkGray))
+    } else {
+        Span::styled(text.to_string(), Style::default().fg(Color::White))
+    }
+}
@@ -0,0 +1,109 @@
+use crate::app::forms::SecurityGroupInspector;
+use crate::aws::types::SecurityGroupRule;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub fn draw_security_groups(f: &mut Frame, inspector: &mut SecurityGroupInspector) {
+    let popup_area = centered_rect(80, 70, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!("Security Groups: {}", inspector.instance_id))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    let label = Style::default().fg(Color::Gray);
+    let mut lines = vec![];
+    if inspector.groups.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "The instance has no security groups",
+            label,
+        )));
+    }
+    for group in &inspector.groups {
+        lines.push(Line::from(vec![
+            Span::styled(
+                group.group_id.as_str(),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!(" ({})", group.group_name), label),
+            Span::styled(format!("  {}", group.description), label),
+        ]));
+        push_rules(&mut lines, "Inbound", "Source", &group.inbound_rules);
+        push_rules(&mut lines, "Outbound", "Destination", &group.outbound_rules);
+        lines.push(Line::from(""));
+    }
+
+    // Keep the last line reachable without scrolling past it
+    let max_scroll = (lines.len() as u16).saturating_sub(chunks[0].height);
+    inspector.scroll = inspector.scroll.min(max_scroll);
+
+    f.render_widget(
+        Paragraph::new(lines).scroll((inspector.scroll, 0)),
+        chunks[0],
+    );
+
+    let footer = Line::from(vec![
+        Span::styled("↑↓ PgUp PgDn", Style::default().fg(Color::Green)),
+        Span::styled(" Scroll   ", label),
+        Span::styled("Esc", Style::default().fg(Color::Green)),
+        Span::styled(" to close", label),
+    ]);
+    f.render_widget(
+        Paragraph::new(footer).alignment(Alignment::Center),
+        chunks[1],
+    );
+}
+
+/// One direction's rules as aligned protocol / port / peer columns
+fn push_rules(lines: &mut Vec<Line<'_>>, direction: &str, peer: &str, rules: &[SecurityGroupRule]) {
+    let header = Style::default().fg(Color::Cyan);
+    lines.push(Line::from(Span::styled(format!("  {}", direction), header)));
+    if rules.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "    no rules",
+            Style::default().fg(Color::DarkGray),
+        )));
+        return;
+    }
+
+    lines.push(Line::from(Span::styled(
+        format!("    {:<8} {:<12} {}", "Protocol", "Ports", peer),
+        Style::default().fg(Color::Gray),
+    )));
+    for rule in rules {
+        let mut spans = vec![Span::styled(
+            format!(
+                "    {:<8} {:<12} {}",
+                rule.protocol_label(),
+                rule.port_range(),
+                rule.sources.join(", ")
+            ),
+            Style::default().fg(Color::White),
+        )];
+        if let Some(description) = &rule.description {
+            spans.push(Span::styled(
+                format!("  {}", description),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+}
@@ -0,0 +1,199 @@
+use crate::app::setup_wizard::{SetupStep, SetupWizard};
+use crate::config::user_config::DisplayConfig;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+pub fn draw_setup_wizard(f: &mut Frame, wizard: &SetupWizard) {
+    let popup_area = centered_rect(60, 60, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(
+            "Welcome to NimbusCTL: step {} of {}",
+            wizard.step.number(),
+            SetupStep::ALL.len()
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(2),
+        ])
+        .split(inner);
+
+    let label = Style::default().fg(Color::Gray);
+    let heading = Paragraph::new(vec![
+        Line::from(Span::styled(
+            wizard.step.title(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(step_hint(wizard), label)),
+    ])
+    .wrap(Wrap { trim: false });
+    f.render_widget(heading, chunks[0]);
+
+    match wizard.step {
+        SetupStep::Profile if wizard.profiles.is_empty() => {
+            f.render_widget(
+                Paragraph::new(no_profiles_guidance()).wrap(Wrap { trim: false }),
+                chunks[1],
+            );
+        }
+        SetupStep::Profile => {
+            let items = wizard
+                .profiles
+                .iter()
+                .map(|name| ListItem::new(name.as_str()))
+                .collect();
+            draw_choices(f, chunks[1], items, wizard.profile);
+        }
+        SetupStep::Region => {
+            let items = wizard
+                .regions
+                .iter()
+                .map(|region| {
+                    let mut spans = vec![
+                        Span::raw(region.name.as_str()),
+                        Span::styled(format!(" - {}", region.display_name), label),
+                    ];
+                    if region.opt_in {
+                        spans.push(Span::styled(
+                            " (opt-in)",
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                    }
+                    ListItem::new(Line::from(spans))
+                })
+                .collect();
+            draw_choices(f, chunks[1], items, wizard.region);
+        }
+        SetupStep::Theme => {
+            let items = DisplayConfig::THEMES
+                .iter()
+                .map(|theme| ListItem::new(*theme))
+                .collect();
+            draw_choices(f, chunks[1], items, wizard.theme);
+        }
+        SetupStep::Confirmations => {
+            let (mark, state) = if wizard.confirm_destructive_actions {
+                (
+                    "[x]",
+                    "Ask before stopping, terminating or deleting resources",
+                )
+            } else {
+                ("[ ]", "Destructive actions run as soon as they are chosen")
+            };
+            f.render_widget(
+                Paragraph::new(vec![
+                    Line::from(vec![
+                        Span::styled(
+                            format!("{} ", mark),
+                            Style::default()
+                                .fg(Color::Yellow)
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled(
+                            "Confirm destructive actions",
+                            Style::default().fg(Color::White),
+                        ),
+                    ]),
+                    Line::from(Span::styled(state, label)),
+                ])
+                .wrap(Wrap { trim: false }),
+                chunks[1],
+            );
+        }
+    }
+
+    let mut footer = vec![];
+    if let Some(error) = &wizard.error {
+        footer.push(Line::from(Span::styled(
+            error.as_str(),
+            Style::default().fg(Color::Red),
+        )));
+    }
+    let enter = if wizard.is_last_step() {
+        " Save  "
+    } else {
+        " Next  "
+    };
+    footer.push(Line::from(vec![
+        Span::styled("↑↓", Style::default().fg(Color::Green)),
+        Span::styled(" Choose  ", label),
+        Span::styled("Enter", Style::default().fg(Color::Green)),
+        Span::styled(enter, label),
+        Span::styled("←", Style::default().fg(Color::Green)),
+        Span::styled(" Back  ", label),
+        Span::styled("Esc", Style::default().fg(Color::Green)),
+        Span::styled(" Skip and use defaults", label),
+    ]));
+    f.render_widget(
+        Paragraph::new(footer).alignment(Alignment::Center),
+        chunks[2],
+    );
+}
+
+fn step_hint(wizard: &SetupWizard) -> &'static str {
+    match wizard.step {
+        SetupStep::Profile if wizard.profiles.is_empty() => "No AWS profiles were found",
+        SetupStep::Profile => "Used at startup unless AWS_PROFILE is set",
+        SetupStep::Region => "Used at startup unless AWS_REGION is set",
+        SetupStep::Theme => "Colors used throughout the interface",
+        SetupStep::Confirmations => "Space toggles",
+    }
+}
+
+fn no_profiles_guidance() -> Vec<Line<'static>> {
+    let label = Style::default().fg(Color::Gray);
+    let command = Style::default().fg(Color::Yellow);
+    vec![
+        Line::from(Span::styled("Either create a profile:", label)),
+        Line::from(Span::styled("  aws configure", command)),
+        Line::from(Span::styled(
+            "  (writes ~/.aws/config and ~/.aws/credentials)",
+            label,
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            "or set credentials in the environment:",
+            label,
+        )),
+        Line::from(Span::styled("  export AWS_ACCESS_KEY_ID=...", command)),
+        Line::from(Span::styled("  export AWS_SECRET_ACCESS_KEY=...", command)),
+        Line::from(Span::styled("  export AWS_REGION=us-east-1", command)),
+        Line::from(""),
+        Line::from(Span::styled(
+            "then restart NimbusCTL. Enter continues without a default profile.",
+            label,
+        )),
+    ]
+}
+
+fn draw_choices(f: &mut Frame, area: Rect, items: Vec<ListItem>, selected: usize) {
+    let list = List::new(items)
+        .highlight_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("▶ ");
+    let mut state = ListState::default().with_selected(Some(selected));
+    f.render_stateful_widget(list, area, &mut state);
+}
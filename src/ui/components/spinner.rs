@@ -0,0 +1,25 @@
+use crate::app::load_state::LoadState;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const FRAME_MILLIS: u128 = 100;
+
+/// A service's resource count for navigation labels: "—" before a listing
+/// starts, the spinner while the first one is in flight, "✗" if it failed
+pub fn count_badge(count: &LoadState<usize>) -> String {
+    match count {
+        LoadState::NotLoaded => "—".to_string(),
+        LoadState::Loading => spinner_frame().to_string(),
+        LoadState::Loaded(count) => count.to_string(),
+        LoadState::Failed(_) => "✗".to_string(),
+    }
+}
+
+/// Current spinner frame, derived from the wall clock so every redraw advances it
+pub fn spinner_frame() -> &'static str {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    FRAMES[(millis / FRAME_MILLIS) as usize % FRAMES.len()]
+}
@@ -0,0 +1,86 @@
+use crate::app::operations::ProgressDisplay;
+use crate::app::state::AppState;
+use crate::ui::components::progress_bar::draw_progress_bar;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+/// Show the pending key sequence, whose completions are listed by the
+/// which-key popup, or the progress of a background operation, and the
+/// countdown to temporary credentials expiring
+pub fn draw_status_bar(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(16),
+            Constraint::Length(32),
+        ])
+        .split(area);
+
+    if !app_state.pending_keys.is_empty() {
+        let line = Line::from(vec![
+            Span::styled(
+                format!(" {}-", app_state.pending_keys),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::raw("  "),
+            Span::styled("waiting for next key", Style::default().fg(Color::Gray)),
+        ]);
+        f.render_widget(Paragraph::new(line), chunks[0]);
+    } else if let Some((label, fraction)) =
+        app_state.operations.progress(ProgressDisplay::StatusBar)
+    {
+        let gauge_area = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Max(40), Constraint::Min(0)])
+            .split(chunks[0])[0];
+        draw_progress_bar(f, gauge_area, label, fraction);
+    }
+
+    if app_state.operations.is_busy() {
+        let limiter = app_state.operations.limiter();
+        let in_use = limiter.in_use();
+        let color = if in_use >= limiter.limit() {
+            Color::Yellow
+        } else {
+            Color::Gray
+        };
+        f.render_widget(
+            Paragraph::new(Span::styled(
+                format!("{}/{} requests ", in_use, limiter.limit()),
+                Style::default().fg(color),
+            ))
+            .alignment(Alignment::Right),
+            chunks[1],
+        );
+    }
+
+    if let Some(time_left) = app_state.credentials_time_left() {
+        let seconds = time_left.num_seconds().max(0);
+        let color = match seconds {
+            0..=120 => Color::Red,
+            121..=600 => Color::Yellow,
+            _ => Color::Gray,
+        };
+        let text = if seconds == 0 {
+            "Credentials expired ".to_string()
+        } else {
+            format!(
+                "Credentials expire in {}:{:02}:{:02} ",
+                seconds / 3600,
+                seconds % 3600 / 60,
+                seconds % 60
+            )
+        };
+        f.render_widget(
+            Paragraph::new(Span::styled(text, Style::default().fg(color)))
+                .alignment(Alignment::Right),
+            chunks[2],
+        );
+    }
+}
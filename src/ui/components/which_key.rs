@@ -0,0 +1,53 @@
+use crate::app::state::AppState;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use unicode_width::UnicodeWidthStr;
+
+/// Transient list of the keys that continue the pending sequence, anchored to
+/// the bottom right of `area` just above the status bar
+pub fn draw_which_key(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let hints = app_state.key_sequence_hints();
+    if hints.is_empty() {
+        return;
+    }
+
+    let lines: Vec<Line> = hints
+        .iter()
+        .map(|hint| {
+            let label_style = if hint.available {
+                Style::default().fg(Color::White)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            Line::from(vec![
+                Span::styled(format!(" {} ", hint.key), Style::default().fg(Color::Green)),
+                Span::styled("→ ", Style::default().fg(Color::Gray)),
+                Span::styled(hint.label.clone(), label_style),
+            ])
+        })
+        .collect();
+
+    let content_width = lines.iter().map(Line::width).max().unwrap_or(0) as u16;
+    let title = format!(" {}… ", app_state.pending_keys);
+    let width = (content_width.max(title.width() as u16) + 3).min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let popup_area = Rect {
+        x: area.x + area.width.saturating_sub(width),
+        y: area.y + area.height.saturating_sub(height),
+        width,
+        height,
+    };
+
+    f.render_widget(Clear, popup_area);
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().bg(Color::Black));
+    f.render_widget(Paragraph::new(lines).block(block), popup_area);
+}
@@ -0,0 +1,56 @@
+use crate::app::forms::ZonePicker;
+use crate::ui::layout::centered_rect;
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+pub fn draw_zone_picker(f: &mut Frame, picker: &ZonePicker, active: Option<&String>) {
+    let popup_area = centered_rect(40, 50, f.area());
+
+    f.render_widget(Clear, popup_area);
+
+    let options = std::iter::once(("All zones", active.is_none())).chain(
+        picker
+            .zones
+            .iter()
+            .map(|zone| (zone.as_str(), active == Some(zone))),
+    );
+
+    let items: Vec<ListItem> = options
+        .map(|(label, is_active)| {
+            let mut spans = vec![Span::styled(label, Style::default().fg(Color::White))];
+            if is_active {
+                spans.push(Span::styled(
+                    " (current)",
+                    Style::default().fg(Color::Green),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let block = Block::default()
+        .title(format!(
+            "{} Availability Zone",
+            picker.service_type.display_name()
+        ))
+        .title_bottom(Line::from(vec![
+            Span::styled(" Enter", Style::default().fg(Color::Green)),
+            Span::styled(" to filter, ", Style::default().fg(Color::Gray)),
+            Span::styled("Esc", Style::default().fg(Color::Green)),
+            Span::styled(" to cancel ", Style::default().fg(Color::Gray)),
+        ]))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().fg(Color::Yellow).bg(Color::DarkGray));
+
+    let mut list_state = ListState::default().with_selected(Some(picker.selected));
+    f.render_stateful_widget(list, popup_area, &mut list_state);
+}
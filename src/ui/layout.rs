@@ -1,11 +1,19 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 
-pub fn create_main_layout(area: Rect) -> Vec<Rect> {
+/// Smallest terminal the fixed-height layouts render correctly in
+pub const MIN_WIDTH: u16 = 80;
+pub const MIN_HEIGHT: u16 = 24;
+
+pub fn is_too_small(area: Rect) -> bool {
+    area.width < MIN_WIDTH || area.height < MIN_HEIGHT
+}
+
+pub fn create_main_layout(area: Rect, show_status_bar: bool) -> Vec<Rect> {
     Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Min(0),    // Main content
-            Constraint::Length(0), // Status bar
+            Constraint::Min(0),                             // Main content
+            Constraint::Length(u16::from(show_status_bar)), // Status bar
         ])
         .split(area)
         .to_vec()
@@ -22,13 +30,14 @@ pub fn create_header_layout(area: Rect) -> Vec<Rect> {
         .to_vec()
 }
 
-pub fn create_dashboard_layout(area: Rect) -> Vec<Rect> {
-    // First create header + content vertical split
+pub fn create_dashboard_layout(area: Rect, summary_height: u16) -> Vec<Rect> {
+    // First create header + summary + content vertical split
     let vertical_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // Header
-            Constraint::Min(0),    // Main content
+            Constraint::Length(3),              // Header
+            Constraint::Length(summary_height), // Account summary (0 when disabled)
+            Constraint::Min(0),                 // Main content
         ])
         .split(area);
 
@@ -36,12 +45,13 @@ pub fn create_dashboard_layout(area: Rect) -> Vec<Rect> {
     let main_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(vertical_chunks[1]);
+        .split(vertical_chunks[2]);
 
     vec![
         vertical_chunks[0], // Header area
         main_chunks[0],     // left content
         main_chunks[1],     // right content
+        vertical_chunks[1], // Account summary
     ]
 }
 
@@ -76,3 +86,28 @@ pub fn create_settings_layout(area: Rect) -> Vec<Rect> {
         right_chunks[1], // Bottom right
     ]
 }
+
+/// A rectangle covering the given percentages of `r`, centered in it.
+/// Percentages above 100 are clamped so the result never exceeds `r`.
+pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let percent_x = percent_x.min(100);
+    let percent_y = percent_y.min(100);
+
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
@@ -0,0 +1,129 @@
+use crate::app::state::AppState;
+use crate::ui::components::header;
+use crate::ui::layout::create_header_layout;
+use crate::ui::styles::{get_default_block, get_service_color};
+use crate::utils::helpers::truncate_to_width;
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Paragraph, Row, Table},
+    Frame,
+};
+
+/// Draw the Activity Log page, returning the largest useful scroll offset
+pub fn draw_activity_log(f: &mut Frame, area: Rect, app_state: &AppState) -> usize {
+    let header_chunks = create_header_layout(area);
+    header::draw_header(f, header_chunks[0], app_state, "Activity Log");
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(header_chunks[1]);
+
+    let filter = &app_state.activity_filter;
+    let entries = app_state.recent_activity.filtered(filter);
+    let label = Style::default().fg(Color::Gray);
+    let value = |selected: Option<&str>| match selected {
+        Some(value) => Span::styled(
+            value.to_string(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        None => Span::styled("All", Style::default().fg(Color::White)),
+    };
+    let filter_line = Line::from(vec![
+        Span::styled(" Service: ", label),
+        value(filter.service.map(|s| s.display_name())),
+        Span::styled("  Region: ", label),
+        value(filter.region.as_deref()),
+        Span::styled("  Action: ", label),
+        value(filter.action.as_deref()),
+        Span::styled("  Sort: ", label),
+        Span::styled(
+            if filter.oldest_first {
+                "Oldest first"
+            } else {
+                "Newest first"
+            },
+            Style::default().fg(Color::White),
+        ),
+    ]);
+    f.render_widget(Paragraph::new(filter_line), chunks[0]);
+
+    let title = if filter.is_active() {
+        format!(
+            "Activity ({} of {})",
+            entries.len(),
+            app_state.recent_activity.len()
+        )
+    } else {
+        format!("Activity ({})", entries.len())
+    };
+    let block = get_default_block(&title);
+
+    if entries.is_empty() {
+        let message = if app_state.recent_activity.is_empty() {
+            "No activity yet; commands you run are recorded here"
+        } else {
+            "No activity matches the filters"
+        };
+        f.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                message,
+                Style::default().fg(Color::DarkGray),
+            )))
+            .block(block),
+            chunks[1],
+        );
+        return 0;
+    }
+
+    // Borders and the column header take three rows
+    let visible = usize::from(chunks[1].height.saturating_sub(3));
+    let max_scroll = entries.len().saturating_sub(visible);
+    let scroll = app_state.activity_scroll.min(max_scroll);
+
+    let rows: Vec<Row> = entries
+        .iter()
+        .skip(scroll)
+        .take(visible)
+        .map(|entry| {
+            let time: DateTime<Local> = entry.timestamp.into();
+            Row::new(vec![
+                Span::styled(time.format("%Y-%m-%d %H:%M:%S").to_string(), label),
+                Span::styled(
+                    entry.service_type.display_name(),
+                    Style::default().fg(get_service_color(entry.service_type.display_name())),
+                ),
+                Span::styled(entry.region.clone(), label),
+                Span::styled(entry.action.clone(), Style::default().fg(Color::Yellow)),
+                Span::styled(
+                    truncate_to_width(&entry.resource_name, 40).into_owned(),
+                    Style::default().fg(Color::White),
+                ),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(19),
+            Constraint::Length(8),
+            Constraint::Length(15),
+            Constraint::Length(28),
+            Constraint::Min(10),
+        ],
+    )
+    .header(
+        Row::new(vec!["Time", "Service", "Region", "Action", "Resource"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .block(block);
+    f.render_widget(table, chunks[1]);
+
+    max_scroll
+}
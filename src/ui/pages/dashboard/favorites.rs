@@ -1,5 +1,5 @@
 use crate::aws::types::ServiceType;
-use crate::utils::error::Result;
+use crate::utils::error::{AppError, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -15,11 +15,26 @@ pub struct FavoriteResource {
     pub added_at: chrono::DateTime<chrono::Utc>,
     pub last_accessed: chrono::DateTime<chrono::Utc>,
     pub access_count: u32,
+    /// Why the resource was pinned, e.g. "prod web primary"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// A palette command kept for quick access from the Favorites overlay
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FavoriteCommand {
+    pub id: String,
+    /// Name when it was favorited, shown even where the command is not offered
+    pub name: String,
+    pub added_at: chrono::DateTime<chrono::Utc>,
 }
 
 pub struct FavoritesManager {
     favorites: HashMap<String, FavoriteResource>,
     config_path: PathBuf,
+    /// Favorite commands in the order they were added
+    commands: Vec<FavoriteCommand>,
+    commands_path: PathBuf,
 }
 
 impl FavoritesManager {
@@ -27,8 +42,13 @@ impl FavoritesManager {
         let config_dir = dirs::config_dir()
             .ok_or("Cannot find config directory")?
             .join("nimbus-ctl");
+        Self::with_dir(config_dir)
+    }
 
+    /// Load the favorites saved in `config_dir`, if any
+    pub fn with_dir(config_dir: PathBuf) -> Result<Self> {
         let config_path = config_dir.join("favorites.json");
+        let commands_path = config_dir.join("favorite_commands.json");
 
         let favorites = if config_path.exists() {
             let content = std::fs::read_to_string(&config_path)?;
@@ -37,9 +57,18 @@ impl FavoritesManager {
             HashMap::new()
         };
 
+        let commands = if commands_path.exists() {
+            let content = std::fs::read_to_string(&commands_path)?;
+            serde_json::from_str(&content)?
+        } else {
+            Vec::new()
+        };
+
         Ok(Self {
             favorites,
             config_path,
+            commands,
+            commands_path,
         })
     }
 
@@ -59,6 +88,46 @@ impl FavoritesManager {
         favorites
     }
 
+    /// Favorites grouped by service in `ServiceType::all()` order, skipping empty groups.
+    /// `max_items` caps each group when `limit_per_group` is set, otherwise the total.
+    pub fn get_grouped_favorites(
+        &self,
+        max_items: usize,
+        show_recent_first: bool,
+        limit_per_group: bool,
+    ) -> Vec<(ServiceType, Vec<&FavoriteResource>)> {
+        let mut remaining = max_items;
+        let mut groups = Vec::new();
+
+        for service_type in ServiceType::all() {
+            let mut favorites: Vec<&FavoriteResource> = self
+                .favorites
+                .values()
+                .filter(|f| f.service_type == service_type)
+                .collect();
+            if show_recent_first {
+                favorites.sort_by_key(|f| std::cmp::Reverse(f.last_accessed));
+            } else {
+                favorites.sort_by_key(|f| f.added_at);
+            }
+
+            let limit = if limit_per_group {
+                max_items
+            } else {
+                remaining
+            };
+            favorites.truncate(limit);
+            if favorites.is_empty() {
+                continue;
+            }
+
+            remaining = remaining.saturating_sub(favorites.len());
+            groups.push((service_type, favorites));
+        }
+
+        groups
+    }
+
     pub fn get_favorites_by_service(&self, service_type: ServiceType) -> Vec<&FavoriteResource> {
         let mut favorites: Vec<&FavoriteResource> = self
             .favorites
@@ -86,6 +155,16 @@ impl FavoritesManager {
         Ok(())
     }
 
+    /// Set or clear (`None`) the note of a favorite resource
+    pub fn set_note(&mut self, resource_id: &str, note: Option<String>) -> Result<()> {
+        let favorite = self
+            .favorites
+            .get_mut(resource_id)
+            .ok_or_else(|| AppError::ResourceNotFound(resource_id.to_string()))?;
+        favorite.note = note;
+        self.save()
+    }
+
     pub fn get_most_accessed(&self, limit: usize) -> Vec<&FavoriteResource> {
         let mut favorites: Vec<&FavoriteResource> = self.favorites.values().collect();
         favorites.sort_by(|a, b| b.access_count.cmp(&a.access_count));
@@ -122,6 +201,9 @@ impl FavoritesManager {
             .filter(|f| {
                 f.name.to_lowercase().contains(&query_lower)
                     || f.id.to_lowercase().contains(&query_lower)
+                    || f.note
+                        .as_ref()
+                        .is_some_and(|note| note.to_lowercase().contains(&query_lower))
                     || f.tags
                         .values()
                         .any(|v| v.to_lowercase().contains(&query_lower))
@@ -133,6 +215,35 @@ impl FavoritesManager {
         self.favorites.len()
     }
 
+    pub fn get_favorite_commands(&self) -> &[FavoriteCommand] {
+        &self.commands
+    }
+
+    pub fn is_favorite_command(&self, command_id: &str) -> bool {
+        self.commands.iter().any(|c| c.id == command_id)
+    }
+
+    /// Favorite the command, or unfavorite it if it already was; returns
+    /// whether it is a favorite now
+    pub fn toggle_favorite_command(&mut self, command_id: &str, name: &str) -> Result<bool> {
+        let favorited = match self.commands.iter().position(|c| c.id == command_id) {
+            Some(index) => {
+                self.commands.remove(index);
+                false
+            }
+            None => {
+                self.commands.push(FavoriteCommand {
+                    id: command_id.to_string(),
+                    name: name.to_string(),
+                    added_at: chrono::Utc::now(),
+                });
+                true
+            }
+        };
+        self.save_commands()?;
+        Ok(favorited)
+    }
+
     pub fn count_by_service(&self, service_type: ServiceType) -> usize {
         self.favorites
             .values()
@@ -150,6 +261,16 @@ impl FavoritesManager {
         Ok(())
     }
 
+    fn save_commands(&self) -> Result<()> {
+        if let Some(parent) = self.commands_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let content = serde_json::to_string_pretty(&self.commands)?;
+        std::fs::write(&self.commands_path, content)?;
+        Ok(())
+    }
+
     pub fn export_favorites(&self) -> Result<String> {
         let json = serde_json::to_string_pretty(&self.favorites)?;
         Ok(json)
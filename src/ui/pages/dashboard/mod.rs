@@ -1,4 +1,8 @@
 pub mod favorites;
 pub mod page;
 pub mod recent;
-pub mod widgets;
\ No newline at end of file
+pub mod recently_viewed;
+pub mod widgets;
+
+#[cfg(test)]
+mod widgets_test;
@@ -1,62 +1,425 @@
+use crate::app::load_state::LoadState;
 use crate::app::state::AppState;
+use crate::aws::types::ServiceType;
 use crate::ui::components::header;
+use crate::ui::components::spinner::count_badge;
 use crate::ui::layout::create_dashboard_layout;
-use crate::ui::styles::get_default_block;
+use crate::ui::pages::dashboard::favorites::FavoriteResource;
+use crate::ui::pages::dashboard::widgets::{DashboardWidget, WidgetType};
+use crate::ui::styles::{get_default_block, get_selected_block, state_color};
 use ratatui::{
-    layout::Rect,
-    style::{Color, Style},
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{List, ListItem},
+    widgets::{List, ListItem, ListState, Paragraph},
     Frame,
 };
 
 pub fn draw_dashboard(f: &mut Frame, area: Rect, app_state: &AppState) {
     // Use centralized dashboard layout function that covers header to bottom
-    let layout_areas = create_dashboard_layout(area);
-    // layout_areas: [header, top_left, top_right, bottom_left, bottom_right]
+    let summary_widget = app_state.dashboard_layout.account_summary_widget();
+    let summary_height = if summary_widget.is_some() { 4 } else { 0 };
+    let layout_areas = create_dashboard_layout(area, summary_height);
+    // layout_areas: [header, left, right, account_summary]
 
     // Draw header
     header::draw_header(f, layout_areas[0], app_state, "Nimbus CTL");
 
-    // Draw widgets using layout areas
-    draw_favorites_widget(f, layout_areas[1], app_state); // Top left
-    draw_recent_activity_widget(f, layout_areas[2], app_state); // Top right
+    if let Some(widget) = summary_widget {
+        draw_account_summary_widget(f, layout_areas[3], app_state, widget);
+    }
+
+    // Draw widgets using layout areas; optional widgets stack below favorites
+    let cost_summary = app_state.dashboard_layout.cost_summary_config();
+    let region_overview = app_state.dashboard_layout.region_overview_config();
+    let mut constraints = vec![Constraint::Min(0)];
+    if let Some(top_services) = cost_summary {
+        // Total, blank line and one row per service, plus borders
+        constraints.push(Constraint::Length(top_services as u16 + 4));
+    }
+    if region_overview.is_some() {
+        constraints.push(Constraint::Percentage(40));
+    }
+    let left = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(layout_areas[1]);
+    draw_favorites_widget(f, left[0], app_state); // Top left
+    let mut next = 1;
+    if let Some(top_services) = cost_summary {
+        draw_cost_summary_widget(f, left[next], app_state, top_services);
+        next += 1;
+    }
+    if let Some((service_type, show_all_regions)) = region_overview {
+        draw_region_overview_widget(f, left[next], app_state, service_type, show_all_regions);
+    }
+
+    let recently_viewed = app_state.dashboard_layout.recently_viewed_config();
+    let resource_counts = app_state.dashboard_layout.resource_counts_widget();
+    let mut constraints = vec![Constraint::Min(0)];
+    if recently_viewed.is_some() {
+        constraints.push(Constraint::Percentage(50));
+    }
+    if let Some(widget) = resource_counts {
+        let rows = match &widget.widget_type {
+            WidgetType::ResourceCounts { services, .. } => services.len() as u16,
+            _ => 0,
+        };
+        constraints.push(Constraint::Length(rows + 2));
+    }
+    let right = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(layout_areas[2]);
+    draw_recent_activity_widget(f, right[0], app_state); // Top right
+    let mut next = 1;
+    if let Some(max_items) = recently_viewed {
+        draw_recently_viewed_widget(f, right[next], app_state, max_items);
+        next += 1;
+    }
+    if let Some(widget) = resource_counts {
+        draw_resource_counts_widget(f, right[next], app_state, widget);
+    }
+}
+
+/// States shown per service in the resource counts widget
+const STATE_BREAKDOWN_LIMIT: usize = 3;
+
+fn draw_resource_counts_widget(
+    f: &mut Frame,
+    area: Rect,
+    app_state: &AppState,
+    widget: &DashboardWidget,
+) {
+    let WidgetType::ResourceCounts {
+        services,
+        show_percentages,
+    } = &widget.widget_type
+    else {
+        return;
+    };
+
+    let label_style = Style::default().fg(Color::Gray);
+    let value_style = Style::default()
+        .fg(Color::White)
+        .add_modifier(Modifier::BOLD);
+
+    let counts: Vec<(ServiceType, LoadState<usize>)> = services
+        .iter()
+        .map(|service_type| (*service_type, app_state.resource_count(*service_type)))
+        .collect();
+    let total: usize = counts.iter().filter_map(|(_, count)| count.loaded()).sum();
+
+    let lines: Vec<Line> = counts
+        .into_iter()
+        .map(|(service_type, count)| {
+            let mut spans = vec![
+                Span::styled(
+                    format!(
+                        "{} {:<16}",
+                        service_type.icon(),
+                        service_type.display_name()
+                    ),
+                    label_style,
+                ),
+                Span::styled(count_badge(&count), value_style),
+            ];
+            if let (true, LoadState::Loaded(count), false) = (*show_percentages, count, total == 0)
+            {
+                spans.push(Span::styled(
+                    format!("  {:>3}%", count * 100 / total),
+                    label_style,
+                ));
+            }
+            // The most common states, for an at-a-glance health check
+            for (state, count) in app_state
+                .state_counts(service_type)
+                .into_iter()
+                .take(STATE_BREAKDOWN_LIMIT)
+            {
+                spans.push(Span::styled(
+                    format!("  {} {}", count, state),
+                    Style::default().fg(state_color(&state)),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect();
+
+    let block = if app_state
+        .dashboard_layout
+        .get_selected_widget()
+        .is_some_and(|w| w.id == widget.id)
+    {
+        get_selected_block(&widget.title)
+    } else {
+        get_default_block(&widget.title)
+    };
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn draw_account_summary_widget(
+    f: &mut Frame,
+    area: Rect,
+    app_state: &AppState,
+    widget: &DashboardWidget,
+) {
+    let WidgetType::AccountSummary { services } = &widget.widget_type else {
+        return;
+    };
+
+    let label_style = Style::default().fg(Color::Gray);
+    let value_style = Style::default()
+        .fg(Color::White)
+        .add_modifier(Modifier::BOLD);
+
+    let (account_id, alias) = match &app_state.account_summary {
+        Some(summary) => (
+            summary.account_id.clone(),
+            summary.alias.clone().unwrap_or_else(|| "—".to_string()),
+        ),
+        None => ("Loading...".to_string(), "—".to_string()),
+    };
+
+    let identity = Line::from(vec![
+        Span::styled("Account: ", label_style),
+        Span::styled(account_id, value_style),
+        Span::styled("  Alias: ", label_style),
+        Span::styled(alias, value_style),
+        Span::styled("  Profile: ", label_style),
+        Span::styled(&app_state.current_profile, value_style),
+        Span::styled("  Region: ", label_style),
+        Span::styled(&app_state.current_region, value_style),
+    ]);
+
+    // Counts come from the cache, so services not yet loaded show a dash or spinner
+    let mut counts = Vec::new();
+    for service_type in services {
+        let count = count_badge(&app_state.resource_count(*service_type));
+        counts.push(Span::styled(
+            format!("{} {} ", service_type.icon(), service_type.display_name()),
+            label_style,
+        ));
+        counts.push(Span::styled(count, value_style));
+        counts.push(Span::raw("   "));
+    }
+
+    let block = if app_state
+        .dashboard_layout
+        .get_selected_widget()
+        .is_some_and(|w| w.id == widget.id)
+    {
+        get_selected_block(&widget.title)
+    } else {
+        get_default_block(&widget.title)
+    };
+    let paragraph = Paragraph::new(vec![identity, Line::from(counts)]).block(block);
+    f.render_widget(paragraph, area);
+}
+
+fn draw_cost_summary_widget(f: &mut Frame, area: Rect, app_state: &AppState, top_services: usize) {
+    let label_style = Style::default().fg(Color::Gray);
+    let value_style = Style::default()
+        .fg(Color::White)
+        .add_modifier(Modifier::BOLD);
+
+    let lines = match &app_state.cost_summary {
+        None => vec![Line::from(Span::styled("Loading...", label_style))],
+        Some(Err(message)) => vec![Line::from(Span::styled(
+            message.as_str(),
+            Style::default().fg(Color::Yellow),
+        ))],
+        Some(Ok(summary)) => {
+            let mut lines = vec![
+                Line::from(vec![
+                    Span::styled(
+                        format!(
+                            "{} – {}: ",
+                            summary.period_start.format("%b %d"),
+                            summary.period_end.format("%b %d")
+                        ),
+                        label_style,
+                    ),
+                    Span::styled(
+                        format!("{:.2} {}", summary.total, summary.currency),
+                        value_style,
+                    ),
+                ]),
+                Line::from(""),
+            ];
+            if summary.top_services.is_empty() {
+                lines.push(Line::from(Span::styled(
+                    "No spend recorded yet",
+                    label_style,
+                )));
+            }
+            for (service, amount) in summary.top_services.iter().take(top_services) {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{:>10.2}  ", amount), value_style),
+                    Span::styled(service.as_str(), label_style),
+                ]));
+            }
+            lines
+        }
+    };
+
+    let focused = app_state
+        .dashboard_layout
+        .get_selected_widget()
+        .is_some_and(|w| w.id == "cost_summary");
+    let block = if focused {
+        get_selected_block("Cost (Month to Date)")
+    } else {
+        get_default_block("Cost (Month to Date)")
+    };
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn draw_region_overview_widget(
+    f: &mut Frame,
+    area: Rect,
+    app_state: &AppState,
+    service_type: ServiceType,
+    show_all_regions: bool,
+) {
+    let label_style = Style::default().fg(Color::Gray);
+    let value_style = Style::default()
+        .fg(Color::White)
+        .add_modifier(Modifier::BOLD);
+
+    let mut lines = Vec::new();
+    for region in &app_state.available_regions {
+        let key = (region.name.clone(), service_type);
+        // A failed region gets a marker rather than a count of 0, which would read as empty
+        let value = match app_state.resources.get(&key) {
+            Some(LoadState::Failed(reason)) => Span::styled(
+                format!("✗ error: {}", reason),
+                Style::default().fg(Color::Red),
+            ),
+            Some(LoadState::Loaded(resources)) => {
+                if resources.is_empty() && !show_all_regions {
+                    continue;
+                }
+                Span::styled(resources.len().to_string(), value_style)
+            }
+            Some(LoadState::Loading | LoadState::NotLoaded) | None => {
+                Span::styled("…", label_style)
+            }
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<16}", region.name), label_style),
+            value,
+        ]));
+    }
+
+    let title = format!("{} by Region", service_type.display_name());
+    let focused = app_state
+        .dashboard_layout
+        .get_selected_widget()
+        .is_some_and(|w| w.id == "region_overview");
+    let block = if focused {
+        get_selected_block(&title)
+    } else {
+        get_default_block(&title)
+    };
+    f.render_widget(Paragraph::new(lines).block(block), area);
 }
 
 fn draw_favorites_widget(f: &mut Frame, area: Rect, app_state: &AppState) {
-    let favorites = app_state.favorites_manager.get_favorites();
+    let (max_items, show_recent_first, limit_per_group) =
+        app_state.dashboard_layout.favorites_config();
+    let focused = app_state
+        .dashboard_layout
+        .get_selected_widget()
+        .is_some_and(|w| w.id == "favorites");
+    let mut selected_row = None;
+
+    let groups = app_state.favorites_manager.get_grouped_favorites(
+        max_items,
+        show_recent_first,
+        limit_per_group,
+    );
 
-    let items: Vec<ListItem> = if favorites.is_empty() {
+    let items: Vec<ListItem> = if groups.is_empty() {
         vec![ListItem::new(Line::from(vec![Span::styled(
             "No favorite resources",
             Style::default().fg(Color::Gray),
         )]))]
     } else {
-        favorites
-            .into_iter()
-            .take(5)
-            .map(|fav| {
-                ListItem::new(Line::from(vec![
-                    Span::styled(
-                        format!("[{}] ", fav.service_type.display_name()),
-                        Style::default().fg(Color::Blue),
-                    ),
-                    Span::styled(&fav.name, Style::default().fg(Color::White)),
-                    Span::raw(" "),
-                    Span::styled(
-                        format!("({})", fav.region),
+        let mut items = Vec::new();
+        let mut favorite_index = 0;
+        for (service_type, favorites) in groups {
+            items.push(ListItem::new(Line::from(vec![Span::styled(
+                format!("{} {}", service_type.icon(), service_type.display_name()),
+                Style::default()
+                    .fg(Color::Blue)
+                    .add_modifier(Modifier::BOLD),
+            )])));
+
+            for fav in favorites {
+                if focused && favorite_index == app_state.dashboard_layout.selected_item() {
+                    selected_row = Some(items.len());
+                }
+                favorite_index += 1;
+
+                // Unresolvable favorites stay visible so they can still be found and removed
+                let (name_style, region_style) = if is_favorite_resolvable(fav, app_state) {
+                    (
+                        Style::default().fg(Color::White),
                         Style::default().fg(Color::Gray),
-                    ),
-                ]))
-            })
-            .collect()
+                    )
+                } else {
+                    (
+                        Style::default().fg(Color::DarkGray),
+                        Style::default().fg(Color::DarkGray),
+                    )
+                };
+
+                let mut spans = vec![
+                    Span::raw("  "),
+                    Span::styled(&fav.name, name_style),
+                    Span::raw(" "),
+                    Span::styled(format!("({})", fav.region), region_style),
+                ];
+                if let Some(note) = &fav.note {
+                    spans.push(Span::styled(
+                        format!("  {}", note),
+                        Style::default()
+                            .fg(Color::Yellow)
+                            .add_modifier(Modifier::ITALIC),
+                    ));
+                }
+                items.push(ListItem::new(Line::from(spans)));
+            }
+        }
+        items
     };
 
+    let block = if focused {
+        get_selected_block("Favorite Resources")
+    } else {
+        get_default_block("Favorite Resources")
+    };
     let list = List::new(items)
-        .block(get_default_block("Favorite Resources"))
+        .block(block)
         .highlight_style(Style::default().bg(Color::DarkGray));
 
-    f.render_widget(list, area);
+    let mut list_state = ListState::default().with_selected(selected_row);
+    f.render_stateful_widget(list, area, &mut list_state);
+}
+
+/// A favorite resolves when it lives in the current region and, once that
+/// region's listing has been loaded, still appears in it
+fn is_favorite_resolvable(fav: &FavoriteResource, app_state: &AppState) -> bool {
+    let global = matches!(fav.service_type, ServiceType::IAM);
+    if !global && fav.region != app_state.current_region {
+        return false;
+    }
+
+    match app_state.cached_resources(fav.service_type) {
+        Some(resources) => resources.iter().any(|r| r.id == fav.id),
+        None => true,
+    }
 }
 
 fn draw_recent_activity_widget(f: &mut Frame, area: Rect, app_state: &AppState) {
@@ -89,3 +452,47 @@ fn draw_recent_activity_widget(f: &mut Frame, area: Rect, app_state: &AppState)
 
     f.render_widget(list, area);
 }
+
+fn draw_recently_viewed_widget(f: &mut Frame, area: Rect, app_state: &AppState, max_items: usize) {
+    let focused = app_state
+        .dashboard_layout
+        .get_selected_widget()
+        .is_some_and(|w| w.id == "recently_viewed");
+    let entries = app_state.recently_viewed.entries(max_items);
+
+    let items: Vec<ListItem> = if entries.is_empty() {
+        vec![ListItem::new(Line::from(vec![Span::styled(
+            "No recently viewed resources",
+            Style::default().fg(Color::Gray),
+        )]))]
+    } else {
+        entries
+            .iter()
+            .map(|entry| {
+                ListItem::new(Line::from(vec![
+                    Span::raw(format!("{} ", entry.service_type.icon())),
+                    Span::styled(&entry.name, Style::default().fg(Color::White)),
+                    Span::raw(" "),
+                    Span::styled(
+                        format!("({})", entry.region),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ]))
+            })
+            .collect()
+    };
+
+    let block = if focused {
+        get_selected_block("Recently Viewed")
+    } else {
+        get_default_block("Recently Viewed")
+    };
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().bg(Color::DarkGray));
+
+    let selected_row =
+        (focused && !entries.is_empty()).then(|| app_state.dashboard_layout.selected_item());
+    let mut list_state = ListState::default().with_selected(selected_row);
+    f.render_stateful_widget(list, area, &mut list_state);
+}
@@ -0,0 +1,101 @@
+use crate::aws::types::ServiceType;
+use crate::utils::error::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewedResource {
+    pub id: String,
+    pub name: String,
+    pub service_type: ServiceType,
+    pub region: String,
+    pub viewed_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Resources the user opened, most recent first. Unlike favorites these are
+/// recorded automatically, and unlike recent activity they track navigation
+/// rather than executed commands.
+pub struct RecentlyViewed {
+    entries: VecDeque<ViewedResource>,
+    max_items: usize,
+    config_path: PathBuf,
+}
+
+impl RecentlyViewed {
+    pub fn config_path() -> Result<PathBuf> {
+        Ok(dirs::config_dir()
+            .ok_or("Cannot find config directory")?
+            .join("nimbus-ctl")
+            .join("recently_viewed.json"))
+    }
+
+    pub fn new(max_items: usize) -> Result<Self> {
+        Self::load(max_items, Self::config_path()?)
+    }
+
+    /// The list saved at `config_path`, empty if nothing was saved there yet
+    pub fn load(max_items: usize, config_path: PathBuf) -> Result<Self> {
+        let mut loaded = Self::empty(max_items, config_path);
+        if loaded.config_path.exists() {
+            let content = std::fs::read_to_string(&loaded.config_path)?;
+            let entries: Vec<ViewedResource> = serde_json::from_str(&content)?;
+            loaded.entries = entries.into_iter().take(max_items).collect();
+        }
+        Ok(loaded)
+    }
+
+    /// An empty list that saves to `config_path`, replacing whatever is there
+    pub fn empty(max_items: usize, config_path: PathBuf) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            max_items,
+            config_path,
+        }
+    }
+
+    /// Move the resource to the front, adding it if it was not seen before
+    pub fn record(&mut self, resource: ViewedResource) -> Result<()> {
+        self.entries.retain(|entry| {
+            !(entry.id == resource.id
+                && entry.service_type == resource.service_type
+                && entry.region == resource.region)
+        });
+
+        self.entries.push_front(resource);
+        self.entries.truncate(self.max_items);
+
+        self.save()
+    }
+
+    /// Drop a resource that no longer exists, returning whether it was listed
+    pub fn remove(&mut self, id: &str, service_type: ServiceType, region: &str) -> Result<bool> {
+        let before = self.entries.len();
+        self.entries.retain(|entry| {
+            !(entry.id == id && entry.service_type == service_type && entry.region == region)
+        });
+        if self.entries.len() == before {
+            return Ok(false);
+        }
+        self.save()?;
+        Ok(true)
+    }
+
+    pub fn entries(&self, limit: usize) -> Vec<&ViewedResource> {
+        self.entries.iter().take(limit).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn save(&self) -> Result<()> {
+        if let Some(parent) = self.config_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let content = serde_json::to_string_pretty(&self.entries)?;
+        std::fs::write(&self.config_path, content)?;
+        Ok(())
+    }
+}
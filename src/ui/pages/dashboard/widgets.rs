@@ -1,9 +1,11 @@
 use crate::app::state::AppState;
 use crate::aws::types::ServiceType;
+use crate::utils::error::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DashboardWidget {
     pub id: String,
     pub title: String,
@@ -13,28 +15,36 @@ pub struct DashboardWidget {
     pub size: Size,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Position {
     pub x: u16,
     pub y: u16,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Size {
     pub width: u16,
     pub height: u16,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum WidgetType {
     FavoriteResources {
         max_items: usize,
         show_recent_first: bool,
+        limit_per_group: bool,
     },
     RecentActivity {
         max_items: usize,
+        #[serde(with = "duration_secs")]
         time_window: chrono::Duration,
     },
+    RecentlyViewed {
+        max_items: usize,
+    },
+    CostSummary {
+        top_services: usize,
+    },
     ResourceCounts {
         services: Vec<ServiceType>,
         show_percentages: bool,
@@ -43,14 +53,18 @@ pub enum WidgetType {
         actions: Vec<QuickAction>,
     },
     RegionOverview {
+        service_type: ServiceType,
         show_all_regions: bool,
     },
     TaggedResources {
         tag_filters: Vec<TagFilter>,
     },
+    AccountSummary {
+        services: Vec<ServiceType>,
+    },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuickAction {
     pub id: String,
     pub name: String,
@@ -60,13 +74,13 @@ pub struct QuickAction {
     pub hotkey: Option<char>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TagFilter {
     pub key: String,
     pub value: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ActionType {
     Create,
     Read,
@@ -80,10 +94,11 @@ pub enum ActionType {
 pub struct DashboardLayout {
     pub widgets: Vec<DashboardWidget>,
     selected_widget: Option<usize>,
+    selected_item: usize,
     layout_config: LayoutConfig,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LayoutConfig {
     pub columns: u16,
     pub rows: u16,
@@ -91,18 +106,113 @@ pub struct LayoutConfig {
     pub auto_arrange: bool,
 }
 
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        Self {
+            columns: 2,
+            rows: 3,
+            padding: 1,
+            auto_arrange: true,
+        }
+    }
+}
+
+/// The layout as written to `dashboard_layout.json`. Widgets are read one by
+/// one as JSON values so one this version cannot parse does not lose the rest.
+#[derive(Serialize, Deserialize)]
+struct SavedLayout<W> {
+    widgets: Vec<W>,
+    #[serde(default)]
+    layout_config: LayoutConfig,
+}
+
 impl DashboardLayout {
     pub fn new() -> Self {
         Self {
             widgets: Self::default_widgets(),
             selected_widget: None,
-            layout_config: LayoutConfig {
-                columns: 2,
-                rows: 3,
-                padding: 1,
-                auto_arrange: true,
+            selected_item: 0,
+            layout_config: LayoutConfig::default(),
+        }
+    }
+
+    fn config_path() -> Result<PathBuf> {
+        Ok(dirs::config_dir()
+            .ok_or("Cannot find config directory")?
+            .join("nimbus-ctl")
+            .join("dashboard_layout.json"))
+    }
+
+    /// The layout saved by the last run, or the default one if none was
+    /// saved, with a warning for each saved widget that could not be read.
+    /// Widgets added since the layout was saved are appended to it.
+    pub fn load() -> Result<(Self, Vec<String>)> {
+        let config_path = Self::config_path()?;
+        if !config_path.exists() {
+            return Ok((Self::new(), vec![]));
+        }
+
+        Self::from_saved(&std::fs::read_to_string(&config_path)?)
+    }
+
+    /// Read a layout written by `to_saved`
+    pub fn from_saved(content: &str) -> Result<(Self, Vec<String>)> {
+        let saved: SavedLayout<serde_json::Value> = serde_json::from_str(content)?;
+        let mut warnings = vec![];
+        let mut widgets: Vec<DashboardWidget> = vec![];
+        for value in saved.widgets {
+            let id = value
+                .get("id")
+                .and_then(|id| id.as_str())
+                .unwrap_or("unnamed")
+                .to_string();
+            match serde_json::from_value::<DashboardWidget>(value) {
+                Ok(widget) if widgets.iter().all(|w| w.id != widget.id) => widgets.push(widget),
+                Ok(_) => {}
+                Err(e) => {
+                    tracing::warn!("Skipping saved dashboard widget '{}': {}", id, e);
+                    warnings.push(format!(
+                        "Dashboard widget '{}' was saved by another version and was skipped",
+                        id
+                    ));
+                }
+            }
+        }
+        for widget in Self::default_widgets() {
+            if widgets.iter().all(|w| w.id != widget.id) {
+                widgets.push(widget);
+            }
+        }
+
+        Ok((
+            Self {
+                widgets,
+                layout_config: saved.layout_config,
+                ..Self::new()
             },
+            warnings,
+        ))
+    }
+
+    /// Write the widgets, their positions, sizes and enabled flags, and the
+    /// layout config; the selection is not kept
+    pub fn save(&self) -> Result<()> {
+        let config_path = Self::config_path()?;
+        if let Some(parent) = config_path.parent() {
+            std::fs::create_dir_all(parent)?;
         }
+
+        std::fs::write(&config_path, self.to_saved()?)?;
+        Ok(())
+    }
+
+    /// The widgets and layout settings as saved between runs
+    pub fn to_saved(&self) -> Result<String> {
+        let saved = SavedLayout {
+            widgets: self.widgets.iter().collect(),
+            layout_config: self.layout_config.clone(),
+        };
+        Ok(serde_json::to_string_pretty(&saved)?)
     }
 
     fn default_widgets() -> Vec<DashboardWidget> {
@@ -113,6 +223,7 @@ impl DashboardLayout {
                 widget_type: WidgetType::FavoriteResources {
                     max_items: 5,
                     show_recent_first: true,
+                    limit_per_group: false,
                 },
                 enabled: true,
                 position: Position { x: 0, y: 0 },
@@ -135,13 +246,168 @@ impl DashboardLayout {
                     height: 100,
                 },
             },
+            DashboardWidget {
+                id: "recently_viewed".to_string(),
+                title: "Recently Viewed".to_string(),
+                widget_type: WidgetType::RecentlyViewed { max_items: 5 },
+                enabled: true,
+                position: Position { x: 50, y: 50 },
+                size: Size {
+                    width: 50,
+                    height: 50,
+                },
+            },
+            DashboardWidget {
+                id: "account_summary".to_string(),
+                title: "Account Summary".to_string(),
+                widget_type: WidgetType::AccountSummary {
+                    services: vec![
+                        ServiceType::EC2,
+                        ServiceType::S3,
+                        ServiceType::RDS,
+                        ServiceType::EKS,
+                    ],
+                },
+                // Opt-in so existing dashboard layouts are unchanged
+                enabled: false,
+                position: Position { x: 0, y: 0 },
+                size: Size {
+                    width: 100,
+                    height: 20,
+                },
+            },
+            DashboardWidget {
+                id: "resource_counts".to_string(),
+                title: "Resource Counts".to_string(),
+                widget_type: WidgetType::ResourceCounts {
+                    services: ServiceType::all(),
+                    show_percentages: false,
+                },
+                // Opt-in so existing dashboard layouts are unchanged
+                enabled: false,
+                position: Position { x: 50, y: 70 },
+                size: Size {
+                    width: 50,
+                    height: 30,
+                },
+            },
+            DashboardWidget {
+                id: "cost_summary".to_string(),
+                title: "Cost (Month to Date)".to_string(),
+                widget_type: WidgetType::CostSummary { top_services: 3 },
+                // Opt-in: Cost Explorer bills every request and many roles lack ce: access
+                enabled: false,
+                position: Position { x: 0, y: 70 },
+                size: Size {
+                    width: 50,
+                    height: 30,
+                },
+            },
+            DashboardWidget {
+                id: "region_overview".to_string(),
+                title: "Regions".to_string(),
+                widget_type: WidgetType::RegionOverview {
+                    service_type: ServiceType::EC2,
+                    show_all_regions: true,
+                },
+                // Opt-in: it lists the service in every available region
+                enabled: false,
+                position: Position { x: 0, y: 50 },
+                size: Size {
+                    width: 50,
+                    height: 20,
+                },
+            },
         ]
     }
 
+    /// Turn on widgets listed in the config, including the opt-in ones that
+    /// start disabled
+    pub fn enable_widgets(&mut self, ids: &[String]) {
+        for widget in &mut self.widgets {
+            if ids.contains(&widget.id) {
+                widget.enabled = true;
+            }
+        }
+    }
+
     pub fn get_widget_by_id(&self, id: &str) -> Option<&DashboardWidget> {
         self.widgets.iter().find(|w| w.id == id)
     }
 
+    /// Settings of the favorites widget as (max_items, show_recent_first, limit_per_group)
+    pub fn favorites_config(&self) -> (usize, bool, bool) {
+        match self.get_widget_by_id("favorites").map(|w| &w.widget_type) {
+            Some(WidgetType::FavoriteResources {
+                max_items,
+                show_recent_first,
+                limit_per_group,
+            }) => (*max_items, *show_recent_first, *limit_per_group),
+            _ => (5, true, false),
+        }
+    }
+
+    /// The recently viewed widget and its item limit, if it is enabled
+    pub fn recently_viewed_config(&self) -> Option<usize> {
+        match self.get_widget_by_id("recently_viewed") {
+            Some(DashboardWidget {
+                enabled: true,
+                widget_type: WidgetType::RecentlyViewed { max_items },
+                ..
+            }) => Some(*max_items),
+            _ => None,
+        }
+    }
+
+    /// Select a widget by id, e.g. when a command jumps to it
+    pub fn focus_widget(&mut self, id: &str) {
+        if let Some(index) = self.widgets.iter().position(|w| w.id == id && w.enabled) {
+            self.selected_widget = Some(index);
+            self.selected_item = 0;
+        }
+    }
+
+    /// How many services the cost widget lists, if it is enabled
+    pub fn cost_summary_config(&self) -> Option<usize> {
+        match self.get_widget_by_id("cost_summary") {
+            Some(DashboardWidget {
+                enabled: true,
+                widget_type: WidgetType::CostSummary { top_services },
+                ..
+            }) => Some(*top_services),
+            _ => None,
+        }
+    }
+
+    /// The service the region overview counts and whether it lists regions
+    /// with no resources, if it is enabled
+    pub fn region_overview_config(&self) -> Option<(ServiceType, bool)> {
+        match self.get_widget_by_id("region_overview") {
+            Some(DashboardWidget {
+                enabled: true,
+                widget_type:
+                    WidgetType::RegionOverview {
+                        service_type,
+                        show_all_regions,
+                    },
+                ..
+            }) => Some((*service_type, *show_all_regions)),
+            _ => None,
+        }
+    }
+
+    /// The resource counts widget, if it has been enabled
+    pub fn resource_counts_widget(&self) -> Option<&DashboardWidget> {
+        self.get_widget_by_id("resource_counts")
+            .filter(|widget| widget.enabled)
+    }
+
+    /// The account summary widget, if it has been enabled
+    pub fn account_summary_widget(&self) -> Option<&DashboardWidget> {
+        self.get_widget_by_id("account_summary")
+            .filter(|widget| widget.enabled)
+    }
+
     pub fn get_selected_widget(&self) -> Option<&DashboardWidget> {
         self.selected_widget.and_then(|i| self.widgets.get(i))
     }
@@ -155,20 +421,68 @@ impl DashboardLayout {
     pub fn select_next_widget(&mut self) {
         let widget_count = self.widgets.len();
         if widget_count > 0 {
-            self.selected_widget = Some(match self.selected_widget {
-                Some(i) => (i + 1) % widget_count,
-                None => 0,
-            });
+            let start = self.selected_widget.map_or(0, |i| i + 1);
+            let next = (0..widget_count)
+                .map(|step| (start + step) % widget_count)
+                .find(|&i| self.widgets[i].enabled);
+            self.select_enabled_widget(next);
         }
     }
 
     pub fn select_previous_widget(&mut self) {
         let widget_count = self.widgets.len();
         if widget_count > 0 {
-            self.selected_widget = Some(match self.selected_widget {
-                Some(i) => (i + widget_count - 1) % widget_count,
-                None => widget_count - 1,
-            });
+            let start = self.selected_widget.unwrap_or(widget_count) + widget_count;
+            let previous = (1..=widget_count)
+                .map(|step| (start - step) % widget_count)
+                .find(|&i| self.widgets[i].enabled);
+            self.select_enabled_widget(previous);
+        }
+    }
+
+    /// Disabled widgets are never drawn, so Tab skips over them
+    fn select_enabled_widget(&mut self, index: Option<usize>) {
+        if let Some(index) = index {
+            self.selected_widget = Some(index);
+            self.selected_item = 0;
+        }
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.selected_widget = None;
+        self.selected_item = 0;
+    }
+
+    /// Item highlighted within the selected widget
+    pub fn selected_item(&self) -> usize {
+        self.selected_item
+    }
+
+    pub fn select_next_item(&mut self, item_count: usize) {
+        if self.selected_item + 1 < item_count {
+            self.selected_item += 1;
         }
     }
+
+    pub fn select_previous_item(&mut self) {
+        self.selected_item = self.selected_item.saturating_sub(1);
+    }
+}
+
+/// `chrono::Duration` has no serde support; saved as whole seconds
+mod duration_secs {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        duration: &chrono::Duration,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_i64(duration.num_seconds())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<chrono::Duration, D::Error> {
+        i64::deserialize(deserializer).map(chrono::Duration::seconds)
+    }
 }
@@ -0,0 +1,53 @@
+use crate::ui::pages::dashboard::widgets::DashboardLayout;
+
+#[test]
+fn saved_layout_round_trips_order_and_flags() {
+    let mut layout = DashboardLayout::new();
+    layout.widgets.reverse();
+    layout.widgets[0].enabled = !layout.widgets[0].enabled;
+    layout.widgets[1].size.height = 42;
+    let saved = layout.to_saved().unwrap();
+
+    let (restored, warnings) = DashboardLayout::from_saved(&saved).unwrap();
+
+    assert!(warnings.is_empty());
+    let ids = |layout: &DashboardLayout| -> Vec<String> {
+        layout.widgets.iter().map(|w| w.id.clone()).collect()
+    };
+    assert_eq!(ids(&restored), ids(&layout));
+    assert_eq!(restored.widgets[0].enabled, layout.widgets[0].enabled);
+    assert_eq!(restored.widgets[1].size.height, 42);
+}
+
+#[test]
+fn unknown_saved_widget_is_skipped_and_missing_defaults_are_added() {
+    let saved = r#"{
+        "widgets": [
+            {
+                "id": "recently_viewed",
+                "title": "Recently Viewed",
+                "widget_type": { "RecentlyViewed": { "max_items": 9 } },
+                "enabled": false,
+                "position": { "x": 0, "y": 0 },
+                "size": { "width": 100, "height": 50 }
+            },
+            {
+                "id": "weather",
+                "title": "Weather",
+                "widget_type": { "Weather": { "city": "Oslo" } },
+                "enabled": true,
+                "position": { "x": 0, "y": 0 },
+                "size": { "width": 10, "height": 10 }
+            }
+        ]
+    }"#;
+
+    let (layout, warnings) = DashboardLayout::from_saved(saved).unwrap();
+
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("weather"));
+    assert_eq!(layout.widgets[0].id, "recently_viewed");
+    assert!(!layout.widgets[0].enabled);
+    assert!(layout.get_widget_by_id("weather").is_none());
+    assert_eq!(layout.widgets.len(), DashboardLayout::new().widgets.len());
+}
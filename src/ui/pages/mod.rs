@@ -1,3 +1,4 @@
+pub mod activity_log;
 pub mod dashboard;
 pub mod resource_detail;
 pub mod resource_list;
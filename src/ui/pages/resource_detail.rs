@@ -1,23 +1,30 @@
-use crate::app::state::AppState;
-use crate::aws::types::{ResourceId, ServiceType};
+use crate::app::load_state::LoadState;
+use crate::app::state::{AppState, DetailTab};
+use crate::aws::instance_types::{self, PRICE_REGION};
+use crate::aws::services::iam;
+use crate::aws::types::{EksNodeGroup, RelatedResource, ResourceEvent, ResourceId, ServiceType};
 use crate::ui::components::header;
 use crate::ui::layout::create_header_layout;
-use crate::ui::styles::get_default_block;
+use crate::ui::styles::{get_default_block, state_color};
 use ratatui::{
-    layout::{Constraint, Direction, Layout, Rect},
+    layout::{Constraint, Direction, Layout, Margin, Rect},
     style::{Color, Style},
     text::{Line, Span},
-    widgets::Paragraph,
+    widgets::{
+        List, ListItem, ListState, Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState,
+        Table, TableState, Tabs,
+    },
     Frame,
 };
 
+/// Draw the detail page, returning the largest scroll offset the info panel can use
 pub fn draw_resource_detail(
     f: &mut Frame,
     area: Rect,
     app_state: &AppState,
     service_type: ServiceType,
     resource_id: &ResourceId,
-) {
+) -> u16 {
     // Use centralized header layout function
     let header_chunks = create_header_layout(area);
 
@@ -26,7 +33,7 @@ pub fn draw_resource_detail(
     header::draw_header(f, header_chunks[0], app_state, &page_title);
 
     // Draw resource detail content
-    draw_resource_detail_content(f, header_chunks[1], app_state, service_type, resource_id);
+    draw_resource_detail_content(f, header_chunks[1], app_state, service_type, resource_id)
 }
 
 fn draw_resource_detail_content(
@@ -35,19 +42,320 @@ fn draw_resource_detail_content(
     app_state: &AppState,
     service_type: ServiceType,
     resource_id: &ResourceId,
-) {
+) -> u16 {
+    if let LoadState::Failed(_) = app_state.describe_state(service_type, resource_id) {
+        draw_missing_resource(f, area, service_type, resource_id);
+        return 0;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(area);
+
+    let tabs = DetailTab::for_service(service_type);
+    let titles = tabs
+        .iter()
+        .enumerate()
+        .map(|(i, tab)| format!("{} {}", i + 1, tab.title()));
+    let selected = tabs.iter().position(|tab| *tab == app_state.detail_tab);
+    f.render_widget(
+        Tabs::new(titles)
+            .select(selected)
+            .style(Style::default().fg(Color::Gray))
+            .highlight_style(Style::default().fg(Color::Yellow)),
+        chunks[0],
+    );
+
+    match app_state.detail_tab {
+        DetailTab::Overview => draw_overview(f, chunks[1], app_state, service_type, resource_id),
+        DetailTab::Events => {
+            let events = app_state
+                .resource_events
+                .get(&(app_state.current_region.clone(), resource_id.clone()));
+            draw_events(f, chunks[1], events, app_state.detail_scroll)
+        }
+        DetailTab::RawJson => {
+            let lines = match app_state.resource_json(service_type, resource_id) {
+                Ok(json) => json
+                    .lines()
+                    .map(|line| Line::from(line.to_string()))
+                    .collect(),
+                Err(e) => vec![Line::from(Span::styled(
+                    format!("Cannot show resource as JSON: {}", e),
+                    Style::default().fg(Color::Red),
+                ))],
+            };
+            draw_scrollable(f, chunks[1], lines, "Raw JSON", app_state.detail_scroll)
+        }
+    }
+}
+
+/// Shown in place of the tabs once a describe call found the resource gone
+fn draw_missing_resource(f: &mut Frame, area: Rect, service_type: ServiceType, resource_id: &str) {
+    let lines = vec![
+        Line::from(Span::styled(
+            format!("{} no longer exists", resource_id),
+            Style::default().fg(Color::Yellow),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(
+                "The {} resource was deleted or terminated outside NimbusCTL.",
+                service_type.display_name()
+            ),
+            Style::default().fg(Color::Gray),
+        )),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("Enter", Style::default().fg(Color::Green)),
+            Span::styled(
+                format!(" open the {} list   ", service_type.display_name()),
+                Style::default().fg(Color::Gray),
+            ),
+            Span::styled("Esc", Style::default().fg(Color::Green)),
+            Span::styled(" go back", Style::default().fg(Color::Gray)),
+        ]),
+    ];
+    f.render_widget(
+        Paragraph::new(lines).block(get_default_block("Resource Not Found")),
+        area,
+    );
+}
+
+fn draw_overview(
+    f: &mut Frame,
+    area: Rect,
+    app_state: &AppState,
+    service_type: ServiceType,
+    resource_id: &ResourceId,
+) -> u16 {
+    // Services without related resources get no section at all
+    let related_height = if RelatedResource::supported(service_type) {
+        let rows = app_state.related_resources.as_ref().map_or(1, Vec::len);
+        u16::try_from(rows.clamp(1, 6)).unwrap_or(6) + 2
+    } else {
+        0
+    };
+
+    let node_groups = app_state
+        .node_groups
+        .get(&(app_state.current_region.clone(), resource_id.clone()));
+    let node_groups_height = if service_type == ServiceType::EKS {
+        // Header row plus one row per group, or one line of status text
+        let rows = node_groups.map_or(1, |groups| groups.len().max(1) + 1);
+        u16::try_from(rows.min(8)).unwrap_or(8) + 2
+    } else {
+        0
+    };
+
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Min(0), Constraint::Length(8)])
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(related_height),
+            Constraint::Length(node_groups_height),
+            Constraint::Length(8),
+        ])
         .split(area);
 
-    // Draw resource information
+    // Actions stay pinned below while the information scrolls
+    draw_actions_panel(f, main_chunks[3], app_state);
+    if related_height > 0 {
+        draw_related_panel(f, main_chunks[1], app_state);
+    }
+    if node_groups_height > 0 {
+        draw_node_groups_panel(
+            f,
+            main_chunks[2],
+            node_groups,
+            app_state.selected_node_group,
+        );
+    }
     draw_resource_info(f, main_chunks[0], app_state, service_type, resource_id);
+    // The fields list follows the selected field rather than the scroll offset
+    0
+}
+
+/// One label/value pair of the Overview panel, selectable and copyable on its own
+#[derive(Debug, Clone)]
+pub struct DetailField {
+    pub label: &'static str,
+    pub value: String,
+    pub color: Color,
+}
+
+impl DetailField {
+    fn new(label: &'static str, value: impl Into<String>) -> Self {
+        Self {
+            label,
+            value: value.into(),
+            color: Color::White,
+        }
+    }
+
+    fn colored(label: &'static str, value: impl Into<String>, color: Color) -> Self {
+        Self {
+            label,
+            value: value.into(),
+            color,
+        }
+    }
+
+    /// Case-insensitive match on the label or the value
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.label.to_lowercase().contains(&query) || self.value.to_lowercase().contains(&query)
+    }
+}
+
+/// Every field the Overview panel shows for a resource, in display order
+pub fn overview_fields(
+    app_state: &AppState,
+    service_type: ServiceType,
+    resource_id: &ResourceId,
+) -> Vec<DetailField> {
+    match service_type {
+        ServiceType::EC2 => {
+            let mut fields = vec![
+                DetailField::new("Instance ID", resource_id),
+                state_field(app_state, service_type, resource_id),
+                DetailField::new("Launch Time", "2024-01-15 10:30:00"),
+            ];
+            fields.extend(instance_type_fields(app_state, resource_id));
+            fields.extend([
+                DetailField::new("Uptime", "23 days, 14 hours"),
+                DetailField::new("Availability Zone", "us-east-1a"),
+                DetailField::new("Platform", "Linux"),
+                DetailField::new("Public IP", "54.1.2.3"),
+                DetailField::new("Private IP", "10.0.1.5"),
+                DetailField::new("VPC", "vpc-12345678"),
+                DetailField::new("Subnet", "subnet-abcdef12"),
+                DetailField::new("Security Groups", "sg-web-servers, sg-default"),
+            ]);
+            fields
+        }
+        ServiceType::S3 => vec![
+            DetailField::new("Bucket Name", resource_id),
+            DetailField::new("Region", &app_state.current_region),
+            DetailField::new("Creation Date", "2024-01-01"),
+            DetailField::colored("Versioning", "Enabled", Color::Green),
+            DetailField::colored("Encryption", "Enabled", Color::Green),
+        ],
+        ServiceType::IAM => iam_fields(app_state, resource_id),
+        _ => vec![
+            DetailField::new("Resource ID", resource_id),
+            DetailField::new("Service", service_type.display_name()),
+            state_field(app_state, service_type, resource_id),
+            DetailField::new("Region", &app_state.current_region),
+        ],
+    }
+}
+
+/// The resource's state as last listed, in its state color
+fn state_field(
+    app_state: &AppState,
+    service_type: ServiceType,
+    resource_id: &ResourceId,
+) -> DetailField {
+    match app_state
+        .cached_resources(service_type)
+        .and_then(|resources| resources.iter().find(|r| &r.id == resource_id))
+        .map(|resource| resource.state.as_str())
+        .filter(|state| !state.is_empty())
+    {
+        Some(state) => DetailField::colored("State", state, state_color(state)),
+        None => DetailField::colored("State", "unknown", Color::DarkGray),
+    }
+}
+
+/// An instance's type with its vCPUs, memory and list price when the type is
+/// in the embedded table. The price is left out once Cost Explorer has
+/// reported that the profile has no billing access.
+fn instance_type_fields(app_state: &AppState, resource_id: &ResourceId) -> Vec<DetailField> {
+    let Some(instance_type) = app_state
+        .cached_resources(ServiceType::EC2)
+        .and_then(|resources| resources.iter().find(|r| &r.id == resource_id))
+        .and_then(|resource| resource.instance_type.as_deref())
+    else {
+        return vec![DetailField::colored("Type", "unknown", Color::DarkGray)];
+    };
+
+    let mut fields = vec![DetailField::new("Type", instance_type)];
+    let Some(spec) = instance_types::lookup(instance_type) else {
+        fields.push(DetailField::colored(
+            "Hardware",
+            "not in the instance type table",
+            Color::DarkGray,
+        ));
+        return fields;
+    };
+    fields.push(DetailField::new("vCPUs", spec.vcpus.to_string()));
+    fields.push(DetailField::new(
+        "Memory",
+        format!("{} GiB", spec.memory_gib),
+    ));
+    fields.push(match &app_state.cost_summary {
+        Some(Err(reason)) => DetailField::colored("Hourly Price", reason, Color::DarkGray),
+        _ => DetailField::new(
+            "Hourly Price",
+            format!(
+                "~${:.4}/hr on-demand ({} Linux list price)",
+                spec.hourly_usd, PRICE_REGION
+            ),
+        ),
+    });
+    fields
+}
 
-    // Draw actions
-    draw_actions_panel(f, main_chunks[1], app_state, service_type);
+fn iam_fields(app_state: &AppState, resource_id: &ResourceId) -> Vec<DetailField> {
+    let arn = app_state
+        .cached_resources(ServiceType::IAM)
+        .and_then(|resources| resources.iter().find(|r| &r.id == resource_id))
+        .map_or_else(String::new, |r| r.arn.clone());
+    if let Some(role_name) = iam::role_name(resource_id) {
+        return vec![
+            DetailField::new("Role Name", role_name),
+            DetailField::new("Type", "Role"),
+            DetailField::new("ARN", arn),
+        ];
+    }
+
+    let mut fields = vec![
+        DetailField::new("User Name", resource_id),
+        DetailField::new("Type", "User"),
+        DetailField::new("ARN", arn),
+    ];
+    let details = app_state
+        .iam_user_details
+        .get(&(app_state.current_region.clone(), resource_id.clone()));
+    match details {
+        Some(details) => {
+            let join = |items: &[String]| match items {
+                [] => "(none)".to_string(),
+                items => items.join(", "),
+            };
+            fields.push(DetailField::new("Groups", join(&details.groups)));
+            fields.push(DetailField::new(
+                "Attached Policies",
+                join(&details.attached_policies),
+            ));
+        }
+        None if app_state.user_config.aws.fetch_iam_user_details => {
+            fields.push(DetailField::colored("Groups", "loading…", Color::DarkGray));
+            fields.push(DetailField::colored(
+                "Attached Policies",
+                "loading…",
+                Color::DarkGray,
+            ));
+        }
+        None => {}
+    }
+    fields
 }
 
+/// The Overview fields as a list with the selected one highlighted; the list
+/// follows the selection, so the page scroll offset is not used
 fn draw_resource_info(
     f: &mut Frame,
     area: Rect,
@@ -55,189 +363,295 @@ fn draw_resource_info(
     service_type: ServiceType,
     resource_id: &ResourceId,
 ) {
-    let info_lines = match service_type {
-        ServiceType::EC2 => vec![
-            Line::from(vec![
-                Span::styled("Instance ID: ", Style::default().fg(Color::Gray)),
-                Span::styled(resource_id, Style::default().fg(Color::White)),
-            ]),
-            Line::from(vec![
-                Span::styled("State: ", Style::default().fg(Color::Gray)),
-                Span::styled("running", Style::default().fg(Color::Green)),
-                Span::raw("                "),
-                Span::styled("Launch Time: ", Style::default().fg(Color::Gray)),
-                Span::styled("2024-01-15 10:30:00", Style::default().fg(Color::White)),
-            ]),
-            Line::from(vec![
-                Span::styled("Type: ", Style::default().fg(Color::Gray)),
-                Span::styled("t3.medium", Style::default().fg(Color::White)),
-                Span::raw("               "),
-                Span::styled("Uptime: ", Style::default().fg(Color::Gray)),
-                Span::styled("23 days, 14 hours", Style::default().fg(Color::White)),
-            ]),
-            Line::from(vec![
-                Span::styled("Availability Zone: ", Style::default().fg(Color::Gray)),
-                Span::styled("us-east-1a", Style::default().fg(Color::White)),
-                Span::raw("         "),
-                Span::styled("Platform: ", Style::default().fg(Color::Gray)),
-                Span::styled("Linux", Style::default().fg(Color::White)),
-            ]),
-            Line::from(vec![
-                Span::styled("Public IP: ", Style::default().fg(Color::Gray)),
-                Span::styled("54.1.2.3", Style::default().fg(Color::White)),
-                Span::raw("          "),
-                Span::styled("Private IP: ", Style::default().fg(Color::Gray)),
-                Span::styled("10.0.1.5", Style::default().fg(Color::White)),
-            ]),
-            Line::from(vec![
-                Span::styled("VPC: ", Style::default().fg(Color::Gray)),
-                Span::styled("vpc-12345678", Style::default().fg(Color::White)),
-                Span::raw("             "),
-                Span::styled("Subnet: ", Style::default().fg(Color::Gray)),
-                Span::styled("subnet-abcdef12", Style::default().fg(Color::White)),
-            ]),
-            Line::from(vec![
-                Span::styled("Security Groups: ", Style::default().fg(Color::Gray)),
+    let fields = app_state.visible_detail_fields();
+    let items: Vec<ListItem> = fields
+        .iter()
+        .map(|field| {
+            ListItem::new(Line::from(vec![
                 Span::styled(
-                    "sg-web-servers, sg-default",
-                    Style::default().fg(Color::White),
+                    format!("{}: ", field.label),
+                    Style::default().fg(Color::Gray),
                 ),
-            ]),
-        ],
-        ServiceType::S3 => vec![
-            Line::from(vec![
-                Span::styled("Bucket Name: ", Style::default().fg(Color::Gray)),
-                Span::styled(resource_id, Style::default().fg(Color::White)),
-            ]),
-            Line::from(vec![
-                Span::styled("Region: ", Style::default().fg(Color::Gray)),
-                Span::styled(&app_state.current_region, Style::default().fg(Color::White)),
-            ]),
-            Line::from(vec![
-                Span::styled("Creation Date: ", Style::default().fg(Color::Gray)),
-                Span::styled("2024-01-01", Style::default().fg(Color::White)),
-            ]),
-            Line::from(vec![
-                Span::styled("Versioning: ", Style::default().fg(Color::Gray)),
-                Span::styled("Enabled", Style::default().fg(Color::Green)),
-            ]),
-            Line::from(vec![
-                Span::styled("Encryption: ", Style::default().fg(Color::Gray)),
-                Span::styled("Enabled", Style::default().fg(Color::Green)),
-            ]),
-        ],
-        _ => vec![
-            Line::from(vec![
-                Span::styled("Resource ID: ", Style::default().fg(Color::Gray)),
-                Span::styled(resource_id, Style::default().fg(Color::White)),
-            ]),
-            Line::from(vec![
-                Span::styled("Service: ", Style::default().fg(Color::Gray)),
-                Span::styled(
-                    service_type.display_name(),
-                    Style::default().fg(Color::White),
-                ),
-            ]),
-            Line::from(vec![
-                Span::styled("Region: ", Style::default().fg(Color::Gray)),
-                Span::styled(&app_state.current_region, Style::default().fg(Color::White)),
-            ]),
-        ],
+                Span::styled(field.value.as_str(), Style::default().fg(field.color)),
+            ]))
+        })
+        .collect();
+
+    let mut title = match app_state.describe_state(service_type, resource_id) {
+        LoadState::Loaded(resource) => format!("Resource: {}", resource.name),
+        LoadState::Loading => format!("Resource: {} (describing…)", resource_id),
+        LoadState::NotLoaded | LoadState::Failed(_) => format!("Resource: {}", resource_id),
     };
+    if app_state.detail_filter_editing {
+        title.push_str(&format!(" /{}▏", app_state.detail_filter));
+    } else if !app_state.detail_filter.is_empty() {
+        title.push_str(&format!(" (matching \"{}\")", app_state.detail_filter));
+    }
+
+    if items.is_empty() {
+        f.render_widget(
+            Paragraph::new(Span::styled(
+                "No fields match the search",
+                Style::default().fg(Color::DarkGray),
+            ))
+            .block(get_default_block(&title)),
+            area,
+        );
+        return;
+    }
+
+    let selected = app_state.detail_field.min(items.len() - 1);
+    let count = items.len();
+    let list = List::new(items)
+        .block(get_default_block(&title))
+        .highlight_style(Style::default().bg(Color::DarkGray));
+    let mut state = ListState::default().with_selected(Some(selected));
+    f.render_stateful_widget(list, area, &mut state);
 
-    let resource_name = match service_type {
-        ServiceType::EC2 => "web-server-prod",
-        ServiceType::S3 => "assets-prod-bucket",
-        _ => "Resource Details",
+    let visible_lines = usize::from(area.height.saturating_sub(2));
+    if count > visible_lines {
+        let mut scrollbar_state = ScrollbarState::new(count)
+            .position(selected)
+            .viewport_content_length(visible_lines);
+        f.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight),
+            area.inner(Margin {
+                vertical: 1,
+                horizontal: 0,
+            }),
+            &mut scrollbar_state,
+        );
+    }
+}
+
+/// Recent events, newest first, one line each
+fn draw_events(f: &mut Frame, area: Rect, events: Option<&Vec<ResourceEvent>>, scroll: u16) -> u16 {
+    let status = |text: &'static str| {
+        vec![Line::from(Span::styled(
+            text,
+            Style::default().fg(Color::Gray),
+        ))]
     };
+    let lines = match events {
+        None => status("Loading events..."),
+        Some(events) if events.is_empty() => status("No recent events"),
+        Some(events) => events
+            .iter()
+            .map(|event| {
+                let timestamp = event.timestamp.map_or_else(
+                    || "—".to_string(),
+                    |timestamp| timestamp.format("%Y-%m-%d %H:%M:%S").to_string(),
+                );
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:<21}", timestamp),
+                        Style::default().fg(Color::Gray),
+                    ),
+                    Span::styled(
+                        format!("{:<16}", event.source),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::styled(event.message.as_str(), Style::default().fg(Color::White)),
+                ])
+            })
+            .collect(),
+    };
+    draw_scrollable(f, area, lines, "Events", scroll)
+}
 
-    let title = format!("Resource: {}", resource_name);
-    let paragraph = Paragraph::new(info_lines).block(get_default_block(&title));
+/// Draw bordered lines scrolled to `scroll`, returning the largest useful offset
+fn draw_scrollable(f: &mut Frame, area: Rect, lines: Vec<Line>, title: &str, scroll: u16) -> u16 {
+    // Lines beyond the bordered area can only be reached by scrolling
+    let visible_lines = area.height.saturating_sub(2);
+    let content_lines = u16::try_from(lines.len()).unwrap_or(u16::MAX);
+    let max_scroll = content_lines.saturating_sub(visible_lines);
+    let scroll = scroll.min(max_scroll);
+
+    let paragraph = Paragraph::new(lines)
+        .block(get_default_block(title))
+        .scroll((scroll, 0));
 
     f.render_widget(paragraph, area);
+
+    if max_scroll > 0 {
+        let mut scrollbar_state = ScrollbarState::new(usize::from(max_scroll))
+            .position(usize::from(scroll))
+            .viewport_content_length(usize::from(visible_lines));
+        f.render_stateful_widget(
+            Scrollbar::new(ScrollbarOrientation::VerticalRight),
+            area.inner(Margin {
+                vertical: 1,
+                horizontal: 0,
+            }),
+            &mut scrollbar_state,
+        );
+    }
+
+    max_scroll
 }
 
-fn draw_actions_panel(f: &mut Frame, area: Rect, app_state: &AppState, service_type: ServiceType) {
-    let actions = get_service_actions(service_type);
+fn draw_related_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let block = get_default_block("Related Resources");
+    let related = match &app_state.related_resources {
+        None => {
+            let loading = Paragraph::new(Line::from(Span::styled(
+                "Loading...",
+                Style::default().fg(Color::Gray),
+            )));
+            f.render_widget(loading.block(block), area);
+            return;
+        }
+        Some(related) if related.is_empty() => {
+            let empty = Paragraph::new(Line::from(Span::styled(
+                "No related resources",
+                Style::default().fg(Color::Gray),
+            )));
+            f.render_widget(empty.block(block), area);
+            return;
+        }
+        Some(related) => related,
+    };
 
-    let action_lines: Vec<Line> = actions
+    let items: Vec<ListItem> = related
+        .iter()
+        .map(|resource| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<16}", resource.kind),
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::styled(resource.id.as_str(), Style::default().fg(Color::White)),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().bg(Color::DarkGray))
+        .highlight_symbol("► ");
+    let mut list_state = ListState::default().with_selected(app_state.related_index);
+    f.render_stateful_widget(list, area, &mut list_state);
+}
+
+fn draw_node_groups_panel(
+    f: &mut Frame,
+    area: Rect,
+    node_groups: Option<&Vec<EksNodeGroup>>,
+    selected: usize,
+) {
+    let block = get_default_block("Node Groups");
+    let message = match node_groups {
+        None => Some("Loading node groups..."),
+        Some(groups) if groups.is_empty() => {
+            Some("No managed node groups; the cluster may run on Fargate or self-managed nodes")
+        }
+        Some(_) => None,
+    };
+    if let Some(message) = message {
+        let paragraph = Paragraph::new(Line::from(Span::styled(
+            message,
+            Style::default().fg(Color::Gray),
+        )));
+        f.render_widget(paragraph.block(block), area);
+        return;
+    }
+
+    let rows: Vec<Row> = node_groups
         .into_iter()
-        .map(|action| {
-            Line::from(vec![
-                Span::styled(action.key, Style::default().fg(Color::Green)),
-                Span::raw(" "),
-                Span::styled(action.description, Style::default().fg(Color::White)),
+        .flatten()
+        .map(|group| {
+            let status_color = match group.status.as_str() {
+                "ACTIVE" => Color::Green,
+                "CREATING" | "UPDATING" | "DELETING" => Color::Yellow,
+                _ => Color::Red,
+            };
+            Row::new(vec![
+                Span::styled(group.name.as_str(), Style::default().fg(Color::White)),
+                Span::styled(group.status.as_str(), Style::default().fg(status_color)),
+                Span::styled(
+                    format!(
+                        "{} / {} / {}",
+                        group.desired_size, group.min_size, group.max_size
+                    ),
+                    Style::default().fg(Color::White),
+                ),
+                Span::styled(
+                    group.instance_types.join(", "),
+                    Style::default().fg(Color::White),
+                ),
+                Span::styled(
+                    group.capacity_type.as_deref().unwrap_or("-"),
+                    Style::default().fg(Color::Gray),
+                ),
             ])
         })
         .collect();
 
-    let paragraph = Paragraph::new(action_lines).block(get_default_block("Actions"));
-
-    f.render_widget(paragraph, area);
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Percentage(28),
+            Constraint::Length(12),
+            Constraint::Length(16),
+            Constraint::Min(12),
+            Constraint::Length(10),
+        ],
+    )
+    .header(
+        Row::new(vec![
+            "Name",
+            "Status",
+            "Desired/Min/Max",
+            "Instance Types",
+            "Capacity",
+        ])
+        .style(Style::default().fg(Color::Gray)),
+    )
+    .block(block)
+    .row_highlight_style(Style::default().bg(Color::DarkGray));
+    let mut table_state = TableState::default().with_selected(Some(selected));
+    f.render_stateful_widget(table, area, &mut table_state);
 }
 
-struct ServiceAction {
-    key: &'static str,
-    description: &'static str,
-}
+fn draw_actions_panel(f: &mut Frame, area: Rect, app_state: &AppState) {
+    let actions = app_state.resource_actions();
+    // One line each inside the borders, keeping the last for the menu hint
+    let rows = area.height.saturating_sub(3) as usize;
+    // An overflowing list gives up its last row to the "+N more" line
+    let shown = if actions.len() > rows {
+        rows.saturating_sub(1)
+    } else {
+        rows
+    };
 
-fn get_service_actions(service_type: ServiceType) -> Vec<ServiceAction> {
-    match service_type {
-        ServiceType::EC2 => vec![
-            ServiceAction {
-                key: "[S]",
-                description: "Stop Instance",
-            },
-            ServiceAction {
-                key: "[R]",
-                description: "Reboot Instance",
-            },
-            ServiceAction {
-                key: "[T]",
-                description: "Terminate Instance",
-            },
-            ServiceAction {
-                key: "[⭐]",
-                description: "Toggle Favorite",
-            },
-        ],
-        ServiceType::S3 => vec![
-            ServiceAction {
-                key: "[D]",
-                description: "Delete Bucket",
-            },
-            ServiceAction {
-                key: "[V]",
-                description: "View Objects",
-            },
-            ServiceAction {
-                key: "[⭐]",
-                description: "Toggle Favorite",
-            },
-        ],
-        ServiceType::RDS => vec![
-            ServiceAction {
-                key: "[S]",
-                description: "Stop Database",
-            },
-            ServiceAction {
-                key: "[R]",
-                description: "Reboot Database",
-            },
-            ServiceAction {
-                key: "[⭐]",
-                description: "Toggle Favorite",
-            },
-        ],
-        _ => vec![
-            ServiceAction {
-                key: "[⭐]",
-                description: "Toggle Favorite",
-            },
-            ServiceAction {
-                key: "[E]",
-                description: "Edit Resource",
-            },
-        ],
+    let mut action_lines: Vec<Line> = actions
+        .iter()
+        .take(shown)
+        .map(|command| {
+            Line::from(vec![
+                Span::raw(format!("{} ", command.icon)),
+                Span::styled(command.name.as_str(), Style::default().fg(Color::White)),
+            ])
+        })
+        .collect();
+    if actions.is_empty() {
+        action_lines.push(Line::from(Span::styled(
+            "No actions available right now",
+            Style::default().fg(Color::DarkGray),
+        )));
+    } else if actions.len() > shown {
+        action_lines.push(Line::from(Span::styled(
+            format!("+{} more", actions.len() - shown),
+            Style::default().fg(Color::DarkGray),
+        )));
     }
+    action_lines.push(Line::from(vec![
+        Span::styled("[a]", Style::default().fg(Color::Green)),
+        Span::raw(" "),
+        Span::styled("Open action menu", Style::default().fg(Color::White)),
+    ]));
+
+    let paragraph = Paragraph::new(action_lines).block(get_default_block("Actions"));
+
+    f.render_widget(paragraph, area);
 }
@@ -1,15 +1,20 @@
+use crate::app::load_state::LoadState;
 use crate::app::state::AppState;
 use crate::aws::types::ServiceType;
+use crate::config::user_config::StateFilter;
 use crate::ui::components::header;
+use crate::ui::components::spinner::spinner_frame;
 use crate::ui::layout::{create_header_layout, create_resource_list_layout};
-use crate::ui::styles::get_default_block;
+use crate::ui::styles::{get_default_block, state_color};
+use crate::utils::helpers::{format_duration, pad_to_width, truncate_to_width};
 use ratatui::{
-    layout::Rect,
-    style::{Color, Style},
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{List, ListItem, Paragraph},
     Frame,
 };
+use unicode_width::UnicodeWidthStr;
 
 pub fn draw_resource_list(
     f: &mut Frame,
@@ -24,6 +29,11 @@ pub fn draw_resource_list(
     let page_title = format!("{} Resources", service_type.display_name());
     header::draw_header(f, header_chunks[0], app_state, &page_title);
 
+    if app_state.is_permission_denied(service_type) {
+        draw_permission_denied(f, header_chunks[1], app_state, service_type);
+        return;
+    }
+
     // Use centralized resource list layout for main content
     let resource_chunks = create_resource_list_layout(header_chunks[1]);
     // resource_chunks: [list_area, detail_area] (60/40 split)
@@ -32,13 +42,57 @@ pub fn draw_resource_list(
     draw_resource_detail_panel(f, resource_chunks[1], app_state, service_type);
 }
 
+/// Empty state for a service the active credentials cannot list
+fn draw_permission_denied(
+    f: &mut Frame,
+    area: Rect,
+    app_state: &AppState,
+    service_type: ServiceType,
+) {
+    let lines = vec![
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            format!("🔒 No permission to list {}", service_type.display_name()),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            format!(
+                "Profile '{}' was denied access in {}.",
+                app_state.current_profile, app_state.current_region
+            ),
+            Style::default().fg(Color::Gray),
+        )]),
+        Line::from(vec![Span::styled(
+            "Its IAM policy needs read access to this service; other services are unaffected.",
+            Style::default().fg(Color::Gray),
+        )]),
+        Line::from(""),
+        Line::from(vec![Span::styled(
+            "Switch profile from the command palette (Ctrl+O) to try again",
+            Style::default().fg(Color::Green),
+        )]),
+    ];
+
+    let title = format!("🔒 {} Resources", service_type.display_name());
+    let paragraph = Paragraph::new(lines)
+        .alignment(Alignment::Center)
+        .block(get_default_block(&title));
+    f.render_widget(paragraph, area);
+}
+
 fn draw_resource_list_panel(
     f: &mut Frame,
     area: Rect,
     app_state: &AppState,
     service_type: ServiceType,
 ) {
-    let resources = get_mock_resources(service_type);
+    let resources = app_state.visible_resources(service_type);
+    let marked = app_state.marked_for(service_type);
+    // Inside the borders
+    let row_width = area.width.saturating_sub(2) as usize;
 
     let items: Vec<ListItem> = resources
         .into_iter()
@@ -49,15 +103,100 @@ fn draw_resource_list_panel(
             } else {
                 Style::default()
             };
+            let is_marked = marked.contains(&resource.id);
+            let marker = if is_marked { "✓" } else { "►" };
+            let color = if is_marked { Color::Cyan } else { Color::White };
 
-            ListItem::new(Line::from(vec![Span::styled(
-                format!("► {}", resource.id),
-                style.fg(Color::White),
-            )]))
+            let progress = app_state.cluster_creation(&resource.id).map(|creation| {
+                let elapsed =
+                    chrono::Duration::from_std(creation.started_at.elapsed()).unwrap_or_default();
+                format!(
+                    "  {} {}… {}",
+                    spinner_frame(),
+                    creation.status().to_lowercase(),
+                    format_duration(&elapsed)
+                )
+            });
+            // Creation progress stands in for the state while a cluster is created
+            let (suffix, suffix_color) = match progress {
+                Some(progress) => (progress, Color::Yellow),
+                None if resource.state.is_empty() => (String::new(), Color::Gray),
+                None => (
+                    format!("  {}", resource.state),
+                    state_color(&resource.state),
+                ),
+            };
+            // The id gives way to the state or progress when the row is too
+            // narrow, and is padded so states line up at the right edge
+            let id_width = row_width
+                .saturating_sub(marker.width() + 1)
+                .saturating_sub(suffix.width());
+
+            let mut spans = vec![Span::styled(
+                format!("{} {}", marker, pad_to_width(&resource.id, id_width)),
+                style.fg(color),
+            )];
+            if !suffix.is_empty() {
+                spans.push(Span::styled(suffix, style.fg(suffix_color)));
+            }
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
-    let title = format!("{} Resources", service_type.display_name());
+    let mut title = match app_state.zone_filters.get(&service_type) {
+        Some(zone) => format!("{} Resources ({})", service_type.display_name(), zone),
+        None => format!("{} Resources", service_type.display_name()),
+    };
+    let state_filter = app_state
+        .user_config
+        .resource_list
+        .state_filter(service_type);
+    if state_filter != StateFilter::All {
+        title.push_str(&format!(" [{}]", state_filter.label()));
+    }
+    if service_type == ServiceType::IAM {
+        if let Some(resources) = app_state.cached_resources(service_type) {
+            let roles = resources.iter().filter(|r| r.state == "Role").count();
+            title.push_str(&format!(
+                " - {} users, {} roles",
+                resources.len() - roles,
+                roles
+            ));
+            if app_state.is_paginating_iam() {
+                title.push_str(&format!(" {} loading more", spinner_frame()));
+            }
+        }
+    }
+    if !marked.is_empty() {
+        title.push_str(&format!(" - {} marked", marked.len()));
+    }
+    if items.is_empty() {
+        let gray = Style::default().fg(Color::Gray);
+        let (message, style) = match app_state.listing(service_type) {
+            LoadState::NotLoaded => ("Not loaded yet".to_string(), gray),
+            LoadState::Loading => (format!("{} Loading…", spinner_frame()), gray),
+            LoadState::Failed(error) => (
+                format!("Failed to load: {}", error),
+                Style::default().fg(Color::Red),
+            ),
+            LoadState::Loaded(resources) if resources.is_empty() => {
+                ("No resources found".to_string(), gray)
+            }
+            LoadState::Loaded(resources) => (
+                format!(
+                    "All {} resources are hidden by the active filters",
+                    resources.len()
+                ),
+                gray,
+            ),
+        };
+        let paragraph = Paragraph::new(Line::from(Span::styled(message, style)))
+            .alignment(Alignment::Center)
+            .block(get_default_block(&title));
+        f.render_widget(paragraph, area);
+        return;
+    }
+
     let list = List::new(items)
         .block(get_default_block(&title))
         .highlight_style(Style::default().bg(Color::DarkGray));
@@ -71,7 +210,8 @@ fn draw_resource_detail_panel(
     app_state: &AppState,
     service_type: ServiceType,
 ) {
-    let resources = get_mock_resources(service_type);
+    let resources = app_state.visible_resources(service_type);
+    let value_width = area.width.saturating_sub(2) as usize;
 
     let detail_lines = if let Some(resource) = resources.get(app_state.selected_resource_index) {
         vec![
@@ -80,7 +220,7 @@ fn draw_resource_detail_panel(
                 Style::default().fg(Color::Gray),
             )]),
             Line::from(vec![Span::styled(
-                &resource.id,
+                truncate_to_width(&resource.id, value_width),
                 Style::default().fg(Color::White),
             )]),
             Line::from(""),
@@ -89,7 +229,7 @@ fn draw_resource_detail_panel(
                 Style::default().fg(Color::Gray),
             )]),
             Line::from(vec![Span::styled(
-                &resource.name,
+                truncate_to_width(&resource.name, value_width),
                 Style::default().fg(Color::White),
             )]),
             Line::from(""),
@@ -98,8 +238,8 @@ fn draw_resource_detail_panel(
                 Style::default().fg(Color::Gray),
             )]),
             Line::from(vec![Span::styled(
-                &resource.state,
-                Style::default().fg(Color::Green),
+                truncate_to_width(&resource.state, value_width),
+                Style::default().fg(state_color(&resource.state)),
             )]),
             Line::from(""),
             Line::from(vec![Span::styled(
@@ -135,74 +275,3 @@ fn draw_resource_detail_panel(
 
     f.render_widget(paragraph, area);
 }
-
-#[derive(Clone)]
-struct MockResource {
-    id: String,
-    name: String,
-    state: String,
-    region: String,
-}
-
-fn get_mock_resources(service_type: ServiceType) -> Vec<MockResource> {
-    match service_type {
-        ServiceType::EC2 => vec![
-            MockResource {
-                id: "i-1234567890abcdef0".to_string(),
-                name: "web-server-prod".to_string(),
-                state: "running".to_string(),
-                region: "us-east-1".to_string(),
-            },
-            MockResource {
-                id: "i-0987654321fedcba9".to_string(),
-                name: "api-server-prod".to_string(),
-                state: "running".to_string(),
-                region: "us-east-1".to_string(),
-            },
-            MockResource {
-                id: "i-abcdef1234567890".to_string(),
-                name: "background-worker".to_string(),
-                state: "stopped".to_string(),
-                region: "us-east-1".to_string(),
-            },
-        ],
-        ServiceType::S3 => vec![
-            MockResource {
-                id: "assets-prod-bucket".to_string(),
-                name: "assets-prod-bucket".to_string(),
-                state: "active".to_string(),
-                region: "us-east-1".to_string(),
-            },
-            MockResource {
-                id: "logs-bucket".to_string(),
-                name: "logs-bucket".to_string(),
-                state: "active".to_string(),
-                region: "us-east-1".to_string(),
-            },
-        ],
-        ServiceType::RDS => vec![MockResource {
-            id: "db-prod-mysql".to_string(),
-            name: "production-database".to_string(),
-            state: "available".to_string(),
-            region: "us-east-1".to_string(),
-        }],
-        ServiceType::IAM => vec![MockResource {
-            id: "user-1".to_string(),
-            name: "admin-user".to_string(),
-            state: "active".to_string(),
-            region: "global".to_string(),
-        }],
-        ServiceType::Secrets => vec![MockResource {
-            id: "secret-1".to_string(),
-            name: "db-password".to_string(),
-            state: "active".to_string(),
-            region: "us-east-1".to_string(),
-        }],
-        ServiceType::EKS => vec![MockResource {
-            id: "cluster-1".to_string(),
-            name: "production-cluster".to_string(),
-            state: "active".to_string(),
-            region: "us-east-1".to_string(),
-        }],
-    }
-}
@@ -9,6 +9,7 @@ use ratatui::{
     widgets::Paragraph,
     Frame,
 };
+use std::time::Duration;
 
 pub fn draw_settings(f: &mut Frame, area: Rect, app_state: &AppState) {
     // Use centralized header layout function
@@ -45,9 +46,16 @@ fn draw_aws_settings(f: &mut Frame, area: Rect, app_state: &AppState) {
             ),
         ]),
         Line::from(vec![
-            Span::styled("Auto Refresh: ", Style::default().fg(Color::Gray)),
+            Span::styled("List Refresh: ", Style::default().fg(Color::Gray)),
             Span::styled(
-                format!("{}s", app_state.user_config.aws.auto_refresh_interval),
+                refresh_label(app_state.user_config.list_refresh_interval()),
+                Style::default().fg(Color::White),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Detail Refresh: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                refresh_label(app_state.user_config.detail_refresh_interval()),
                 Style::default().fg(Color::White),
             ),
         ]),
@@ -61,6 +69,7 @@ fn draw_aws_settings(f: &mut Frame, area: Rect, app_state: &AppState) {
                     .to_string(),
                 Style::default().fg(Color::White),
             ),
+            Span::styled("  (+/- to change)", Style::default().fg(Color::DarkGray)),
         ]),
     ];
 
@@ -69,6 +78,13 @@ fn draw_aws_settings(f: &mut Frame, area: Rect, app_state: &AppState) {
     f.render_widget(paragraph, area);
 }
 
+fn refresh_label(interval: Option<Duration>) -> String {
+    match interval {
+        Some(interval) => format!("{}s", interval.as_secs()),
+        None => "Off".to_string(),
+    }
+}
+
 fn draw_display_settings(f: &mut Frame, area: Rect, app_state: &AppState) {
     let display_lines = vec![
         Line::from(vec![
@@ -201,6 +217,17 @@ fn draw_behavior_settings(f: &mut Frame, area: Rect, app_state: &AppState) {
                 Style::default().fg(Color::White),
             ),
         ]),
+        Line::from(vec![
+            Span::styled("Pause When Unfocused: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                if app_state.user_config.behavior.pause_when_unfocused {
+                    "Yes"
+                } else {
+                    "No"
+                },
+                Style::default().fg(Color::White),
+            ),
+        ]),
     ];
 
     let paragraph = Paragraph::new(behavior_lines).block(get_default_block("Behavior Settings"));
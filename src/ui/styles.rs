@@ -77,12 +77,27 @@ pub fn get_service_color(service: &str) -> Color {
     }
 }
 
-pub fn get_state_color(state: &str) -> Color {
+/// Dim red for resources that are gone or on their way out
+const ENDED_STATE_COLOR: Color = Color::Indexed(88);
+
+/// The color a resource state is drawn in, the same in lists, detail pages
+/// and dashboard counts: healthy green, stopping yellow, stopped or failed
+/// red, in-progress blue, and ending dim red. Case-insensitive, so EKS's
+/// `ACTIVE` matches EC2's `running`; unknown states are gray.
+pub fn state_color(state: &str) -> Color {
     match state.to_lowercase().as_str() {
-        "running" | "active" | "available" | "ok" => Color::Green,
-        "stopped" | "inactive" | "unavailable" | "error" => Color::Red,
-        "starting" | "stopping" | "pending" | "warning" => Color::Yellow,
-        "terminated" | "deleted" => Color::DarkGray,
+        "running" | "available" | "active" | "ok" | "in-use" | "enabled" => Color::Green,
+        "stopping" | "warning" => Color::Yellow,
+        "stopped"
+        | "inactive"
+        | "unavailable"
+        | "failed"
+        | "error"
+        | "incompatible-network"
+        | "storage-full" => Color::Red,
+        "pending" | "creating" | "starting" | "updating" | "modifying" | "rebooting"
+        | "backing-up" | "upgrading" | "rotating" => Color::Blue,
+        "terminated" | "shutting-down" | "deleting" | "deleted" => ENDED_STATE_COLOR,
         _ => Color::Gray,
     }
 }
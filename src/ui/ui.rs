@@ -1,12 +1,36 @@
+use crate::app::operations::ProgressDisplay;
 use crate::app::state::{AppPage, AppState};
-use crate::ui::components::{command_palette, help_panel, quick_nav};
-use crate::ui::layout::create_main_layout;
-use crate::ui::pages::{dashboard, resource_detail, resource_list, settings};
-use ratatui::Frame;
+use crate::ui::components::{
+    action_menu, assume_role_form, batch_results, bucket_form, cheat_sheet_form, cluster_form,
+    command_explanation, command_palette, confirm_dialog, favorite_note_form, favorites_overlay,
+    help_panel, instance_form, log_tail, node_group_size_form, notification, notification_overlay,
+    permission_report, preset_form, profile_comparison, profile_validation, progress_bar,
+    quick_nav, raw_inspector, resource_changes, resource_export_form, secret_form, security_groups,
+    setup_wizard, status_bar, tag_edit_form, view_state_form, which_key, zone_picker,
+};
+use crate::ui::layout::{self, create_main_layout};
+use crate::ui::pages::{activity_log, dashboard, resource_detail, resource_list, settings};
+use ratatui::{
+    layout::Alignment,
+    style::{Color, Style},
+    text::Line,
+    widgets::{Paragraph, Wrap},
+    Frame,
+};
 
 pub fn draw_ui(f: &mut Frame, app_state: &mut AppState) {
+    // Fixed-height headers leave no room for content below this size
+    if layout::is_too_small(f.area()) {
+        draw_too_small(f);
+        return;
+    }
+
     // Use centralized main layout function
-    let main_chunks = create_main_layout(f.area());
+    let keys_pending = !app_state.pending_keys.is_empty();
+    let show_status_bar = keys_pending
+        || app_state.operations.is_busy()
+        || app_state.credentials_time_left().is_some();
+    let main_chunks = create_main_layout(f.area(), show_status_bar);
 
     // Draw main content based on current page
     match &app_state.current_page {
@@ -17,17 +41,37 @@ pub fn draw_ui(f: &mut Frame, app_state: &mut AppState) {
             resource_list::draw_resource_list(f, main_chunks[0], app_state, *service_type);
         }
         AppPage::ResourceDetail(service_type, resource_id) => {
-            resource_detail::draw_resource_detail(
+            let max_scroll = resource_detail::draw_resource_detail(
                 f,
                 main_chunks[0],
                 app_state,
                 *service_type,
                 resource_id,
             );
+            // Keep the offset reachable so scrolling back up responds immediately
+            app_state.detail_scroll = app_state.detail_scroll.min(max_scroll);
         }
         AppPage::Settings => {
             settings::draw_settings(f, main_chunks[0], app_state);
         }
+        AppPage::ActivityLog => {
+            let max_scroll = activity_log::draw_activity_log(f, main_chunks[0], app_state);
+            app_state.activity_scroll = app_state.activity_scroll.min(max_scroll);
+        }
+    }
+
+    if show_status_bar {
+        status_bar::draw_status_bar(f, main_chunks[1], app_state);
+    }
+    if keys_pending {
+        which_key::draw_which_key(f, main_chunks[0], app_state);
+    }
+
+    match &app_state.notification_overlay {
+        Some(overlay) => {
+            notification_overlay::draw_notification_overlay(f, overlay, &app_state.notifications)
+        }
+        None => notification::draw_notifications(f, main_chunks[0], &app_state.notifications),
     }
 
     // Draw help panel if visible
@@ -44,4 +88,160 @@ pub fn draw_ui(f: &mut Frame, app_state: &mut AppState) {
     if app_state.command_palette.is_visible() {
         command_palette::draw_command_palette(f, app_state);
     }
+
+    if let Some(command) = &app_state.explained_command {
+        command_explanation::draw_command_explanation(f, command);
+    }
+
+    if let Some(menu) = &app_state.action_menu {
+        action_menu::draw_action_menu(f, menu);
+    }
+
+    if let Some(form) = &app_state.secret_form {
+        secret_form::draw_secret_form(f, form);
+    }
+
+    if let Some(form) = &app_state.bucket_form {
+        bucket_form::draw_bucket_form(f, form, &app_state.current_region);
+    }
+
+    if let Some(form) = &app_state.cluster_form {
+        cluster_form::draw_cluster_form(f, form, &app_state.current_region);
+    }
+
+    if let Some(form) = &app_state.assume_role_form {
+        assume_role_form::draw_assume_role_form(f, form, &app_state.current_profile);
+    }
+
+    if let Some(form) = &app_state.instance_form {
+        instance_form::draw_instance_form(f, form, &app_state.current_region);
+    }
+
+    if let Some(form) = &app_state.view_state_form {
+        view_state_form::draw_view_state_form(f, form);
+    }
+
+    if let Some(form) = &app_state.node_group_size_form {
+        node_group_size_form::draw_node_group_size_form(f, form);
+    }
+
+    if let Some(picker) = &app_state.zone_picker {
+        let active = app_state.zone_filters.get(&picker.service_type);
+        zone_picker::draw_zone_picker(f, picker, active);
+    }
+
+    if let Some((left, right)) = &app_state.profile_comparison {
+        profile_comparison::draw_profile_comparison(f, left, right);
+    }
+
+    if let Some(validation) = &app_state.profile_validation {
+        profile_validation::draw_profile_validation(
+            f,
+            validation,
+            &mut app_state.profile_validation_scroll,
+        );
+    }
+
+    if app_state.permission_report_visible {
+        if let Some(report) = app_state.permission_reports.get(&app_state.current_profile) {
+            permission_report::draw_permission_report(f, report);
+        }
+    }
+
+    if let Some(inspector) = &mut app_state.raw_inspector {
+        raw_inspector::draw_raw_inspector(f, inspector);
+    }
+
+    if let Some(form) = &app_state.cheat_sheet_form {
+        cheat_sheet_form::draw_cheat_sheet_form(f, form);
+    }
+
+    if let Some(form) = &app_state.resource_export_form {
+        let total = app_state
+            .cached_resources(form.service_type)
+            .map_or(0, Vec::len);
+        resource_export_form::draw_resource_export_form(
+            f,
+            form,
+            &app_state.active_list_filters(form.service_type),
+            app_state.visible_resources(form.service_type).len(),
+            total,
+        );
+    }
+
+    if let Some(form) = &app_state.favorite_note_form {
+        favorite_note_form::draw_favorite_note_form(f, form);
+    }
+
+    if let Some(form) = &app_state.tag_edit_form {
+        tag_edit_form::draw_tag_edit_form(f, form);
+    }
+
+    if let Some(form) = &app_state.preset_form {
+        preset_form::draw_preset_form(f, form);
+    }
+
+    if let Some(changes) = &mut app_state.resource_changes {
+        resource_changes::draw_resource_changes(f, changes);
+    }
+
+    if let Some(overlay) = &app_state.favorites_overlay {
+        favorites_overlay::draw_favorites_overlay(
+            f,
+            overlay,
+            &app_state.favorite_resources(),
+            &app_state.favorite_commands(),
+        );
+    }
+
+    if let Some(tail) = &mut app_state.log_tail {
+        log_tail::draw_log_tail(f, tail);
+    }
+
+    if let Some(inspector) = &mut app_state.security_group_inspector {
+        security_groups::draw_security_groups(f, inspector);
+    }
+
+    if let Some(results) = &app_state.batch_results {
+        batch_results::draw_batch_results(f, results, &mut app_state.batch_results_scroll);
+    }
+
+    if let Some((label, fraction)) = app_state.operations.progress(ProgressDisplay::Overlay) {
+        progress_bar::draw_progress_overlay(f, label, fraction);
+    }
+
+    if let Some(wizard) = &app_state.setup_wizard {
+        setup_wizard::draw_setup_wizard(f, wizard);
+    }
+
+    // Draw confirmation dialog on top of everything else
+    if let Some(confirmation) = &app_state.pending_confirmation {
+        confirm_dialog::draw_confirm_dialog(f, confirmation);
+    }
+}
+
+fn draw_too_small(f: &mut Frame) {
+    let area = f.area();
+    let lines = vec![
+        Line::from(format!(
+            "Terminal too small (need at least {}x{})",
+            layout::MIN_WIDTH,
+            layout::MIN_HEIGHT
+        )),
+        Line::from(format!("Current size: {}x{}", area.width, area.height)),
+    ];
+    // Center vertically when there is room for it
+    let top = area.height.saturating_sub(lines.len() as u16) / 2;
+    let message_area = ratatui::layout::Rect {
+        y: area.y + top,
+        height: area.height - top,
+        ..area
+    };
+    f.render_widget(
+        Paragraph::new(lines)
+            .style(Style::default().fg(Color::Yellow))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        message_area,
+    );
 }
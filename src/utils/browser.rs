@@ -0,0 +1,23 @@
+use crate::utils::clipboard::copy_to_clipboard;
+use crate::utils::error::Result;
+
+/// How a URL was handed off to the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlHandoff {
+    /// Opened in the default browser
+    Opened,
+    /// No browser could be launched (e.g. headless), copied to the clipboard instead
+    Copied,
+}
+
+/// Open a URL in the default browser, falling back to the clipboard
+pub fn open_url_or_copy(url: &str) -> Result<UrlHandoff> {
+    match open::that(url) {
+        Ok(()) => Ok(UrlHandoff::Opened),
+        Err(e) => {
+            tracing::debug!("Failed to launch browser: {}", e);
+            copy_to_clipboard(url)?;
+            Ok(UrlHandoff::Copied)
+        }
+    }
+}
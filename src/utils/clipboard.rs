@@ -0,0 +1,11 @@
+use crate::utils::error::{AppError, Result};
+
+/// Copy text to the system clipboard
+pub fn copy_to_clipboard(text: &str) -> Result<()> {
+    let mut clipboard = arboard::Clipboard::new()
+        .map_err(|e| AppError::General(format!("Clipboard unavailable: {}", e)))?;
+
+    clipboard
+        .set_text(text.to_string())
+        .map_err(|e| AppError::General(format!("Failed to copy to clipboard: {}", e)))
+}
@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -17,18 +18,44 @@ pub enum AppError {
     #[error("Configuration error: {0}")]
     Config(String),
 
+    #[error("Failed to parse {}: {message}", path.display())]
+    Ini { path: PathBuf, message: String },
+
+    #[error("Invalid configuration file: {0}")]
+    TomlParse(#[from] toml::de::Error),
+
+    #[error("Failed to serialize configuration: {0}")]
+    TomlSerialize(#[from] toml::ser::Error),
+
     #[error("Profile error: {0}")]
     Profile(String),
 
     #[error("Resource not found: {0}")]
     ResourceNotFound(String),
 
+    #[error("{0} already exists")]
+    ResourceExists(String),
+
+    #[error(
+        "Rotation is not set up for secret {0}; attach a rotation Lambda in Secrets Manager first"
+    )]
+    RotationNotConfigured(String),
+
+    #[error("CloudWatch logging is not enabled for {0}; turn on log exports for it first")]
+    LoggingNotEnabled(String),
+
+    #[error("Access denied: {0}")]
+    AccessDenied(String),
+
     #[error("Authentication error: {0}")]
     Auth(String),
 
     #[error("Network error: {0}")]
     Network(String),
 
+    #[error("{0}")]
+    Validation(String),
+
     #[error("Parsing error: {0}")]
     Parse(String),
 
@@ -36,6 +63,75 @@ pub enum AppError {
     General(String),
 }
 
+impl AppError {
+    /// Whether the error came from a file that exists but could not be parsed
+    pub fn is_parse_error(&self) -> bool {
+        matches!(
+            self,
+            AppError::Ini { .. } | AppError::TomlParse(_) | AppError::Serde(_) | AppError::Parse(_)
+        )
+    }
+
+    /// Whether the credentials lack permission for the call (`AccessDenied`,
+    /// `UnauthorizedOperation`), as opposed to a failure worth retrying
+    pub fn is_permission_error(&self) -> bool {
+        match self {
+            AppError::AccessDenied(_) => true,
+            AppError::AwsSdk(message) => {
+                message.contains("AccessDenied") || message.contains("UnauthorizedOperation")
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the service reported the resource as gone (`NotFound`,
+    /// `NoSuchBucket`, `NoSuchEntity`), e.g. after it was deleted elsewhere
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            AppError::ResourceNotFound(_) => true,
+            AppError::AwsSdk(message) => {
+                message.contains("NotFound")
+                    || message.contains("NoSuchBucket")
+                    || message.contains("NoSuchEntity")
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the machine could not reach AWS at all (DNS failure, refused
+    /// or timed out connections), rather than AWS rejecting the request
+    pub fn is_connectivity_error(&self) -> bool {
+        match self {
+            AppError::Network(_) => true,
+            AppError::AwsSdk(message) => {
+                let message = message.to_lowercase();
+                [
+                    "dispatch failure",
+                    "dns error",
+                    "connection refused",
+                    "timed out",
+                ]
+                .iter()
+                .any(|pattern| message.contains(pattern))
+            }
+            _ => false,
+        }
+    }
+
+    /// Short reason a region could not be reached, for per-region summaries
+    pub fn region_failure_reason(&self) -> String {
+        match self {
+            AppError::Network(_) => "endpoint unreachable".to_string(),
+            AppError::AwsSdk(message)
+                if message.contains("OptInRequired") || message.contains("AuthFailure") =>
+            {
+                "region not enabled".to_string()
+            }
+            other => other.to_string(),
+        }
+    }
+}
+
 impl From<Box<dyn std::error::Error + Send + Sync>> for AppError {
     fn from(err: Box<dyn std::error::Error + Send + Sync>) -> Self {
         AppError::General(err.to_string())
@@ -1,5 +1,8 @@
 use chrono::{DateTime, Utc};
+use std::borrow::Cow;
 use std::time::SystemTime;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 pub fn format_timestamp(timestamp: &DateTime<Utc>) -> String {
     timestamp.format("%Y-%m-%d %H:%M:%S UTC").to_string()
@@ -48,12 +51,48 @@ pub fn extract_region_from_arn(arn: &str) -> crate::utils::error::Result<String>
     }
 }
 
-pub fn truncate_string(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()
-    } else {
-        format!("{}...", &s[..max_len.saturating_sub(3)])
+/// `text` cut to at most `width` terminal columns, ending in `…` when
+/// shortened. Wide characters (CJK, emoji) count as two columns and are never
+/// split; a grapheme cluster (a letter with its accents, a ZWJ emoji
+/// sequence) is kept or dropped whole.
+pub fn truncate_to_width(text: &str, width: usize) -> Cow<'_, str> {
+    if text.width() <= width {
+        return Cow::Borrowed(text);
+    }
+    if width == 0 {
+        return Cow::Borrowed("");
+    }
+
+    // One column is kept for the ellipsis
+    let budget = width - 1;
+    let mut used = 0;
+    let mut end = 0;
+    for (i, grapheme) in text.grapheme_indices(true) {
+        let grapheme_width = grapheme.width();
+        if used + grapheme_width > budget {
+            break;
+        }
+        used += grapheme_width;
+        end = i + grapheme.len();
     }
+    Cow::Owned(format!("{}…", &text[..end]))
+}
+
+/// Remove the last user-perceived character from `text`, so backspace deletes
+/// `é` typed as `e` plus a combining accent, or a multi-codepoint emoji, in
+/// one keypress
+pub fn pop_grapheme(text: &mut String) {
+    if let Some((i, _)) = text.grapheme_indices(true).next_back() {
+        text.truncate(i);
+    }
+}
+
+/// `text` truncated to `width` columns and padded with spaces to exactly
+/// `width`, so columns after it line up
+pub fn pad_to_width(text: &str, width: usize) -> String {
+    let truncated = truncate_to_width(text, width);
+    let padding = width.saturating_sub(truncated.width());
+    format!("{}{}", truncated, " ".repeat(padding))
 }
 
 pub fn system_time_to_datetime(time: SystemTime) -> DateTime<Utc> {
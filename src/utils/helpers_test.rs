@@ -0,0 +1,79 @@
+use crate::utils::helpers::{pad_to_width, pop_grapheme, truncate_to_width};
+use unicode_width::UnicodeWidthStr;
+
+#[test]
+fn text_that_fits_is_unchanged() {
+    assert_eq!(truncate_to_width("i-0abc", 6), "i-0abc");
+    assert_eq!(truncate_to_width("日本", 4), "日本");
+    assert_eq!(truncate_to_width("anything", 0), "");
+    assert_eq!(truncate_to_width("", 0), "");
+}
+
+#[test]
+fn ascii_is_cut_with_an_ellipsis() {
+    assert_eq!(truncate_to_width("my-long-bucket-name", 8), "my-long…");
+    assert_eq!(truncate_to_width("abc", 1), "…");
+}
+
+#[test]
+fn wide_cjk_characters_are_never_split() {
+    // Each character is two columns wide
+    let text = "日本語のバケット";
+    assert_eq!(text.width(), 16);
+
+    assert_eq!(truncate_to_width(text, 7), "日本語…");
+    // An odd budget leaves a column free rather than splitting a character
+    assert_eq!(truncate_to_width(text, 6), "日本…");
+    assert!(truncate_to_width(text, 6).width() <= 6);
+}
+
+#[test]
+fn emoji_count_as_two_columns() {
+    let text = "🚀🚀🚀 launch";
+    assert_eq!(truncate_to_width(text, 5), "🚀🚀…");
+    assert_eq!(truncate_to_width(text, 4), "🚀…");
+}
+
+#[test]
+fn combining_marks_stay_with_their_base_character() {
+    // "e" followed by a combining acute accent is one column
+    let text = "cafe\u{301}-bar";
+    assert_eq!(truncate_to_width(text, 6), "cafe\u{301}-…");
+}
+
+#[test]
+fn padded_columns_line_up_with_mixed_widths() {
+    let cells = ["i-0abc", "日本語のインスタンス", "🚀 rocket-service", "db"];
+    for cell in cells {
+        assert_eq!(pad_to_width(cell, 10).width(), 10, "cell {:?}", cell);
+    }
+    assert_eq!(pad_to_width("日本語のインスタンス", 10), "日本語の… ");
+    assert_eq!(pad_to_width("db", 4), "db  ");
+}
+
+#[test]
+fn backspace_removes_accented_letters_whole() {
+    let mut precomposed = String::from("café");
+    pop_grapheme(&mut precomposed);
+    assert_eq!(precomposed, "caf");
+
+    // "e" followed by a combining acute accent goes in one keypress
+    let mut combining = String::from("cafe\u{301}");
+    pop_grapheme(&mut combining);
+    assert_eq!(combining, "caf");
+}
+
+#[test]
+fn backspace_removes_emoji_sequences_whole() {
+    let mut family = String::from("team-👨\u{200d}👩\u{200d}👧");
+    pop_grapheme(&mut family);
+    assert_eq!(family, "team-");
+
+    let mut flag = String::from("eu-🇮🇪");
+    pop_grapheme(&mut flag);
+    assert_eq!(flag, "eu-");
+
+    let mut empty = String::new();
+    pop_grapheme(&mut empty);
+    assert_eq!(empty, "");
+}
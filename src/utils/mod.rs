@@ -1,2 +1,12 @@
+pub mod browser;
+pub mod clipboard;
 pub mod error;
 pub mod helpers;
+pub mod shell;
+
+#[cfg(test)]
+mod helpers_test;
+#[cfg(test)]
+mod shell_test;
+#[cfg(test)]
+pub mod temp_dir;
@@ -0,0 +1,73 @@
+use crate::utils::error::{AppError, Result};
+
+/// Substitute `{name}` placeholders, quoting each value so it is passed to the
+/// shell as a single literal argument. The template is scanned once, so a
+/// value that itself contains `{name}` is never expanded again; unknown
+/// placeholders are left as written.
+pub fn render_command_template(template: &str, values: &[(&str, &str)]) -> String {
+    let mut command = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        command.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let value = after.find('}').and_then(|close| {
+            values
+                .iter()
+                .find(|(name, _)| *name == &after[..close])
+                .map(|(_, value)| (close, value))
+        });
+        match value {
+            Some((close, value)) => {
+                command.push_str(&shell_quote(value));
+                rest = &after[close + 1..];
+            }
+            None => {
+                command.push('{');
+                rest = after;
+            }
+        }
+    }
+    command.push_str(rest);
+    command
+}
+
+#[cfg(not(windows))]
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+#[cfg(windows)]
+fn shell_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\"\""))
+}
+
+/// Run a command line through the platform shell, returning its combined output.
+/// A non-zero exit status is reported as an error carrying stderr.
+pub async fn run_shell_command(command: &str) -> Result<String> {
+    let mut process = if cfg!(windows) {
+        let mut process = tokio::process::Command::new("cmd");
+        process.arg("/C").arg(command);
+        process
+    } else {
+        let mut process = tokio::process::Command::new("sh");
+        process.arg("-c").arg(command);
+        process
+    };
+
+    // Dropping the future on cancellation must not leave the child running
+    let output = process.kill_on_drop(true).output().await?;
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+
+    if !output.status.success() {
+        let detail = if stderr.is_empty() { stdout } else { stderr };
+        return Err(AppError::General(format!("{}: {}", output.status, detail)));
+    }
+
+    Ok(match (stdout.is_empty(), stderr.is_empty()) {
+        (true, true) => String::new(),
+        (false, true) => stdout,
+        (true, false) => stderr,
+        (false, false) => format!("{}\n{}", stdout, stderr),
+    })
+}
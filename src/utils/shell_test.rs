@@ -0,0 +1,28 @@
+use crate::utils::shell::render_command_template;
+
+#[cfg(not(windows))]
+#[test]
+fn values_are_quoted_and_never_expanded_again() {
+    let command = render_command_template(
+        "ssh {resource_id} --profile {profile} --region {region}",
+        &[
+            ("resource_id", "i-{profile}"),
+            ("profile", "it's {region}"),
+            ("region", "us-east-1"),
+        ],
+    );
+
+    assert_eq!(
+        command,
+        "ssh 'i-{profile}' --profile 'it'\\''s {region}' --region 'us-east-1'"
+    );
+}
+
+#[cfg(not(windows))]
+#[test]
+fn unknown_placeholders_and_stray_braces_are_kept() {
+    let command =
+        render_command_template("echo {unknown} {{region}} {", &[("region", "eu-west-1")]);
+
+    assert_eq!(command, "echo {unknown} {'eu-west-1'} {");
+}